@@ -2,8 +2,24 @@ use std::collections::HashMap;
 
 use crate::internal::prelude::*;
 use crate::json::{self, from_number, Value};
-use crate::model::application::component::{ButtonStyle, InputTextStyle};
+use crate::model::application::component::{ButtonStyle, InputTextStyle, SeparatorSpacing};
 use crate::model::channel::ReactionType;
+#[cfg(feature = "utils")]
+use crate::utils::Colour;
+
+fn push_component(map: &mut HashMap<&'static str, Value>, key: &'static str, component: Value) {
+    let components = map.entry(key).or_insert_with(|| Value::from(Vec::<Value>::new()));
+    let components_array = components.as_array_mut().expect("Must be an array");
+
+    components_array.push(component);
+}
+
+fn unfurled_media(url: String) -> Value {
+    let mut map = JsonMap::new();
+    map.insert("url".to_string(), Value::from(url));
+
+    Value::from(map)
+}
 
 /// A builder for creating several [`ActionRow`]s.
 ///
@@ -50,6 +66,147 @@ impl CreateComponents {
 
         self
     }
+
+    /// Creates a container.
+    ///
+    /// **Note**: This is a Components V2 component, which requires the message
+    /// to have the [`MessageFlags::IS_COMPONENTS_V2`] flag, such as when set
+    /// via [`CreateMessage::components_v2`].
+    ///
+    /// # Examples
+    ///
+    /// Sending a container with a text display and a media gallery:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::ChannelId;
+    /// use serenity::builder::{CreateMediaGalleryItem, CreateTextDisplay};
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::new("token");
+    /// let first = CreateMediaGalleryItem::new("https://example.com/1.png");
+    /// let second = CreateMediaGalleryItem::new("https://example.com/2.png");
+    ///
+    /// ChannelId(7)
+    ///     .send_message(&http, |m| {
+    ///         m.components_v2(|c| {
+    ///             c.create_container(|container| {
+    ///                 container
+    ///                     .accent_colour(0x00ff_aa00)
+    ///                     .add_text_display(CreateTextDisplay::new("# Today's pictures"))
+    ///                     .create_media_gallery(|g| g.add_item(first).add_item(second))
+    ///             })
+    ///         })
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`MessageFlags::IS_COMPONENTS_V2`]: crate::model::channel::MessageFlags::IS_COMPONENTS_V2
+    /// [`CreateMessage::components_v2`]: super::CreateMessage::components_v2
+    pub fn create_container<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateContainer) -> &mut CreateContainer,
+    {
+        let mut data = CreateContainer::default();
+        f(&mut data);
+
+        self.add_container(data);
+
+        self
+    }
+
+    /// Adds a container.
+    ///
+    /// Refer to [`Self::create_container`] for more information.
+    pub fn add_container(&mut self, container: CreateContainer) -> &mut Self {
+        self.0.push(container.build());
+
+        self
+    }
+
+    /// Adds a text display.
+    ///
+    /// **Note**: This is a Components V2 component, refer to
+    /// [`Self::create_container`] for more information.
+    pub fn add_text_display(&mut self, text_display: CreateTextDisplay) -> &mut Self {
+        self.0.push(text_display.build());
+
+        self
+    }
+
+    /// Creates a section.
+    ///
+    /// **Note**: This is a Components V2 component, refer to
+    /// [`Self::create_container`] for more information.
+    pub fn create_section<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateSection) -> &mut CreateSection,
+    {
+        let mut data = CreateSection::default();
+        f(&mut data);
+
+        self.add_section(data);
+
+        self
+    }
+
+    /// Adds a section.
+    ///
+    /// **Note**: This is a Components V2 component, refer to
+    /// [`Self::create_container`] for more information.
+    pub fn add_section(&mut self, section: CreateSection) -> &mut Self {
+        self.0.push(section.build());
+
+        self
+    }
+
+    /// Creates a media gallery.
+    ///
+    /// **Note**: This is a Components V2 component, refer to
+    /// [`Self::create_container`] for more information.
+    pub fn create_media_gallery<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateMediaGallery) -> &mut CreateMediaGallery,
+    {
+        let mut data = CreateMediaGallery::default();
+        f(&mut data);
+
+        self.add_media_gallery(data);
+
+        self
+    }
+
+    /// Adds a media gallery.
+    ///
+    /// **Note**: This is a Components V2 component, refer to
+    /// [`Self::create_container`] for more information.
+    pub fn add_media_gallery(&mut self, media_gallery: CreateMediaGallery) -> &mut Self {
+        self.0.push(media_gallery.build());
+
+        self
+    }
+
+    /// Adds a separator.
+    ///
+    /// **Note**: This is a Components V2 component, refer to
+    /// [`Self::create_container`] for more information.
+    pub fn add_separator(&mut self, separator: CreateSeparator) -> &mut Self {
+        self.0.push(separator.build());
+
+        self
+    }
+
+    /// Adds a file.
+    ///
+    /// **Note**: This is a Components V2 component, refer to
+    /// [`Self::create_container`] for more information.
+    pub fn add_file(&mut self, file: CreateFile) -> &mut Self {
+        self.0.push(file.build());
+
+        self
+    }
 }
 
 /// A builder for creating an [`ActionRow`].
@@ -452,3 +609,351 @@ impl CreateInputText {
         json::hashmap_to_json_map(self.0.clone()).into()
     }
 }
+
+/// A builder for creating a container, a Components V2 component which groups
+/// other components with an optional accent colour, like an embed.
+///
+/// Refer to [`CreateComponents::create_container`] for more information.
+#[derive(Clone, Debug, Default)]
+pub struct CreateContainer(pub HashMap<&'static str, Value>);
+
+impl CreateContainer {
+    /// Set the colour of the left-hand side of the container.
+    #[cfg(feature = "utils")]
+    #[inline]
+    pub fn accent_colour<C: Into<Colour>>(&mut self, colour: C) -> &mut Self {
+        self._accent_colour(colour.into());
+        self
+    }
+
+    #[cfg(feature = "utils")]
+    fn _accent_colour(&mut self, colour: Colour) {
+        self.0.insert("accent_color", from_number(u64::from(colour.0)));
+    }
+
+    /// Set the colour of the left-hand side of the container.
+    #[cfg(not(feature = "utils"))]
+    pub fn accent_colour(&mut self, colour: u32) -> &mut Self {
+        self.0.insert("accent_color", from_number(colour));
+        self
+    }
+
+    /// Sets whether the container is blurred out as a spoiler.
+    pub fn spoiler(&mut self, spoiler: bool) -> &mut Self {
+        self.0.insert("spoiler", Value::from(spoiler));
+        self
+    }
+
+    /// Creates an action row.
+    pub fn create_action_row<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateActionRow) -> &mut CreateActionRow,
+    {
+        let mut data = CreateActionRow::default();
+        f(&mut data);
+
+        self.add_action_row(data);
+
+        self
+    }
+
+    /// Adds an action row.
+    pub fn add_action_row(&mut self, mut row: CreateActionRow) -> &mut Self {
+        push_component(&mut self.0, "components", row.build());
+        self
+    }
+
+    /// Adds a text display.
+    pub fn add_text_display(&mut self, text_display: CreateTextDisplay) -> &mut Self {
+        push_component(&mut self.0, "components", text_display.build());
+        self
+    }
+
+    /// Creates a section.
+    pub fn create_section<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateSection) -> &mut CreateSection,
+    {
+        let mut data = CreateSection::default();
+        f(&mut data);
+
+        self.add_section(data);
+
+        self
+    }
+
+    /// Adds a section.
+    pub fn add_section(&mut self, section: CreateSection) -> &mut Self {
+        push_component(&mut self.0, "components", section.build());
+        self
+    }
+
+    /// Creates a media gallery.
+    pub fn create_media_gallery<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateMediaGallery) -> &mut CreateMediaGallery,
+    {
+        let mut data = CreateMediaGallery::default();
+        f(&mut data);
+
+        self.add_media_gallery(data);
+
+        self
+    }
+
+    /// Adds a media gallery.
+    pub fn add_media_gallery(&mut self, media_gallery: CreateMediaGallery) -> &mut Self {
+        push_component(&mut self.0, "components", media_gallery.build());
+        self
+    }
+
+    /// Adds a separator.
+    pub fn add_separator(&mut self, separator: CreateSeparator) -> &mut Self {
+        push_component(&mut self.0, "components", separator.build());
+        self
+    }
+
+    /// Adds a file.
+    pub fn add_file(&mut self, file: CreateFile) -> &mut Self {
+        push_component(&mut self.0, "components", file.build());
+        self
+    }
+
+    #[must_use]
+    pub fn build(mut self) -> Value {
+        self.0.insert("type", from_number(17_u8));
+
+        json::hashmap_to_json_map(self.0.clone()).into()
+    }
+}
+
+/// A builder for creating a text display, a Components V2 component which
+/// displays markdown formatted text, like the content of a message.
+#[derive(Clone, Debug, Default)]
+pub struct CreateTextDisplay(pub HashMap<&'static str, Value>);
+
+impl CreateTextDisplay {
+    /// Creates a text display with the given content.
+    pub fn new<D: ToString>(content: D) -> Self {
+        let mut text_display = Self::default();
+        text_display.content(content);
+        text_display
+    }
+
+    /// Sets the markdown formatted content of the text display.
+    pub fn content<D: ToString>(&mut self, content: D) -> &mut Self {
+        self.0.insert("content", Value::from(content.to_string()));
+        self
+    }
+
+    #[must_use]
+    pub fn build(mut self) -> Value {
+        self.0.insert("type", from_number(10_u8));
+
+        json::hashmap_to_json_map(self.0.clone()).into()
+    }
+}
+
+/// A builder for creating a section, a Components V2 component which displays
+/// up to 3 text displays next to an accessory, either a button or a thumbnail.
+#[derive(Clone, Debug, Default)]
+pub struct CreateSection(pub HashMap<&'static str, Value>);
+
+impl CreateSection {
+    /// Adds a text display.
+    ///
+    /// **Note**: A section can have at most 3 text displays.
+    pub fn add_text_display(&mut self, text_display: CreateTextDisplay) -> &mut Self {
+        push_component(&mut self.0, "components", text_display.build());
+        self
+    }
+
+    /// Sets a button as the accessory of the section.
+    ///
+    /// **Note**: This will replace the current accessory.
+    pub fn button(&mut self, button: CreateButton) -> &mut Self {
+        self.0.insert("accessory", button.build());
+        self
+    }
+
+    /// Sets a thumbnail as the accessory of the section.
+    ///
+    /// **Note**: This will replace the current accessory.
+    pub fn thumbnail(&mut self, thumbnail: CreateThumbnail) -> &mut Self {
+        self.0.insert("accessory", thumbnail.build());
+        self
+    }
+
+    #[must_use]
+    pub fn build(mut self) -> Value {
+        self.0.insert("type", from_number(9_u8));
+
+        json::hashmap_to_json_map(self.0.clone()).into()
+    }
+}
+
+/// A builder for creating a thumbnail, a Components V2 component which can
+/// only be used as the accessory of a [`CreateSection`].
+#[derive(Clone, Debug, Default)]
+pub struct CreateThumbnail(pub HashMap<&'static str, Value>);
+
+impl CreateThumbnail {
+    /// Creates a thumbnail of the media at the given URL.
+    pub fn new<D: ToString>(url: D) -> Self {
+        let mut thumbnail = Self::default();
+        thumbnail.url(url);
+        thumbnail
+    }
+
+    /// Sets the URL of the media, either an external URL or an
+    /// `attachment://<filename>` URL of a file uploaded with the message.
+    pub fn url<D: ToString>(&mut self, url: D) -> &mut Self {
+        self.0.insert("media", unfurled_media(url.to_string()));
+        self
+    }
+
+    /// Sets the alt text of the media.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::from(description.to_string()));
+        self
+    }
+
+    /// Sets whether the media is blurred out as a spoiler.
+    pub fn spoiler(&mut self, spoiler: bool) -> &mut Self {
+        self.0.insert("spoiler", Value::from(spoiler));
+        self
+    }
+
+    #[must_use]
+    pub fn build(mut self) -> Value {
+        self.0.insert("type", from_number(11_u8));
+
+        json::hashmap_to_json_map(self.0.clone()).into()
+    }
+}
+
+/// A builder for creating a media gallery, a Components V2 component which
+/// displays 1 to 10 images or videos in a grid.
+#[derive(Clone, Debug, Default)]
+pub struct CreateMediaGallery(pub HashMap<&'static str, Value>);
+
+impl CreateMediaGallery {
+    /// Creates an item.
+    pub fn create_item<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateMediaGalleryItem) -> &mut CreateMediaGalleryItem,
+    {
+        let mut data = CreateMediaGalleryItem::default();
+        f(&mut data);
+
+        self.add_item(data);
+
+        self
+    }
+
+    /// Adds an item.
+    ///
+    /// **Note**: A media gallery can have at most 10 items.
+    pub fn add_item(&mut self, item: CreateMediaGalleryItem) -> &mut Self {
+        push_component(&mut self.0, "items", json::hashmap_to_json_map(item.0).into());
+        self
+    }
+
+    #[must_use]
+    pub fn build(mut self) -> Value {
+        self.0.insert("type", from_number(12_u8));
+
+        json::hashmap_to_json_map(self.0.clone()).into()
+    }
+}
+
+/// A builder for creating an item of a [`CreateMediaGallery`].
+#[derive(Clone, Debug, Default)]
+pub struct CreateMediaGalleryItem(pub HashMap<&'static str, Value>);
+
+impl CreateMediaGalleryItem {
+    /// Creates an item of the media at the given URL.
+    pub fn new<D: ToString>(url: D) -> Self {
+        let mut item = Self::default();
+        item.url(url);
+        item
+    }
+
+    /// Sets the URL of the media, either an external URL or an
+    /// `attachment://<filename>` URL of a file uploaded with the message.
+    pub fn url<D: ToString>(&mut self, url: D) -> &mut Self {
+        self.0.insert("media", unfurled_media(url.to_string()));
+        self
+    }
+
+    /// Sets the alt text of the media.
+    pub fn description<D: ToString>(&mut self, description: D) -> &mut Self {
+        self.0.insert("description", Value::from(description.to_string()));
+        self
+    }
+
+    /// Sets whether the media is blurred out as a spoiler.
+    pub fn spoiler(&mut self, spoiler: bool) -> &mut Self {
+        self.0.insert("spoiler", Value::from(spoiler));
+        self
+    }
+}
+
+/// A builder for creating a separator, a Components V2 component which adds
+/// vertical padding between other components, optionally with a divider line.
+#[derive(Clone, Debug, Default)]
+pub struct CreateSeparator(pub HashMap<&'static str, Value>);
+
+impl CreateSeparator {
+    /// Sets whether a divider line is displayed. Defaults to `true`.
+    pub fn divider(&mut self, divider: bool) -> &mut Self {
+        self.0.insert("divider", Value::from(divider));
+        self
+    }
+
+    /// Sets the size of the padding. Defaults to [`SeparatorSpacing::Small`].
+    pub fn spacing(&mut self, spacing: SeparatorSpacing) -> &mut Self {
+        self.0.insert("spacing", from_number(spacing as u8));
+        self
+    }
+
+    #[must_use]
+    pub fn build(mut self) -> Value {
+        self.0.insert("type", from_number(14_u8));
+
+        json::hashmap_to_json_map(self.0.clone()).into()
+    }
+}
+
+/// A builder for creating a file, a Components V2 component which displays a
+/// file uploaded with the message.
+#[derive(Clone, Debug, Default)]
+pub struct CreateFile(pub HashMap<&'static str, Value>);
+
+impl CreateFile {
+    /// Creates a file component of the uploaded file at the given URL.
+    pub fn new<D: ToString>(url: D) -> Self {
+        let mut file = Self::default();
+        file.url(url);
+        file
+    }
+
+    /// Sets the `attachment://<filename>` URL of the uploaded file.
+    pub fn url<D: ToString>(&mut self, url: D) -> &mut Self {
+        self.0.insert("file", unfurled_media(url.to_string()));
+        self
+    }
+
+    /// Sets whether the file is blurred out as a spoiler.
+    pub fn spoiler(&mut self, spoiler: bool) -> &mut Self {
+        self.0.insert("spoiler", Value::from(spoiler));
+        self
+    }
+
+    #[must_use]
+    pub fn build(mut self) -> Value {
+        self.0.insert("type", from_number(13_u8));
+
+        json::hashmap_to_json_map(self.0.clone()).into()
+    }
+}
@@ -15,7 +15,10 @@
 //! [here]: https://discord.com/developers/docs/resources/channel#embed-object
 
 use std::collections::HashMap;
+use std::error::Error as StdError;
+use std::fmt;
 
+use crate::constants;
 use crate::json::{self, from_number, json, Value};
 use crate::model::channel::Embed;
 use crate::model::Timestamp;
@@ -195,8 +198,10 @@ impl CreateEmbed {
 
     /// Set the timestamp.
     ///
-    /// You can pass a [`Timestamp`] or anything that converts into it (
-    /// [`chrono::DateTime`] or [`&str`]). If giving a string, it must be in RFC 3339 format:
+    /// You can pass a [`Timestamp`] or anything that converts into it (a
+    /// [`SystemTime`], a [`chrono::DateTime`] or `time::OffsetDateTime` depending on the
+    /// enabled features, or a [`&str`]). A UNIX timestamp can be converted with
+    /// [`Timestamp::from_unix_timestamp`]. If giving a string, it must be in RFC 3339 format:
     ///
     /// - `2017-01-03T23:00:00Z`
     /// - `2004-06-08T16:04:23Z`
@@ -212,6 +217,16 @@ impl CreateEmbed {
     /// embed.title("hello").timestamp("2004-06-08T16:04:23Z");
     /// ```
     ///
+    /// Passing the current time:
+    ///
+    /// ```rust
+    /// # use serenity::builder::CreateEmbed;
+    /// use std::time::SystemTime;
+    ///
+    /// let mut embed = CreateEmbed::default();
+    /// embed.title("hello").timestamp(SystemTime::now());
+    /// ```
+    ///
     /// Creating a join-log:
     ///
     /// Note: this example isn't efficient and is for demonstrative purposes.
@@ -260,6 +275,8 @@ impl CreateEmbed {
     /// #     Ok(())
     /// # }
     /// ```
+    ///
+    /// [`SystemTime`]: std::time::SystemTime
     #[inline]
     pub fn timestamp<T: Into<Timestamp>>(&mut self, timestamp: T) -> &mut Self {
         self._timestamp(timestamp.into());
@@ -298,6 +315,26 @@ impl CreateEmbed {
 
         self
     }
+
+    /// Checks the embed against Discord's length limits, so that an oversized embed can be
+    /// caught before the request is made.
+    ///
+    /// This is done automatically when sending a message with [`CreateMessage`] or editing one
+    /// with [`EditMessage`], unless skipped with [`CreateMessage::skip_embed_validation`] or
+    /// [`EditMessage::skip_embed_validation`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EmbedError`] naming the first limit that was exceeded, along with the amount
+    /// it was exceeded by.
+    ///
+    /// [`CreateMessage`]: crate::builder::CreateMessage
+    /// [`CreateMessage::skip_embed_validation`]: crate::builder::CreateMessage::skip_embed_validation
+    /// [`EditMessage`]: crate::builder::EditMessage
+    /// [`EditMessage::skip_embed_validation`]: crate::builder::EditMessage::skip_embed_validation
+    pub fn validate(&self) -> Result<(), EmbedError> {
+        validate_embed(|key| self.0.get(key))
+    }
 }
 
 impl Default for CreateEmbed {
@@ -430,9 +467,168 @@ impl CreateEmbedFooter {
     }
 }
 
+/// A limit of an embed that was exceeded, as returned by [`CreateEmbed::validate`].
+///
+/// Each variant contains the number of unicode code points over the limit, or for
+/// [`Self::TooManyFields`], the number of fields over the limit.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum EmbedError {
+    /// The title is longer than 256 characters.
+    TitleTooLong(usize),
+    /// The description is longer than 4096 characters.
+    DescriptionTooLong(usize),
+    /// The embed has more than 25 fields.
+    TooManyFields(usize),
+    /// The name of the field at `index` is longer than 256 characters.
+    FieldNameTooLong {
+        index: usize,
+        overflow: usize,
+    },
+    /// The value of the field at `index` is longer than 1024 characters.
+    FieldValueTooLong {
+        index: usize,
+        overflow: usize,
+    },
+    /// The footer text is longer than 2048 characters.
+    FooterTooLong(usize),
+    /// The author name is longer than 256 characters.
+    AuthorNameTooLong(usize),
+    /// The combined text of the title, description, fields, footer and author name is longer
+    /// than 6000 characters.
+    TooLarge(usize),
+}
+
+impl fmt::Display for EmbedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::TitleTooLong(overflow) => {
+                write!(f, "Embed title is {} characters too long.", overflow)
+            },
+            Self::DescriptionTooLong(overflow) => {
+                write!(f, "Embed description is {} characters too long.", overflow)
+            },
+            Self::TooManyFields(overflow) => {
+                write!(f, "Embed has {} fields too many.", overflow)
+            },
+            Self::FieldNameTooLong {
+                index,
+                overflow,
+            } => {
+                write!(f, "Name of embed field {} is {} characters too long.", index, overflow)
+            },
+            Self::FieldValueTooLong {
+                index,
+                overflow,
+            } => {
+                write!(f, "Value of embed field {} is {} characters too long.", index, overflow)
+            },
+            Self::FooterTooLong(overflow) => {
+                write!(f, "Embed footer is {} characters too long.", overflow)
+            },
+            Self::AuthorNameTooLong(overflow) => {
+                write!(f, "Embed author name is {} characters too long.", overflow)
+            },
+            Self::TooLarge(overflow) => write!(f, "Embed is {} characters too large.", overflow),
+        }
+    }
+}
+
+impl StdError for EmbedError {}
+
+fn text_length(value: Option<&Value>) -> usize {
+    match value {
+        Some(Value::String(text)) => text.chars().count(),
+        _ => 0,
+    }
+}
+
+fn nested_text_length(value: Option<&Value>, key: &str) -> usize {
+    match value {
+        Some(Value::Object(map)) => text_length(map.get(key)),
+        _ => 0,
+    }
+}
+
+fn check_length(
+    length: usize,
+    max: usize,
+    error: impl FnOnce(usize) -> EmbedError,
+) -> Result<usize, EmbedError> {
+    if length > max {
+        Err(error(length - max))
+    } else {
+        Ok(length)
+    }
+}
+
+/// Key under which [`CreateMessage::skip_embed_validation`] and
+/// [`EditMessage::skip_embed_validation`] mark a builder. It is removed before
+/// the builder is sent.
+///
+/// [`CreateMessage::skip_embed_validation`]: crate::builder::CreateMessage::skip_embed_validation
+/// [`EditMessage::skip_embed_validation`]: crate::builder::EditMessage::skip_embed_validation
+pub(crate) const SKIP_EMBED_VALIDATION: &str = "skip_embed_validation";
+
+/// Validates an embed, given a way to look up its keys. This allows checking both a
+/// [`CreateEmbed`] and an embed that has already been converted to JSON.
+pub(crate) fn validate_embed<'a>(
+    get: impl Fn(&str) -> Option<&'a Value>,
+) -> Result<(), EmbedError> {
+    let mut total = 0;
+
+    total += check_length(
+        text_length(get("title")),
+        constants::EMBED_TITLE_MAX_LENGTH,
+        EmbedError::TitleTooLong,
+    )?;
+    total += check_length(
+        text_length(get("description")),
+        constants::EMBED_DESCRIPTION_MAX_LENGTH,
+        EmbedError::DescriptionTooLong,
+    )?;
+    total += check_length(
+        nested_text_length(get("footer"), "text"),
+        constants::EMBED_FOOTER_MAX_LENGTH,
+        EmbedError::FooterTooLong,
+    )?;
+    total += check_length(
+        nested_text_length(get("author"), "name"),
+        constants::EMBED_AUTHOR_MAX_LENGTH,
+        EmbedError::AuthorNameTooLong,
+    )?;
+
+    if let Some(Value::Array(fields)) = get("fields") {
+        check_length(fields.len(), constants::EMBED_FIELD_MAX_COUNT, EmbedError::TooManyFields)?;
+
+        for (index, field) in fields.iter().enumerate() {
+            total += check_length(
+                nested_text_length(Some(field), "name"),
+                constants::EMBED_FIELD_NAME_MAX_LENGTH,
+                |overflow| EmbedError::FieldNameTooLong {
+                    index,
+                    overflow,
+                },
+            )?;
+            total += check_length(
+                nested_text_length(Some(field), "value"),
+                constants::EMBED_FIELD_VALUE_MAX_LENGTH,
+                |overflow| EmbedError::FieldValueTooLong {
+                    index,
+                    overflow,
+                },
+            )?;
+        }
+    }
+
+    check_length(total, constants::EMBED_MAX_LENGTH, EmbedError::TooLarge)?;
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
-    use super::CreateEmbed;
+    use super::{CreateEmbed, EmbedError};
     use crate::json::{self, json, Value};
     use crate::model::channel::{Embed, EmbedField, EmbedFooter, EmbedImage, EmbedVideo};
     use crate::utils::Colour;
@@ -518,4 +714,59 @@ mod test {
 
         assert_eq!(built, obj);
     }
+
+    #[test]
+    fn test_timestamp_system_time() {
+        use std::time::{Duration, SystemTime};
+
+        use crate::model::Timestamp;
+
+        let now = SystemTime::now();
+        let secs = now.duration_since(SystemTime::UNIX_EPOCH).unwrap().as_secs();
+
+        let mut builder = CreateEmbed::default();
+        builder.timestamp(now);
+
+        let timestamp = |builder: &CreateEmbed| match builder.0.get("timestamp") {
+            Some(Value::String(timestamp)) => Timestamp::parse(timestamp).unwrap(),
+            _ => panic!("timestamp is not a string"),
+        };
+        assert_eq!(timestamp(&builder).unix_timestamp(), secs as i64);
+
+        builder.timestamp(SystemTime::UNIX_EPOCH + Duration::from_secs(1462015105));
+        assert_eq!(timestamp(&builder), Timestamp::from_unix_timestamp(1462015105).unwrap());
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut builder = CreateEmbed::default();
+        builder.title("a".repeat(256)).description("ä".repeat(4096));
+        builder.footer(|f| f.text("footer")).author(|a| a.name("author"));
+        assert_eq!(builder.validate(), Ok(()));
+
+        builder.title("a".repeat(260));
+        assert_eq!(builder.validate(), Err(EmbedError::TitleTooLong(4)));
+        builder.title("title");
+
+        builder.author(|a| a.name("a".repeat(257)));
+        assert_eq!(builder.validate(), Err(EmbedError::AuthorNameTooLong(1)));
+        builder.author(|a| a.name("author"));
+
+        builder.field("name", "value", false).field("name", "v".repeat(1030), false);
+        assert_eq!(
+            builder.validate(),
+            Err(EmbedError::FieldValueTooLong {
+                index: 1,
+                overflow: 6,
+            })
+        );
+
+        let mut builder = CreateEmbed::default();
+        builder.fields((0..26).map(|i| (i.to_string(), "value", false)));
+        assert_eq!(builder.validate(), Err(EmbedError::TooManyFields(1)));
+
+        let mut builder = CreateEmbed::default();
+        builder.description("a".repeat(4000)).footer(|f| f.text("a".repeat(2001)));
+        assert_eq!(builder.validate(), Err(EmbedError::TooLarge(1)));
+    }
 }
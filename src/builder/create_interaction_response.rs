@@ -37,6 +37,16 @@ impl<'a> CreateInteractionResponse<'a> {
         self.1 = data.1;
         self
     }
+
+    /// Responds with [`InteractionResponseType::PremiumRequired`], prompting the user to
+    /// upgrade to a premium SKU of the application.
+    ///
+    /// This response type carries no data, so any previously set response data is removed.
+    pub fn premium_required(&mut self) -> &mut Self {
+        self.0.remove("data");
+        self.1.clear();
+        self.kind(InteractionResponseType::PremiumRequired)
+    }
 }
 
 impl<'a> Default for CreateInteractionResponse<'a> {
@@ -225,6 +225,33 @@ impl CreateInvite {
         self.0.insert("target_application_id", from_number(target_application_id.0));
         self
     }
+
+    /// Targets this invite at the stream of the given user, setting
+    /// [`Self::target_type`] to [`InviteTargetType::Stream`].
+    ///
+    /// The user must be streaming in the voice channel the invite is created
+    /// for.
+    ///
+    /// Only one target may be set on an invite, so this removes any
+    /// application previously set via [`Self::target_application`].
+    pub fn target_user(&mut self, user_id: UserId) -> &mut Self {
+        self.0.remove("target_application_id");
+        self.target_type(InviteTargetType::Stream).target_user_id(user_id)
+    }
+
+    /// Targets this invite at an embedded application (an activity), setting
+    /// [`Self::target_type`] to [`InviteTargetType::EmmbeddedApplication`].
+    ///
+    /// Refer to [`Self::target_application_id`] for a list of known
+    /// applications.
+    ///
+    /// Only one target may be set on an invite, so this removes any user
+    /// previously set via [`Self::target_user`].
+    pub fn target_application(&mut self, application_id: ApplicationId) -> &mut Self {
+        self.0.remove("target_user_id");
+        self.target_type(InviteTargetType::EmmbeddedApplication)
+            .target_application_id(application_id)
+    }
 }
 
 impl Default for CreateInvite {
@@ -2,14 +2,14 @@ use std::collections::HashMap;
 #[cfg(not(feature = "model"))]
 use std::marker::PhantomData;
 
-use super::{CreateAllowedMentions, CreateEmbed};
+use super::{CreateAllowedMentions, CreateEmbed, SKIP_EMBED_VALIDATION};
 use crate::builder::CreateComponents;
 use crate::internal::prelude::*;
 use crate::json::{self, from_number, to_value};
 #[cfg(feature = "model")]
 use crate::model::channel::AttachmentType;
-use crate::model::channel::{MessageFlags, MessageReference, ReactionType};
-use crate::model::id::StickerId;
+use crate::model::channel::{MessageFlags, MessageReference, MessageReferenceKind, ReactionType};
+use crate::model::id::{ChannelId, MessageId, StickerId};
 
 /// A builder to specify the contents of an [`Http::send_message`] request,
 /// primarily meant for use through [`ChannelId::send_message`].
@@ -202,6 +202,33 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Sends the given audio file as a voice message.
+    ///
+    /// The `waveform` is a base64 encoded bytearray of up to 256 samples, describing the
+    /// volume of the audio over time. Voice messages can not have any content, embeds, or
+    /// other files, so this replaces the file list and sets the message flags to
+    /// [`MessageFlags::IS_VOICE_MESSAGE`]. To set further flags, call [`Self::flags`]
+    /// afterwards with [`MessageFlags::IS_VOICE_MESSAGE`] included.
+    #[cfg(feature = "model")]
+    pub fn voice_message<T: Into<AttachmentType<'a>>>(
+        &mut self,
+        file: T,
+        duration_secs: f64,
+        waveform: impl Into<String>,
+    ) -> &mut Self {
+        let file = file.into();
+        let attachment = json::json!({
+            "id": 0,
+            "filename": file.filename().ok().flatten(),
+            "duration_secs": duration_secs,
+            "waveform": waveform.into(),
+        });
+
+        self.0.insert("attachments", Value::from(vec![attachment]));
+        self.2 = vec![file];
+        self.flags(MessageFlags::IS_VOICE_MESSAGE)
+    }
+
     /// Set the allowed mentions for the message.
     pub fn allowed_mentions<F>(&mut self, f: F) -> &mut Self
     where
@@ -223,6 +250,26 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Forwards the given message with this message.
+    ///
+    /// **Note**: A forward can't have any content, embeds, files, stickers or
+    /// components of its own, as it only carries the forwarded message. Sending
+    /// one that does will return [`ModelError::ForwardWithContent`].
+    ///
+    /// [`ModelError::ForwardWithContent`]: crate::model::ModelError::ForwardWithContent
+    #[allow(clippy::unwrap_used)] // allowing unwrap here because serializing MessageReference should never error
+    pub fn forward(
+        &mut self,
+        channel_id: impl Into<ChannelId>,
+        message_id: impl Into<MessageId>,
+    ) -> &mut Self {
+        let mut reference = MessageReference::from((channel_id.into(), message_id.into()));
+        reference.kind = MessageReferenceKind::Forward;
+
+        self.0.insert("message_reference", to_value(reference).unwrap());
+        self
+    }
+
     /// Creates components for this message.
     pub fn components<F>(&mut self, f: F) -> &mut Self
     where
@@ -241,6 +288,29 @@ impl<'a> CreateMessage<'a> {
         self
     }
 
+    /// Creates Components V2 components for this message, such as containers and
+    /// text displays, and sets the [`MessageFlags::IS_COMPONENTS_V2`] flag.
+    ///
+    /// **Note**: Messages with this flag cannot have content, embeds, or stickers,
+    /// sending one that does will return [`ModelError::ComponentsV2WithContent`].
+    /// Text is instead sent via [`CreateTextDisplay`]s. Setting [`Self::flags`]
+    /// afterwards overwrites the flag.
+    ///
+    /// [`CreateTextDisplay`]: crate::builder::CreateTextDisplay
+    /// [`ModelError::ComponentsV2WithContent`]: crate::model::ModelError::ComponentsV2WithContent
+    pub fn components_v2<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut CreateComponents) -> &mut CreateComponents,
+    {
+        let flags = self
+            .0
+            .get("flags")
+            .and_then(Value::as_u64)
+            .map_or_else(MessageFlags::empty, MessageFlags::from_bits_truncate);
+
+        self.components(f).flags(flags | MessageFlags::IS_COMPONENTS_V2)
+    }
+
     /// Sets the flags for the message.
     pub fn flags(&mut self, flags: MessageFlags) -> &mut Self {
         self.0.insert("flags", from_number(flags.bits()));
@@ -303,6 +373,47 @@ impl<'a> CreateMessage<'a> {
         self.0.insert("sticker_ids", Value::from(Vec::<Value>::new()));
         self.add_sticker_ids(sticker_ids)
     }
+
+    /// Sets a nonce that can be used to verify that the message was sent. It
+    /// is returned as the [`Message::nonce`] of the sent message.
+    ///
+    /// **Note**: The nonce can be at most 25 characters long.
+    ///
+    /// [`Message::nonce`]: crate::model::channel::Message::nonce
+    #[inline]
+    pub fn nonce<D: ToString>(&mut self, nonce: D) -> &mut Self {
+        self._nonce(nonce.to_string())
+    }
+
+    fn _nonce(&mut self, nonce: String) -> &mut Self {
+        self.0.insert("nonce", Value::from(nonce));
+        self
+    }
+
+    /// Sets whether Discord should deduplicate the message by its
+    /// [`Self::nonce`].
+    ///
+    /// If enabled and a message with the same nonce was sent by the current
+    /// user in the past few minutes, that message is returned instead of a new
+    /// one being created. This makes it safe to retry a send.
+    pub fn enforce_nonce(&mut self, enforce_nonce: bool) -> &mut Self {
+        self.0.insert("enforce_nonce", Value::from(enforce_nonce));
+        self
+    }
+
+    /// Sets whether to skip checking the embeds against Discord's limits before sending.
+    ///
+    /// By default, each embed is checked with [`CreateEmbed::validate`], so that an embed
+    /// which is too large is returned as an error instead of being rejected by Discord.
+    /// The number of embeds is checked either way.
+    pub fn skip_embed_validation(&mut self, skip: bool) -> &mut Self {
+        if skip {
+            self.0.insert(SKIP_EMBED_VALIDATION, Value::from(true));
+        } else {
+            self.0.remove(SKIP_EMBED_VALIDATION);
+        }
+        self
+    }
 }
 
 impl<'a> Default for CreateMessage<'a> {
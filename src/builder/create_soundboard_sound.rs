@@ -0,0 +1,53 @@
+use std::collections::HashMap;
+
+use crate::internal::prelude::*;
+use crate::json::from_number;
+use crate::model::id::EmojiId;
+
+/// A builder to create a [`SoundboardSound`] in a guild, for use via
+/// [`GuildId::create_soundboard_sound`].
+///
+/// The [`Self::name`] and [`Self::sound`] fields are required.
+///
+/// [`SoundboardSound`]: crate::model::soundboard::SoundboardSound
+/// [`GuildId::create_soundboard_sound`]: crate::model::id::GuildId::create_soundboard_sound
+#[derive(Clone, Debug, Default)]
+pub struct CreateSoundboardSound(pub HashMap<&'static str, Value>);
+
+impl CreateSoundboardSound {
+    /// The name of the sound.
+    ///
+    /// **Note**: Must be between 2 and 32 characters long.
+    pub fn name<S: ToString>(&mut self, name: S) -> &mut Self {
+        self.0.insert("name", Value::from(name.to_string()));
+        self
+    }
+
+    /// The sound file, as a base64 encoded data URI of an MP3 or OGG file.
+    ///
+    /// For example, `data:audio/ogg;base64,...`.
+    ///
+    /// **Note**: Must be at most 512 KiB large, and at most 5.2 seconds long.
+    pub fn sound<S: ToString>(&mut self, sound: S) -> &mut Self {
+        self.0.insert("sound", Value::from(sound.to_string()));
+        self
+    }
+
+    /// The volume of the sound, from 0 to 1. Defaults to 1.
+    pub fn volume(&mut self, volume: f64) -> &mut Self {
+        self.0.insert("volume", Value::from(volume));
+        self
+    }
+
+    /// The Id of the custom emoji of the sound.
+    pub fn emoji_id<E: Into<EmojiId>>(&mut self, emoji_id: E) -> &mut Self {
+        self.0.insert("emoji_id", from_number(emoji_id.into().0));
+        self
+    }
+
+    /// The unicode character of the standard emoji of the sound.
+    pub fn emoji_name<S: ToString>(&mut self, emoji_name: S) -> &mut Self {
+        self.0.insert("emoji_name", Value::from(emoji_name.to_string()));
+        self
+    }
+}
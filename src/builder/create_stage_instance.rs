@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 
 use crate::json::{from_number, Value};
+use crate::model::channel::StageInstancePrivacyLevel;
+use crate::model::id::ScheduledEventId;
 
 /// Creates a [`StageInstance`].
 ///
@@ -16,9 +18,37 @@ impl CreateStageInstance {
     }
 
     /// Sets the topic of the stage channel instance.
+    ///
+    /// **Note**: Must be between 1 and 120 characters long.
     pub fn topic<D: ToString>(&mut self, topic: D) -> &mut Self {
         self.0.insert("topic", Value::from(topic.to_string()));
 
         self
     }
+
+    /// Sets who the stage instance is visible to. Defaults to
+    /// [`StageInstancePrivacyLevel::GuildOnly`].
+    pub fn privacy_level(&mut self, privacy_level: StageInstancePrivacyLevel) -> &mut Self {
+        self.0.insert("privacy_level", from_number(privacy_level as u8));
+
+        self
+    }
+
+    /// Sets whether `@everyone` is notified that the stage instance has started.
+    ///
+    /// **Note**: Requires the [Mention Everyone] permission.
+    ///
+    /// [Mention Everyone]: crate::model::permissions::Permissions::MENTION_EVERYONE
+    pub fn send_start_notification(&mut self, send_start_notification: bool) -> &mut Self {
+        self.0.insert("send_start_notification", Value::from(send_start_notification));
+
+        self
+    }
+
+    /// Sets the scheduled event associated with the stage instance.
+    pub fn guild_scheduled_event_id(&mut self, id: impl Into<ScheduledEventId>) -> &mut Self {
+        self.0.insert("guild_scheduled_event_id", from_number(id.into().0));
+
+        self
+    }
 }
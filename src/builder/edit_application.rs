@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+
+use crate::internal::prelude::*;
+use crate::json::{from_number, to_value, NULL};
+use crate::model::application::{ApplicationFlags, InstallParams};
+
+/// A builder to edit the current application, for use via
+/// [`CurrentApplicationInfo::edit`].
+///
+/// [`CurrentApplicationInfo::edit`]: crate::model::application::CurrentApplicationInfo::edit
+#[derive(Clone, Debug, Default)]
+pub struct EditApplication(pub HashMap<&'static str, Value>);
+
+impl EditApplication {
+    /// The default custom authorization URL of the application.
+    ///
+    /// Pass [`None`] to remove it.
+    pub fn custom_install_url<S: ToString>(&mut self, url: Option<S>) -> &mut Self {
+        let value = url.map_or(NULL, |url| Value::from(url.to_string()));
+        self.0.insert("custom_install_url", value);
+        self
+    }
+
+    /// The description of the application.
+    pub fn description<S: ToString>(&mut self, description: S) -> &mut Self {
+        self.0.insert("description", Value::from(description.to_string()));
+        self
+    }
+
+    /// The role connection verification URL of the application.
+    ///
+    /// Pass [`None`] to remove it.
+    pub fn role_connections_verification_url<S: ToString>(&mut self, url: Option<S>) -> &mut Self {
+        let value = url.map_or(NULL, |url| Value::from(url.to_string()));
+        self.0.insert("role_connections_verification_url", value);
+        self
+    }
+
+    /// The settings of the application's default in-app authorization link.
+    ///
+    /// Pass [`None`] to remove them.
+    pub fn install_params(&mut self, install_params: Option<&InstallParams>) -> &mut Self {
+        let value = install_params.and_then(|params| to_value(params).ok()).unwrap_or(NULL);
+        self.0.insert("install_params", value);
+        self
+    }
+
+    /// The flags of the application.
+    ///
+    /// **Note**: Only the limited gateway intent flags, such as
+    /// [`ApplicationFlags::GATEWAY_PRESENCE_LIMITED`], can be changed.
+    pub fn flags(&mut self, flags: ApplicationFlags) -> &mut Self {
+        self.0.insert("flags", from_number(flags.bits()));
+        self
+    }
+
+    /// The icon of the application, as a base64-encoded image.
+    ///
+    /// Pass [`None`] to remove it.
+    pub fn icon(&mut self, icon: Option<&str>) -> &mut Self {
+        let value = icon.map_or(NULL, |icon| Value::from(icon.to_string()));
+        self.0.insert("icon", value);
+        self
+    }
+
+    /// The cover image of the application's store embed, as a base64-encoded
+    /// image.
+    ///
+    /// Pass [`None`] to remove it.
+    pub fn cover_image(&mut self, cover_image: Option<&str>) -> &mut Self {
+        let value = cover_image.map_or(NULL, |image| Value::from(image.to_string()));
+        self.0.insert("cover_image", value);
+        self
+    }
+
+    /// The interactions endpoint URL of the application, to receive
+    /// interactions over HTTP instead of the gateway.
+    ///
+    /// Pass [`None`] to remove it.
+    pub fn interactions_endpoint_url<S: ToString>(&mut self, url: Option<S>) -> &mut Self {
+        let value = url.map_or(NULL, |url| Value::from(url.to_string()));
+        self.0.insert("interactions_endpoint_url", value);
+        self
+    }
+
+    /// The tags describing the application's functionality.
+    ///
+    /// **Note**: At most 5 tags of up to 20 characters each are allowed.
+    pub fn tags<It, S>(&mut self, tags: It) -> &mut Self
+    where
+        It: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        let tags = tags.into_iter().map(|tag| Value::from(tag.to_string())).collect();
+        self.0.insert("tags", Value::Array(tags));
+        self
+    }
+}
@@ -4,6 +4,14 @@ use crate::json::{json, Value};
 use crate::model::guild::automod::{Action, EventType, Trigger};
 use crate::model::id::{ChannelId, RoleId};
 
+/// A builder for creating or editing an auto moderation [`Rule`].
+///
+/// Used with [`GuildId::create_automod_rule`] and
+/// [`GuildId::edit_automod_rule`].
+///
+/// [`Rule`]: crate::model::guild::automod::Rule
+/// [`GuildId::create_automod_rule`]: crate::model::id::GuildId::create_automod_rule
+/// [`GuildId::edit_automod_rule`]: crate::model::id::GuildId::edit_automod_rule
 #[derive(Clone, Debug)]
 pub struct EditAutoModRule(pub HashMap<&'static str, Value>);
 
@@ -29,15 +37,33 @@ impl EditAutoModRule {
         self.0.insert("trigger_type", u8::from(trigger.kind()).into());
 
         match trigger {
-            Trigger::Keyword(keyword_filter) => {
+            Trigger::Keyword {
+                strings,
+                regex_patterns,
+                allow_list,
+            } => {
                 let value = json!({
-                    "keyword_filter": keyword_filter,
+                    "keyword_filter": strings,
+                    "regex_patterns": regex_patterns,
+                    "allow_list": allow_list,
                 });
                 self.0.insert("trigger_metadata", value);
             },
-            Trigger::KeywordPreset(presets) => {
+            Trigger::KeywordPreset {
+                presets,
+                allow_list,
+            } => {
                 let value = json!({
                     "presets": presets,
+                    "allow_list": allow_list,
+                });
+                self.0.insert("trigger_metadata", value);
+            },
+            Trigger::MentionSpam {
+                mention_total_limit,
+            } => {
+                let value = json!({
+                    "mention_total_limit": mention_total_limit,
                 });
                 self.0.insert("trigger_metadata", value);
             },
@@ -69,7 +95,7 @@ impl EditAutoModRule {
                         json!({
                             "type": kind,
                             "metadata": {
-                                "duration_seconds": duration,
+                                "duration_seconds": duration.as_secs(),
                             },
                         })
                     },
@@ -94,8 +94,12 @@ impl EditGuild {
 
     /// Set the features of the guild.
     ///
-    /// **Note**: Requires that the guild have the `DISCOVERABLE` feature enabled.
-    /// You can check this through a guild's [`features`] list.
+    /// Only some features can be toggled this way, such as `COMMUNITY`, `DISCOVERABLE` and
+    /// `INVITES_DISABLED`. The other features of the guild must be passed along unchanged.
+    ///
+    /// **Note**: Enabling `COMMUNITY` requires the guild to have a rules channel and a public
+    /// updates channel, see [`Self::rules_channel_id`] and [`Self::public_updates_channel_id`].
+    /// You can check the current features through a guild's [`features`] list.
     ///
     /// [`features`]: crate::model::guild::Guild::features
     pub fn features(&mut self, features: Vec<String>) -> &mut Self {
@@ -194,6 +198,40 @@ impl EditGuild {
         self
     }
 
+    /// Set the channel ID where admins and moderators receive safety alerts
+    /// from Discord.
+    ///
+    /// **Note**:
+    /// This feature is for Community guilds only.
+    pub fn safety_alerts_channel_id(&mut self, channel_id: Option<ChannelId>) -> &mut Self {
+        let channel_id = channel_id.map_or(NULL, |x| Value::from(x.0));
+        self.0.insert("safety_alerts_channel_id", channel_id);
+        self
+    }
+
+    /// Set whether the boost progress bar is shown.
+    ///
+    /// # Examples
+    ///
+    /// Showing the boost progress bar:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::{http::Http, model::id::GuildId};
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let http = Http::new("token");
+    /// #     let mut guild = GuildId(0).to_partial_guild(&http).await?;
+    /// guild.edit(&http, |g| g.premium_progress_bar_enabled(true)).await?;
+    ///
+    /// assert!(guild.premium_progress_bar_enabled);
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn premium_progress_bar_enabled(&mut self, enabled: bool) -> &mut Self {
+        self.0.insert("premium_progress_bar_enabled", Value::from(enabled));
+        self
+    }
+
     /// Set the preferred locale used in Server Discovery and update messages
     /// from Discord.
     ///
@@ -298,4 +336,66 @@ impl EditGuild {
         self.0.insert("system_channel_flags", system_channel_flags.bits().into());
         self
     }
+
+    /// Checks that enabling the `COMMUNITY` feature leaves the guild with both a rules channel
+    /// and a public updates channel.
+    ///
+    /// `current` is the guild's current rules channel and public updates channel, if known.
+    /// If it isn't, only channels explicitly removed by this builder are rejected.
+    pub(crate) fn check_community_channels(
+        &self,
+        current: Option<(Option<ChannelId>, Option<ChannelId>)>,
+    ) -> Result<()> {
+        let enables_community = match self.0.get("features") {
+            Some(Value::Array(features)) => features
+                .iter()
+                .any(|feature| matches!(feature, Value::String(feature) if feature == "COMMUNITY")),
+            _ => false,
+        };
+
+        if !enables_community {
+            return Ok(());
+        }
+
+        let (has_rules, has_public_updates) =
+            current.map_or((true, true), |(rules, updates)| (rules.is_some(), updates.is_some()));
+
+        let is_set = |key, current| match self.0.get(key) {
+            Some(value) => !value.is_null(),
+            None => current,
+        };
+
+        if is_set("rules_channel_id", has_rules)
+            && is_set("public_updates_channel_id", has_public_updates)
+        {
+            Ok(())
+        } else {
+            Err(Error::Model(ModelError::MissingCommunityChannels))
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::EditGuild;
+    use crate::model::id::ChannelId;
+
+    #[test]
+    fn test_check_community_channels() {
+        let mut builder = EditGuild::default();
+        builder.features(vec!["COMMUNITY".to_string()]);
+
+        // Unknown current channels are assumed to be set.
+        assert!(builder.check_community_channels(None).is_ok());
+        assert!(builder.check_community_channels(Some((Some(ChannelId(1)), None))).is_err());
+
+        builder.public_updates_channel_id(Some(ChannelId(2)));
+        assert!(builder.check_community_channels(Some((Some(ChannelId(1)), None))).is_ok());
+
+        builder.rules_channel_id(None);
+        assert!(builder.check_community_channels(None).is_err());
+
+        builder.features(vec![]);
+        assert!(builder.check_community_channels(None).is_ok());
+    }
 }
@@ -1,8 +1,10 @@
 use std::collections::HashMap;
 
+use crate::constants;
 use crate::internal::prelude::*;
 use crate::json;
 use crate::model::guild::GuildWelcomeChannelEmoji;
+use crate::model::ModelError;
 
 /// A builder to specify the fields to edit in a [`GuildWelcomeScreen`].
 ///
@@ -25,6 +27,10 @@ impl EditGuildWelcomeScreen {
         self
     }
 
+    /// Adds a channel to show in the welcome screen, created via the given
+    /// builder.
+    ///
+    /// **Note**: At most 5 channels can be shown.
     pub fn create_welcome_channel<F>(&mut self, f: F) -> &mut Self
     where
         F: FnOnce(&mut CreateGuildWelcomeChannel) -> &mut CreateGuildWelcomeChannel,
@@ -37,6 +43,9 @@ impl EditGuildWelcomeScreen {
         self
     }
 
+    /// Adds a channel to show in the welcome screen.
+    ///
+    /// **Note**: At most 5 channels can be shown.
     pub fn add_welcome_channel(&mut self, channel: CreateGuildWelcomeChannel) -> &mut Self {
         let new_data = json::hashmap_to_json_map(channel.0);
 
@@ -49,6 +58,10 @@ impl EditGuildWelcomeScreen {
         self
     }
 
+    /// Sets the channels to show in the welcome screen, replacing any channels
+    /// added before.
+    ///
+    /// **Note**: At most 5 channels can be shown.
     pub fn set_welcome_channels(&mut self, channels: Vec<CreateGuildWelcomeChannel>) -> &mut Self {
         let new_channels = channels
             .into_iter()
@@ -59,6 +72,18 @@ impl EditGuildWelcomeScreen {
 
         self
     }
+
+    /// Checks that at most 5 welcome channels are set.
+    pub(crate) fn check_welcome_channels(&self) -> Result<()> {
+        match self.0.get("welcome_channels") {
+            Some(Value::Array(channels))
+                if channels.len() > constants::WELCOME_CHANNEL_MAX_COUNT =>
+            {
+                Err(Error::Model(ModelError::WelcomeChannelAmount))
+            },
+            _ => Ok(()),
+        }
+    }
 }
 
 /// A builder for creating a [`GuildWelcomeChannel`].
@@ -100,3 +125,26 @@ impl CreateGuildWelcomeChannel {
         self
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{CreateGuildWelcomeChannel, EditGuildWelcomeScreen};
+
+    #[test]
+    fn test_check_welcome_channels() {
+        let channel = |id| {
+            let mut channel = CreateGuildWelcomeChannel::default();
+            channel.id(id).description("channel");
+            channel
+        };
+
+        let mut builder = EditGuildWelcomeScreen::default();
+        assert!(builder.check_welcome_channels().is_ok());
+
+        builder.set_welcome_channels((1..=5).map(channel).collect());
+        assert!(builder.check_welcome_channels().is_ok());
+
+        builder.add_welcome_channel(channel(6));
+        assert!(builder.check_welcome_channels().is_err());
+    }
+}
@@ -1,6 +1,6 @@
 use std::collections::HashMap;
 
-use super::{CreateAllowedMentions, CreateEmbed};
+use super::{CreateAllowedMentions, CreateEmbed, SKIP_EMBED_VALIDATION};
 use crate::builder::CreateComponents;
 use crate::internal::prelude::*;
 use crate::json::{self, from_number};
@@ -9,6 +9,10 @@ use crate::model::id::AttachmentId;
 
 /// A builder to specify the fields to edit in an existing message.
 ///
+/// Fields which are not set are omitted from the request, and are left
+/// unchanged by Discord. To remove all embeds or components of the message,
+/// use [`Self::clear_embeds`] or [`Self::clear_components`].
+///
 /// # Examples
 ///
 /// Editing the content of a [`Message`] to `"hello"`:
@@ -120,6 +124,14 @@ impl<'a> EditMessage<'a> {
         self
     }
 
+    /// Removes all embeds from the message.
+    ///
+    /// **Note**: This will replace all embeds added to this builder so far.
+    pub fn clear_embeds(&mut self) -> &mut Self {
+        self.0.insert("embeds", Value::from(Vec::<Value>::new()));
+        self
+    }
+
     /// Suppress or unsuppress embeds in the message, this includes those generated by Discord
     /// themselves.
     pub fn suppress_embeds(&mut self, suppress: bool) -> &mut Self {
@@ -163,6 +175,14 @@ impl<'a> EditMessage<'a> {
         self
     }
 
+    /// Removes all components from the message.
+    ///
+    /// **Note**: This will replace all components set on this builder so far.
+    pub fn clear_components(&mut self) -> &mut Self {
+        self.0.insert("components", Value::from(Vec::<Value>::new()));
+        self
+    }
+
     /// Sets the flags for the message.
     pub fn flags(&mut self, flags: MessageFlags) -> &mut Self {
         self.0.insert("flags", from_number(flags.bits()));
@@ -215,4 +235,18 @@ impl<'a> EditMessage<'a> {
 
         self
     }
+
+    /// Sets whether to skip checking the embeds against Discord's limits before editing.
+    ///
+    /// By default, each embed is checked with [`CreateEmbed::validate`], so that an embed
+    /// which is too large is returned as an error instead of being rejected by Discord.
+    /// The number of embeds is checked either way.
+    pub fn skip_embed_validation(&mut self, skip: bool) -> &mut Self {
+        if skip {
+            self.0.insert(SKIP_EMBED_VALIDATION, Value::from(true));
+        } else {
+            self.0.remove(SKIP_EMBED_VALIDATION);
+        }
+        self
+    }
 }
@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+
+use crate::internal::prelude::*;
+use crate::json::{from_number, NULL};
+use crate::model::id::EmojiId;
+
+/// A builder to edit a [`SoundboardSound`] of a guild, for use via
+/// [`GuildId::edit_soundboard_sound`].
+///
+/// [`SoundboardSound`]: crate::model::soundboard::SoundboardSound
+/// [`GuildId::edit_soundboard_sound`]: crate::model::id::GuildId::edit_soundboard_sound
+#[derive(Clone, Debug, Default)]
+pub struct EditSoundboardSound(pub HashMap<&'static str, Value>);
+
+impl EditSoundboardSound {
+    /// The name of the sound.
+    ///
+    /// **Note**: Must be between 2 and 32 characters long.
+    pub fn name<S: ToString>(&mut self, name: S) -> &mut Self {
+        self.0.insert("name", Value::from(name.to_string()));
+        self
+    }
+
+    /// The volume of the sound, from 0 to 1.
+    pub fn volume(&mut self, volume: f64) -> &mut Self {
+        self.0.insert("volume", Value::from(volume));
+        self
+    }
+
+    /// The Id of the custom emoji of the sound.
+    ///
+    /// Pass [`None`] to remove the emoji.
+    pub fn emoji_id<E: Into<EmojiId>>(&mut self, emoji_id: Option<E>) -> &mut Self {
+        let value = emoji_id.map_or(NULL, |id| from_number(id.into().0));
+        self.0.insert("emoji_id", value);
+        self
+    }
+
+    /// The unicode character of the standard emoji of the sound.
+    ///
+    /// Pass [`None`] to remove the emoji.
+    pub fn emoji_name<S: ToString>(&mut self, emoji_name: Option<S>) -> &mut Self {
+        let value = emoji_name.map_or(NULL, |name| Value::from(name.to_string()));
+        self.0.insert("emoji_name", value);
+        self
+    }
+}
@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
-use crate::json::Value;
+use crate::json::{from_number, Value};
+use crate::model::channel::StageInstancePrivacyLevel;
 
 /// Edits a [`StageInstance`].
 ///
@@ -10,9 +11,18 @@ pub struct EditStageInstance(pub HashMap<&'static str, Value>);
 
 impl EditStageInstance {
     /// Sets the topic of the stage channel instance.
+    ///
+    /// **Note**: Must be between 1 and 120 characters long.
     pub fn topic<D: ToString>(&mut self, topic: D) -> &mut Self {
         self.0.insert("topic", Value::from(topic.to_string()));
 
         self
     }
+
+    /// Sets who the stage instance is visible to.
+    pub fn privacy_level(&mut self, privacy_level: StageInstancePrivacyLevel) -> &mut Self {
+        self.0.insert("privacy_level", from_number(privacy_level as u8));
+
+        self
+    }
 }
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
+use std::fmt::Write as _;
 
+use crate::internal::prelude::*;
 use crate::model::id::MessageId;
+use crate::model::ModelError;
 
 /// Builds a request to the API to retrieve messages.
 ///
@@ -11,11 +14,13 @@ use crate::model::id::MessageId;
 /// - [`Self::around`]
 /// - [`Self::before`]
 ///
-/// These can not be mixed, and the first in the list alphabetically will be
-/// used. If one is not specified, `most_recent` will be used.
+/// At most one of these can be set, otherwise retrieving the messages returns
+/// a [`ModelError::MultipleMessageAnchors`]. If one is not specified, the most
+/// recent messages are retrieved.
 ///
-/// The fourth parameter is to specify the number of messages to retrieve. This
-/// does not _need_ to be called and defaults to a value of 50.
+/// The fourth parameter is to specify the number of messages to retrieve,
+/// between 1 and 100. This does not _need_ to be called and defaults to a
+/// value of 50.
 ///
 /// This should be used only for retrieving messages; see
 /// [`GuildChannel::messages`] for examples.
@@ -43,6 +48,7 @@ use crate::model::id::MessageId;
 /// ```
 ///
 /// [`GuildChannel::messages`]: crate::model::channel::GuildChannel::messages
+/// [`ModelError::MultipleMessageAnchors`]: crate::model::ModelError::MultipleMessageAnchors
 #[derive(Clone, Debug, Default)]
 pub struct GetMessages(pub HashMap<&'static str, u64>);
 
@@ -87,11 +93,77 @@ impl GetMessages {
     ///
     /// If this is not specified, a default value of 50 is used.
     ///
-    /// **Note**: This field is capped to 100 messages due to a Discord
-    /// limitation. If an amount larger than 100 is supplied, it will be
-    /// reduced.
+    /// **Note**: Discord only allows retrieving between 1 and 100 messages at
+    /// once. Retrieving the messages with any other limit returns a
+    /// [`ModelError::MessageFetchLimit`].
+    ///
+    /// [`ModelError::MessageFetchLimit`]: crate::model::ModelError::MessageFetchLimit
     pub fn limit(&mut self, limit: u64) -> &mut Self {
-        self.0.insert("limit", if limit > 100 { 100 } else { limit });
+        self.0.insert("limit", limit);
         self
     }
+
+    /// Builds the query string of the request, checking that at most one
+    /// message to retrieve the messages relative to is set and that the limit
+    /// is within Discord's bounds.
+    pub(crate) fn build_query(&self) -> Result<String> {
+        let limit = self.0.get("limit").copied().unwrap_or(50);
+
+        if !(1..=100).contains(&limit) {
+            return Err(Error::Model(ModelError::MessageFetchLimit(limit)));
+        }
+
+        let mut query = format!("?limit={}", limit);
+        let mut anchors = ["after", "around", "before"]
+            .iter()
+            .filter_map(|key| self.0.get(key).map(|id| (key, id)));
+
+        if let Some((key, id)) = anchors.next() {
+            if anchors.next().is_some() {
+                return Err(Error::Model(ModelError::MultipleMessageAnchors));
+            }
+
+            write!(query, "&{}={}", key, id)?;
+        }
+
+        Ok(query)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::GetMessages;
+    use crate::model::ModelError;
+    use crate::Error;
+
+    #[test]
+    fn test_build_query() {
+        let mut builder = GetMessages::default();
+        assert_eq!(builder.build_query().unwrap(), "?limit=50");
+
+        builder.before(2).limit(100);
+        assert_eq!(builder.build_query().unwrap(), "?limit=100&before=2");
+
+        builder.after(1);
+        assert!(matches!(
+            builder.build_query(),
+            Err(Error::Model(ModelError::MultipleMessageAnchors))
+        ));
+
+        let mut builder = GetMessages::default();
+        builder.around(3).limit(0);
+        assert!(matches!(
+            builder.build_query(),
+            Err(Error::Model(ModelError::MessageFetchLimit(0)))
+        ));
+
+        builder.limit(101);
+        assert!(matches!(
+            builder.build_query(),
+            Err(Error::Model(ModelError::MessageFetchLimit(101)))
+        ));
+
+        builder.limit(1);
+        assert_eq!(builder.build_query().unwrap(), "?limit=1&around=3");
+    }
 }
@@ -20,9 +20,11 @@ mod create_interaction_response_followup;
 mod create_invite;
 mod create_message;
 mod create_scheduled_event;
+mod create_soundboard_sound;
 mod create_stage_instance;
 mod create_sticker;
 mod create_thread;
+mod edit_application;
 mod edit_automod_rule;
 mod edit_channel;
 mod edit_guild;
@@ -34,6 +36,7 @@ mod edit_message;
 mod edit_profile;
 mod edit_role;
 mod edit_scheduled_event;
+mod edit_soundboard_sound;
 mod edit_stage_instance;
 mod edit_sticker;
 mod edit_thread;
@@ -63,12 +66,23 @@ pub use self::create_components::{
     CreateActionRow,
     CreateButton,
     CreateComponents,
+    CreateContainer,
+    CreateFile,
     CreateInputText,
+    CreateMediaGallery,
+    CreateMediaGalleryItem,
+    CreateSection,
     CreateSelectMenu,
     CreateSelectMenuOption,
     CreateSelectMenuOptions,
+    CreateSeparator,
+    CreateTextDisplay,
+    CreateThumbnail,
 };
-pub use self::create_embed::{CreateEmbed, CreateEmbedAuthor, CreateEmbedFooter};
+#[cfg(feature = "model")]
+pub(crate) use self::create_embed::validate_embed;
+pub(crate) use self::create_embed::SKIP_EMBED_VALIDATION;
+pub use self::create_embed::{CreateEmbed, CreateEmbedAuthor, CreateEmbedFooter, EmbedError};
 pub use self::create_interaction_response::{
     CreateAutocompleteResponse,
     CreateInteractionResponse,
@@ -78,9 +92,11 @@ pub use self::create_interaction_response_followup::CreateInteractionResponseFol
 pub use self::create_invite::CreateInvite;
 pub use self::create_message::CreateMessage;
 pub use self::create_scheduled_event::CreateScheduledEvent;
+pub use self::create_soundboard_sound::CreateSoundboardSound;
 pub use self::create_stage_instance::CreateStageInstance;
 pub use self::create_sticker::CreateSticker;
 pub use self::create_thread::CreateThread;
+pub use self::edit_application::EditApplication;
 pub use self::edit_automod_rule::EditAutoModRule;
 pub use self::edit_channel::EditChannel;
 pub use self::edit_guild::EditGuild;
@@ -92,6 +108,7 @@ pub use self::edit_message::EditMessage;
 pub use self::edit_profile::EditProfile;
 pub use self::edit_role::EditRole;
 pub use self::edit_scheduled_event::EditScheduledEvent;
+pub use self::edit_soundboard_sound::EditSoundboardSound;
 pub use self::edit_stage_instance::EditStageInstance;
 pub use self::edit_sticker::EditSticker;
 pub use self::edit_thread::EditThread;
@@ -0,0 +1,33 @@
+use std::error::Error as StdError;
+use std::fmt;
+
+/// An error returned from the [`Cache`].
+///
+/// This is always wrapped within the library's generic [`Error::Cache`]
+/// variant.
+///
+/// [`Cache`]: super::Cache
+/// [`Error::Cache`]: crate::Error::Cache
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum Error {
+    /// When a snapshot passed to [`Cache::load_from`] was written in a format
+    /// version this version of the library can not read.
+    ///
+    /// Provides the version of the snapshot.
+    ///
+    /// [`Cache::load_from`]: super::Cache::load_from
+    UnsupportedSnapshotVersion(u32),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnsupportedSnapshotVersion(version) => {
+                write!(f, "Unsupported cache snapshot version {}", version)
+            },
+        }
+    }
+}
+
+impl StdError for Error {}
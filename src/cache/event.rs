@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 use super::{Cache, CacheUpdate};
 use crate::model::channel::{Channel, GuildChannel, Message};
@@ -21,6 +21,7 @@ use crate::model::event::{
     GuildUnavailableEvent,
     GuildUpdateEvent,
     MessageCreateEvent,
+    MessageDeleteBulkEvent,
     MessageUpdateEvent,
     PresenceUpdateEvent,
     PresencesReplaceEvent,
@@ -31,6 +32,7 @@ use crate::model::event::{
     UserUpdateEvent,
     VoiceStateUpdateEvent,
 };
+use crate::model::gateway::Presence;
 use crate::model::guild::{Guild, Member, Role};
 use crate::model::user::{CurrentUser, OnlineStatus};
 use crate::model::voice::VoiceState;
@@ -57,21 +59,17 @@ impl CacheUpdate for ChannelCreateEvent {
                     return Some(Channel::Private(channel.clone()));
                 }
 
-                let id = {
-                    let user_id = {
-                        cache.update_user_entry(&channel.recipient);
+                let user_id = {
+                    cache.update_user_entry(&channel.recipient);
 
-                        channel.recipient.id
-                    };
-
-                    if let Some(u) = cache.users.get(&user_id) {
-                        channel.recipient = u.clone();
-                    }
-
-                    channel.id
+                    channel.recipient.id
                 };
 
-                cache.private_channels.insert(id, channel.clone()).map(Channel::Private)
+                if let Some(u) = cache.users.get(&user_id) {
+                    channel.recipient = u.clone();
+                }
+
+                cache.insert_private_channel(channel.clone()).map(Channel::Private)
             },
             Channel::Category(ref category) => {
                 let (guild_id, channel_id) = (category.guild_id, category.id);
@@ -112,6 +110,7 @@ impl CacheUpdate for ChannelDeleteEvent {
                 let id = { channel.id };
 
                 cache.private_channels.remove(&id);
+                cache.user_private_channels.remove(&channel.recipient.id);
             },
         };
 
@@ -123,38 +122,39 @@ impl CacheUpdate for ChannelDeleteEvent {
 }
 
 impl CacheUpdate for ChannelUpdateEvent {
-    type Output = ();
+    type Output = Channel;
 
-    fn update(&mut self, cache: &Cache) -> Option<()> {
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
         match self.channel {
             Channel::Guild(ref channel) => {
                 let (guild_id, channel_id) = (channel.guild_id, channel.id);
 
-                cache.channels.insert(channel_id, channel.clone());
+                let old_channel = cache.channels.insert(channel_id, channel.clone());
 
                 cache
                     .guilds
                     .get_mut(&guild_id)
                     .map(|mut g| g.channels.insert(channel_id, self.channel.clone()));
+
+                old_channel.map(Channel::Guild)
             },
-            Channel::Private(ref channel) => {
-                if let Some(mut c) = cache.private_channels.get_mut(&channel.id) {
-                    c.clone_from(channel);
-                }
-            },
+            Channel::Private(ref channel) => cache
+                .private_channels
+                .get_mut(&channel.id)
+                .map(|mut c| Channel::Private(std::mem::replace(&mut *c, channel.clone()))),
             Channel::Category(ref category) => {
                 let (guild_id, channel_id) = (category.guild_id, category.id);
 
-                cache.categories.insert(channel_id, category.clone());
+                let old_category = cache.categories.insert(channel_id, category.clone());
 
                 cache
                     .guilds
                     .get_mut(&guild_id)
                     .map(|mut g| g.channels.insert(channel_id, self.channel.clone()));
+
+                old_category.map(Channel::Category)
             },
         }
-
-        None
     }
 }
 
@@ -185,6 +185,11 @@ impl CacheUpdate for GuildCreateEvent {
         cache.unavailable_guilds.remove(&self.guild.id);
         let mut guild = self.guild.clone();
 
+        guild.members.retain(|user_id, _| cache.caches_member(*user_id));
+        if !cache.settings().cache_presences {
+            guild.presences.clear();
+        }
+
         for (user_id, member) in &mut guild.members {
             cache.update_user_entry(&member.user);
             if let Some(u) = cache.user(user_id) {
@@ -214,29 +219,7 @@ impl CacheUpdate for GuildDeleteEvent {
     type Output = Guild;
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        match cache.guilds.remove(&self.guild.id) {
-            Some(guild) => {
-                for (channel_id, channel) in &guild.1.channels {
-                    match channel {
-                        Channel::Guild(_) => {
-                            // Remove the channel from the cache.
-                            cache.channels.remove(channel_id);
-
-                            // Remove the channel's cached messages.
-                            cache.messages.remove(channel_id);
-                        },
-                        Channel::Category(_) => {
-                            // Remove the category from the cache
-                            cache.categories.remove(channel_id);
-                        },
-                        _ => {},
-                    }
-                }
-
-                Some(guild.1)
-            },
-            None => None,
-        }
+        cache.remove_guild(self.guild.id)
     }
 }
 
@@ -257,14 +240,19 @@ impl CacheUpdate for GuildMemberAddEvent {
 
     fn update(&mut self, cache: &Cache) -> Option<()> {
         let user_id = self.member.user.id;
-        cache.update_user_entry(&self.member.user);
-        if let Some(u) = cache.user(user_id) {
-            self.member.user = u;
+        let caches_member = cache.caches_member(user_id);
+        if caches_member {
+            cache.update_user_entry(&self.member.user);
+            if let Some(u) = cache.user(user_id) {
+                self.member.user = u;
+            }
         }
 
         if let Some(mut guild) = cache.guilds.get_mut(&self.member.guild_id) {
             guild.member_count += 1;
-            guild.members.insert(user_id, self.member.clone());
+            if caches_member {
+                guild.members.insert(user_id, self.member.clone());
+            }
         }
 
         None
@@ -288,6 +276,10 @@ impl CacheUpdate for GuildMemberUpdateEvent {
     type Output = Member;
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        if !cache.caches_member(self.user.id) {
+            return None;
+        }
+
         cache.update_user_entry(&self.user);
 
         if let Some(mut guild) = cache.guilds.get_mut(&self.guild_id) {
@@ -311,20 +303,7 @@ impl CacheUpdate for GuildMemberUpdateEvent {
             };
 
             if item.is_none() {
-                guild.members.insert(self.user.id, Member {
-                    deaf: false,
-                    guild_id: self.guild_id,
-                    joined_at: Some(self.joined_at),
-                    mute: false,
-                    nick: self.nick.clone(),
-                    roles: self.roles.clone(),
-                    user: self.user.clone(),
-                    pending: self.pending,
-                    premium_since: self.premium_since,
-                    permissions: None,
-                    avatar: self.avatar.clone(),
-                    communication_disabled_until: self.communication_disabled_until,
-                });
+                guild.members.insert(self.user.id, self.to_member());
             }
 
             item
@@ -334,16 +313,43 @@ impl CacheUpdate for GuildMemberUpdateEvent {
     }
 }
 
+impl GuildMemberUpdateEvent {
+    /// Creates a member out of the update's data, for when the member is not cached.
+    pub(crate) fn to_member(&self) -> Member {
+        Member {
+            deaf: self.deaf,
+            guild_id: self.guild_id,
+            joined_at: Some(self.joined_at),
+            mute: self.mute,
+            nick: self.nick.clone(),
+            roles: self.roles.clone(),
+            user: self.user.clone(),
+            pending: self.pending,
+            premium_since: self.premium_since,
+            permissions: None,
+            avatar: self.avatar.clone(),
+            communication_disabled_until: self.communication_disabled_until,
+        }
+    }
+}
+
 impl CacheUpdate for GuildMembersChunkEvent {
     type Output = ();
 
     fn update(&mut self, cache: &Cache) -> Option<()> {
-        for member in self.members.values() {
+        let members = self
+            .members
+            .iter()
+            .filter(|(user_id, _)| cache.caches_member(**user_id))
+            .map(|(user_id, member)| (*user_id, member.clone()))
+            .collect::<HashMap<_, _>>();
+
+        for member in members.values() {
             cache.update_user_entry(&member.user);
         }
 
         if let Some(mut g) = cache.guilds.get_mut(&self.guild_id) {
-            g.members.extend(self.members.clone());
+            g.members.extend(members);
         }
 
         None
@@ -375,13 +381,10 @@ impl CacheUpdate for GuildRoleUpdateEvent {
     type Output = Role;
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        if let Some(mut guild) = cache.guilds.get_mut(&self.role.guild_id) {
-            if let Some(role) = guild.roles.get_mut(&self.role.id) {
-                return Some(std::mem::replace(role, self.role.clone()));
-            }
-        }
-
-        None
+        cache
+            .guilds
+            .get_mut(&self.role.guild_id)
+            .and_then(|mut guild| guild.roles.insert(self.role.id, self.role.clone()))
     }
 }
 
@@ -451,9 +454,10 @@ impl CacheUpdate for MessageCreateEvent {
     type Output = Message;
 
     fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
-        let max = cache.settings().max_messages;
+        let settings = cache.settings();
+        let max = settings.max_messages;
 
-        if max == 0 {
+        if max == 0 || !settings.cache_messages {
             return None;
         }
 
@@ -477,6 +481,23 @@ impl CacheUpdate for MessageCreateEvent {
     }
 }
 
+impl CacheUpdate for MessageDeleteBulkEvent {
+    /// The deleted messages that were in the cache.
+    type Output = Vec<Message>;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        let messages = cache.messages.get(&self.channel_id)?;
+
+        if let Some(mut queue) = cache.message_queue.get_mut(&self.channel_id) {
+            queue.retain(|id| !self.ids.contains(id));
+        }
+
+        let removed = self.ids.iter().filter_map(|id| messages.remove(id)).map(|i| i.1).collect();
+
+        Some(removed)
+    }
+}
+
 impl CacheUpdate for MessageUpdateEvent {
     type Output = Message;
 
@@ -488,7 +509,7 @@ impl CacheUpdate for MessageUpdateEvent {
         let Self {
             id, channel_id, content, edited_timestamp, tts, mention_everyone, mentions,
             mention_roles, mention_channels, attachments, embeds, reactions, pinned, flags,
-            components, sticker_items,
+            components, sticker_items, message_snapshots,
 
             author: _, timestamp: _,  nonce: _, kind: _, stickers: _,  guild_id: _,
         } = &self;
@@ -511,15 +532,20 @@ impl CacheUpdate for MessageUpdateEvent {
         if let Some(x) = reactions { message.reactions = x.clone() }
         if let Some(x) = components { message.components = x.clone() }
         if let Some(x) = sticker_items { message.sticker_items = x.clone() }
+        if let Some(x) = message_snapshots { message.message_snapshots = x.clone() }
 
         Some(old_message)
     }
 }
 
 impl CacheUpdate for PresenceUpdateEvent {
-    type Output = ();
+    type Output = Presence;
+
+    fn update(&mut self, cache: &Cache) -> Option<Self::Output> {
+        if !cache.settings().cache_presences {
+            return None;
+        }
 
-    fn update(&mut self, cache: &Cache) -> Option<()> {
         if let Some(user) = self.presence.user.to_user() {
             cache.update_user_entry(&user);
         }
@@ -531,15 +557,17 @@ impl CacheUpdate for PresenceUpdateEvent {
         if let Some(guild_id) = self.presence.guild_id {
             if let Some(mut guild) = cache.guilds.get_mut(&guild_id) {
                 // If the member went offline, remove them from the presence list.
-                if self.presence.status == OnlineStatus::Offline {
-                    guild.presences.remove(&self.presence.user.id);
+                let old = if self.presence.status == OnlineStatus::Offline {
+                    guild.presences.remove(&self.presence.user.id)
                 } else {
-                    guild.presences.insert(self.presence.user.id, self.presence.clone());
-                }
+                    guild.presences.insert(self.presence.user.id, self.presence.clone())
+                };
 
                 // Create a partial member instance out of the presence update
                 // data.
-                if let Some(user) = self.presence.user.to_user() {
+                if let Some(user) =
+                    self.presence.user.to_user().filter(|user| cache.caches_member(user.id))
+                {
                     guild.members.entry(self.presence.user.id).or_insert_with(|| Member {
                         deaf: false,
                         guild_id,
@@ -555,14 +583,16 @@ impl CacheUpdate for PresenceUpdateEvent {
                         communication_disabled_until: None,
                     });
                 }
+
+                return old;
             }
+
+            None
         } else if self.presence.status == OnlineStatus::Offline {
-            cache.presences.remove(&self.presence.user.id);
+            cache.presences.remove(&self.presence.user.id).map(|(_, presence)| presence)
         } else {
-            cache.presences.insert(self.presence.user.id, self.presence.clone());
+            cache.presences.insert(self.presence.user.id, self.presence.clone())
         }
-
-        None
     }
 }
 
@@ -570,6 +600,10 @@ impl CacheUpdate for PresencesReplaceEvent {
     type Output = ();
 
     fn update(&mut self, cache: &Cache) -> Option<()> {
+        if !cache.settings().cache_presences {
+            return None;
+        }
+
         for presence in &self.presences {
             cache.presences.insert(presence.user.id, presence.clone());
         }
@@ -612,6 +646,10 @@ impl CacheUpdate for ReadyEvent {
         // `ready.private_channels` will always be empty, and possibly be removed in the future.
         // So don't handle it at all.
 
+        if !cache.settings().cache_presences {
+            ready.presences.clear();
+        }
+
         for (user_id, presence) in &mut ready.presences {
             if let Some(user) = presence.user.to_user() {
                 cache.update_user_entry(&user);
@@ -692,7 +730,9 @@ impl CacheUpdate for VoiceStateUpdateEvent {
         if let Some(guild_id) = self.voice_state.guild_id {
             if let Some(mut guild) = cache.guilds.get_mut(&guild_id) {
                 if let Some(member) = &self.voice_state.member {
-                    guild.members.insert(member.user.id, member.clone());
+                    if cache.caches_member(member.user.id) {
+                        guild.members.insert(member.user.id, member.clone());
+                    }
                 }
 
                 if self.voice_state.channel_id.is_some() {
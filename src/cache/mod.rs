@@ -48,10 +48,13 @@ use tracing::instrument;
 
 use crate::model::prelude::*;
 mod cache_update;
+mod error;
 mod event;
+mod persistence;
 mod settings;
 
 pub use self::cache_update::CacheUpdate;
+pub use self::error::Error as CacheError;
 pub use self::settings::Settings;
 
 type MessageCache = DashMap<ChannelId, DashMap<MessageId, Message>>;
@@ -151,6 +154,9 @@ pub struct Cache {
     /// A map of direct message channels that the current user has open with
     /// other users.
     pub(crate) private_channels: DashMap<ChannelId, PrivateChannel>,
+    /// A map of users to the Id of the direct message channel that the current
+    /// user has open with them, indexing into [`Self::private_channels`].
+    pub(crate) user_private_channels: DashMap<UserId, ChannelId>,
     /// The total number of shards being used by the bot.
     pub(crate) shard_count: RwLock<u64>,
     /// A list of guilds which are "unavailable". Refer to the documentation for
@@ -679,6 +685,60 @@ impl Cache {
         self.guilds.get(&guild_id).map(|g| g.roles.clone())
     }
 
+    /// Clones the voice states of the users in a guild's voice channels, keyed
+    /// by user.
+    ///
+    /// Users leaving voice channels are removed, and all voice states of a
+    /// guild are removed along with it, such as when it becomes unavailable.
+    ///
+    /// Returns [`None`] if the guild is not cached.
+    ///
+    /// **Note**: Requires the [`GatewayIntents::GUILD_VOICE_STATES`] intent.
+    #[inline]
+    pub fn guild_voice_states(
+        &self,
+        guild_id: impl Into<GuildId>,
+    ) -> Option<HashMap<UserId, VoiceState>> {
+        self.guilds.get(&guild_id.into()).map(|g| g.voice_states.clone())
+    }
+
+    /// Clones the voice state of a user in a guild.
+    ///
+    /// Returns [`None`] if the guild is not cached or the user is not in any
+    /// of its voice channels. Refer to [`Self::guild_voice_states`] for more
+    /// information.
+    #[inline]
+    pub fn voice_state(
+        &self,
+        guild_id: impl Into<GuildId>,
+        user_id: impl Into<UserId>,
+    ) -> Option<VoiceState> {
+        self.guilds.get(&guild_id.into())?.voice_states.get(&user_id.into()).cloned()
+    }
+
+    /// Clones the voice states of the users in a guild's voice channel, i.e.
+    /// who is in the channel.
+    ///
+    /// Returns [`None`] if the channel or its guild is not cached. Refer to
+    /// [`Self::guild_voice_states`] for more information.
+    pub fn channel_voice_states(
+        &self,
+        channel_id: impl Into<ChannelId>,
+    ) -> Option<Vec<VoiceState>> {
+        let channel_id = channel_id.into();
+        let guild_id = self.channels.get(&channel_id)?.guild_id;
+        let guild = self.guilds.get(&guild_id)?;
+
+        let states = guild
+            .voice_states
+            .values()
+            .filter(|state| state.channel_id == Some(channel_id))
+            .cloned()
+            .collect();
+
+        Some(states)
+    }
+
     /// This method clones and returns all unavailable guilds.
     #[inline]
     pub fn unavailable_guilds(&self) -> DashSet<GuildId> {
@@ -807,6 +867,37 @@ impl Cache {
         self.private_channels.get(&channel_id).map(|i| i.clone())
     }
 
+    /// Retrieves the [`PrivateChannel`] that the current user has open with a
+    /// user, if it is cached.
+    ///
+    /// This is used by [`UserId::create_dm_channel`] to avoid opening the same
+    /// channel repeatedly.
+    #[inline]
+    pub fn user_private_channel(&self, user_id: impl Into<UserId>) -> Option<PrivateChannel> {
+        let channel_id = *self.user_private_channels.get(&user_id.into())?;
+
+        self._private_channel(channel_id)
+    }
+
+    /// Removes the cached [`PrivateChannel`] that the current user has open with
+    /// a user, returning it if it was cached.
+    ///
+    /// The next call to [`UserId::create_dm_channel`] will then open the
+    /// channel again.
+    pub fn remove_user_private_channel(
+        &self,
+        user_id: impl Into<UserId>,
+    ) -> Option<PrivateChannel> {
+        let (_, channel_id) = self.user_private_channels.remove(&user_id.into())?;
+
+        self.private_channels.remove(&channel_id).map(|(_, channel)| channel)
+    }
+
+    pub(crate) fn insert_private_channel(&self, channel: PrivateChannel) -> Option<PrivateChannel> {
+        self.user_private_channels.insert(channel.recipient.id, channel.id);
+        self.private_channels.insert(channel.id, channel)
+    }
+
     /// Retrieves a [`Guild`]'s role by their Ids.
     ///
     /// **Note**: This will clone the entire role. Instead, retrieve the guild
@@ -841,6 +932,21 @@ impl Cache {
         self.guilds.get(&guild_id).and_then(|g| g.roles.get(&role_id).cloned())
     }
 
+    /// Retrieves a [`Sticker`] of any cached [`Guild`] by its Id.
+    ///
+    /// **Note**: Only guild stickers are cached, standard stickers such as
+    /// those of Nitro sticker packs are not.
+    ///
+    /// [`Guild`]: crate::model::guild::Guild
+    #[inline]
+    pub fn sticker(&self, sticker_id: impl Into<StickerId>) -> Option<Sticker> {
+        self._sticker(sticker_id.into())
+    }
+
+    fn _sticker(&self, sticker_id: StickerId) -> Option<Sticker> {
+        self.guilds.iter().find_map(|guild| guild.stickers.get(&sticker_id).cloned())
+    }
+
     /// Returns the settings.
     ///
     /// # Examples
@@ -866,6 +972,12 @@ impl Cache {
         self.settings.write().max_messages = max;
     }
 
+    /// Whether the member of the given user should be cached, according to
+    /// [`Settings::cache_members`]. The current user's member is always cached.
+    pub(crate) fn caches_member(&self, user_id: UserId) -> bool {
+        self.settings.read().cache_members || self.user.read().id == user_id
+    }
+
     /// Retrieves a [`User`] from the cache's [`Self::users`] map, if it exists.
     ///
     /// The only advantage of this method is that you can pass in anything that
@@ -998,6 +1110,56 @@ impl Cache {
         e.update(self)
     }
 
+    /// Replaces the emojis of a guild, if it is cached, such as with the ones
+    /// fetched by [`GuildId::emojis`].
+    #[cfg(feature = "model")]
+    pub(crate) fn set_guild_emojis(&self, guild_id: GuildId, emojis: &[Emoji]) {
+        if let Some(mut guild) = self.guilds.get_mut(&guild_id) {
+            guild.emojis = emojis.iter().map(|emoji| (emoji.id, emoji.clone())).collect();
+        }
+    }
+
+    /// Adds or replaces an emoji of a guild, if it is cached, such as one
+    /// created or edited via [`GuildId::create_emoji`].
+    #[cfg(feature = "model")]
+    pub(crate) fn insert_guild_emoji(&self, guild_id: GuildId, emoji: &Emoji) {
+        if let Some(mut guild) = self.guilds.get_mut(&guild_id) {
+            guild.emojis.insert(emoji.id, emoji.clone());
+        }
+    }
+
+    /// Removes an emoji of a guild, if it is cached.
+    #[cfg(feature = "model")]
+    pub(crate) fn remove_guild_emoji(&self, guild_id: GuildId, emoji_id: EmojiId) {
+        if let Some(mut guild) = self.guilds.get_mut(&guild_id) {
+            guild.emojis.remove(&emoji_id);
+        }
+    }
+
+    /// Removes a guild from the cache, along with its channels and their cached messages.
+    pub(crate) fn remove_guild(&self, guild_id: GuildId) -> Option<Guild> {
+        let (_, guild) = self.guilds.remove(&guild_id)?;
+
+        for (channel_id, channel) in &guild.channels {
+            match channel {
+                Channel::Guild(_) => {
+                    // Remove the channel from the cache.
+                    self.channels.remove(channel_id);
+
+                    // Remove the channel's cached messages.
+                    self.messages.remove(channel_id);
+                },
+                Channel::Category(_) => {
+                    // Remove the category from the cache
+                    self.categories.remove(channel_id);
+                },
+                _ => {},
+            }
+        }
+
+        Some(guild)
+    }
+
     pub(crate) fn update_user_entry(&self, user: &User) {
         match self.users.entry(user.id) {
             Entry::Vacant(e) => {
@@ -1021,6 +1183,7 @@ impl Default for Cache {
             messages: DashMap::default(),
             presences: DashMap::default(),
             private_channels: DashMap::with_capacity(128),
+            user_private_channels: DashMap::with_capacity(128),
             settings: RwLock::new(Settings::default()),
             shard_count: RwLock::new(1),
             unavailable_guilds: DashSet::default(),
@@ -1037,8 +1200,8 @@ impl Default for Cache {
 mod test {
     use std::collections::HashMap;
 
-    use crate::cache::{Cache, CacheUpdate, Settings};
-    use crate::json::from_number;
+    use crate::cache::{Cache, CacheError, CacheUpdate, Settings};
+    use crate::json::{from_number, from_value, json, Value};
     use crate::model::prelude::*;
 
     #[test]
@@ -1059,10 +1222,12 @@ mod test {
                     bot: false,
                     discriminator: 1,
                     name: "user 1".to_owned(),
+                    global_name: None,
                     public_flags: None,
                     banner: None,
                     member: None,
                     accent_colour: None,
+                    avatar_decoration_data: None,
                 },
                 channel_id: ChannelId(2),
                 guild_id: Some(GuildId(1)),
@@ -1084,6 +1249,7 @@ mod test {
                 activity: None,
                 application: None,
                 message_reference: None,
+                message_snapshots: vec![],
                 flags: None,
                 sticker_items: vec![],
                 referenced_message: None,
@@ -1120,6 +1286,23 @@ mod test {
             assert!(!channel.contains_key(&MessageId(3)));
         }
 
+        // Bulk deleting returns the deleted messages that were cached, and removes them.
+        let mut bulk_delete = MessageDeleteBulkEvent {
+            guild_id: event.message.guild_id,
+            channel_id: event.message.channel_id,
+            ids: vec![MessageId(4), MessageId(6)],
+        };
+        let deleted = cache.update(&mut bulk_delete).unwrap();
+        assert_eq!(deleted.len(), 1);
+        assert_eq!(deleted[0].id, MessageId(4));
+        assert_eq!(cache.messages.get(&event.message.channel_id).unwrap().len(), 1);
+
+        {
+            let queue = cache.message_queue.get(&event.message.channel_id).unwrap();
+
+            assert!(!queue.contains(&MessageId(4)));
+        }
+
         let channel = Channel::Guild(GuildChannel {
             id: event.message.channel_id,
             bitrate: None,
@@ -1192,6 +1375,8 @@ mod test {
                     system_channel_flags: SystemChannelFlags::default(),
                     rules_channel_id: None,
                     public_updates_channel_id: None,
+                    safety_alerts_channel_id: None,
+                    premium_progress_bar_enabled: false,
                     verification_level: VerificationLevel::Low,
                     voice_states: HashMap::new(),
                     description: None,
@@ -1233,4 +1418,412 @@ mod test {
         // Assert that the channel's message cache no longer exists.
         assert!(!cache.messages.contains_key(&ChannelId(2)));
     }
+
+    #[test]
+    fn test_cache_user_private_channels() {
+        let cache = Cache::default();
+        let user = User {
+            id: UserId(2),
+            avatar: None,
+            bot: false,
+            discriminator: 1,
+            name: "user 1".to_owned(),
+            global_name: None,
+            public_flags: None,
+            banner: None,
+            member: None,
+            accent_colour: None,
+            avatar_decoration_data: None,
+        };
+        let channel = PrivateChannel {
+            id: ChannelId(3),
+            last_message_id: None,
+            last_pin_timestamp: None,
+            kind: ChannelType::Private,
+            recipient: user,
+        };
+
+        assert!(cache.user_private_channel(UserId(2)).is_none());
+
+        let mut event = ChannelCreateEvent {
+            channel: Channel::Private(channel.clone()),
+        };
+        cache.update(&mut event);
+        assert_eq!(cache.user_private_channel(UserId(2)).map(|c| c.id), Some(ChannelId(3)));
+
+        let mut event = ChannelDeleteEvent {
+            channel: Channel::Private(channel.clone()),
+        };
+        cache.update(&mut event);
+        assert!(cache.user_private_channel(UserId(2)).is_none());
+
+        cache.insert_private_channel(channel);
+        assert_eq!(cache.remove_user_private_channel(UserId(2)).map(|c| c.id), Some(ChannelId(3)));
+        assert!(cache.private_channel(ChannelId(3)).is_none());
+    }
+
+    #[test]
+    fn test_cache_presence_update_previous() {
+        let cache = Cache::default();
+        let presence_event = |status: &str| -> PresenceUpdateEvent {
+            from_value(json!({
+                "user": {"id": "2"},
+                "status": status,
+                "activities": [],
+            }))
+            .unwrap()
+        };
+
+        // The first presence seen for a user has no previous state.
+        let mut first = presence_event("online");
+        assert!(cache.update(&mut first).is_none());
+
+        let mut second = presence_event("idle");
+        let previous = cache.update(&mut second).unwrap();
+        assert_eq!(previous.status, OnlineStatus::Online);
+        assert_eq!(cache.presences.get(&UserId(2)).unwrap().status, OnlineStatus::Idle);
+
+        // Going offline removes the presence and still reports the previous state.
+        let mut third = presence_event("offline");
+        assert_eq!(cache.update(&mut third).unwrap().status, OnlineStatus::Idle);
+        assert!(!cache.presences.contains_key(&UserId(2)));
+    }
+
+    /// Creates the event of an empty guild with an Id of 1, overriding the
+    /// given fields.
+    fn guild_create(fields: Value) -> GuildCreateEvent {
+        let mut guild = json!({
+            "id": "1",
+            "name": "guild",
+            "icon": null,
+            "splash": null,
+            "discovery_splash": null,
+            "owner_id": "4",
+            "afk_channel_id": null,
+            "afk_timeout": 0,
+            "verification_level": 0,
+            "default_message_notifications": 0,
+            "explicit_content_filter": 0,
+            "roles": [],
+            "emojis": [],
+            "features": [],
+            "mfa_level": 0,
+            "application_id": null,
+            "system_channel_id": null,
+            "system_channel_flags": 0,
+            "rules_channel_id": null,
+            "vanity_url_code": null,
+            "description": null,
+            "banner": null,
+            "premium_tier": 0,
+            "preferred_locale": "en-US",
+            "public_updates_channel_id": null,
+            "nsfw_level": 0,
+            "premium_progress_bar_enabled": false,
+            "joined_at": "2021-01-01T00:00:00+00:00",
+            "large": false,
+            "member_count": 0,
+            "members": [],
+            "presences": [],
+            "voice_states": [],
+            "threads": [],
+            "stage_instances": [],
+            "stickers": [],
+            "channels": [],
+        });
+
+        if let (Some(guild), Value::Object(fields)) = (guild.as_object_mut(), fields) {
+            guild.extend(fields);
+        }
+
+        from_value(guild).unwrap()
+    }
+
+    #[test]
+    fn test_cache_update_returns_previous() {
+        let cache = Cache::default();
+        let guild_channel = |name: &str| {
+            json!({
+                "id": "2",
+                "guild_id": "1",
+                "type": 0,
+                "name": name,
+                "position": 0,
+                "permission_overwrites": [],
+                "nsfw": false,
+            })
+        };
+        let role = |name: &str| {
+            json!({
+                "id": "3",
+                "guild_id": "1",
+                "name": name,
+                "color": 0,
+                "hoist": false,
+                "managed": false,
+                "permissions": "0",
+                "position": 1,
+            })
+        };
+
+        let mut guild_create = guild_create(json!({"channels": [guild_channel("old")]}));
+        cache.update(&mut guild_create);
+
+        let mut channel_update = ChannelUpdateEvent {
+            channel: from_value(guild_channel("new")).unwrap(),
+        };
+        let old = cache.update(&mut channel_update).unwrap().guild().unwrap();
+        assert_eq!(old.name, "old");
+        assert_eq!(cache.guild_channel(ChannelId(2)).unwrap().name, "new");
+
+        // A role that was not cached is inserted, without a previous value.
+        let mut role_update: GuildRoleUpdateEvent =
+            from_value(json!({"guild_id": "1", "role": role("old")})).unwrap();
+        assert!(cache.update(&mut role_update).is_none());
+
+        let mut role_update: GuildRoleUpdateEvent =
+            from_value(json!({"guild_id": "1", "role": role("new")})).unwrap();
+        assert_eq!(cache.update(&mut role_update).unwrap().name, "old");
+        assert_eq!(cache.role(GuildId(1), RoleId(3)).unwrap().name, "new");
+    }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn test_cache_set_guild_emojis() {
+        let cache = Cache::default();
+        cache.update(&mut guild_create(json!({
+            "emojis": [{"id": "7", "name": "old", "roles": []}],
+        })));
+
+        let emoji: Emoji = from_value(json!({"id": "8", "name": "new", "roles": []})).unwrap();
+        cache.set_guild_emojis(GuildId(1), &[emoji]);
+        let emojis = cache.guild_field(GuildId(1), |g| g.emojis.clone()).unwrap();
+        assert_eq!(emojis.keys().collect::<Vec<_>>(), [&EmojiId(8)]);
+        assert_eq!(emojis[&EmojiId(8)].name, "new");
+
+        // Guilds that are not cached are not inserted.
+        cache.set_guild_emojis(GuildId(2), &[]);
+        assert!(cache.guild(GuildId(2)).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn test_cache_insert_remove_guild_emoji() {
+        let cache = Cache::default();
+        cache.update(&mut guild_create(json!({
+            "emojis": [{"id": "7", "name": "old", "roles": []}],
+        })));
+
+        let emoji: Emoji = from_value(json!({"id": "7", "name": "renamed", "roles": []})).unwrap();
+        cache.insert_guild_emoji(GuildId(1), &emoji);
+        let emoji: Emoji = from_value(json!({"id": "8", "name": "new", "roles": []})).unwrap();
+        cache.insert_guild_emoji(GuildId(1), &emoji);
+
+        let guild = cache.guild(GuildId(1)).unwrap();
+        let mut names = guild.emojis_iter().map(|e| e.name.as_str()).collect::<Vec<_>>();
+        names.sort_unstable();
+        assert_eq!(names, ["new", "renamed"]);
+
+        cache.remove_guild_emoji(GuildId(1), EmojiId(7));
+        let emojis = cache.guild_field(GuildId(1), |g| g.emojis.clone()).unwrap();
+        assert_eq!(emojis.keys().collect::<Vec<_>>(), [&EmojiId(8)]);
+    }
+
+    #[test]
+    fn test_cache_population_toggles() {
+        let mut settings = Settings::new();
+        settings.cache_presences(false).cache_members(false);
+        let cache = Cache::new_with_settings(settings);
+        cache.user.write().id = UserId(9);
+
+        let member = |id: &str| {
+            json!({
+                "user": {"id": id, "username": "user", "discriminator": "0001", "avatar": null},
+                "roles": [],
+                "joined_at": "2021-01-01T00:00:00+00:00",
+                "deaf": false,
+                "mute": false,
+            })
+        };
+        let mut guild_create = guild_create(json!({
+            "member_count": 2,
+            "members": [member("5"), member("9")],
+            "presences": [{"user": {"id": "5"}, "status": "online", "activities": []}],
+        }));
+        cache.update(&mut guild_create);
+
+        // Only the current user's member is kept, for permission checks.
+        assert!(cache.member(GuildId(1), UserId(5)).is_none());
+        assert!(cache.member(GuildId(1), UserId(9)).is_some());
+        assert!(cache.user(UserId(5)).is_none());
+        assert!(cache.guild_field(GuildId(1), |g| g.presences.is_empty()).unwrap());
+
+        let mut new_member = member("6");
+        new_member["guild_id"] = json!("1");
+        let mut member_add: GuildMemberAddEvent = from_value(new_member).unwrap();
+        cache.update(&mut member_add);
+        assert!(cache.member(GuildId(1), UserId(6)).is_none());
+        assert_eq!(cache.guild_field(GuildId(1), |g| g.member_count), Some(3));
+
+        let mut presence: PresenceUpdateEvent = from_value(json!({
+            "user": {"id": "2"},
+            "status": "online",
+            "activities": [],
+        }))
+        .unwrap();
+        assert!(cache.update(&mut presence).is_none());
+        assert!(cache.presences.is_empty());
+    }
+
+    /// Creates a cache containing an empty guild with an Id of 1.
+    fn cache_with_guild() -> Cache {
+        let cache = Cache::default();
+        cache.update(&mut guild_create(json!({})));
+
+        cache
+    }
+
+    fn voice_state_update(channel_id: Option<&str>) -> VoiceStateUpdateEvent {
+        from_value(json!({
+            "guild_id": "1",
+            "channel_id": channel_id,
+            "user_id": "5",
+            "session_id": "session",
+            "deaf": false,
+            "mute": false,
+            "self_deaf": false,
+            "self_mute": false,
+            "self_video": false,
+            "suppress": false,
+            "request_to_speak_timestamp": null,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_cache_voice_state_join() {
+        let cache = cache_with_guild();
+
+        // The user was not in a voice channel before.
+        assert!(cache.update(&mut voice_state_update(Some("2"))).is_none());
+        let state = cache.guild_field(GuildId(1), |g| g.voice_states[&UserId(5)].clone()).unwrap();
+        assert_eq!(state.channel_id, Some(ChannelId(2)));
+    }
+
+    #[test]
+    fn test_cache_voice_state_move() {
+        let cache = cache_with_guild();
+        cache.update(&mut voice_state_update(Some("2")));
+
+        let old = cache.update(&mut voice_state_update(Some("3"))).unwrap();
+        assert_eq!(old.channel_id, Some(ChannelId(2)));
+        let state = cache.guild_field(GuildId(1), |g| g.voice_states[&UserId(5)].clone()).unwrap();
+        assert_eq!(state.channel_id, Some(ChannelId(3)));
+    }
+
+    #[test]
+    fn test_cache_voice_state_leave() {
+        let cache = cache_with_guild();
+        cache.update(&mut voice_state_update(Some("2")));
+
+        let old = cache.update(&mut voice_state_update(None)).unwrap();
+        assert_eq!(old.channel_id, Some(ChannelId(2)));
+        assert!(cache.guild_field(GuildId(1), |g| g.voice_states.is_empty()).unwrap());
+
+        // Leaving again has no previous state.
+        assert!(cache.update(&mut voice_state_update(None)).is_none());
+    }
+
+    #[test]
+    fn test_cache_voice_state_queries() {
+        let cache = cache_with_guild();
+        let mut channel_create = ChannelCreateEvent {
+            channel: from_value(json!({"id": "2", "guild_id": "1", "type": 2, "name": "voice"}))
+                .unwrap(),
+        };
+        cache.update(&mut channel_create);
+
+        assert!(cache.guild_voice_states(GuildId(1)).unwrap().is_empty());
+        assert!(cache.channel_voice_states(ChannelId(2)).unwrap().is_empty());
+
+        cache.update(&mut voice_state_update(Some("2")));
+        let state = cache.voice_state(GuildId(1), UserId(5)).unwrap();
+        assert_eq!(state.channel_id, Some(ChannelId(2)));
+        assert_eq!(cache.guild_voice_states(GuildId(1)).unwrap().len(), 1);
+        let in_channel = cache.channel_voice_states(ChannelId(2)).unwrap();
+        assert_eq!(in_channel.len(), 1);
+        assert_eq!(in_channel[0].user_id, UserId(5));
+
+        // The voice states are removed along with the guild when it becomes unavailable.
+        let mut guild_delete: GuildDeleteEvent =
+            from_value(json!({"id": "1", "unavailable": true})).unwrap();
+        cache.update(&mut guild_delete);
+        assert!(cache.voice_state(GuildId(1), UserId(5)).is_none());
+        assert!(cache.guild_voice_states(GuildId(1)).is_none());
+        assert!(cache.channel_voice_states(ChannelId(2)).is_none());
+    }
+
+    #[test]
+    fn test_cache_snapshot() {
+        let cache = Cache::new();
+        cache.user.write().id = UserId(9);
+
+        let role = json!({
+            "id": "1",
+            "name": "@everyone",
+            "color": 0,
+            "hoist": false,
+            "position": 0,
+            "permissions": "0",
+            "managed": false,
+            "mentionable": false,
+        });
+        let member = json!({
+            "user": {"id": "5", "username": "user", "discriminator": "0001", "avatar": null},
+            "roles": [],
+            "joined_at": "2021-01-01T00:00:00+00:00",
+            "deaf": false,
+            "mute": false,
+        });
+        let presence = json!({"user": {"id": "5"}, "status": "online", "activities": []});
+        let channel = json!({"id": "2", "type": 0, "name": "general", "position": 0});
+        let mut guild_create = guild_create(json!({
+            "owner_id": "5",
+            "roles": [role],
+            "member_count": 1,
+            "members": [member],
+            "presences": [presence],
+            "channels": [channel],
+        }));
+        cache.update(&mut guild_create);
+        cache.unavailable_guilds.insert(GuildId(3));
+
+        let mut snapshot = Vec::new();
+        cache.save_to(&mut snapshot).unwrap();
+
+        let loaded = Cache::new();
+        loaded.load_from(snapshot.as_slice()).unwrap();
+        assert_eq!(loaded.current_user_id(), UserId(9));
+        assert_eq!(loaded.guild_field(GuildId(1), |g| g.name.clone()).as_deref(), Some("guild"));
+        assert!(loaded.member(GuildId(1), UserId(5)).is_some());
+        assert!(loaded.role(GuildId(1), RoleId(1)).is_some());
+        assert_eq!(loaded.guild_channel(ChannelId(2)).unwrap().guild_id, GuildId(1));
+        assert!(loaded.user(UserId(5)).is_some());
+        assert!(loaded.unavailable_guilds.contains(&GuildId(3)));
+        // Presences are only persisted when enabled.
+        assert!(loaded.guild_field(GuildId(1), |g| g.presences.is_empty()).unwrap());
+
+        cache.settings.write().persist_presences(true);
+        let mut snapshot = Vec::new();
+        cache.save_to(&mut snapshot).unwrap();
+        let loaded = Cache::new();
+        loaded.load_from(snapshot.as_slice()).unwrap();
+        assert!(loaded.guild_field(GuildId(1), |g| g.presences.contains_key(&UserId(5))).unwrap());
+
+        let outdated = json!({"version": 0, "cache": {}}).to_string();
+        assert!(matches!(
+            Cache::new().load_from(outdated.as_bytes()),
+            Err(crate::Error::Cache(CacheError::UnsupportedSnapshotVersion(0)))
+        ));
+    }
 }
@@ -0,0 +1,210 @@
+use std::hash::Hash;
+use std::io::{Read, Write};
+
+use dashmap::DashMap;
+use serde::ser::{Serialize, Serializer};
+
+use super::{Cache, CacheError};
+use crate::internal::prelude::*;
+use crate::json::{from_reader, from_value, to_writer};
+use crate::model::prelude::*;
+
+/// The version of the format written by [`Cache::save_to`].
+///
+/// This must be bumped whenever the snapshot format changes in a way older
+/// snapshots can not be read with.
+const SNAPSHOT_VERSION: u32 = 1;
+
+#[derive(Serialize)]
+struct SnapshotRef<'a> {
+    version: u32,
+    cache: CacheRef<'a>,
+}
+
+#[derive(Serialize)]
+struct CacheRef<'a> {
+    user: CurrentUser,
+    guilds: Guilds<'a>,
+    unavailable_guilds: Vec<GuildId>,
+    channels: Values<'a, ChannelId, GuildChannel>,
+    categories: Values<'a, ChannelId, ChannelCategory>,
+    private_channels: Values<'a, ChannelId, PrivateChannel>,
+    users: Values<'a, UserId, User>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    presences: Option<Values<'a, UserId, Presence>>,
+}
+
+#[derive(Deserialize)]
+struct Snapshot {
+    version: u32,
+    cache: Value,
+}
+
+#[derive(Deserialize)]
+struct CacheData {
+    user: CurrentUser,
+    guilds: Vec<Guild>,
+    unavailable_guilds: Vec<GuildId>,
+    channels: Vec<GuildChannel>,
+    categories: Vec<ChannelCategory>,
+    private_channels: Vec<PrivateChannel>,
+    users: Vec<User>,
+    #[serde(default)]
+    presences: Vec<Presence>,
+}
+
+/// Serializes the values of a map one at a time, without collecting them.
+struct Values<'a, K, V>(&'a DashMap<K, V>);
+
+impl<K: Eq + Hash, V: Clone + Serialize> Serialize for Values<'_, K, V> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter().map(|entry| entry.value().clone()))
+    }
+}
+
+struct Guilds<'a> {
+    guilds: &'a DashMap<GuildId, Guild>,
+    include_presences: bool,
+}
+
+impl Serialize for Guilds<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
+        serializer.collect_seq(self.guilds.iter().map(|entry| {
+            let mut guild = entry.value().clone();
+
+            if !self.include_presences {
+                guild.presences.clear();
+            }
+
+            guild
+        }))
+    }
+}
+
+impl Cache {
+    /// Writes a snapshot of the guilds, channels, users and the current user
+    /// to the given writer, to be loaded via [`Self::load_from`], e.g. on the
+    /// next start of the bot.
+    ///
+    /// Messages are never included, and presences only if
+    /// [`Settings::persist_presences`] is enabled.
+    ///
+    /// # Examples
+    ///
+    /// Save the cache to a file when shutting down:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// # fn run(cache: &Cache) -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::fs::File;
+    /// use std::io::BufWriter;
+    ///
+    /// cache.save_to(BufWriter::new(File::create("cache.json")?))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Json`] if the snapshot could not be serialized or
+    /// written to the writer.
+    ///
+    /// [`Settings::persist_presences`]: super::Settings::persist_presences
+    /// [`Error::Json`]: crate::Error::Json
+    pub fn save_to<W: Write>(&self, writer: W) -> Result<()> {
+        let include_presences = self.settings.read().persist_presences;
+
+        let snapshot = SnapshotRef {
+            version: SNAPSHOT_VERSION,
+            cache: CacheRef {
+                user: self.current_user(),
+                guilds: Guilds {
+                    guilds: &self.guilds,
+                    include_presences,
+                },
+                unavailable_guilds: self.unavailable_guilds.iter().map(|id| *id).collect(),
+                channels: Values(&self.channels),
+                categories: Values(&self.categories),
+                private_channels: Values(&self.private_channels),
+                users: Values(&self.users),
+                presences: include_presences.then(|| Values(&self.presences)),
+            },
+        };
+
+        to_writer(writer, &snapshot)
+    }
+
+    /// Hydrates the cache from a snapshot written by [`Self::save_to`].
+    ///
+    /// Entries already in the cache are replaced by those of the snapshot with
+    /// the same Id, and are kept otherwise. The cache is then kept up to date
+    /// by the events received from the gateway as usual.
+    ///
+    /// # Examples
+    ///
+    /// Load the cache from a file, if one was saved:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::cache::Cache;
+    /// # fn run(cache: &Cache) -> Result<(), Box<dyn std::error::Error>> {
+    /// use std::fs::File;
+    /// use std::io::BufReader;
+    ///
+    /// if let Ok(file) = File::open("cache.json") {
+    ///     cache.load_from(BufReader::new(file))?;
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`CacheError::UnsupportedSnapshotVersion`] if the snapshot was
+    /// written by an incompatible version of the library, or an
+    /// [`Error::Json`] if it could not be read or deserialized. The cache is
+    /// left unchanged in both cases.
+    ///
+    /// [`Error::Json`]: crate::Error::Json
+    pub fn load_from<R: Read>(&self, reader: R) -> Result<()> {
+        let snapshot: Snapshot = from_reader(reader)?;
+
+        if snapshot.version != SNAPSHOT_VERSION {
+            return Err(CacheError::UnsupportedSnapshotVersion(snapshot.version).into());
+        }
+
+        let data: CacheData = from_value(snapshot.cache)?;
+
+        *self.user.write() = data.user;
+
+        for guild_id in data.unavailable_guilds {
+            self.unavailable_guilds.insert(guild_id);
+        }
+
+        for guild in data.guilds {
+            self.unavailable_guilds.remove(&guild.id);
+            self.guilds.insert(guild.id, guild);
+        }
+
+        for channel in data.channels {
+            self.channels.insert(channel.id, channel);
+        }
+
+        for category in data.categories {
+            self.categories.insert(category.id, category);
+        }
+
+        for channel in data.private_channels {
+            self.insert_private_channel(channel);
+        }
+
+        for user in data.users {
+            self.users.insert(user.id, user);
+        }
+
+        for presence in data.presences {
+            self.presences.insert(presence.user.id, presence);
+        }
+
+        Ok(())
+    }
+}
@@ -10,13 +10,58 @@
 /// let mut settings = CacheSettings::new();
 /// settings.max_messages(10);
 /// ```
-#[derive(Clone, Debug, Default)]
+#[derive(Clone, Debug)]
 #[non_exhaustive]
 pub struct Settings {
     /// The maximum number of messages to store in a channel's message cache.
     ///
     /// Defaults to 0.
     pub max_messages: usize,
+    /// Whether messages are cached at all, on top of [`max_messages`].
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [`max_messages`]: #structfield.max_messages
+    pub cache_messages: bool,
+    /// Whether guild and private presences are cached.
+    ///
+    /// Defaults to `true`.
+    pub cache_presences: bool,
+    /// Whether guild members, and the users received along with them, are
+    /// cached.
+    ///
+    /// The current user's own member is cached regardless, so that permission
+    /// checks done on the bot's behalf keep working. Look-ups of other members,
+    /// such as [`Cache::member`], return `None`, and methods such as
+    /// [`GuildId::member`] will fetch them over HTTP instead.
+    ///
+    /// Defaults to `true`.
+    ///
+    /// [`Cache::member`]: super::Cache::member
+    /// [`GuildId::member`]: crate::model::id::GuildId::member
+    pub cache_members: bool,
+    /// Whether presences are included in the snapshots written by
+    /// [`Cache::save_to`].
+    ///
+    /// Presences change often, so they are likely to be outdated by the time
+    /// a snapshot is loaded.
+    ///
+    /// Defaults to `false`.
+    ///
+    /// [`Cache::save_to`]: super::Cache::save_to
+    pub persist_presences: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            max_messages: 0,
+            cache_messages: true,
+            cache_presences: true,
+            cache_members: true,
+            persist_presences: false,
+        }
+    }
 }
 
 impl Settings {
@@ -48,4 +93,59 @@ impl Settings {
 
         self
     }
+
+    /// Sets whether messages are cached.
+    ///
+    /// Refer to [`cache_messages`] for more information.
+    ///
+    /// [`cache_messages`]: #structfield.cache_messages
+    pub fn cache_messages(&mut self, cache_messages: bool) -> &mut Self {
+        self.cache_messages = cache_messages;
+
+        self
+    }
+
+    /// Sets whether presences are cached.
+    ///
+    /// Refer to [`cache_presences`] for more information.
+    ///
+    /// [`cache_presences`]: #structfield.cache_presences
+    pub fn cache_presences(&mut self, cache_presences: bool) -> &mut Self {
+        self.cache_presences = cache_presences;
+
+        self
+    }
+
+    /// Sets whether guild members are cached.
+    ///
+    /// Refer to [`cache_members`] for more information.
+    ///
+    /// # Examples
+    ///
+    /// Only cache guild and channel metadata:
+    ///
+    /// ```rust
+    /// use serenity::cache::Settings;
+    ///
+    /// let mut settings = Settings::new();
+    /// settings.cache_messages(false).cache_presences(false).cache_members(false);
+    /// ```
+    ///
+    /// [`cache_members`]: #structfield.cache_members
+    pub fn cache_members(&mut self, cache_members: bool) -> &mut Self {
+        self.cache_members = cache_members;
+
+        self
+    }
+
+    /// Sets whether presences are included in cache snapshots.
+    ///
+    /// Refer to [`persist_presences`] for more information.
+    ///
+    /// [`persist_presences`]: #structfield.persist_presences
+    pub fn persist_presences(&mut self, persist_presences: bool) -> &mut Self {
+        self.persist_presences = persist_presences;
+
+        self
+    }
 }
@@ -3,10 +3,17 @@
 
 use super::ShardId;
 use crate::gateway::ConnectionStage;
+#[cfg(feature = "cache")]
+use crate::model::id::GuildId;
 
-#[allow(clippy::enum_variant_names)]
 #[derive(Clone, Debug)]
 pub(crate) enum ClientEvent {
+    /// The guilds of a shard's [`Ready`] event were all received, or the wait
+    /// for them timed out.
+    ///
+    /// [`Ready`]: crate::model::gateway::Ready
+    #[cfg(feature = "cache")]
+    CacheReady(Vec<GuildId>),
     ShardStageUpdate(ShardStageUpdateEvent),
 }
 
@@ -50,17 +50,26 @@ mod shard_messenger;
 mod shard_queuer;
 mod shard_runner;
 mod shard_runner_message;
+mod shard_settings;
 
+use std::collections::HashMap;
 use std::fmt;
-use std::time::Duration as StdDuration;
+use std::sync::Arc;
+use std::time::{Duration as StdDuration, Instant};
 
-pub use self::shard_manager::{ShardManager, ShardManagerOptions};
+use futures::channel::mpsc::UnboundedSender as Sender;
+use tokio::sync::Mutex;
+
+pub use self::shard_manager::{ShardHealth, ShardManager, ShardManagerHealth, ShardManagerOptions};
 pub use self::shard_manager_monitor::{ShardManagerError, ShardManagerMonitor};
 pub use self::shard_messenger::ShardMessenger;
 pub use self::shard_queuer::ShardQueuer;
+pub(crate) use self::shard_runner::ShardQueueMetrics;
 pub use self::shard_runner::{ShardRunner, ShardRunnerOptions};
 pub use self::shard_runner_message::{ChunkGuildFilter, ShardRunnerMessage};
+pub use self::shard_settings::ShardSettings;
 use crate::gateway::ConnectionStage;
+use crate::model::gateway::GatewayIntents;
 
 /// A message either for a [`ShardManager`] or a [`ShardRunner`].
 // Once we can use `Box` as part of a pattern, we will reconsider boxing.
@@ -79,7 +88,14 @@ pub enum ShardManagerMessage {
     /// Indicator that a [`ShardManagerMonitor`] should restart a shard.
     Restart(ShardId),
     /// An update from a shard runner,
-    ShardUpdate { id: ShardId, latency: Option<StdDuration>, stage: ConnectionStage },
+    #[non_exhaustive]
+    ShardUpdate {
+        id: ShardId,
+        latency: Option<StdDuration>,
+        stage: ConnectionStage,
+        last_heartbeat_ack: Option<Instant>,
+        seq: u64,
+    },
     /// Indicator that a [`ShardManagerMonitor`] should fully shutdown a shard
     /// without bringing it back up.
     Shutdown(ShardId, u16),
@@ -115,6 +131,7 @@ pub enum ShardManagerMessage {
 ///
 /// This should usually be wrapped in a [`ShardClientMessage`].
 #[derive(Clone, Debug)]
+#[non_exhaustive]
 pub enum ShardQueuerMessage {
     /// Message to start a shard, where the 0-index element is the ID of the
     /// Shard to start and the 1-index element is the total shards in use.
@@ -123,11 +140,26 @@ pub enum ShardQueuerMessage {
     Shutdown,
     /// Message to dequeue/shutdown a shard.
     ShutdownShard(ShardId, u16),
+    /// Message to set the gateway intents of shards started from now on.
+    SetIntents(GatewayIntents),
+    /// Message to start a shard like [`Self::Start`], but to record its runner
+    /// in the given map and to send its [`ShardManagerMessage`]s over the
+    /// given channel, instead of the queuer's own.
+    ///
+    /// This is used by [`ShardManager::reshard`] to start a new set of shards
+    /// next to the running ones. The start is skipped if the channel has been
+    /// closed in the meantime.
+    StartStaged {
+        id: ShardId,
+        total: ShardId,
+        runners: Arc<Mutex<HashMap<ShardId, ShardRunnerInfo>>>,
+        manager_tx: Sender<ShardManagerMessage>,
+    },
 }
 
 /// A light tuplestruct wrapper around a u64 to verify type correctness when
 /// working with the IDs of shards.
-#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 pub struct ShardId(pub u64);
 
 impl fmt::Display for ShardId {
@@ -141,6 +173,7 @@ impl fmt::Display for ShardId {
 /// The [`ShardId`] is not included because, as it stands, you probably already
 /// know the Id if you obtained this.
 #[derive(Debug)]
+#[non_exhaustive]
 pub struct ShardRunnerInfo {
     /// The latency between when a heartbeat was sent and when the
     /// acknowledgement was received.
@@ -150,6 +183,11 @@ pub struct ShardRunnerInfo {
     pub runner_tx: ShardMessenger,
     /// The current connection stage of the shard.
     pub stage: ConnectionStage,
+    /// When the last heartbeat acknowledgement was received.
+    pub last_heartbeat_ack: Option<Instant>,
+    /// The sequence number of the last event received, as of the last update
+    /// from the shard runner.
+    pub seq: u64,
 }
 
 impl AsRef<ShardMessenger> for ShardRunnerInfo {
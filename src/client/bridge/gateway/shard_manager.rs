@@ -1,26 +1,33 @@
 use std::collections::{HashMap, VecDeque};
+use std::convert::TryFrom;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use futures::channel::mpsc::{self, UnboundedReceiver as Receiver, UnboundedSender as Sender};
 use futures::StreamExt;
-use tokio::sync::{Mutex, RwLock};
+use tokio::sync::{watch, Mutex, RwLock};
 use tokio::time::timeout;
-use tracing::{info, instrument, warn};
+use tracing::{debug, info, instrument, warn};
 use typemap_rev::TypeMap;
 
 use super::{
+    ShardClientMessage,
     ShardId,
     ShardManagerMessage,
     ShardManagerMonitor,
     ShardQueuer,
     ShardQueuerMessage,
     ShardRunnerInfo,
+    ShardSettings,
 };
 #[cfg(feature = "voice")]
 use crate::client::bridge::voice::VoiceGatewayManager;
 use crate::client::{EventHandler, RawEventHandler};
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
+use crate::gateway::{ConnectionStage, CurrentPresence, GatewayError, InterMessage};
 use crate::internal::prelude::*;
 use crate::internal::tokio::spawn_named;
 use crate::model::gateway::GatewayIntents;
@@ -112,17 +119,34 @@ pub struct ShardManager {
     shard_total: u64,
     shard_queuer: Sender<ShardQueuerMessage>,
     shard_shutdown: Receiver<ShardId>,
+    /// The presence set for all shards, given to shards when they are started.
+    presence: Arc<Mutex<Option<CurrentPresence>>>,
+    /// Notified by the monitor whenever it recorded an update of a shard.
+    shard_updates: watch::Receiver<()>,
+    /// Whether a new set of shards is being started by [`Self::reshard`].
+    resharding: Arc<AtomicBool>,
 }
 
 impl ShardManager {
     /// Creates a new shard manager, returning both the manager and a monitor
     /// for usage in a separate thread.
     pub async fn new(opt: ShardManagerOptions<'_>) -> (Arc<Mutex<Self>>, ShardManagerMonitor) {
+        Self::new_with_settings(opt, ShardSettings::default()).await
+    }
+
+    /// Creates a new shard manager like [`Self::new`], applying the given
+    /// settings to every shard it starts.
+    pub async fn new_with_settings(
+        opt: ShardManagerOptions<'_>,
+        settings: ShardSettings,
+    ) -> (Arc<Mutex<Self>>, ShardManagerMonitor) {
         let (thread_tx, thread_rx) = mpsc::unbounded();
         let (shard_queue_tx, shard_queue_rx) = mpsc::unbounded();
 
         let runners = Arc::new(Mutex::new(HashMap::new()));
+        let presence = Arc::new(Mutex::new(None));
         let (shutdown_send, shutdown_recv) = mpsc::unbounded();
+        let (updates_send, updates_recv) = watch::channel(());
 
         let mut shard_queuer = ShardQueuer {
             data: Arc::clone(opt.data),
@@ -140,6 +164,8 @@ impl ShardManager {
             ws_url: Arc::clone(opt.ws_url),
             cache_and_http: Arc::clone(opt.cache_and_http),
             intents: opt.intents,
+            settings,
+            presence: Arc::clone(&presence),
         };
 
         spawn_named("shard_queuer::run", async move {
@@ -153,13 +179,18 @@ impl ShardManager {
             shard_queuer: shard_queue_tx,
             shard_total: opt.shard_total,
             shard_shutdown: shutdown_recv,
-            runners,
+            runners: Arc::clone(&runners),
+            presence,
+            shard_updates: updates_recv,
+            resharding: Arc::new(AtomicBool::new(false)),
         }));
 
         (Arc::clone(&manager), ShardManagerMonitor {
             rx: thread_rx,
             manager,
             shutdown: shutdown_send,
+            runners,
+            shard_updates: updates_send,
         })
     }
 
@@ -207,7 +238,23 @@ impl ShardManager {
     ///
     /// This sends a shutdown signal to a shard's associated [`ShardRunner`],
     /// and then queues a initialization of a shard runner for the same shard
-    /// via the [`ShardQueuer`].
+    /// via the [`ShardQueuer`]. Other shards are left untouched.
+    ///
+    /// The restarted shard starts a new session with a fresh identify rather
+    /// than resuming. As the [`ShardQueuer`] starts shards one at a time, the
+    /// identify rate limit is respected.
+    ///
+    /// Returns a future that resolves once the restarted shard has reached
+    /// [`ConnectionStage::Connected`]. It does not need to be awaited.
+    ///
+    /// While [`Self::reshard`] is starting a new set of shards, the restart is
+    /// dropped and the returned future resolves immediately, as the shard is
+    /// about to be replaced.
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`GatewayError::ConnectTimeout`] if
+    /// the shard did not connect within a minute.
     ///
     /// # Examples
     ///
@@ -232,21 +279,135 @@ impl ShardManager {
     /// let mut client =
     ///     Client::builder(&token, GatewayIntents::default()).event_handler(Handler).await?;
     ///
-    /// // restart shard ID 7
-    /// client.shard_manager.lock().await.restart(ShardId(7)).await;
+    /// // restart shard ID 7, and wait for it to connect again
+    /// let connected = client.shard_manager.lock().await.restart(ShardId(7)).await;
+    /// connected.await?;
     /// #     Ok(())
     /// # }
     /// ```
     ///
     /// [`ShardRunner`]: super::ShardRunner
+    // The returned future only needs to be awaited to wait for the reconnect.
+    #[allow(clippy::async_yields_async)]
     #[instrument(skip(self))]
-    pub async fn restart(&mut self, shard_id: ShardId) {
+    pub async fn restart(
+        &mut self,
+        shard_id: ShardId,
+    ) -> impl Future<Output = Result<()>> + Send + 'static {
+        if self.resharding.load(Ordering::Acquire) {
+            warn!("Dropping the restart of shard {}, as the shards are being resharded", shard_id);
+
+            return wait_until_connected(
+                Arc::clone(&self.runners),
+                self.shard_updates.clone(),
+                vec![],
+            );
+        }
+
         info!("Restarting shard {}", shard_id);
         self.shutdown(shard_id, 4000).await;
 
         let shard_total = self.shard_total;
 
         self.boot([shard_id, ShardId(shard_total)]);
+
+        wait_until_connected(Arc::clone(&self.runners), self.shard_updates.clone(), vec![shard_id])
+    }
+
+    /// Changes the total number of shards without downtime.
+    ///
+    /// If the manager is responsible for every shard, it stays responsible
+    /// for all `total` shards. Otherwise, such as when the shards are split
+    /// across processes, it keeps its range of shards, limited to those below
+    /// `total`.
+    ///
+    /// A new set of shards is started via the [`ShardQueuer`] while the old
+    /// set keeps running. Once every new shard has reached [`ConnectionStage::Connected`],
+    /// the new shards replace the old ones in [`Self::runners`], and the old
+    /// shards are shut down.
+    ///
+    /// Until then, the new shards are tracked separately, so they are not
+    /// mistaken for the old shards sharing their Ids. New shards that fail are
+    /// started again, while restarts of the old shards are dropped.
+    ///
+    /// Returns a future that performs the swap once the new shards connected.
+    /// The manager does not need to be locked while it runs, and it should be
+    /// awaited, as the old shards keep running if it is dropped early.
+    ///
+    /// While both sets are running, events are received by both of them, so
+    /// the event handler may see some events twice.
+    ///
+    /// **Note**: Increasing the number of shards is always possible.
+    /// Decreasing it is best-effort, as Discord closes the connection of a
+    /// shard that would be responsible for too many guilds.
+    ///
+    /// **Note**: As shards are started one at a time to respect the identify
+    /// rate limit, this may take a while.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # async fn run(client: Client) -> Result<(), Box<dyn std::error::Error>> {
+    /// let swapped = client.shard_manager.lock().await.reshard(16).await;
+    /// swapped.await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`GatewayError::ConnectTimeout`] if
+    /// the new shards did not connect in time, allowing a minute per shard, or
+    /// to one of the other [`GatewayError`]s if they were rejected by Discord.
+    /// The new shards are shut down, and the old shards keep running.
+    // The returned future only needs to be awaited to wait for the swap.
+    #[allow(clippy::async_yields_async)]
+    #[instrument(skip(self))]
+    pub async fn reshard(
+        &mut self,
+        total: u64,
+    ) -> impl Future<Output = Result<()>> + Send + 'static {
+        let unchanged = total == self.shard_total;
+
+        let staged = Arc::new(Mutex::new(HashMap::new()));
+        let (staged_tx, staged_rx) = mpsc::unbounded();
+        let (index, init) =
+            resharded_range(self.shard_index, self.shard_init, self.shard_total, total);
+        let shard_ids = (index..index + init).map(ShardId).collect::<Vec<_>>();
+
+        if !unchanged {
+            info!("Resharding from {} to {} shards", self.shard_total, total);
+
+            self.resharding.store(true, Ordering::Release);
+            self.shard_index = index;
+            self.shard_init = init;
+            self.shard_total = total;
+
+            for &shard_id in &shard_ids {
+                self.boot_staged([shard_id, ShardId(total)], &staged, &staged_tx);
+            }
+        }
+
+        let swap = StagedShards {
+            runners: staged,
+            tx: staged_tx,
+            rx: staged_rx,
+            shard_queuer: self.shard_queuer.clone(),
+            shard_ids,
+            total,
+            resharding: Arc::clone(&self.resharding),
+        };
+        let runners = Arc::clone(&self.runners);
+        let monitor_tx = self.monitor_tx.clone();
+
+        async move {
+            if unchanged {
+                return Ok(());
+            }
+
+            swap.swap_into(&runners, monitor_tx).await
+        }
     }
 
     /// Returns the [`ShardId`]s of the shards that have been instantiated and
@@ -258,6 +419,106 @@ impl ShardManager {
         self.runners.lock().await.keys().copied().collect()
     }
 
+    /// Returns a snapshot of the state of every instantiated shard, such as to
+    /// serve liveness or readiness probes.
+    ///
+    /// The shards are sorted by their Id.
+    #[instrument(skip(self))]
+    pub async fn health(&self) -> ShardManagerHealth {
+        let now = Instant::now();
+        let runners = self.runners.lock().await;
+
+        let mut shards = runners
+            .iter()
+            .map(|(&id, runner)| ShardHealth {
+                id,
+                stage: runner.stage,
+                latency: runner.latency,
+                last_heartbeat_ack: runner
+                    .last_heartbeat_ack
+                    .map(|ack| now.saturating_duration_since(ack)),
+                seq: runner.seq,
+                queue_depth: runner.runner_tx.queue_metrics.depth(),
+                queue_high_watermark: runner.runner_tx.queue_metrics.high_watermark(),
+            })
+            .collect::<Vec<_>>();
+
+        shards.sort_unstable_by_key(|shard| shard.id);
+
+        ShardManagerHealth {
+            shards,
+        }
+    }
+
+    /// Sets the presence of every shard, including shards that are started
+    /// after this call, such as when restarting or resharding.
+    ///
+    /// Each running shard sends a single presence update, so this stays well
+    /// within the gateway's per-connection command limit, as long as it is not
+    /// called in quick succession.
+    ///
+    /// **Note**: [`OnlineStatus::Offline`] is not a valid status for the current
+    /// user, so [`OnlineStatus::Invisible`] is used instead.
+    ///
+    /// [`OnlineStatus::Offline`]: crate::model::user::OnlineStatus::Offline
+    /// [`OnlineStatus::Invisible`]: crate::model::user::OnlineStatus::Invisible
+    #[instrument(skip(self))]
+    pub async fn set_presence_all(&self, presence: CurrentPresence) {
+        let (activity, status) = presence.clone();
+        *self.presence.lock().await = Some(presence);
+
+        for runner in self.runners.lock().await.values() {
+            runner.runner_tx.set_presence(activity.clone(), status);
+        }
+    }
+
+    /// Changes the gateway intents of every shard, including shards that are
+    /// started after this call, such as when restarting or resharding.
+    ///
+    /// As intents can only be set when identifying, every running shard is
+    /// restarted via the [`ShardQueuer`], starting a new session with a fresh
+    /// identify carrying the new intents.
+    ///
+    /// Returns a future that resolves once all restarted shards have reached
+    /// [`ConnectionStage::Connected`]. It does not need to be awaited.
+    ///
+    /// **Note**: Shards do not resume their previous sessions, so any events
+    /// sent while a shard is reconnecting are lost.
+    ///
+    /// **Note**: Privileged intents must be enabled for the bot in the
+    /// developer portal. Otherwise, the restarted shards are rejected, and
+    /// the [`ShardManagerMonitor`] shuts down all shards with
+    /// [`ShardManagerError::DisallowedGatewayIntents`].
+    ///
+    /// # Errors
+    ///
+    /// The returned future resolves to a [`GatewayError::ConnectTimeout`] if
+    /// the shards did not connect in time, allowing a minute per shard as they
+    /// are started one after another.
+    ///
+    /// [`ShardManagerError::DisallowedGatewayIntents`]: super::ShardManagerError::DisallowedGatewayIntents
+    // The returned future only needs to be awaited to wait for the reconnect.
+    #[allow(clippy::async_yields_async)]
+    #[instrument(skip(self))]
+    pub async fn update_intents(
+        &mut self,
+        intents: GatewayIntents,
+    ) -> impl Future<Output = Result<()>> + Send + 'static {
+        info!("Updating gateway intents to {:?}", intents);
+
+        drop(self.shard_queuer.unbounded_send(ShardQueuerMessage::SetIntents(intents)));
+
+        let shard_ids = self.shards_instantiated().await;
+        let shard_total = self.shard_total;
+
+        for &shard_id in &shard_ids {
+            self.shutdown(shard_id, 4000).await;
+            self.boot([shard_id, ShardId(shard_total)]);
+        }
+
+        wait_until_connected(Arc::clone(&self.runners), self.shard_updates.clone(), shard_ids)
+    }
+
     /// Attempts to shut down the shard runner by Id.
     ///
     /// Returns a boolean indicating whether a shard runner was present. This is
@@ -276,19 +537,20 @@ impl ShardManager {
 
         drop(self.shard_queuer.unbounded_send(ShardQueuerMessage::ShutdownShard(shard_id, code)));
 
-        match timeout(TIMEOUT, self.shard_shutdown.next()).await {
-            Ok(Some(shutdown_shard_id)) => {
-                if shutdown_shard_id != shard_id {
-                    warn!(
-                        "Failed to cleanly shutdown shard {}: Shutdown channel sent incorrect ID",
-                        shard_id,
-                    );
+        // Confirmations of other shards, such as of those replaced by
+        // `reshard`, are skipped.
+        let finished = async {
+            while let Some(shutdown_shard_id) = self.shard_shutdown.next().await {
+                if shutdown_shard_id == shard_id {
+                    break;
                 }
-            },
-            Ok(None) => (),
-            Err(why) => {
-                warn!("Failed to cleanly shutdown shard {}, reached timeout: {:?}", shard_id, why);
-            },
+
+                debug!("Skipping shutdown confirmation of shard {}", shutdown_shard_id);
+            }
+        };
+
+        if let Err(why) = timeout(TIMEOUT, finished).await {
+            warn!("Failed to cleanly shutdown shard {}, reached timeout: {:?}", shard_id, why);
         }
 
         self.runners.lock().await.remove(&shard_id);
@@ -329,6 +591,207 @@ impl ShardManager {
 
         drop(self.shard_queuer.unbounded_send(msg));
     }
+
+    fn boot_staged(
+        &mut self,
+        shard_info: [ShardId; 2],
+        runners: &Arc<Mutex<HashMap<ShardId, ShardRunnerInfo>>>,
+        manager_tx: &Sender<ShardManagerMessage>,
+    ) {
+        info!("Telling shard queuer to stage shard {}", shard_info[0]);
+
+        let msg = ShardQueuerMessage::StartStaged {
+            id: shard_info[0],
+            total: shard_info[1],
+            runners: Arc::clone(runners),
+            manager_tx: manager_tx.clone(),
+        };
+
+        drop(self.shard_queuer.unbounded_send(msg));
+    }
+}
+
+/// A set of shards being started by [`ShardManager::reshard`], which report to
+/// their own channel until they replace the running shards.
+struct StagedShards {
+    runners: Arc<Mutex<HashMap<ShardId, ShardRunnerInfo>>>,
+    tx: Sender<ShardManagerMessage>,
+    rx: Receiver<ShardManagerMessage>,
+    shard_queuer: Sender<ShardQueuerMessage>,
+    shard_ids: Vec<ShardId>,
+    total: u64,
+    resharding: Arc<AtomicBool>,
+}
+
+impl StagedShards {
+    /// Waits for all staged shards to connect, and then replaces the given
+    /// runners with them, shutting the replaced runners down.
+    async fn swap_into(
+        mut self,
+        runners: &Mutex<HashMap<ShardId, ShardRunnerInfo>>,
+        monitor_tx: Sender<ShardManagerMessage>,
+    ) -> Result<()> {
+        let limit =
+            CONNECT_TIMEOUT * u32::try_from(self.shard_ids.len()).unwrap_or(u32::MAX).max(1);
+
+        match timeout(limit, self.wait_until_connected()).await {
+            Ok(Ok(())) => {},
+            Ok(Err(why)) => return Err(why),
+            Err(_) => return Err(Error::Gateway(GatewayError::ConnectTimeout)),
+        }
+
+        let staged = std::mem::take(&mut *self.runners.lock().await);
+        let old_runners = std::mem::replace(&mut *runners.lock().await, staged);
+
+        info!("New shards connected, shutting down the {} old shards", old_runners.len());
+
+        for (shard_id, runner) in &old_runners {
+            let shutdown = ShardManagerMessage::Shutdown(*shard_id, 1000);
+            let msg = InterMessage::Client(Box::new(ShardClientMessage::Manager(shutdown)));
+
+            drop(runner.runner_tx.tx.unbounded_send(msg));
+        }
+
+        // From now on, the new shards report to the monitor like any other.
+        let mut rx = std::mem::replace(&mut self.rx, mpsc::unbounded().1);
+        spawn_named("shard_manager::forward_staged", async move {
+            while let Some(msg) = rx.next().await {
+                if monitor_tx.unbounded_send(msg).is_err() {
+                    break;
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    async fn wait_until_connected(&mut self) -> Result<()> {
+        while let Some(msg) = self.rx.next().await {
+            match msg {
+                ShardManagerMessage::ShardUpdate {
+                    id,
+                    latency,
+                    stage,
+                    last_heartbeat_ack,
+                    seq,
+                } => {
+                    let mut runners = self.runners.lock().await;
+
+                    if let Some(runner) = runners.get_mut(&id) {
+                        runner.latency = latency;
+                        runner.stage = stage;
+                        runner.last_heartbeat_ack = last_heartbeat_ack;
+                        runner.seq = seq;
+                    }
+
+                    let connected = self.shard_ids.iter().all(|id| {
+                        runners.get(id).map_or(false, |r| r.stage == ConnectionStage::Connected)
+                    });
+
+                    if connected {
+                        return Ok(());
+                    }
+                },
+                ShardManagerMessage::Restart(id) => {
+                    warn!("Staged shard {} failed, starting it again", id);
+
+                    self.runners.lock().await.remove(&id);
+
+                    drop(self.shard_queuer.unbounded_send(ShardQueuerMessage::StartStaged {
+                        id,
+                        total: ShardId(self.total),
+                        runners: Arc::clone(&self.runners),
+                        manager_tx: self.tx.clone(),
+                    }));
+                },
+                ShardManagerMessage::ShardInvalidAuthentication => {
+                    return Err(Error::Gateway(GatewayError::InvalidAuthentication));
+                },
+                ShardManagerMessage::ShardInvalidGatewayIntents => {
+                    return Err(Error::Gateway(GatewayError::InvalidGatewayIntents));
+                },
+                ShardManagerMessage::ShardDisallowedGatewayIntents => {
+                    return Err(Error::Gateway(GatewayError::DisallowedGatewayIntents));
+                },
+                _ => {},
+            }
+        }
+
+        Err(Error::Gateway(GatewayError::ConnectTimeout))
+    }
+}
+
+impl Drop for StagedShards {
+    /// Shuts down the staged shards that did not replace the running ones, such
+    /// as after a timeout. Pending starts are skipped by the queuer, as the
+    /// channel is closed.
+    fn drop(&mut self) {
+        self.rx.close();
+        self.resharding.store(false, Ordering::Release);
+
+        if let Ok(runners) = self.runners.try_lock() {
+            for (shard_id, runner) in runners.iter() {
+                let shutdown = ShardManagerMessage::Shutdown(*shard_id, 1000);
+                let msg = InterMessage::Client(Box::new(ShardClientMessage::Manager(shutdown)));
+
+                drop(runner.runner_tx.tx.unbounded_send(msg));
+            }
+        }
+    }
+}
+
+/// Returns the index of the first shard and the number of shards a manager is
+/// responsible for after resharding from `total_before` to `total` shards.
+///
+/// A manager responsible for every shard stays so. Otherwise, it keeps its
+/// range, limited to the shards below `total`.
+fn resharded_range(index: u64, init: u64, total_before: u64, total: u64) -> (u64, u64) {
+    if index == 0 && init == total_before {
+        (0, total)
+    } else {
+        (index, init.min(total.saturating_sub(index)))
+    }
+}
+
+/// How long each shard waited for by [`wait_until_connected`] may take to
+/// connect. As shards are started one after another, the timeout grows with
+/// their number.
+const CONNECT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Waits until all given shards have reached [`ConnectionStage::Connected`],
+/// checking their stage whenever the monitor recorded an update.
+async fn wait_until_connected(
+    runners: Arc<Mutex<HashMap<ShardId, ShardRunnerInfo>>>,
+    mut updates: watch::Receiver<()>,
+    shard_ids: Vec<ShardId>,
+) -> Result<()> {
+    let limit = CONNECT_TIMEOUT * u32::try_from(shard_ids.len()).unwrap_or(u32::MAX).max(1);
+
+    let wait = async {
+        loop {
+            let connected = {
+                let runners = runners.lock().await;
+
+                shard_ids.iter().all(|id| {
+                    runners.get(id).map_or(false, |r| r.stage == ConnectionStage::Connected)
+                })
+            };
+
+            if connected {
+                return Ok(());
+            }
+
+            // The monitor stopped, so no shard will connect anymore.
+            if updates.changed().await.is_err() {
+                return Err(Error::Gateway(GatewayError::ConnectTimeout));
+            }
+        }
+    };
+
+    match timeout(limit, wait).await {
+        Ok(result) => result,
+        Err(_) => Err(Error::Gateway(GatewayError::ConnectTimeout)),
+    }
 }
 
 impl Drop for ShardManager {
@@ -359,3 +822,223 @@ pub struct ShardManagerOptions<'a> {
     pub cache_and_http: &'a Arc<CacheAndHttp>,
     pub intents: GatewayIntents,
 }
+
+/// The state of every shard of a [`ShardManager`], as returned by
+/// [`ShardManager::health`].
+#[derive(Clone, Debug, Serialize)]
+#[non_exhaustive]
+pub struct ShardManagerHealth {
+    /// The state of each instantiated shard, sorted by Id.
+    pub shards: Vec<ShardHealth>,
+}
+
+impl ShardManagerHealth {
+    /// Whether there is at least one shard, and all shards are connected.
+    #[must_use]
+    pub fn is_healthy(&self) -> bool {
+        !self.shards.is_empty()
+            && self.shards.iter().all(|shard| shard.stage == ConnectionStage::Connected)
+    }
+}
+
+/// The state of a single shard, as part of a [`ShardManagerHealth`].
+#[derive(Clone, Debug, Serialize)]
+#[non_exhaustive]
+pub struct ShardHealth {
+    /// The Id of the shard.
+    pub id: ShardId,
+    /// The current connection stage of the shard.
+    pub stage: ConnectionStage,
+    /// The latency between when the last heartbeat was sent and when its
+    /// acknowledgement was received.
+    pub latency: Option<Duration>,
+    /// The time elapsed since the last heartbeat acknowledgement was received.
+    pub last_heartbeat_ack: Option<Duration>,
+    /// The sequence number of the last event received, as of the last
+    /// heartbeat acknowledgement or stage change.
+    pub seq: u64,
+    /// The number of events dispatched by the shard runner whose
+    /// [`EventHandler`] invocation has not finished yet.
+    pub queue_depth: usize,
+    /// The highest number of events whose [`EventHandler`] invocation had not
+    /// finished at once, since the shard runner was started.
+    pub queue_high_watermark: usize,
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use std::sync::atomic::AtomicBool;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use futures::channel::mpsc::{self, UnboundedSender as Sender};
+    use futures::StreamExt;
+    use tokio::sync::Mutex;
+
+    use super::{resharded_range, ShardHealth, ShardManagerHealth, StagedShards};
+    use crate::client::bridge::gateway::{
+        ShardClientMessage,
+        ShardId,
+        ShardManagerMessage,
+        ShardMessenger,
+        ShardQueuerMessage,
+        ShardRunnerInfo,
+    };
+    use crate::gateway::{ConnectionStage, InterMessage};
+
+    fn runner(tx: Sender<InterMessage>) -> ShardRunnerInfo {
+        ShardRunnerInfo {
+            latency: None,
+            runner_tx: ShardMessenger::new(tx),
+            stage: ConnectionStage::Disconnected,
+            last_heartbeat_ack: None,
+            seq: 0,
+        }
+    }
+
+    fn is_shutdown(msg: Option<InterMessage>, shard_id: ShardId) -> bool {
+        match msg {
+            Some(InterMessage::Client(msg)) => match *msg {
+                ShardClientMessage::Manager(ShardManagerMessage::Shutdown(id, code)) => {
+                    id == shard_id && code == 1000
+                },
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    fn connected(seq: u64) -> ShardManagerMessage {
+        ShardManagerMessage::ShardUpdate {
+            id: ShardId(0),
+            latency: None,
+            stage: ConnectionStage::Connected,
+            last_heartbeat_ack: None,
+            seq,
+        }
+    }
+
+    #[tokio::test]
+    async fn staged_swap() {
+        let (old_tx, mut old_rx) = mpsc::unbounded();
+        let runners = Mutex::new(std::iter::once((ShardId(0), runner(old_tx))).collect());
+
+        let (new_tx, _new_rx) = mpsc::unbounded();
+        let staged = Mutex::new(std::iter::once((ShardId(0), runner(new_tx))).collect());
+        let (tx, rx) = mpsc::unbounded();
+        let (shard_queuer, _queuer_rx) = mpsc::unbounded();
+        let (monitor_tx, mut monitor_rx) = mpsc::unbounded();
+
+        tx.unbounded_send(connected(3)).unwrap();
+
+        let swap = StagedShards {
+            runners: Arc::new(staged),
+            tx: tx.clone(),
+            rx,
+            shard_queuer,
+            shard_ids: vec![ShardId(0)],
+            total: 1,
+            resharding: Arc::new(AtomicBool::new(true)),
+        };
+        swap.swap_into(&runners, monitor_tx).await.unwrap();
+
+        let runners = runners.lock().await;
+        assert_eq!(runners[&ShardId(0)].stage, ConnectionStage::Connected);
+        assert_eq!(runners[&ShardId(0)].seq, 3);
+        assert!(is_shutdown(old_rx.next().await, ShardId(0)));
+
+        // After the swap, the new shards report to the monitor.
+        tx.unbounded_send(connected(4)).unwrap();
+        assert_eq!(monitor_rx.next().await, Some(connected(4)));
+    }
+
+    #[tokio::test]
+    async fn staged_failure() {
+        let runners = Mutex::new(HashMap::new());
+
+        let (failed_tx, mut failed_rx) = mpsc::unbounded();
+        let (other_tx, mut other_rx) = mpsc::unbounded();
+        let staged = Mutex::new(
+            vec![(ShardId(0), runner(failed_tx)), (ShardId(1), runner(other_tx))]
+                .into_iter()
+                .collect(),
+        );
+        let (tx, rx) = mpsc::unbounded();
+        let (shard_queuer, mut queuer_rx) = mpsc::unbounded();
+        let (monitor_tx, _monitor_rx) = mpsc::unbounded();
+
+        tx.unbounded_send(ShardManagerMessage::Restart(ShardId(0))).unwrap();
+        tx.unbounded_send(ShardManagerMessage::ShardInvalidAuthentication).unwrap();
+
+        let swap = StagedShards {
+            runners: Arc::new(staged),
+            tx: tx.clone(),
+            rx,
+            shard_queuer,
+            shard_ids: vec![ShardId(0), ShardId(1)],
+            total: 2,
+            resharding: Arc::new(AtomicBool::new(true)),
+        };
+        assert!(swap.swap_into(&runners, monitor_tx).await.is_err());
+        assert!(runners.lock().await.is_empty());
+
+        // The failed shard was started again, and is then skipped by the queuer.
+        match queuer_rx.next().await {
+            Some(ShardQueuerMessage::StartStaged {
+                id,
+                manager_tx,
+                ..
+            }) => {
+                assert_eq!(id, ShardId(0));
+                assert!(manager_tx.is_closed());
+            },
+            _ => panic!("expected a staged start"),
+        }
+
+        // The remaining staged shards are shut down, while the failed shard was
+        // already removed.
+        assert!(is_shutdown(other_rx.next().await, ShardId(1)));
+        assert!(matches!(failed_rx.try_next(), Ok(None)));
+    }
+
+    #[test]
+    fn reshard_range() {
+        // A manager running every shard keeps running every shard.
+        assert_eq!(resharded_range(0, 4, 4, 8), (0, 8));
+        // A manager running a part of the shards keeps its part.
+        assert_eq!(resharded_range(4, 4, 8, 16), (4, 4));
+        assert_eq!(resharded_range(4, 4, 8, 6), (4, 2));
+        assert_eq!(resharded_range(4, 4, 8, 2), (4, 0));
+    }
+
+    #[test]
+    fn health() {
+        let shard = |id, stage| ShardHealth {
+            id: ShardId(id),
+            stage,
+            latency: Some(Duration::from_millis(40)),
+            last_heartbeat_ack: None,
+            seq: 3,
+            queue_depth: 1,
+            queue_high_watermark: 4,
+        };
+
+        let mut health = ShardManagerHealth {
+            shards: vec![shard(0, ConnectionStage::Connected)],
+        };
+        assert!(health.is_healthy());
+
+        let value = serde_json::to_value(&health).unwrap();
+        assert_eq!(value["shards"][0]["id"], 0);
+        assert_eq!(value["shards"][0]["stage"], "Connected");
+        assert_eq!(value["shards"][0]["seq"], 3);
+        assert_eq!(value["shards"][0]["queue_high_watermark"], 4);
+
+        health.shards.push(shard(1, ConnectionStage::Resuming));
+        assert!(!health.is_healthy());
+
+        health.shards.clear();
+        assert!(!health.is_healthy());
+    }
+}
@@ -1,11 +1,12 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use futures::channel::mpsc::{UnboundedReceiver as Receiver, UnboundedSender as Sender};
 use futures::StreamExt;
-use tokio::sync::Mutex;
+use tokio::sync::{watch, Mutex};
 use tracing::{debug, instrument, warn};
 
-use super::{ShardManager, ShardManagerMessage};
+use super::{ShardManager, ShardManagerMessage, ShardRunnerInfo};
 use crate::client::bridge::gateway::ShardId;
 
 /// The shard manager monitor monitors the shard manager and performs actions
@@ -22,6 +23,12 @@ pub struct ShardManagerMonitor {
     pub rx: Receiver<ShardManagerMessage>,
     /// The mpsc Sender channel to inform the manager that a shard has just properly shut down
     pub shutdown: Sender<ShardId>,
+    /// A clone of the manager's runners, so that shard updates can be
+    /// recorded without locking the manager.
+    pub(crate) runners: Arc<Mutex<HashMap<ShardId, ShardRunnerInfo>>>,
+    /// Notifies the manager whenever a shard update was recorded, such as to
+    /// wait for restarted shards to connect.
+    pub(crate) shard_updates: watch::Sender<()>,
 }
 #[derive(Debug)]
 pub enum ShardManagerError {
@@ -60,21 +67,26 @@ impl ShardManagerMonitor {
         while let Some(value) = self.rx.next().await {
             match value {
                 ShardManagerMessage::Restart(shard_id) => {
-                    self.manager.lock().await.restart(shard_id).await;
+                    drop(self.manager.lock().await.restart(shard_id).await);
                     drop(self.shutdown.unbounded_send(shard_id));
                 },
                 ShardManagerMessage::ShardUpdate {
                     id,
                     latency,
                     stage,
+                    last_heartbeat_ack,
+                    seq,
                 } => {
-                    let manager = self.manager.lock().await;
-                    let mut runners = manager.runners.lock().await;
+                    let mut runners = self.runners.lock().await;
 
                     if let Some(runner) = runners.get_mut(&id) {
                         runner.latency = latency;
                         runner.stage = stage;
+                        runner.last_heartbeat_ack = last_heartbeat_ack;
+                        runner.seq = seq;
                     }
+
+                    drop(self.shard_updates.send(()));
                 },
                 ShardManagerMessage::Shutdown(shard_id, code) => {
                     self.manager.lock().await.shutdown(shard_id, code).await;
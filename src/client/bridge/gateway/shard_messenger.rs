@@ -1,7 +1,9 @@
+use std::sync::Arc;
+
 use async_tungstenite::tungstenite::Message;
 use futures::channel::mpsc::{TrySendError, UnboundedSender as Sender};
 
-use super::{ChunkGuildFilter, ShardClientMessage, ShardRunnerMessage};
+use super::{ChunkGuildFilter, ShardClientMessage, ShardQueueMetrics, ShardRunnerMessage};
 #[cfg(feature = "collector")]
 use crate::collector::{
     ComponentInteractionFilter,
@@ -23,6 +25,9 @@ use crate::model::prelude::*;
 #[derive(Clone, Debug)]
 pub struct ShardMessenger {
     pub(crate) tx: Sender<InterMessage>,
+    // the events waiting on the event handler of the shard runner, if this
+    // was given out by its shard queuer
+    pub(crate) queue_metrics: Arc<ShardQueueMetrics>,
 }
 
 impl ShardMessenger {
@@ -36,6 +41,7 @@ impl ShardMessenger {
     pub fn new(tx: Sender<InterMessage>) -> Self {
         Self {
             tx,
+            queue_metrics: Arc::default(),
         }
     }
 
@@ -119,6 +125,16 @@ impl ShardMessenger {
         }));
     }
 
+    /// Requests that the soundboard sounds of the given guilds be sent over
+    /// the gateway.
+    ///
+    /// Refer to [`Shard::request_soundboard_sounds`] for more information.
+    ///
+    /// [`Shard::request_soundboard_sounds`]: crate::gateway::Shard::request_soundboard_sounds
+    pub fn request_soundboard_sounds(&self, guild_ids: Vec<GuildId>) {
+        drop(self.send_to_shard(ShardRunnerMessage::RequestSoundboardSounds(guild_ids)));
+    }
+
     /// Sets the user's current activity, if any.
     ///
     /// Other presence settings are maintained.
@@ -17,13 +17,14 @@ use super::{
     ShardRunner,
     ShardRunnerInfo,
     ShardRunnerOptions,
+    ShardSettings,
 };
 #[cfg(feature = "voice")]
 use crate::client::bridge::voice::VoiceGatewayManager;
 use crate::client::{EventHandler, RawEventHandler};
 #[cfg(feature = "framework")]
 use crate::framework::Framework;
-use crate::gateway::{ConnectionStage, InterMessage, Shard};
+use crate::gateway::{ConnectionStage, CurrentPresence, InterMessage, Shard};
 use crate::internal::prelude::*;
 use crate::internal::tokio::spawn_named;
 use crate::model::gateway::GatewayIntents;
@@ -80,6 +81,16 @@ pub struct ShardQueuer {
     pub ws_url: Arc<Mutex<String>>,
     pub cache_and_http: Arc<CacheAndHttp>,
     pub intents: GatewayIntents,
+    /// The settings applied to every shard that is started, such as the ones
+    /// given to the [`Client`].
+    ///
+    /// [`Client`]: crate::Client
+    pub settings: ShardSettings,
+    /// The presence to give to shards when they are started, if one was set
+    /// with [`ShardManager::set_presence_all`].
+    ///
+    /// [`ShardManager::set_presence_all`]: super::ShardManager::set_presence_all
+    pub presence: Arc<Mutex<Option<CurrentPresence>>>,
 }
 
 impl ShardQueuer {
@@ -119,10 +130,27 @@ impl ShardQueuer {
                     debug!("[Shard Queuer] Received to shutdown shard {} with {}.", shard.0, code);
                     self.shutdown(shard, code).await;
                 },
+                Ok(Some(ShardQueuerMessage::SetIntents(intents))) => {
+                    debug!("[Shard Queuer] Received to set intents to {:?}.", intents);
+                    self.intents = intents;
+                },
                 Ok(Some(ShardQueuerMessage::Start(id, total))) => {
                     debug!("[Shard Queuer] Received to start shard {} of {}.", id.0, total.0);
                     self.checked_start(id.0, total.0).await;
                 },
+                Ok(Some(ShardQueuerMessage::StartStaged {
+                    id,
+                    total,
+                    runners,
+                    manager_tx,
+                })) => {
+                    if manager_tx.is_closed() {
+                        debug!("[Shard Queuer] Skipping start of cancelled staged shard {}.", id.0);
+                    } else {
+                        debug!("[Shard Queuer] Received to stage shard {} of {}.", id.0, total.0);
+                        self.checked_start_staged(id.0, total.0, runners, manager_tx).await;
+                    }
+                },
                 Ok(None) => break,
                 Err(_) => {
                     if let Some((id, total)) = self.queue.pop_front() {
@@ -159,7 +187,10 @@ impl ShardQueuer {
         debug!("[Shard Queuer] Checked start for shard {} out of {}", id, total);
         self.check_last_start().await;
 
-        if let Err(why) = self.start(id, total).await {
+        let runners = Arc::clone(&self.runners);
+        let manager_tx = self.manager_tx.clone();
+
+        if let Err(why) = self.start(id, total, runners, manager_tx).await {
             warn!("[Shard Queuer] Err starting shard {}: {:?}", id, why);
             info!("[Shard Queuer] Re-queueing start of shard {}", id);
 
@@ -169,46 +200,104 @@ impl ShardQueuer {
         self.last_start = Some(Instant::now());
     }
 
-    #[instrument(skip(self))]
-    async fn start(&mut self, shard_id: u64, shard_total: u64) -> Result<()> {
+    #[instrument(skip(self, runners))]
+    async fn checked_start_staged(
+        &mut self,
+        id: u64,
+        total: u64,
+        runners: Arc<Mutex<HashMap<ShardId, ShardRunnerInfo>>>,
+        manager_tx: Sender<ShardManagerMessage>,
+    ) {
+        debug!("[Shard Queuer] Checked start for staged shard {} out of {}", id, total);
+        self.check_last_start().await;
+
+        if let Err(why) = self.start(id, total, runners, manager_tx.clone()).await {
+            warn!("[Shard Queuer] Err starting staged shard {}: {:?}", id, why);
+
+            // Let whoever staged the shard decide whether to retry.
+            drop(manager_tx.unbounded_send(ShardManagerMessage::Restart(ShardId(id))));
+        }
+
+        self.last_start = Some(Instant::now());
+    }
+
+    #[instrument(skip(self, runners))]
+    async fn start(
+        &mut self,
+        shard_id: u64,
+        shard_total: u64,
+        runners: Arc<Mutex<HashMap<ShardId, ShardRunnerInfo>>>,
+        manager_tx: Sender<ShardManagerMessage>,
+    ) -> Result<()> {
+        // A runner may have been registered for the shard while this one was
+        // queued, such as after resharding. Keep the registered runner rather
+        // than connecting a second one.
+        if runners.lock().await.contains_key(&ShardId(shard_id)) {
+            warn!("[Shard Queuer] Shard {} is already running, not starting it again", shard_id);
+
+            return Ok(());
+        }
+
         let shard_info = [shard_id, shard_total];
 
-        let mut shard = Shard::new(
+        let mut shard = Shard::new_with_tcp_settings(
             Arc::clone(&self.ws_url),
             &self.cache_and_http.http.token,
             shard_info,
             self.intents,
+            self.settings.tcp_settings,
         )
         .await?;
 
         shard.set_http(Arc::clone(&self.cache_and_http.http));
 
-        let mut runner = ShardRunner::new(ShardRunnerOptions {
+        if let Some((activity, status)) = self.presence.lock().await.clone() {
+            shard.set_presence(status, activity);
+        }
+
+        let options = ShardRunnerOptions {
             data: Arc::clone(&self.data),
             event_handler: self.event_handler.as_ref().map(Arc::clone),
             raw_event_handler: self.raw_event_handler.as_ref().map(Arc::clone),
             #[cfg(feature = "framework")]
             framework: Arc::clone(&self.framework),
-            manager_tx: self.manager_tx.clone(),
+            manager_tx,
             #[cfg(feature = "voice")]
             voice_manager: self.voice_manager.clone(),
             shard,
             cache_and_http: Arc::clone(&self.cache_and_http),
-        });
+        };
+        let mut runner = ShardRunner::new_with_settings(options, self.settings.clone());
 
         let runner_info = ShardRunnerInfo {
             latency: None,
-            runner_tx: ShardMessenger::new(runner.runner_tx()),
+            runner_tx: ShardMessenger {
+                tx: runner.runner_tx(),
+                queue_metrics: runner.queue_metrics(),
+            },
             stage: ConnectionStage::Disconnected,
+            last_heartbeat_ack: None,
+            seq: 0,
         };
 
+        {
+            let mut runners = runners.lock().await;
+
+            // The shard may have been registered while this one was connecting.
+            if runners.contains_key(&ShardId(shard_id)) {
+                warn!("[Shard Queuer] Shard {} is already running, dropping the runner", shard_id);
+
+                return Ok(());
+            }
+
+            runners.insert(ShardId(shard_id), runner_info);
+        }
+
         spawn_named("shard_queuer::stop", async move {
             drop(runner.run().await);
             debug!("[ShardRunner {:?}] Stopping", runner.shard.shard_info());
         });
 
-        self.runners.lock().await.insert(ShardId(shard_id), runner_info);
-
         Ok(())
     }
 
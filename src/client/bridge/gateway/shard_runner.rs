@@ -1,5 +1,9 @@
 use std::borrow::Cow;
+#[cfg(feature = "cache")]
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use async_tungstenite::tungstenite;
 use async_tungstenite::tungstenite::error::Error as TungsteniteError;
@@ -12,11 +16,12 @@ use tracing::{debug, error, info, instrument, trace, warn};
 use typemap_rev::TypeMap;
 
 use super::event::{ClientEvent, ShardStageUpdateEvent};
-use super::{ShardClientMessage, ShardId, ShardManagerMessage, ShardRunnerMessage};
+use super::{ShardClientMessage, ShardId, ShardManagerMessage, ShardRunnerMessage, ShardSettings};
 #[cfg(feature = "voice")]
 use crate::client::bridge::voice::VoiceGatewayManager;
-use crate::client::dispatch::{dispatch, DispatchEvent};
-use crate::client::{EventHandler, RawEventHandler};
+use crate::client::dispatch::{dispatch, dispatch_raw_payload, DispatchEvent, HandlerSettings};
+use crate::client::{EventHandler, RawEventHandler, SharedData};
+use crate::constants::OpCode;
 #[cfg(feature = "collector")]
 use crate::collector::{
     ComponentInteractionFilter,
@@ -35,13 +40,29 @@ use crate::internal::ws_impl::{ReceiverExt, SenderExt};
 #[cfg(feature = "collector")]
 use crate::model::application::interaction::Interaction;
 use crate::model::event::{Event, GatewayEvent};
+#[cfg(feature = "cache")]
+use crate::model::id::GuildId;
+use crate::model::user::OnlineStatus;
 use crate::CacheAndHttp;
 
+/// How long a shard waits for the next guild of its [`Ready`] event before
+/// dispatching [`EventHandler::cache_ready`] without the missing guilds.
+///
+/// [`Ready`]: crate::model::gateway::Ready
+#[cfg(feature = "cache")]
+const CACHE_READY_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How long a shard waits before logging another warning about the events
+/// waiting on its [`EventHandler`].
+const QUEUE_DEPTH_WARNING_INTERVAL: Duration = Duration::from_secs(30);
+
 /// A runner for managing a [`Shard`] and its respective WebSocket client.
 pub struct ShardRunner {
     data: Arc<RwLock<TypeMap>>,
+    shared_data: Arc<SharedData>,
     event_handler: Option<Arc<dyn EventHandler>>,
     raw_event_handler: Option<Arc<dyn RawEventHandler>>,
+    handler_settings: HandlerSettings,
     #[cfg(feature = "framework")]
     framework: Arc<dyn Framework + Send + Sync>,
     manager_tx: Sender<ShardManagerMessage>,
@@ -63,19 +84,35 @@ pub struct ShardRunner {
     component_interaction_filters: Vec<ComponentInteractionFilter>,
     #[cfg(feature = "collector")]
     modal_interaction_filters: Vec<ModalInteractionFilter>,
+    #[cfg(feature = "cache")]
+    pending_guilds: Option<PendingGuilds>,
+    queue_depth_warning: Option<usize>,
+    last_queue_depth_warning: Option<Instant>,
 }
 
 impl ShardRunner {
     /// Creates a new runner for a Shard.
     pub fn new(opt: ShardRunnerOptions) -> Self {
+        Self::new_with_settings(opt, ShardSettings::default())
+    }
+
+    /// Creates a new runner for a Shard like [`Self::new`], with the given
+    /// settings.
+    pub fn new_with_settings(opt: ShardRunnerOptions, settings: ShardSettings) -> Self {
         let (tx, rx) = mpsc::unbounded();
 
         Self {
             runner_rx: rx,
             runner_tx: tx,
             data: opt.data,
+            shared_data: settings.shared_data,
             event_handler: opt.event_handler,
             raw_event_handler: opt.raw_event_handler,
+            handler_settings: HandlerSettings {
+                metrics: settings.dispatch_metrics,
+                catch_panics: settings.catch_panics,
+                queue_metrics: Arc::default(),
+            },
             #[cfg(feature = "framework")]
             framework: opt.framework,
             manager_tx: opt.manager_tx,
@@ -93,6 +130,10 @@ impl ShardRunner {
             component_interaction_filters: vec![],
             #[cfg(feature = "collector")]
             modal_interaction_filters: vec![],
+            #[cfg(feature = "cache")]
+            pending_guilds: None,
+            queue_depth_warning: settings.queue_depth_warning,
+            last_queue_depth_warning: None,
         }
     }
 
@@ -152,6 +193,22 @@ impl ShardRunner {
                 self.dispatch(DispatchEvent::Client(e)).await;
             }
 
+            // New sessions start with the default presence, so re-apply the
+            // presence the shard was given before it connected.
+            if let Some(Event::Ready(_)) = event {
+                let (activity, status) = self.shard.current_presence();
+
+                if activity.is_some() || *status != OnlineStatus::Online {
+                    if let Err(why) = self.shard.update_presence().await {
+                        warn!(
+                            "[ShardRunner {:?}] Failed to restore presence: {:?}",
+                            self.shard.shard_info(),
+                            why
+                        );
+                    }
+                }
+            }
+
             match action {
                 Some(ShardAction::Reconnect(ReconnectType::Reidentify)) => {
                     return self.request_restart().await;
@@ -183,6 +240,9 @@ impl ShardRunner {
                 None => {},
             }
 
+            #[cfg(feature = "cache")]
+            let cache_ready = self.update_pending_guilds(event.as_ref());
+
             if let Some(event) = event {
                 #[cfg(feature = "collector")]
                 {
@@ -190,6 +250,12 @@ impl ShardRunner {
                 }
 
                 self.dispatch(DispatchEvent::Model(event)).await;
+                self.check_queue_depth();
+            }
+
+            #[cfg(feature = "cache")]
+            if let Some(guilds) = cache_ready {
+                self.dispatch(DispatchEvent::Client(ClientEvent::CacheReady(guilds))).await;
             }
 
             if !successful && !self.shard.stage().is_connecting() {
@@ -199,6 +265,51 @@ impl ShardRunner {
         }
     }
 
+    /// Keeps track of which guilds of the last [`Ready`] event are yet to be
+    /// received, returning the received guilds once none are left or the wait
+    /// for them timed out.
+    ///
+    /// [`Ready`]: crate::model::gateway::Ready
+    #[cfg(feature = "cache")]
+    fn update_pending_guilds(&mut self, event: Option<&Event>) -> Option<Vec<GuildId>> {
+        match event {
+            Some(Event::Ready(event)) => {
+                let guilds = &event.ready.guilds;
+
+                self.pending_guilds = Some(PendingGuilds {
+                    received: Vec::with_capacity(guilds.len()),
+                    unavailable: guilds.iter().map(|guild| guild.id).collect(),
+                    deadline: Instant::now() + CACHE_READY_TIMEOUT,
+                });
+            },
+            Some(Event::GuildCreate(event)) => {
+                if let Some(pending) = &mut self.pending_guilds {
+                    if pending.unavailable.remove(&event.guild.id) {
+                        pending.received.push(event.guild.id);
+                        pending.deadline = Instant::now() + CACHE_READY_TIMEOUT;
+                    }
+                }
+            },
+            _ => {},
+        }
+
+        let pending = self.pending_guilds.as_ref()?;
+
+        if !pending.unavailable.is_empty() {
+            if pending.deadline > Instant::now() {
+                return None;
+            }
+
+            warn!(
+                "[ShardRunner {:?}] Timed out waiting for {} guild(s) to become available.",
+                self.shard.shard_info(),
+                pending.unavailable.len(),
+            );
+        }
+
+        self.pending_guilds.take().map(|pending| pending.received)
+    }
+
     /// Lets filters check the `event` to send them to collectors if the `event`
     /// is accepted by them.
     #[cfg(feature = "collector")]
@@ -243,6 +354,45 @@ impl ShardRunner {
         retain_mut(&mut self.event_filters, |f| f.send_event(&mut event));
     }
 
+    /// Returns the counter of events waiting on the [`EventHandler`], which
+    /// the [`ShardManager`] reads to report the [`ShardHealth`] of the shard.
+    ///
+    /// [`ShardManager`]: super::ShardManager
+    /// [`ShardHealth`]: super::ShardHealth
+    pub(super) fn queue_metrics(&self) -> Arc<ShardQueueMetrics> {
+        Arc::clone(&self.handler_settings.queue_metrics)
+    }
+
+    /// Logs a warning if more events are waiting on the [`EventHandler`] than
+    /// the configured threshold, at most once every
+    /// [`QUEUE_DEPTH_WARNING_INTERVAL`].
+    fn check_queue_depth(&mut self) {
+        let threshold = match self.queue_depth_warning {
+            Some(threshold) => threshold,
+            None => return,
+        };
+
+        let depth = self.handler_settings.queue_metrics.depth();
+
+        if depth <= threshold {
+            return;
+        }
+
+        if let Some(last) = self.last_queue_depth_warning {
+            if last.elapsed() < QUEUE_DEPTH_WARNING_INTERVAL {
+                return;
+            }
+        }
+
+        self.last_queue_depth_warning = Some(Instant::now());
+
+        warn!(
+            "[ShardRunner {:?}] {} events are waiting on the event handler, it may be too slow",
+            self.shard.shard_info(),
+            depth,
+        );
+    }
+
     /// Clones the internal copy of the Sender to the shard runner.
     pub(super) fn runner_tx(&self) -> Sender<InterMessage> {
         self.runner_tx.clone()
@@ -331,8 +481,10 @@ impl ShardRunner {
             #[cfg(feature = "framework")]
             &self.framework,
             &self.data,
+            &self.shared_data,
             &self.event_handler,
             &self.raw_event_handler,
+            &self.handler_settings,
             &self.runner_tx,
             self.shard.shard_info()[0],
             Arc::clone(&self.cache_and_http),
@@ -340,6 +492,31 @@ impl ShardRunner {
         .await;
     }
 
+    /// Dispatches an undecoded gateway dispatch payload to the
+    /// [`RawEventHandler`], if one was registered that wants raw payloads.
+    #[instrument(skip(self, payload))]
+    fn dispatch_raw_payload(&self, payload: &Value) {
+        let raw_event_handler = match &self.raw_event_handler {
+            Some(raw_event_handler) if raw_event_handler.wants_raw_payloads() => raw_event_handler,
+            _ => return,
+        };
+
+        if payload.get("op").and_then(Value::as_u64) != Some(OpCode::Event.num()) {
+            return;
+        }
+
+        dispatch_raw_payload(
+            payload.clone(),
+            &self.data,
+            &self.shared_data,
+            raw_event_handler,
+            &self.handler_settings,
+            &self.runner_tx,
+            self.shard.shard_info()[0],
+            &self.cache_and_http,
+        );
+    }
+
     // Handles a received value over the shard runner rx channel.
     //
     // Returns a boolean on whether the shard runner can continue.
@@ -391,6 +568,9 @@ impl ShardRunner {
                 }) => {
                     self.shard.chunk_guild(guild_id, limit, filter, nonce.as_deref()).await.is_ok()
                 },
+                ShardClientMessage::Runner(ShardRunnerMessage::RequestSoundboardSounds(
+                    guild_ids,
+                )) => self.shard.request_soundboard_sounds(&guild_ids).await.is_ok(),
                 ShardClientMessage::Runner(ShardRunnerMessage::Close(code, reason)) => {
                     let reason = reason.unwrap_or_default();
                     let close = CloseFrame {
@@ -538,7 +718,11 @@ impl ShardRunner {
     #[instrument(skip(self))]
     async fn recv_event(&mut self) -> Result<(Option<Event>, Option<ShardAction>, bool)> {
         let gw_event = match self.shard.client.recv_json().await {
-            Ok(Some(value)) => GatewayEvent::deserialize(value).map(Some).map_err(From::from),
+            Ok(Some(value)) => {
+                self.dispatch_raw_payload(&value);
+
+                GatewayEvent::deserialize(value).map(Some).map_err(From::from)
+            },
             Ok(None) => Ok(None),
             Err(Error::Tungstenite(TungsteniteError::Io(_))) => {
                 debug!("Attempting to auto-reconnect");
@@ -659,6 +843,8 @@ impl ShardRunner {
             id: ShardId(self.shard.shard_info()[0]),
             latency: self.shard.latency(),
             stage: self.shard.stage(),
+            last_heartbeat_ack: self.shard.last_heartbeat_ack().copied(),
+            seq: self.shard.seq(),
         }));
     }
 }
@@ -676,3 +862,78 @@ pub struct ShardRunnerOptions {
     pub voice_manager: Option<Arc<dyn VoiceGatewayManager + Send + Sync>>,
     pub cache_and_http: Arc<CacheAndHttp>,
 }
+
+/// The number of events dispatched by a [`ShardRunner`] whose
+/// [`EventHandler`] invocation has not finished yet.
+///
+/// Event handlers are spawned instead of awaited by the runner, so a slow
+/// handler does not hold up the shard. Its invocations pile up instead, which
+/// is what this measures.
+#[derive(Debug, Default)]
+pub(crate) struct ShardQueueMetrics {
+    depth: AtomicUsize,
+    high_watermark: AtomicUsize,
+}
+
+impl ShardQueueMetrics {
+    /// The number of events whose handler has not finished yet.
+    pub(crate) fn depth(&self) -> usize {
+        self.depth.load(Ordering::Relaxed)
+    }
+
+    /// The highest number of events whose handler had not finished at once,
+    /// since the runner was started.
+    pub(crate) fn high_watermark(&self) -> usize {
+        self.high_watermark.load(Ordering::Relaxed)
+    }
+
+    /// Counts an event as waiting on its handler until the returned guard is
+    /// dropped.
+    pub(crate) fn start(self: &Arc<Self>) -> PendingEvent {
+        let depth = self.depth.fetch_add(1, Ordering::Relaxed) + 1;
+        self.high_watermark.fetch_max(depth, Ordering::Relaxed);
+
+        PendingEvent(Arc::clone(self))
+    }
+}
+
+/// An event counted by [`ShardQueueMetrics`], whose handler is finished once
+/// this is dropped, including when the handler panicked.
+pub(crate) struct PendingEvent(Arc<ShardQueueMetrics>);
+
+impl Drop for PendingEvent {
+    fn drop(&mut self) {
+        self.0.depth.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+/// The guilds of a [`Ready`] event that a shard is waiting to receive.
+///
+/// [`Ready`]: crate::model::gateway::Ready
+#[cfg(feature = "cache")]
+struct PendingGuilds {
+    received: Vec<GuildId>,
+    unavailable: HashSet<GuildId>,
+    deadline: Instant,
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+
+    use super::ShardQueueMetrics;
+
+    #[test]
+    fn queue_metrics() {
+        let metrics = Arc::new(ShardQueueMetrics::default());
+        assert_eq!((metrics.depth(), metrics.high_watermark()), (0, 0));
+
+        let first = metrics.start();
+        let second = metrics.start();
+        drop(first);
+        assert_eq!((metrics.depth(), metrics.high_watermark()), (1, 2));
+
+        drop(second);
+        assert_eq!((metrics.depth(), metrics.high_watermark()), (0, 2));
+    }
+}
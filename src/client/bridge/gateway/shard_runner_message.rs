@@ -56,6 +56,9 @@ pub enum ShardRunnerMessage {
     Close(u16, Option<String>),
     /// Indicates that the client is to send a custom WebSocket message.
     Message(Message),
+    /// Indicates that the client is to request the soundboard sounds of the
+    /// given guilds.
+    RequestSoundboardSounds(Vec<GuildId>),
     /// Indicates that the client is to update the shard's presence's activity.
     SetActivity(Option<Activity>),
     /// Indicates that the client is to update the shard's presence in its
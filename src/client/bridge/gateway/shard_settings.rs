@@ -0,0 +1,128 @@
+use std::sync::Arc;
+
+use crate::client::{DispatchMetrics, SharedData};
+use crate::gateway::TcpSettings;
+
+/// Settings applied to every shard started by a [`ShardManager`] or
+/// [`ShardQueuer`], and to every [`ShardRunner`].
+///
+/// The [`Client`] fills these in from its [`ClientBuilder`]. When constructing
+/// the gateway bridge manually, the defaults are those of the builder.
+///
+/// # Examples
+///
+/// ```rust
+/// use serenity::client::bridge::gateway::ShardSettings;
+///
+/// let mut settings = ShardSettings::new();
+/// settings.catch_panics(true).queue_depth_warning(Some(100));
+/// ```
+///
+/// [`ShardManager`]: super::ShardManager
+/// [`ShardQueuer`]: super::ShardQueuer
+/// [`ShardRunner`]: super::ShardRunner
+/// [`Client`]: crate::Client
+/// [`ClientBuilder`]: crate::client::ClientBuilder
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub struct ShardSettings {
+    /// The data shared with every [`Context`], such as registered via
+    /// [`ClientBuilder::data_insert`].
+    ///
+    /// Defaults to no data.
+    ///
+    /// [`Context`]: crate::client::Context
+    /// [`ClientBuilder::data_insert`]: crate::client::ClientBuilder::data_insert
+    pub shared_data: Arc<SharedData>,
+    /// The metrics to record [`EventHandler`] invocations in.
+    ///
+    /// Defaults to `None`, recording no metrics.
+    ///
+    /// [`EventHandler`]: crate::client::EventHandler
+    pub dispatch_metrics: Option<Arc<DispatchMetrics>>,
+    /// Whether to catch and log panics of event handlers, instead of letting
+    /// them unwind.
+    ///
+    /// Defaults to `false`.
+    pub catch_panics: bool,
+    /// The number of events waiting on the [`EventHandler`] of a shard above
+    /// which it logs a warning, at most once every 30 seconds.
+    ///
+    /// An event is waiting on the handler from when it is dispatched until
+    /// the handler's invocation finishes.
+    ///
+    /// Defaults to `None`, logging no warning.
+    ///
+    /// [`EventHandler`]: crate::client::EventHandler
+    pub queue_depth_warning: Option<usize>,
+    /// The TCP options applied to every shard's gateway connection.
+    ///
+    /// Defaults to [`TcpSettings::default`].
+    pub tcp_settings: TcpSettings,
+}
+
+impl ShardSettings {
+    /// Creates new settings with the defaults.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the data shared with every [`Context`].
+    ///
+    /// Refer to [`shared_data`] for more information.
+    ///
+    /// [`Context`]: crate::client::Context
+    /// [`shared_data`]: Self::shared_data
+    pub fn shared_data(&mut self, shared_data: Arc<SharedData>) -> &mut Self {
+        self.shared_data = shared_data;
+        self
+    }
+
+    /// Sets the metrics to record [`EventHandler`] invocations in.
+    ///
+    /// Refer to [`dispatch_metrics`] for more information.
+    ///
+    /// [`EventHandler`]: crate::client::EventHandler
+    /// [`dispatch_metrics`]: Self::dispatch_metrics
+    pub fn dispatch_metrics(
+        &mut self,
+        dispatch_metrics: Option<Arc<DispatchMetrics>>,
+    ) -> &mut Self {
+        self.dispatch_metrics = dispatch_metrics;
+        self
+    }
+
+    /// Sets whether to catch and log panics of event handlers.
+    ///
+    /// Refer to [`catch_panics`] for more information.
+    ///
+    /// [`catch_panics`]: Self::catch_panics
+    pub fn catch_panics(&mut self, catch_panics: bool) -> &mut Self {
+        self.catch_panics = catch_panics;
+        self
+    }
+
+    /// Sets the number of events waiting on the [`EventHandler`] of a shard
+    /// above which it logs a warning.
+    ///
+    /// Refer to [`queue_depth_warning`] for more information.
+    ///
+    /// [`EventHandler`]: crate::client::EventHandler
+    /// [`queue_depth_warning`]: Self::queue_depth_warning
+    pub fn queue_depth_warning(&mut self, threshold: Option<usize>) -> &mut Self {
+        self.queue_depth_warning = threshold;
+        self
+    }
+
+    /// Sets the TCP options of the gateway connections.
+    ///
+    /// Refer to [`tcp_settings`] for more information.
+    ///
+    /// [`tcp_settings`]: Self::tcp_settings
+    pub fn tcp_settings(&mut self, tcp_settings: TcpSettings) -> &mut Self {
+        self.tcp_settings = tcp_settings;
+        self
+    }
+}
@@ -1,15 +1,27 @@
+#[cfg(feature = "collector")]
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 
 use futures::channel::mpsc::UnboundedSender as Sender;
+#[cfg(feature = "collector")]
+use futures::stream::{self, Stream, StreamExt};
 use tokio::sync::RwLock;
 use typemap_rev::TypeMap;
 
+use super::SharedData;
 #[cfg(feature = "cache")]
 pub use crate::cache::Cache;
+#[cfg(feature = "collector")]
+use crate::client::bridge::gateway::ChunkGuildFilter;
 #[cfg(feature = "gateway")]
 use crate::client::bridge::gateway::ShardMessenger;
 #[cfg(feature = "collector")]
-use crate::collector::{ComponentInteractionFilter, MessageFilter, ReactionFilter};
+use crate::collector::{
+    ComponentInteractionFilter,
+    EventCollectorBuilder,
+    MessageFilter,
+    ReactionFilter,
+};
 #[cfg(feature = "gateway")]
 use crate::gateway::InterMessage;
 use crate::http::Http;
@@ -34,8 +46,14 @@ pub struct Context {
     /// A clone of [`Client::data`]. Refer to its documentation for more
     /// information.
     ///
+    /// For read-mostly data, prefer registering it via
+    /// [`ClientBuilder::data_insert`] and accessing it via [`Self::data()`],
+    /// which requires no locking.
+    ///
     /// [`Client::data`]: super::Client::data
+    /// [`ClientBuilder::data_insert`]: super::ClientBuilder::data_insert
     pub data: Arc<RwLock<TypeMap>>,
+    shared_data: Arc<SharedData>,
     /// The messenger to communicate with the shard runner.
     pub shard: ShardMessenger,
     /// The ID of the shard this context is related to.
@@ -50,6 +68,7 @@ impl Context {
     #[cfg(all(feature = "cache", feature = "gateway"))]
     pub(crate) fn new(
         data: Arc<RwLock<TypeMap>>,
+        shared_data: Arc<SharedData>,
         runner_tx: Sender<InterMessage>,
         shard_id: u64,
         http: Arc<Http>,
@@ -59,6 +78,7 @@ impl Context {
             shard: ShardMessenger::new(runner_tx),
             shard_id,
             data,
+            shared_data,
             http,
             cache,
         }
@@ -69,6 +89,7 @@ impl Context {
         Context {
             shard_id,
             data,
+            shared_data: Arc::default(),
             http,
         }
     }
@@ -77,6 +98,7 @@ impl Context {
     #[cfg(all(not(feature = "cache"), feature = "gateway"))]
     pub(crate) fn new(
         data: Arc<RwLock<TypeMap>>,
+        shared_data: Arc<SharedData>,
         runner_tx: Sender<InterMessage>,
         shard_id: u64,
         http: Arc<Http>,
@@ -85,10 +107,78 @@ impl Context {
             shard: ShardMessenger::new(runner_tx),
             shard_id,
             data,
+            shared_data,
             http,
         }
     }
 
+    /// Gets the value of the given type registered via
+    /// [`ClientBuilder::data_insert`].
+    ///
+    /// Unlike [`Self::data`][field], this requires no locking and no
+    /// [`TypeMapKey`], making it suited for read-mostly state such as
+    /// configuration or database pools.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::prelude::*;
+    /// # use serenity::model::channel::Message;
+    /// #
+    /// struct Config {
+    ///     greeting: String,
+    /// }
+    ///
+    /// struct Handler;
+    ///
+    /// #[serenity::async_trait]
+    /// impl EventHandler for Handler {
+    ///     async fn message(&self, ctx: Context, msg: Message) {
+    ///         if msg.content == "!hello" {
+    ///             let config = ctx.data::<Config>();
+    ///             let _ = msg.channel_id.say(&ctx, &config.greeting).await;
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// let mut client = Client::builder("token", GatewayIntents::default())
+    ///     .data_insert(Config {
+    ///         greeting: "Hello!".to_string(),
+    ///     })
+    ///     .event_handler(Handler)
+    ///     .await?;
+    ///
+    /// client.start().await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if no value of the type was registered. Use [`Self::try_data`]
+    /// to handle that case.
+    ///
+    /// [`ClientBuilder::data_insert`]: super::ClientBuilder::data_insert
+    /// [field]: Self#structfield.data
+    /// [`TypeMapKey`]: typemap_rev::TypeMapKey
+    #[must_use]
+    pub fn data<T: Send + Sync + 'static>(&self) -> Arc<T> {
+        match self.try_data() {
+            Some(value) => value,
+            None => panic!("no data of type `{}` was registered", std::any::type_name::<T>()),
+        }
+    }
+
+    /// Gets the value of the given type registered via
+    /// [`ClientBuilder::data_insert`], if any.
+    ///
+    /// [`ClientBuilder::data_insert`]: super::ClientBuilder::data_insert
+    #[must_use]
+    pub fn try_data<T: Send + Sync + 'static>(&self) -> Option<Arc<T>> {
+        self.shared_data.get()
+    }
+
     /// Sets the current user as being [`Online`]. This maintains the current
     /// activity.
     ///
@@ -424,6 +514,91 @@ impl Context {
     pub async fn set_component_interaction_filter(&self, filter: ComponentInteractionFilter) {
         self.shard.set_component_interaction_filter(filter);
     }
+
+    /// Requests the members of a guild over the gateway, returning them as a
+    /// stream.
+    ///
+    /// A unique nonce is generated for the request, so only the
+    /// [`GuildMembersChunkEvent`]s answering it are collected. The stream ends
+    /// once the last chunk has been received, however many chunks Discord
+    /// splits the guild into.
+    ///
+    /// Dropping the stream early stops the collection; the underlying filter
+    /// is removed from the shard once the next event arrives.
+    ///
+    /// Refer to [`ShardMessenger::chunk_guild`] for the meaning of `limit` and
+    /// `filter`.
+    ///
+    /// **Note**: Requesting all members of a guild requires the
+    /// [`GatewayIntents::GUILD_MEMBERS`] intent. If no chunks are received,
+    /// for example because the intent is missing, the stream never ends on its
+    /// own, so consider combining it with a timeout.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::client::Context;
+    /// # use serenity::client::bridge::gateway::ChunkGuildFilter;
+    /// # use serenity::model::id::GuildId;
+    /// #
+    /// # async fn run(ctx: Context) {
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut members =
+    ///     ctx.chunk_guild(GuildId(81384788765712384), None, ChunkGuildFilter::None).boxed();
+    ///
+    /// while let Some(member) = members.next().await {
+    ///     println!("{}", member.user.tag());
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// [`GatewayIntents::GUILD_MEMBERS`]: crate::model::gateway::GatewayIntents::GUILD_MEMBERS
+    #[cfg(feature = "collector")]
+    #[allow(clippy::unwrap_used)]
+    pub fn chunk_guild(
+        &self,
+        guild_id: GuildId,
+        limit: Option<u16>,
+        filter: ChunkGuildFilter,
+    ) -> impl Stream<Item = Member> {
+        static NONCE: AtomicU64 = AtomicU64::new(0);
+
+        let nonce = format!("{}-{}", self.shard_id, NONCE.fetch_add(1, Ordering::Relaxed));
+        let expected = nonce.clone();
+
+        // Cannot fail, as an event type is given and the guild Id is related to
+        // it.
+        let collector = EventCollectorBuilder::new(&self.shard)
+            .add_event_type(EventType::GuildMembersChunk)
+            .add_guild_id(guild_id)
+            .filter(move |event| match &**event {
+                Event::GuildMembersChunk(chunk) => chunk.nonce.as_ref() == Some(&expected),
+                _ => false,
+            })
+            .build()
+            .unwrap();
+
+        self.shard.chunk_guild(guild_id, limit, filter, Some(nonce));
+
+        stream::unfold((collector, false), |(mut collector, finished)| async move {
+            if finished {
+                return None;
+            }
+
+            let event = collector.next().await?;
+            let chunk = match &*event {
+                Event::GuildMembersChunk(chunk) => chunk,
+                _ => return None,
+            };
+
+            let members = chunk.members.values().cloned().collect::<Vec<_>>();
+            let finished = chunk.chunk_index + 1 >= chunk.chunk_count;
+
+            Some((stream::iter(members), (collector, finished)))
+        })
+        .flatten()
+    }
 }
 
 impl AsRef<Http> for Context {
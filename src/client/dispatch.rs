@@ -1,18 +1,22 @@
 #[cfg(feature = "cache")]
 use std::fmt;
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
 use std::sync::Arc;
+use std::time::Instant;
 
 use futures::channel::mpsc::UnboundedSender as Sender;
 use futures::future::{BoxFuture, FutureExt};
 use tokio::sync::RwLock;
-use tracing::instrument;
+use tracing::{error, instrument};
 use typemap_rev::TypeMap;
 
 #[cfg(feature = "gateway")]
 use super::bridge::gateway::event::ClientEvent;
+use super::bridge::gateway::ShardQueueMetrics;
 #[cfg(feature = "gateway")]
 use super::event_handler::{EventHandler, RawEventHandler};
-use super::Context;
+use super::{Context, DispatchMetrics, SharedData};
 #[cfg(feature = "cache")]
 use crate::cache::{Cache, CacheUpdate};
 #[cfg(feature = "framework")]
@@ -20,6 +24,8 @@ use crate::framework::Framework;
 use crate::gateway::InterMessage;
 use crate::http::Http;
 use crate::internal::tokio::spawn_named;
+use crate::json::Value;
+use crate::model::application::interaction::Interaction;
 use crate::model::channel::{Channel, Message};
 use crate::model::event::Event;
 use crate::model::guild::Member;
@@ -45,22 +51,37 @@ fn update<E>(_cache_and_http: &Arc<CacheAndHttp>, _event: &mut E) -> Option<()>
 #[cfg(feature = "cache")]
 fn context(
     data: &Arc<RwLock<TypeMap>>,
+    shared_data: &Arc<SharedData>,
     runner_tx: &Sender<InterMessage>,
     shard_id: u64,
     http: &Arc<Http>,
     cache: &Arc<Cache>,
 ) -> Context {
-    Context::new(Arc::clone(data), runner_tx.clone(), shard_id, Arc::clone(http), Arc::clone(cache))
+    Context::new(
+        Arc::clone(data),
+        Arc::clone(shared_data),
+        runner_tx.clone(),
+        shard_id,
+        Arc::clone(http),
+        Arc::clone(cache),
+    )
 }
 
 #[cfg(not(feature = "cache"))]
 fn context(
     data: &Arc<RwLock<TypeMap>>,
+    shared_data: &Arc<SharedData>,
     runner_tx: &Sender<InterMessage>,
     shard_id: u64,
     http: &Arc<Http>,
 ) -> Context {
-    Context::new(Arc::clone(data), runner_tx.clone(), shard_id, Arc::clone(http))
+    Context::new(
+        Arc::clone(data),
+        Arc::clone(shared_data),
+        runner_tx.clone(),
+        shard_id,
+        Arc::clone(http),
+    )
 }
 
 // Once we can use `Box` as part of a pattern, we will reconsider boxing.
@@ -125,6 +146,9 @@ impl DispatchEvent {
             },
             // Already handled by the framework check macro
             Self::Model(Event::MessageCreate(_)) => {},
+            Self::Model(Event::MessageDeleteBulk(ref mut event)) => {
+                update(cache_and_http, event);
+            },
             Self::Model(Event::MessageUpdate(ref mut event)) => {
                 update(cache_and_http, event);
             },
@@ -157,14 +181,52 @@ impl DispatchEvent {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn dispatch_raw_payload(
+    payload: Value,
+    data: &Arc<RwLock<TypeMap>>,
+    shared_data: &Arc<SharedData>,
+    raw_event_handler: &Arc<dyn RawEventHandler>,
+    handler_settings: &HandlerSettings,
+    runner_tx: &Sender<InterMessage>,
+    shard_id: u64,
+    cache_and_http: &Arc<CacheAndHttp>,
+) {
+    #[cfg(not(feature = "cache"))]
+    let context = context(data, shared_data, runner_tx, shard_id, &cache_and_http.http);
+    #[cfg(feature = "cache")]
+    let context = context(
+        data,
+        shared_data,
+        runner_tx,
+        shard_id,
+        &cache_and_http.http,
+        &cache_and_http.cache,
+    );
+
+    let raw_event_handler = Arc::clone(raw_event_handler);
+    let handler_settings = handler_settings.clone();
+
+    spawn_named("dispatch::raw_event_handler::raw_payload", async move {
+        run_raw_handler(
+            &handler_settings,
+            "raw_payload",
+            raw_event_handler.raw_payload(context, payload),
+        )
+        .await;
+    });
+}
+
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn dispatch<'rec>(
     // #[allow(unused_variables)]
     mut event: DispatchEvent,
     #[cfg(feature = "framework")] framework: &'rec Arc<dyn Framework + Send + Sync>,
     data: &'rec Arc<RwLock<TypeMap>>,
+    shared_data: &'rec Arc<SharedData>,
     event_handler: &'rec Option<Arc<dyn EventHandler>>,
     raw_event_handler: &'rec Option<Arc<dyn RawEventHandler>>,
+    handler_settings: &'rec HandlerSettings,
     runner_tx: &'rec Sender<InterMessage>,
     shard_id: u64,
     cache_and_http: Arc<CacheAndHttp>,
@@ -177,10 +239,17 @@ pub(crate) fn dispatch<'rec>(
                 #[cfg(feature = "framework")]
                 if let DispatchEvent::Model(Event::MessageCreate(event)) = event {
                     #[cfg(not(feature = "cache"))]
-                    let context = context(data, runner_tx, shard_id, &cache_and_http.http);
+                    let context = context(
+                        data,
+                        shared_data,
+                        runner_tx,
+                        shard_id,
+                        &cache_and_http.http,
+                    );
                     #[cfg(feature = "cache")]
                     let context = context(
                         data,
+                        shared_data,
                         runner_tx,
                         shard_id,
                         &cache_and_http.http,
@@ -199,10 +268,17 @@ pub(crate) fn dispatch<'rec>(
                     update(&cache_and_http, &mut event);
 
                     #[cfg(not(feature = "cache"))]
-                    let context = context(data, runner_tx, shard_id, &cache_and_http.http);
+                    let context = context(
+                        data,
+                        shared_data,
+                        runner_tx,
+                        shard_id,
+                        &cache_and_http.http,
+                    );
                     #[cfg(feature = "cache")]
                     let context = context(
                         data,
+                        shared_data,
                         runner_tx,
                         shard_id,
                         &cache_and_http.http,
@@ -212,12 +288,18 @@ pub(crate) fn dispatch<'rec>(
                     #[cfg(not(feature = "framework"))]
                     {
                         // Avoid cloning if there will be no framework dispatch.
-                        dispatch_message(context, event.message, h).await;
+                        dispatch_message(context, event.message, h, handler_settings).await;
                     }
 
                     #[cfg(feature = "framework")]
                     {
-                        dispatch_message(context.clone(), event.message.clone(), h).await;
+                        dispatch_message(
+                            context.clone(),
+                            event.message.clone(),
+                            h,
+                            handler_settings,
+                        )
+                        .await;
 
                         let framework = Arc::clone(framework);
 
@@ -227,7 +309,17 @@ pub(crate) fn dispatch<'rec>(
                     }
                 },
                 other => {
-                    handle_event(other, data, h, runner_tx, shard_id, cache_and_http).await;
+                    handle_event(
+                        other,
+                        data,
+                        shared_data,
+                        h,
+                        handler_settings,
+                        runner_tx,
+                        shard_id,
+                        cache_and_http,
+                    )
+                    .await;
                 },
             },
             (None, Some(ref rh)) => {
@@ -237,10 +329,17 @@ pub(crate) fn dispatch<'rec>(
                     let event_handler = Arc::clone(rh);
 
                     #[cfg(not(feature = "cache"))]
-                    let context = context(data, runner_tx, shard_id, &cache_and_http.http);
+                    let context = context(
+                        data,
+                        shared_data,
+                        runner_tx,
+                        shard_id,
+                        &cache_and_http.http,
+                    );
                     #[cfg(feature = "cache")]
                     let context = context(
                         data,
+                        shared_data,
                         runner_tx,
                         shard_id,
                         &cache_and_http.http,
@@ -250,7 +349,12 @@ pub(crate) fn dispatch<'rec>(
                     #[cfg(not(feature = "framework"))]
                     {
                         // No clone needed, as there will be no framework dispatch.
-                        event_handler.raw_event(context, event).await;
+                        run_raw_handler(
+                            handler_settings,
+                            "raw_event",
+                            event_handler.raw_event(context, event),
+                        )
+                        .await;
                     }
 
                     #[cfg(feature = "framework")]
@@ -258,7 +362,12 @@ pub(crate) fn dispatch<'rec>(
                         if let Event::MessageCreate(ref msg_event) = event {
                             // Must clone in order to dispatch the framework too.
                             let message = msg_event.message.clone();
-                            event_handler.raw_event(context.clone(), event).await;
+                            run_raw_handler(
+                                handler_settings,
+                                "raw_event",
+                                event_handler.raw_event(context.clone(), event),
+                            )
+                            .await;
 
                             let framework = Arc::clone(framework);
 
@@ -267,7 +376,12 @@ pub(crate) fn dispatch<'rec>(
                             });
                         } else {
                             // Avoid cloning if there will be no framework dispatch.
-                            event_handler.raw_event(context, event).await;
+                            run_raw_handler(
+                                handler_settings,
+                                "raw_event",
+                                event_handler.raw_event(context, event),
+                            )
+                            .await;
                         }
                     }
                 }
@@ -276,13 +390,24 @@ pub(crate) fn dispatch<'rec>(
             // and passing no framework, as we dispatch once we are done right here.
             (Some(ref handler), Some(ref raw_handler)) => {
                 #[cfg(not(feature = "cache"))]
-                let context = context(data, runner_tx, shard_id, &cache_and_http.http);
+                let context = context(data, shared_data, runner_tx, shard_id, &cache_and_http.http);
                 #[cfg(feature = "cache")]
-                let context =
-                    context(data, runner_tx, shard_id, &cache_and_http.http, &cache_and_http.cache);
+                let context = context(
+                    data,
+                    shared_data,
+                    runner_tx,
+                    shard_id,
+                    &cache_and_http.http,
+                    &cache_and_http.cache,
+                );
 
                 if let DispatchEvent::Model(ref event) = event {
-                    raw_handler.raw_event(context.clone(), event.clone()).await;
+                    run_raw_handler(
+                        handler_settings,
+                        "raw_event",
+                        raw_handler.raw_event(context.clone(), event.clone()),
+                    )
+                    .await;
                 }
 
                 match event {
@@ -290,12 +415,19 @@ pub(crate) fn dispatch<'rec>(
                         #[cfg(not(feature = "framework"))]
                         {
                             // Avoid cloning if there will be no framework dispatch.
-                            dispatch_message(context, event.message, handler).await;
+                            dispatch_message(context, event.message, handler, handler_settings)
+                                .await;
                         }
 
                         #[cfg(feature = "framework")]
                         {
-                            dispatch_message(context.clone(), event.message.clone(), handler).await;
+                            dispatch_message(
+                                context.clone(),
+                                event.message.clone(),
+                                handler,
+                                handler_settings,
+                            )
+                            .await;
 
                             let framework = Arc::clone(framework);
                             let message = event.message;
@@ -305,8 +437,17 @@ pub(crate) fn dispatch<'rec>(
                         }
                     },
                     other => {
-                        handle_event(other, data, handler, runner_tx, shard_id, cache_and_http)
-                            .await;
+                        handle_event(
+                            other,
+                            data,
+                            shared_data,
+                            handler,
+                            handler_settings,
+                            runner_tx,
+                            shard_id,
+                            cache_and_http,
+                        )
+                        .await;
                     },
                 }
             },
@@ -315,10 +456,107 @@ pub(crate) fn dispatch<'rec>(
     .boxed()
 }
 
+/// The settings applied to every handler invocation.
+#[derive(Clone, Default)]
+pub(crate) struct HandlerSettings {
+    /// The metrics to record [`EventHandler`] invocations in.
+    pub(crate) metrics: Option<Arc<DispatchMetrics>>,
+    /// Whether to catch and log panics of handlers, instead of letting them
+    /// unwind.
+    pub(crate) catch_panics: bool,
+    /// The events waiting on the [`EventHandler`] of the shard.
+    pub(crate) queue_metrics: Arc<ShardQueueMetrics>,
+}
+
+/// Spawns an [`EventHandler`] invocation, timing it if dispatch metrics are registered, and
+/// catching its panic if enabled.
+///
+/// The event is counted as waiting on the handler until the invocation finishes, even if it
+/// panicked.
+///
+/// The `name` of the task's event handler method is recorded as the event name.
+fn spawn_handler<F>(settings: &HandlerSettings, name: &'static str, future: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    let pending = settings.queue_metrics.start();
+
+    if settings.metrics.is_none() && !settings.catch_panics {
+        spawn_named(name, async move {
+            let _pending = pending;
+
+            future.await;
+        });
+
+        return;
+    }
+
+    let event = name.strip_prefix("dispatch::event_handler::").unwrap_or(name);
+    let settings = settings.clone();
+
+    spawn_named(name, async move {
+        let _pending = pending;
+
+        run_handler(settings, event, future).await;
+    });
+}
+
+/// Runs a handler invocation, timing it if dispatch metrics are registered.
+///
+/// If catching panics is enabled, a panic is logged instead of resumed, so that the task
+/// running the handler, such as a shard runner, keeps dispatching events.
+async fn run_handler<F>(settings: HandlerSettings, event: &'static str, future: F)
+where
+    F: Future<Output = ()>,
+{
+    let start = Instant::now();
+    let result = AssertUnwindSafe(future).catch_unwind().await;
+
+    if let Some(metrics) = &settings.metrics {
+        metrics.record(event, start.elapsed(), result.is_err());
+    }
+
+    if let Err(payload) = result {
+        if !settings.catch_panics {
+            // Resume the panic, so the task behaves as it would without metrics.
+            panic::resume_unwind(payload);
+        }
+
+        let message = payload
+            .downcast_ref::<&str>()
+            .copied()
+            .or_else(|| payload.downcast_ref::<String>().map(String::as_str))
+            .unwrap_or("Box<dyn Any>");
+
+        error!("The {} handler panicked: {}", event, message);
+    }
+}
+
+/// Runs a [`RawEventHandler`] invocation inline, catching its panic if enabled.
+///
+/// The invocation is not recorded in the dispatch metrics.
+async fn run_raw_handler<F>(settings: &HandlerSettings, event: &'static str, future: F)
+where
+    F: Future<Output = ()>,
+{
+    if settings.catch_panics {
+        let settings = HandlerSettings {
+            metrics: None,
+            catch_panics: true,
+            queue_metrics: Arc::default(),
+        };
+
+        run_handler(settings, event, future).await;
+    } else {
+        future.await;
+    }
+}
+
 async fn dispatch_message(
     context: Context,
     mut message: Message,
     event_handler: &Arc<dyn EventHandler>,
+    handler_settings: &HandlerSettings,
 ) {
     #[cfg(feature = "model")]
     {
@@ -327,26 +565,35 @@ async fn dispatch_message(
 
     let event_handler = Arc::clone(event_handler);
 
-    spawn_named("dispatch::event_handler::message", async move {
+    spawn_handler(handler_settings, "dispatch::event_handler::message", async move {
         event_handler.message(context, message).await;
     });
 }
 // Once we can use `Box` as part of a pattern, we will reconsider boxing.
 #[allow(clippy::too_many_arguments)]
 #[cfg_attr(feature = "cache", allow(clippy::used_underscore_binding))]
-#[instrument(skip(event, data, event_handler, cache_and_http))]
+#[instrument(skip(event, data, shared_data, event_handler, handler_settings, cache_and_http))]
 async fn handle_event(
     event: DispatchEvent,
     data: &Arc<RwLock<TypeMap>>,
+    shared_data: &Arc<SharedData>,
     event_handler: &Arc<dyn EventHandler>,
+    handler_settings: &HandlerSettings,
     runner_tx: &Sender<InterMessage>,
     shard_id: u64,
     cache_and_http: Arc<CacheAndHttp>,
 ) {
     #[cfg(not(feature = "cache"))]
-    let context = context(data, runner_tx, shard_id, &cache_and_http.http);
+    let context = context(data, shared_data, runner_tx, shard_id, &cache_and_http.http);
     #[cfg(feature = "cache")]
-    let context = context(data, runner_tx, shard_id, &cache_and_http.http, &cache_and_http.cache);
+    let context = context(
+        data,
+        shared_data,
+        runner_tx,
+        shard_id,
+        &cache_and_http.http,
+        &cache_and_http.cache,
+    );
 
     let event_handler = Arc::clone(event_handler);
 
@@ -355,10 +602,24 @@ async fn handle_event(
         DispatchEvent::Model(event) => event,
         DispatchEvent::Client(event) => {
             return match event {
+                #[cfg(feature = "cache")]
+                ClientEvent::CacheReady(guilds) => {
+                    spawn_handler(
+                        handler_settings,
+                        "dispatch::event_handler::cache_ready",
+                        async move {
+                            event_handler.cache_ready(context, guilds).await;
+                        },
+                    );
+                },
                 ClientEvent::ShardStageUpdate(event) => {
-                    spawn_named("dispatch::event_handler::shard_stage_update", async move {
-                        event_handler.shard_stage_update(context, event).await;
-                    });
+                    spawn_handler(
+                        handler_settings,
+                        "dispatch::event_handler::shard_stage_update",
+                        async move {
+                            event_handler.shard_stage_update(context, event).await;
+                        },
+                    );
                 },
             }
         },
@@ -367,7 +628,8 @@ async fn handle_event(
     // Handle Event, this is done to prevent indenting twice (once to destructure DispatchEvent, then to destructure Event)
     match model_event {
         Event::ApplicationCommandPermissionsUpdate(event) => {
-            spawn_named(
+            spawn_handler(
+                handler_settings,
                 "dispatch::event_handler::application_command_permissions_update",
                 async move {
                     event_handler
@@ -377,37 +639,61 @@ async fn handle_event(
             );
         },
         Event::AutoModerationRuleCreate(event) => {
-            spawn_named("dispatch::event_handler::auto_moderation_rule_create", async move {
-                event_handler.auto_moderation_rule_create(context, event.rule).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::auto_moderation_rule_create",
+                async move {
+                    event_handler.auto_moderation_rule_create(context, event.rule).await;
+                },
+            );
         },
         Event::AutoModerationRuleUpdate(event) => {
-            spawn_named("dispatch::event_handler::auto_moderation_rule_update", async move {
-                event_handler.auto_moderation_rule_update(context, event.rule).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::auto_moderation_rule_update",
+                async move {
+                    event_handler.auto_moderation_rule_update(context, event.rule).await;
+                },
+            );
         },
         Event::AutoModerationRuleDelete(event) => {
-            spawn_named("dispatch::event_handler::auto_moderation_rule_delete", async move {
-                event_handler.auto_moderation_rule_delete(context, event.rule).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::auto_moderation_rule_delete",
+                async move {
+                    event_handler.auto_moderation_rule_delete(context, event.rule).await;
+                },
+            );
         },
         Event::AutoModerationActionExecution(event) => {
-            spawn_named("dispatch::event_handler::auto_moderation_action_execution", async move {
-                event_handler.auto_moderation_action_execution(context, event.execution).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::auto_moderation_action_execution",
+                async move {
+                    event_handler.auto_moderation_action_execution(context, event.execution).await;
+                },
+            );
         },
         Event::ChannelCreate(mut event) => {
             update(&cache_and_http, &mut event);
             match event.channel {
                 Channel::Guild(channel) => {
-                    spawn_named("dispatch::event_handler::channel_create", async move {
-                        event_handler.channel_create(context, &channel).await;
-                    });
+                    spawn_handler(
+                        handler_settings,
+                        "dispatch::event_handler::channel_create",
+                        async move {
+                            event_handler.channel_create(context, &channel).await;
+                        },
+                    );
                 },
                 Channel::Category(channel) => {
-                    spawn_named("dispatch::event_handler::category_create", async move {
-                        event_handler.category_create(context, &channel).await;
-                    });
+                    spawn_handler(
+                        handler_settings,
+                        "dispatch::event_handler::category_create",
+                        async move {
+                            event_handler.category_create(context, &channel).await;
+                        },
+                    );
                 },
                 // Private channel create events are no longer sent to bots in the v8 gateway.
                 _ => {},
@@ -419,45 +705,100 @@ async fn handle_event(
             match event.channel {
                 Channel::Private(_) => {},
                 Channel::Guild(channel) => {
-                    spawn_named("dispatch::event_handler::channel_delete", async move {
-                        event_handler.channel_delete(context, &channel).await;
-                    });
+                    spawn_handler(
+                        handler_settings,
+                        "dispatch::event_handler::channel_delete",
+                        async move {
+                            event_handler.channel_delete(context, &channel).await;
+                        },
+                    );
                 },
                 Channel::Category(channel) => {
-                    spawn_named("dispatch::event_handler::category_delete", async move {
-                        event_handler.category_delete(context, &channel).await;
-                    });
+                    spawn_handler(
+                        handler_settings,
+                        "dispatch::event_handler::category_delete",
+                        async move {
+                            event_handler.category_delete(context, &channel).await;
+                        },
+                    );
                 },
             }
         },
         Event::ChannelPinsUpdate(event) => {
-            spawn_named("dispatch::event_handler::channel_pins_update", async move {
-                event_handler.channel_pins_update(context, event).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::channel_pins_update",
+                async move {
+                    event_handler.channel_pins_update(context, event).await;
+                },
+            );
         },
         Event::ChannelUpdate(mut event) => {
-            spawn_named("dispatch::event_handler::channel_update", async move {
-                feature_cache! {{
-                    let old_channel = cache_and_http.cache.as_ref().channel(event.channel.id());
-                    update(&cache_and_http, &mut event);
+            let _before = update(&cache_and_http, &mut event);
 
-                    event_handler.channel_update(context, old_channel, event.channel).await;
+            spawn_handler(handler_settings, "dispatch::event_handler::channel_update", async move {
+                feature_cache! {{
+                    event_handler.channel_update(context, _before, event.channel).await;
                 } else {
-                    update(&cache_and_http, &mut event);
-
                     event_handler.channel_update(context, event.channel).await;
                 }}
             });
         },
+        Event::EntitlementCreate(event) => {
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::entitlement_create",
+                async move {
+                    event_handler.entitlement_create(context, event.entitlement).await;
+                },
+            );
+        },
+        Event::EntitlementUpdate(event) => {
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::entitlement_update",
+                async move {
+                    event_handler.entitlement_update(context, event.entitlement).await;
+                },
+            );
+        },
+        Event::EntitlementDelete(event) => {
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::entitlement_delete",
+                async move {
+                    event_handler.entitlement_delete(context, event.entitlement).await;
+                },
+            );
+        },
+        Event::GuildAuditLogEntryCreate(event) => {
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::guild_audit_log_entry_create",
+                async move {
+                    event_handler
+                        .guild_audit_log_entry_create(context, event.entry, event.guild_id)
+                        .await;
+                },
+            );
+        },
         Event::GuildBanAdd(event) => {
-            spawn_named("dispatch::event_handler::guild_ban_addition", async move {
-                event_handler.guild_ban_addition(context, event.guild_id, event.user).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::guild_ban_addition",
+                async move {
+                    event_handler.guild_ban_addition(context, event.guild_id, event.user).await;
+                },
+            );
         },
         Event::GuildBanRemove(event) => {
-            spawn_named("dispatch::event_handler::guild_ban_removal", async move {
-                event_handler.guild_ban_removal(context, event.guild_id, event.user).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::guild_ban_removal",
+                async move {
+                    event_handler.guild_ban_removal(context, event.guild_id, event.user).await;
+                },
+            );
         },
         Event::GuildCreate(mut event) => {
             #[cfg(feature = "cache")]
@@ -465,26 +806,7 @@ async fn handle_event(
 
             update(&cache_and_http, &mut event);
 
-            #[cfg(feature = "cache")]
-            {
-                let context = context.clone();
-
-                if cache_and_http.cache.unavailable_guilds.is_empty() {
-                    let guild_amount = cache_and_http
-                        .cache
-                        .guilds
-                        .iter()
-                        .map(|i| *i.key())
-                        .collect::<Vec<GuildId>>();
-                    let event_handler = Arc::clone(&event_handler);
-
-                    spawn_named("dispatch::event_handler::cache_ready", async move {
-                        event_handler.cache_ready(context, guild_amount).await;
-                    });
-                }
-            }
-
-            spawn_named("dispatch::event_handler::guild_create", async move {
+            spawn_handler(handler_settings, "dispatch::event_handler::guild_create", async move {
                 feature_cache! {{
                     event_handler.guild_create(context, event.guild, _is_new).await;
                 } else {
@@ -495,7 +817,7 @@ async fn handle_event(
         Event::GuildDelete(mut event) => {
             let _full = update(&cache_and_http, &mut event);
 
-            spawn_named("dispatch::event_handler::guild_delete", async move {
+            spawn_handler(handler_settings, "dispatch::event_handler::guild_delete", async move {
                 feature_cache! {{
                     event_handler.guild_delete(context, event.guild, _full).await;
                 } else {
@@ -506,32 +828,48 @@ async fn handle_event(
         Event::GuildEmojisUpdate(mut event) => {
             update(&cache_and_http, &mut event);
 
-            spawn_named("dispatch::event_handler::guild_emojis_update", async move {
-                event_handler.guild_emojis_update(context, event.guild_id, event.emojis).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::guild_emojis_update",
+                async move {
+                    event_handler.guild_emojis_update(context, event.guild_id, event.emojis).await;
+                },
+            );
         },
         Event::GuildIntegrationsUpdate(event) => {
-            spawn_named("dispatch::event_handler::guild_integrations_update", async move {
-                event_handler.guild_integrations_update(context, event.guild_id).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::guild_integrations_update",
+                async move {
+                    event_handler.guild_integrations_update(context, event.guild_id).await;
+                },
+            );
         },
         Event::GuildMemberAdd(mut event) => {
             update(&cache_and_http, &mut event);
 
-            spawn_named("dispatch::event_handler::guild_member_addition", async move {
-                event_handler.guild_member_addition(context, event.member).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::guild_member_addition",
+                async move {
+                    event_handler.guild_member_addition(context, event.member).await;
+                },
+            );
         },
         Event::GuildMemberRemove(mut event) => {
             let _member = update(&cache_and_http, &mut event);
 
-            spawn_named("dispatch::event_handler::guild_member_removal", async move {
-                feature_cache! {{
-                    event_handler.guild_member_removal(context, event.guild_id, event.user, _member).await;
-                } else {
-                    event_handler.guild_member_removal(context, event.guild_id, event.user).await;
-                }}
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::guild_member_removal",
+                async move {
+                    feature_cache! {{
+                        event_handler.guild_member_removal(context, event.guild_id, event.user, _member).await;
+                    } else {
+                        event_handler.guild_member_removal(context, event.guild_id, event.user).await;
+                    }}
+                },
+            );
         },
         Event::GuildMemberUpdate(mut event) => {
             let _before = update(&cache_and_http, &mut event);
@@ -541,68 +879,95 @@ async fn handle_event(
                 None
             }};
 
-            spawn_named("dispatch::event_handler::guild_member_update", async move {
-                feature_cache! {{
-                    if let Some(after) = _after {
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::guild_member_update",
+                async move {
+                    feature_cache! {{
+                        let after = _after.unwrap_or_else(|| event.to_member());
                         event_handler.guild_member_update(context, _before, after).await;
-                    }
-                } else {
-                    event_handler.guild_member_update(context, event).await;
-                }}
-            });
+                    } else {
+                        event_handler.guild_member_update(context, event).await;
+                    }}
+                },
+            );
         },
         Event::GuildMembersChunk(mut event) => {
             update(&cache_and_http, &mut event);
 
-            spawn_named("dispatch::event_handler::guild_members_chunk", async move {
-                event_handler.guild_members_chunk(context, event).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::guild_members_chunk",
+                async move {
+                    event_handler.guild_members_chunk(context, event).await;
+                },
+            );
         },
         Event::GuildRoleCreate(mut event) => {
             update(&cache_and_http, &mut event);
 
-            spawn_named("dispatch::event_handler::guild_role_create", async move {
-                event_handler.guild_role_create(context, event.role).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::guild_role_create",
+                async move {
+                    event_handler.guild_role_create(context, event.role).await;
+                },
+            );
         },
         Event::GuildRoleDelete(mut event) => {
             let _role = update(&cache_and_http, &mut event);
 
-            spawn_named("dispatch::event_handler::guild_role_delete", async move {
-                feature_cache! {{
-                    event_handler.guild_role_delete(context, event.guild_id, event.role_id, _role).await;
-                } else {
-                    event_handler.guild_role_delete(context, event.guild_id, event.role_id).await;
-                }}
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::guild_role_delete",
+                async move {
+                    feature_cache! {{
+                        event_handler.guild_role_delete(context, event.guild_id, event.role_id, _role).await;
+                    } else {
+                        event_handler.guild_role_delete(context, event.guild_id, event.role_id).await;
+                    }}
+                },
+            );
         },
         Event::GuildRoleUpdate(mut event) => {
             let _before = update(&cache_and_http, &mut event);
 
-            spawn_named("dispatch::event_handler::guild_role_update", async move {
-                feature_cache! {{
-                    event_handler.guild_role_update(context, _before, event.role).await;
-                } else {
-                    event_handler.guild_role_update(context, event.role).await;
-                }}
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::guild_role_update",
+                async move {
+                    feature_cache! {{
+                        event_handler.guild_role_update(context, _before, event.role).await;
+                    } else {
+                        event_handler.guild_role_update(context, event.role).await;
+                    }}
+                },
+            );
         },
         Event::GuildStickersUpdate(mut event) => {
             update(&cache_and_http, &mut event);
 
-            tokio::spawn(async move {
-                event_handler.guild_stickers_update(context, event.guild_id, event.stickers).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::guild_stickers_update",
+                async move {
+                    event_handler.guild_stickers_update(context, event.guild_id, event.stickers).await;
+                },
+            );
         },
         Event::GuildUnavailable(mut event) => {
             update(&cache_and_http, &mut event);
 
-            spawn_named("dispatch::event_handler::guild_unavailable", async move {
-                event_handler.guild_unavailable(context, event.guild_id).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::guild_unavailable",
+                async move {
+                    event_handler.guild_unavailable(context, event.guild_id).await;
+                },
+            );
         },
         Event::GuildUpdate(mut event) => {
-            spawn_named("dispatch::event_handler::guild_update", async move {
+            spawn_handler(handler_settings, "dispatch::event_handler::guild_update", async move {
                 feature_cache! {{
                     let before = cache_and_http.cache
                         .guild(event.guild.id);
@@ -618,26 +983,45 @@ async fn handle_event(
             });
         },
         Event::InviteCreate(event) => {
-            spawn_named("dispatch::event_handler::invite_create", async move {
+            spawn_handler(handler_settings, "dispatch::event_handler::invite_create", async move {
                 event_handler.invite_create(context, event).await;
             });
         },
         Event::InviteDelete(event) => {
-            spawn_named("dispatch::event_handler::invite_delete", async move {
+            spawn_handler(handler_settings, "dispatch::event_handler::invite_delete", async move {
                 event_handler.invite_delete(context, event).await;
             });
         },
         // Already handled by the framework check macro
         Event::MessageCreate(_) => {},
-        Event::MessageDeleteBulk(event) => {
-            spawn_named("dispatch::event_handler::message_delete_bulk", async move {
-                event_handler
-                    .message_delete_bulk(context, event.channel_id, event.ids, event.guild_id)
-                    .await;
-            });
+        Event::MessageDeleteBulk(mut event) => {
+            let _deleted_messages = update(&cache_and_http, &mut event);
+
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::message_delete_bulk",
+                async move {
+                    feature_cache! {{
+                        let deleted_messages = _deleted_messages.unwrap_or_default();
+                        event_handler
+                            .message_delete_bulk(
+                                context,
+                                event.channel_id,
+                                event.ids,
+                                event.guild_id,
+                                deleted_messages,
+                            )
+                            .await;
+                    } else {
+                        event_handler
+                            .message_delete_bulk(context, event.channel_id, event.ids, event.guild_id)
+                            .await;
+                    }}
+                },
+            );
         },
         Event::MessageDelete(event) => {
-            spawn_named("dispatch::event_handler::message_delete", async move {
+            spawn_handler(handler_settings, "dispatch::event_handler::message_delete", async move {
                 event_handler
                     .message_delete(context, event.channel_id, event.message_id, event.guild_id)
                     .await;
@@ -646,7 +1030,7 @@ async fn handle_event(
         Event::MessageUpdate(mut event) => {
             let _before = update(&cache_and_http, &mut event);
 
-            spawn_named("dispatch::event_handler::message_update", async move {
+            spawn_handler(handler_settings, "dispatch::event_handler::message_update", async move {
                 feature_cache! {{
                     let _after = cache_and_http.cache.message(event.channel_id, event.id);
                     event_handler.message_update(context, _before, _after, event).await;
@@ -658,59 +1042,71 @@ async fn handle_event(
         Event::PresencesReplace(mut event) => {
             update(&cache_and_http, &mut event);
 
-            spawn_named("dispatch::event_handler::presence_replace", async move {
-                event_handler.presence_replace(context, event.presences).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::presence_replace",
+                async move {
+                    event_handler.presence_replace(context, event.presences).await;
+                },
+            );
         },
         Event::PresenceUpdate(mut event) => {
-            update(&cache_and_http, &mut event);
+            let _before = update(&cache_and_http, &mut event);
 
-            spawn_named("dispatch::event_handler::presence_update", async move {
-                event_handler.presence_update(context, event.presence).await;
+            spawn_handler(handler_settings, "dispatch::event_handler::presence_update", async move {
+                feature_cache! {{
+                    event_handler.presence_update(context, _before, event.presence).await;
+                } else {
+                    event_handler.presence_update(context, event.presence).await;
+                }}
             });
         },
         Event::ReactionAdd(event) => {
-            spawn_named("dispatch::event_handler::reaction_add", async move {
+            spawn_handler(handler_settings, "dispatch::event_handler::reaction_add", async move {
                 event_handler.reaction_add(context, event.reaction).await;
             });
         },
         Event::ReactionRemove(event) => {
-            spawn_named("dispatch::event_handler::reaction_remove", async move {
+            spawn_handler(handler_settings, "dispatch::event_handler::reaction_remove", async move {
                 event_handler.reaction_remove(context, event.reaction).await;
             });
         },
         Event::ReactionRemoveAll(event) => {
-            spawn_named("dispatch::event_handler::remove_all", async move {
-                event_handler
-                    .reaction_remove_all(context, event.channel_id, event.message_id)
-                    .await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::reaction_remove_all",
+                async move {
+                    event_handler
+                        .reaction_remove_all(context, event.channel_id, event.message_id)
+                        .await;
+                },
+            );
         },
         Event::Ready(mut event) => {
             update(&cache_and_http, &mut event);
-            spawn_named("dispatch::event_handler::ready", async move {
+            spawn_handler(handler_settings, "dispatch::event_handler::ready", async move {
                 event_handler.ready(context, event.ready).await;
             });
         },
         Event::Resumed(event) => {
-            spawn_named("dispatch::event_handler::resume", async move {
+            spawn_handler(handler_settings, "dispatch::event_handler::resume", async move {
                 event_handler.resume(context, event).await;
             });
         },
         Event::TypingStart(event) => {
-            spawn_named("dispatch::event_handler::typing_start", async move {
+            spawn_handler(handler_settings, "dispatch::event_handler::typing_start", async move {
                 event_handler.typing_start(context, event).await;
             });
         },
         Event::Unknown(event) => {
-            spawn_named("dispatch::event_handler::unknown", async move {
+            spawn_handler(handler_settings, "dispatch::event_handler::unknown", async move {
                 event_handler.unknown(context, event.kind, event.value).await;
             });
         },
         Event::UserUpdate(mut event) => {
             let _before = update(&cache_and_http, &mut event);
 
-            spawn_named("dispatch::event_handler::user_update", async move {
+            spawn_handler(handler_settings, "dispatch::event_handler::user_update", async move {
                 feature_cache! {{
                     event_handler.user_update(context, _before.expect("missing old user"), event.current_user).await;
                 } else {
@@ -719,123 +1115,398 @@ async fn handle_event(
             });
         },
         Event::VoiceServerUpdate(event) => {
-            spawn_named("dispatch::event_handler::voice_server_update", async move {
-                event_handler.voice_server_update(context, event).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::voice_server_update",
+                async move {
+                    event_handler.voice_server_update(context, event).await;
+                },
+            );
         },
         Event::VoiceStateUpdate(mut event) => {
             let _before = update(&cache_and_http, &mut event);
 
-            spawn_named("dispatch::event_handler::voice_state_update", async move {
-                feature_cache! {{
-                    event_handler.voice_state_update(context, _before, event.voice_state).await;
-                } else {
-                    event_handler.voice_state_update(context, event.voice_state).await;
-                }}
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::voice_state_update",
+                async move {
+                    feature_cache! {{
+                        event_handler.voice_state_update(context, _before, event.voice_state).await;
+                    } else {
+                        event_handler.voice_state_update(context, event.voice_state).await;
+                    }}
+                },
+            );
         },
         Event::WebhookUpdate(event) => {
-            spawn_named("dispatch::event_handler::webhook_update", async move {
+            spawn_handler(handler_settings, "dispatch::event_handler::webhook_update", async move {
                 event_handler.webhook_update(context, event.guild_id, event.channel_id).await;
             });
         },
         Event::InteractionCreate(event) => {
-            spawn_named("dispatch::event_handler::interaction_create", async move {
-                event_handler.interaction_create(context, event.interaction).await;
-            });
+            let interaction_handler = Arc::clone(&event_handler);
+            let interaction_context = context.clone();
+            let interaction = event.interaction.clone();
+
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::interaction_create",
+                async move {
+                    interaction_handler.interaction_create(interaction_context, interaction).await;
+                },
+            );
+
+            match event.interaction {
+                Interaction::ApplicationCommand(interaction) => {
+                    spawn_handler(
+                        handler_settings,
+                        "dispatch::event_handler::application_command_interaction",
+                        async move {
+                            event_handler
+                                .application_command_interaction(context, interaction)
+                                .await;
+                        },
+                    );
+                },
+                Interaction::MessageComponent(interaction) => {
+                    spawn_handler(
+                        handler_settings,
+                        "dispatch::event_handler::message_component_interaction",
+                        async move {
+                            event_handler.message_component_interaction(context, interaction).await;
+                        },
+                    );
+                },
+                Interaction::Autocomplete(interaction) => {
+                    spawn_handler(
+                        handler_settings,
+                        "dispatch::event_handler::autocomplete_interaction",
+                        async move {
+                            event_handler.autocomplete_interaction(context, interaction).await;
+                        },
+                    );
+                },
+                Interaction::ModalSubmit(interaction) => {
+                    spawn_handler(
+                        handler_settings,
+                        "dispatch::event_handler::modal_submit_interaction",
+                        async move {
+                            event_handler.modal_submit_interaction(context, interaction).await;
+                        },
+                    );
+                },
+                Interaction::Ping(_) => {},
+            }
         },
         Event::IntegrationCreate(event) => {
-            spawn_named("dispatch::event_handler::integration_create", async move {
-                event_handler.integration_create(context, event.integration).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::integration_create",
+                async move {
+                    event_handler.integration_create(context, event.integration).await;
+                },
+            );
         },
         Event::IntegrationUpdate(event) => {
-            spawn_named("dispatch::event_handler::integration_update", async move {
-                event_handler.integration_update(context, event.integration).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::integration_update",
+                async move {
+                    event_handler.integration_update(context, event.integration).await;
+                },
+            );
         },
         Event::IntegrationDelete(event) => {
-            spawn_named("dispatch::event_handler::integration_delete", async move {
-                event_handler
-                    .integration_delete(context, event.id, event.guild_id, event.application_id)
-                    .await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::integration_delete",
+                async move {
+                    event_handler
+                        .integration_delete(context, event.id, event.guild_id, event.application_id)
+                        .await;
+                },
+            );
         },
         Event::StageInstanceCreate(event) => {
-            spawn_named("dispatch::event_handler::stage_instance_create", async move {
-                event_handler.stage_instance_create(context, event.stage_instance).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::stage_instance_create",
+                async move {
+                    event_handler.stage_instance_create(context, event.stage_instance).await;
+                },
+            );
         },
         Event::StageInstanceUpdate(event) => {
-            spawn_named("dispatch::event_handler::stage_instance_update", async move {
-                event_handler.stage_instance_update(context, event.stage_instance).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::stage_instance_update",
+                async move {
+                    event_handler.stage_instance_update(context, event.stage_instance).await;
+                },
+            );
         },
         Event::StageInstanceDelete(event) => {
-            spawn_named("dispatch::event_handler::stage_instance_delete", async move {
-                event_handler.stage_instance_delete(context, event.stage_instance).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::stage_instance_delete",
+                async move {
+                    event_handler.stage_instance_delete(context, event.stage_instance).await;
+                },
+            );
         },
         Event::ThreadCreate(mut event) => {
             update(&cache_and_http, &mut event);
 
-            spawn_named("dispatch::event_handler::thread_create", async move {
+            spawn_handler(handler_settings, "dispatch::event_handler::thread_create", async move {
                 event_handler.thread_create(context, event.thread).await;
             });
         },
         Event::ThreadUpdate(mut event) => {
             update(&cache_and_http, &mut event);
 
-            spawn_named("dispatch::event_handler::thread_update", async move {
+            spawn_handler(handler_settings, "dispatch::event_handler::thread_update", async move {
                 event_handler.thread_update(context, event.thread).await;
             });
         },
         Event::ThreadDelete(mut event) => {
             update(&cache_and_http, &mut event);
 
-            spawn_named("dispatch::event_handler::thread_delete", async move {
+            spawn_handler(handler_settings, "dispatch::event_handler::thread_delete", async move {
                 event_handler.thread_delete(context, event.thread).await;
             });
         },
         Event::ThreadListSync(event) => {
-            spawn_named("dispatch::event_handler::thread_list_sync", async move {
-                event_handler.thread_list_sync(context, event).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::thread_list_sync",
+                async move {
+                    event_handler.thread_list_sync(context, event).await;
+                },
+            );
         },
         Event::ThreadMemberUpdate(event) => {
-            spawn_named("dispatch::event_handler::thread_member_update", async move {
-                event_handler.thread_member_update(context, event.member).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::thread_member_update",
+                async move {
+                    event_handler.thread_member_update(context, event.member).await;
+                },
+            );
         },
         Event::ThreadMembersUpdate(event) => {
-            spawn_named("dispatch::event_handler::thread_members_update", async move {
-                event_handler.thread_members_update(context, event).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::thread_members_update",
+                async move {
+                    event_handler.thread_members_update(context, event).await;
+                },
+            );
         },
         Event::GuildScheduledEventCreate(event) => {
-            spawn_named("dispatch::event_handler::guild_scheduled_event_create", async move {
-                event_handler.guild_scheduled_event_create(context, event.event).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::guild_scheduled_event_create",
+                async move {
+                    event_handler.guild_scheduled_event_create(context, event.event).await;
+                },
+            );
         },
         Event::GuildScheduledEventUpdate(event) => {
-            spawn_named("dispatch::event_handler::guild_scheduled_event_update", async move {
-                event_handler.guild_scheduled_event_update(context, event.event).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::guild_scheduled_event_update",
+                async move {
+                    event_handler.guild_scheduled_event_update(context, event.event).await;
+                },
+            );
         },
         Event::GuildScheduledEventDelete(event) => {
-            spawn_named("dispatch::event_handler::guild_scheduled_event_delete", async move {
-                event_handler.guild_scheduled_event_delete(context, event.event).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::guild_scheduled_event_delete",
+                async move {
+                    event_handler.guild_scheduled_event_delete(context, event.event).await;
+                },
+            );
         },
         Event::GuildScheduledEventUserAdd(event) => {
-            spawn_named("dispatch::event_handler::guild_scheduled_event_user_add", async move {
-                event_handler.guild_scheduled_event_user_add(context, event).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::guild_scheduled_event_user_add",
+                async move {
+                    event_handler.guild_scheduled_event_user_add(context, event).await;
+                },
+            );
         },
         Event::GuildScheduledEventUserRemove(event) => {
-            spawn_named("dispatch::event_handler::guild_scheduled_event_user_remove", async move {
-                event_handler.guild_scheduled_event_user_remove(context, event).await;
-            });
+            spawn_handler(
+                handler_settings,
+                "dispatch::event_handler::guild_scheduled_event_user_remove",
+                async move {
+                    event_handler.guild_scheduled_event_user_remove(context, event).await;
+                },
+            );
         },
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use async_trait::async_trait;
+    use futures::channel::{mpsc, oneshot};
+    use tokio::sync::RwLock;
+    use typemap_rev::TypeMap;
+
+    use super::{
+        dispatch,
+        run_handler,
+        run_raw_handler,
+        spawn_handler,
+        DispatchEvent,
+        HandlerSettings,
+    };
+    #[cfg(feature = "cache")]
+    use crate::cache::Cache;
+    use crate::client::{Context, DispatchMetrics, RawEventHandler};
+    #[cfg(feature = "framework")]
+    use crate::framework::Framework;
+    use crate::http::Http;
+    #[cfg(feature = "framework")]
+    use crate::model::channel::Message;
+    use crate::model::event::{Event, ResumedEvent};
+    use crate::CacheAndHttp;
+
+    #[cfg(feature = "framework")]
+    struct NoFramework;
+
+    #[cfg(feature = "framework")]
+    #[async_trait]
+    impl Framework for NoFramework {
+        async fn dispatch(&self, _: Context, _: Message) {}
+    }
+
+    /// Panics on the first event it receives.
+    #[derive(Default)]
+    struct PanickingHandler {
+        events: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl RawEventHandler for PanickingHandler {
+        async fn raw_event(&self, _: Context, _: Event) {
+            if self.events.fetch_add(1, Ordering::SeqCst) == 0 {
+                panic!("raw handler panicked");
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn dispatch_after_panic() {
+        #[cfg(feature = "framework")]
+        let framework: Arc<dyn Framework + Send + Sync> = Arc::new(NoFramework);
+        let data = Arc::new(RwLock::new(TypeMap::new()));
+        let shared_data = Arc::default();
+        let handler = Arc::new(PanickingHandler::default());
+        let raw_event_handler: Option<Arc<dyn RawEventHandler>> = Some(handler.clone());
+        let settings = HandlerSettings {
+            metrics: None,
+            catch_panics: true,
+            queue_metrics: Arc::default(),
+        };
+        let (runner_tx, _runner_rx) = mpsc::unbounded();
+        let cache_and_http = Arc::new(CacheAndHttp {
+            #[cfg(feature = "cache")]
+            cache: Arc::new(Cache::new()),
+            http: Arc::new(Http::new("")),
+        });
+
+        // The raw event handler runs inline, so a shard runner awaiting this would
+        // otherwise unwind on the first event and never dispatch the second.
+        for _ in 0..2 {
+            let event = DispatchEvent::Model(Event::Resumed(ResumedEvent {
+                trace: vec![],
+            }));
+
+            dispatch(
+                event,
+                #[cfg(feature = "framework")]
+                &framework,
+                &data,
+                &shared_data,
+                &None,
+                &raw_event_handler,
+                &settings,
+                &runner_tx,
+                0,
+                Arc::clone(&cache_and_http),
+            )
+            .await;
+        }
+
+        assert_eq!(handler.events.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn catch_panics() {
+        let metrics = Arc::new(DispatchMetrics::new());
+        let settings = HandlerSettings {
+            metrics: Some(Arc::clone(&metrics)),
+            catch_panics: true,
+            queue_metrics: Arc::default(),
+        };
+
+        // Events following the one whose handler panicked are still dispatched.
+        for i in 0..3 {
+            run_handler(settings.clone(), "message", async move {
+                assert_ne!(i, 1, "handler panicked");
+            })
+            .await;
+            run_raw_handler(&settings, "raw_event", async move {
+                assert_ne!(i, 1, "raw handler panicked");
+            })
+            .await;
+        }
+
+        let message = metrics.snapshot()["message"];
+        assert_eq!(message.count, 3);
+        assert_eq!(message.panics, 1);
+    }
+
+    #[tokio::test]
+    async fn queue_metrics() {
+        let settings = HandlerSettings::default();
+        let queue = &settings.queue_metrics;
+        let (tx, rx) = oneshot::channel();
+
+        spawn_handler(&settings, "dispatch::event_handler::message", async move {
+            rx.await.unwrap();
+        });
+        spawn_handler(&settings, "dispatch::event_handler::message", async {
+            panic!("handler panicked");
+        });
+        assert_eq!((queue.depth(), queue.high_watermark()), (2, 2));
+
+        // The events stop waiting once their handler finished, even if it panicked.
+        tx.send(()).unwrap();
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while queue.depth() > 0 {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .unwrap();
+        assert_eq!(queue.high_watermark(), 2);
+    }
+
+    #[tokio::test]
+    #[should_panic(expected = "raw handler panicked")]
+    async fn fail_fast() {
+        let settings = HandlerSettings::default();
+
+        run_raw_handler(&settings, "raw_event", async { panic!("raw handler panicked") }).await;
+    }
+}
@@ -7,7 +7,12 @@ use crate::client::bridge::gateway::event::*;
 use crate::http::ratelimiting::RatelimitInfo;
 use crate::json::Value;
 use crate::model::application::command::CommandPermission;
+use crate::model::application::interaction::application_command::ApplicationCommandInteraction;
+use crate::model::application::interaction::autocomplete::AutocompleteInteraction;
+use crate::model::application::interaction::message_component::MessageComponentInteraction;
+use crate::model::application::interaction::modal::ModalSubmitInteraction;
 use crate::model::application::interaction::Interaction;
+use crate::model::guild::audit_log::AuditLogEntry;
 use crate::model::guild::automod::{ActionExecution, Rule};
 use crate::model::prelude::*;
 
@@ -27,31 +32,57 @@ pub trait EventHandler: Send + Sync {
     /// Dispatched when an auto moderation rule was created.
     ///
     /// Provides said rule's data.
+    ///
+    /// Note: This event will not trigger unless the
+    /// [`GatewayIntents::AUTO_MODERATION_CONFIGURATION`] intent is enabled.
     async fn auto_moderation_rule_create(&self, _ctx: Context, _rule: Rule) {}
 
     /// Dispatched when an auto moderation rule was updated.
     ///
     /// Provides said rule's data.
+    ///
+    /// Note: This event will not trigger unless the
+    /// [`GatewayIntents::AUTO_MODERATION_CONFIGURATION`] intent is enabled.
     async fn auto_moderation_rule_update(&self, _ctx: Context, _rule: Rule) {}
 
     /// Dispatched when an auto moderation rule was deleted.
     ///
     /// Provides said rule's data.
+    ///
+    /// Note: This event will not trigger unless the
+    /// [`GatewayIntents::AUTO_MODERATION_CONFIGURATION`] intent is enabled.
     async fn auto_moderation_rule_delete(&self, _ctx: Context, _rule: Rule) {}
 
     /// Dispatched when an auto moderation rule was triggered and an action was executed.
     ///
     /// Provides said action execution's data.
+    ///
+    /// Note: This event will not trigger unless the
+    /// [`GatewayIntents::AUTO_MODERATION_EXECUTION`] intent is enabled. The
+    /// [`content`] and [`matched_content`] fields additionally require the
+    /// [`GatewayIntents::MESSAGE_CONTENT`] intent to be non-empty.
+    ///
+    /// [`content`]: ActionExecution::content
+    /// [`matched_content`]: ActionExecution::matched_content
     async fn auto_moderation_action_execution(&self, _ctx: Context, _execution: ActionExecution) {}
 
-    /// Dispatched when the cache has received and inserted all data from
-    /// guilds.
+    /// Dispatched once per shard session when the cache has received and
+    /// inserted all guilds announced by the shard's [`Self::ready`] event.
+    ///
+    /// Unlike [`Self::ready`], which is dispatched before any guild data is
+    /// available, this waits until every guild from the [`Ready`] event has
+    /// been received through a [`Self::guild_create`] event. Cache actions
+    /// performed prior this event may fail as the data could be not inserted
+    /// yet.
     ///
-    /// This process happens upon starting your bot and should be fairly quick.
-    /// However, cache actions performed prior this event may fail as the data
-    /// could be not inserted yet.
+    /// If no further guild from the [`Ready`] event arrives within 10 seconds,
+    /// for example because of an outage, this is dispatched anyway. The
+    /// missing guilds are then left out and remain in
+    /// [`Cache::unavailable_guilds`] until they are received.
     ///
-    /// Provides the cached guilds' ids.
+    /// Provides the ids of the shard's guilds that were received.
+    ///
+    /// [`Cache::unavailable_guilds`]: crate::cache::Cache::unavailable_guilds
     #[cfg(feature = "cache")]
     async fn cache_ready(&self, _ctx: Context, _guilds: Vec<GuildId>) {}
 
@@ -83,6 +114,9 @@ pub trait EventHandler: Send + Sync {
     /// Dispatched when a channel is updated.
     ///
     /// Provides the old channel data, and the new data.
+    ///
+    /// The old data is taken from the cache before the update is applied, and is [`None`] if the
+    /// channel was not cached.
     #[cfg(feature = "cache")]
     async fn channel_update(&self, _ctx: Context, _old: Option<Channel>, _new: Channel) {}
 
@@ -92,6 +126,35 @@ pub trait EventHandler: Send + Sync {
     #[cfg(not(feature = "cache"))]
     async fn channel_update(&self, _ctx: Context, _new_data: Channel) {}
 
+    /// Dispatched when a user subscribes to or purchases a SKU of the application.
+    ///
+    /// Provides the new entitlement.
+    async fn entitlement_create(&self, _ctx: Context, _entitlement: Entitlement) {}
+
+    /// Dispatched when an entitlement is updated, such as when a subscription renews.
+    ///
+    /// Provides the updated entitlement.
+    async fn entitlement_update(&self, _ctx: Context, _entitlement: Entitlement) {}
+
+    /// Dispatched when an entitlement is deleted.
+    ///
+    /// Entitlements are usually only deleted when Discord issues a refund or removes them
+    /// manually. Provides the deleted entitlement.
+    async fn entitlement_delete(&self, _ctx: Context, _entitlement: Entitlement) {}
+
+    /// Dispatched when an entry is added to a guild's audit log.
+    ///
+    /// Provides the new entry and the guild's id. This requires the
+    /// [`Permissions::VIEW_AUDIT_LOG`] permission and the
+    /// [`GatewayIntents::GUILD_BANS`] intent.
+    async fn guild_audit_log_entry_create(
+        &self,
+        _ctx: Context,
+        _entry: AuditLogEntry,
+        _guild_id: GuildId,
+    ) {
+    }
+
     /// Dispatched when a user is banned from a guild.
     ///
     /// Provides the guild's id and the banned user's data.
@@ -202,6 +265,10 @@ pub trait EventHandler: Send + Sync {
     ///
     /// Provides the member's old data (if available) and the new data.
     ///
+    /// The old data is taken from the cache before the update is applied, and is [`None`] if the
+    /// member or their guild was not cached. The new data is the cached member after the update,
+    /// or is built from the event if the guild is not cached.
+    ///
     /// Note: This event will not trigger unless the "guild members" privileged intent
     /// is enabled on the bot application page.
     #[cfg(feature = "cache")]
@@ -255,6 +322,9 @@ pub trait EventHandler: Send + Sync {
     /// Dispatched when a role is updated.
     ///
     /// Provides the guild's id, the role's old (if available) and new data.
+    ///
+    /// The old data is taken from the cache before the update is applied, and is [`None`] if the
+    /// role or its guild was not cached.
     #[cfg(feature = "cache")]
     async fn guild_role_update(
         &self,
@@ -331,9 +401,29 @@ pub trait EventHandler: Send + Sync {
     ) {
     }
 
+    /// Dispatched when multiple messages were deleted at once.
+    ///
+    /// Provides the guild's id, channel's id and the deleted messages' ids, along with the
+    /// deleted messages that were in the cache.
+    ///
+    /// **Note**: Discord only sends the Ids of the deleted messages, so the content of a message
+    /// is only available if it was cached before being deleted. Messages that weren't cached are
+    /// missing from `_cached_messages`, and the ones that were are removed from the cache.
+    #[cfg(feature = "cache")]
+    async fn message_delete_bulk(
+        &self,
+        _ctx: Context,
+        _channel_id: ChannelId,
+        _multiple_deleted_messages_ids: Vec<MessageId>,
+        _guild_id: Option<GuildId>,
+        _cached_messages: Vec<Message>,
+    ) {
+    }
+
     /// Dispatched when multiple messages were deleted at once.
     ///
     /// Provides the guild's id, channel's id and the deleted messages' ids.
+    #[cfg(not(feature = "cache"))]
     async fn message_delete_bulk(
         &self,
         _ctx: Context,
@@ -388,12 +478,31 @@ pub trait EventHandler: Send + Sync {
     /// This event is legacy, and likely no longer sent by discord.
     async fn presence_replace(&self, _ctx: Context, _: Vec<Presence>) {}
 
+    /// Dispatched when a user's presence is updated (e.g off -> on).
+    ///
+    /// Provides the presence's old data (if available) and the new data.
+    ///
+    /// The old data is [`None`] for the first presence seen for a user, or if
+    /// the user was previously offline.
+    ///
+    /// Note: This event will not trigger unless the "guild presences" privileged intent
+    /// is enabled on the bot application page.
+    #[cfg(feature = "cache")]
+    async fn presence_update(
+        &self,
+        _ctx: Context,
+        _old_data_if_available: Option<Presence>,
+        _new_data: Presence,
+    ) {
+    }
+
     /// Dispatched when a user's presence is updated (e.g off -> on).
     ///
     /// Provides the presence's new data.
     ///
     /// Note: This event will not trigger unless the "guild presences" privileged intent
     /// is enabled on the bot application page.
+    #[cfg(not(feature = "cache"))]
     async fn presence_update(&self, _ctx: Context, _new_data: Presence) {}
 
     /// Dispatched upon startup.
@@ -438,6 +547,11 @@ pub trait EventHandler: Send + Sync {
     ///
     /// Provides the old state (if [`GatewayIntents::GUILDS`] is enabled) and the new state of the
     /// guild's voice channels.
+    ///
+    /// The old state is [`None`] when the user joined a voice channel, as well as the first time
+    /// the user's state is observed, e.g. when the guild is not cached. Otherwise, comparing the
+    /// [`VoiceState::channel_id`]s tells whether the user moved (both are set and differ) or left
+    /// (the new one is [`None`]).
     #[cfg(feature = "cache")]
     async fn voice_state_update(&self, _ctx: Context, _old: Option<VoiceState>, _new: VoiceState) {}
 
@@ -461,8 +575,47 @@ pub trait EventHandler: Send + Sync {
     /// Dispatched when an interaction is created (e.g a slash command was used or a button was clicked).
     ///
     /// Provides the created interaction.
+    ///
+    /// **Note**: The handler specific to the interaction's type, such as
+    /// [`Self::application_command_interaction`], is dispatched as well, so a
+    /// bot should only handle each interaction in one of them.
     async fn interaction_create(&self, _ctx: Context, _interaction: Interaction) {}
 
+    /// Dispatched when an application command interaction is created, along
+    /// with [`Self::interaction_create`].
+    ///
+    /// Provides the created interaction.
+    async fn application_command_interaction(
+        &self,
+        _ctx: Context,
+        _interaction: ApplicationCommandInteraction,
+    ) {
+    }
+
+    /// Dispatched when a message component interaction is created, along with
+    /// [`Self::interaction_create`].
+    ///
+    /// Provides the created interaction.
+    async fn message_component_interaction(
+        &self,
+        _ctx: Context,
+        _interaction: MessageComponentInteraction,
+    ) {
+    }
+
+    /// Dispatched when an autocomplete interaction is created, along with
+    /// [`Self::interaction_create`].
+    ///
+    /// Provides the created interaction.
+    async fn autocomplete_interaction(&self, _ctx: Context, _interaction: AutocompleteInteraction) {
+    }
+
+    /// Dispatched when a modal submit interaction is created, along with
+    /// [`Self::interaction_create`].
+    ///
+    /// Provides the created interaction.
+    async fn modal_submit_interaction(&self, _ctx: Context, _interaction: ModalSubmitInteraction) {}
+
     /// Dispatched when a guild integration is created.
     ///
     /// Provides the created integration.
@@ -576,7 +729,14 @@ pub trait EventHandler: Send + Sync {
     ) {
     }
 
-    /// Dispatched when an HTTP rate limit is hit
+    /// Dispatched when an HTTP rate limit is hit, before the request is
+    /// delayed until the rate limit passes.
+    ///
+    /// Provides the route, bucket, delay and limit of the rate limit, and
+    /// whether it was the global rate limit. To receive these when only using
+    /// the HTTP client, use [`HttpBuilder::ratelimit_callback`] instead.
+    ///
+    /// [`HttpBuilder::ratelimit_callback`]: crate::http::HttpBuilder::ratelimit_callback
     async fn ratelimit(&self, _data: RatelimitInfo) {}
 }
 
@@ -585,4 +745,23 @@ pub trait EventHandler: Send + Sync {
 pub trait RawEventHandler: Send + Sync {
     /// Dispatched when any event occurs
     async fn raw_event(&self, _ctx: Context, _ev: Event) {}
+
+    /// Dispatched with the undecoded payload of any gateway dispatch, before
+    /// it is deserialized into an [`Event`].
+    ///
+    /// The payload is the entire gateway message, with the event's name under
+    /// `"t"` and its data under `"d"`. Unlike [`Self::raw_event`], this is also
+    /// dispatched for events which fail to deserialize, such as ones which
+    /// Discord has recently changed.
+    ///
+    /// **Note**: This is only dispatched if [`Self::wants_raw_payloads`]
+    /// returns `true`, as every payload has to be copied for it.
+    async fn raw_payload(&self, _ctx: Context, _payload: Value) {}
+
+    /// Whether [`Self::raw_payload`] should be dispatched.
+    ///
+    /// Defaults to `false`.
+    fn wants_raw_payloads(&self) -> bool {
+        false
+    }
 }
@@ -0,0 +1,128 @@
+use std::collections::HashMap;
+use std::sync::{Mutex, PoisonError};
+use std::time::Duration;
+
+/// Aggregated timings of [`EventHandler`] invocations, keyed by event name.
+///
+/// Register an instance via [`ClientBuilder::dispatch_metrics`] to have every
+/// dispatch timed. When no instance is registered, handlers are dispatched as
+/// they would be otherwise, without any timing.
+///
+/// Event names are those of the [`EventHandler`] methods, e.g. `"message"` or
+/// `"guild_member_update"`. [`RawEventHandler`] invocations are not timed.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// # use serenity::prelude::*;
+/// # use std::sync::Arc;
+/// use serenity::client::DispatchMetrics;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let metrics = Arc::new(DispatchMetrics::new());
+/// let client = Client::builder("token", GatewayIntents::default())
+///     .dispatch_metrics(Arc::clone(&metrics))
+///     .await?;
+///
+/// // Later on, possibly from a command.
+/// for (event, stats) in metrics.snapshot() {
+///     println!("{}: {} calls, {:?} on average", event, stats.count, stats.average_duration());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+///
+/// [`EventHandler`]: super::EventHandler
+/// [`RawEventHandler`]: super::RawEventHandler
+/// [`ClientBuilder::dispatch_metrics`]: super::ClientBuilder::dispatch_metrics
+#[derive(Debug, Default)]
+pub struct DispatchMetrics {
+    events: Mutex<HashMap<&'static str, EventMetrics>>,
+}
+
+impl DispatchMetrics {
+    /// Creates an empty set of metrics.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records a single invocation of the handler for the given event.
+    pub(crate) fn record(&self, event: &'static str, duration: Duration, panicked: bool) {
+        let mut events = self.events.lock().unwrap_or_else(PoisonError::into_inner);
+        let metrics = events.entry(event).or_default();
+
+        metrics.count += 1;
+        metrics.total_duration += duration;
+        metrics.max_duration = metrics.max_duration.max(duration);
+
+        if panicked {
+            metrics.panics += 1;
+        }
+    }
+
+    /// Returns a copy of the metrics recorded so far, keyed by event name.
+    #[must_use]
+    pub fn snapshot(&self) -> HashMap<&'static str, EventMetrics> {
+        self.events.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    /// Clears all of the metrics recorded so far.
+    pub fn reset(&self) {
+        self.events.lock().unwrap_or_else(PoisonError::into_inner).clear();
+    }
+}
+
+/// The metrics of a single event's handler, as returned by
+/// [`DispatchMetrics::snapshot`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
+pub struct EventMetrics {
+    /// The number of times the handler was invoked.
+    pub count: u64,
+    /// The number of invocations that panicked.
+    pub panics: u64,
+    /// The time spent in the handler across all invocations.
+    pub total_duration: Duration,
+    /// The time spent in the longest invocation.
+    pub max_duration: Duration,
+}
+
+impl EventMetrics {
+    /// The average time spent per invocation.
+    #[must_use]
+    pub fn average_duration(&self) -> Duration {
+        if self.count == 0 {
+            return Duration::default();
+        }
+
+        Duration::from_nanos((self.total_duration.as_nanos() / u128::from(self.count)) as u64)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use super::DispatchMetrics;
+
+    #[test]
+    fn record_and_snapshot() {
+        let metrics = DispatchMetrics::new();
+        metrics.record("message", Duration::from_millis(10), false);
+        metrics.record("message", Duration::from_millis(30), true);
+        metrics.record("ready", Duration::from_millis(5), false);
+
+        let snapshot = metrics.snapshot();
+        let message = snapshot["message"];
+        assert_eq!(message.count, 2);
+        assert_eq!(message.panics, 1);
+        assert_eq!(message.total_duration, Duration::from_millis(40));
+        assert_eq!(message.max_duration, Duration::from_millis(30));
+        assert_eq!(message.average_duration(), Duration::from_millis(20));
+        assert_eq!(snapshot["ready"].count, 1);
+
+        metrics.reset();
+        assert!(metrics.snapshot().is_empty());
+    }
+}
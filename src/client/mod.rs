@@ -24,6 +24,9 @@ mod dispatch;
 mod error;
 #[cfg(feature = "gateway")]
 mod event_handler;
+#[cfg(feature = "gateway")]
+mod metrics;
+mod shared_data;
 
 use std::future::Future;
 use std::pin::Pin;
@@ -41,6 +44,7 @@ use self::bridge::gateway::{
     ShardManagerError,
     ShardManagerMonitor,
     ShardManagerOptions,
+    ShardSettings,
 };
 #[cfg(feature = "voice")]
 use self::bridge::voice::VoiceGatewayManager;
@@ -49,7 +53,10 @@ pub use self::error::Error as ClientError;
 #[cfg(feature = "gateway")]
 pub use self::event_handler::{EventHandler, RawEventHandler};
 #[cfg(feature = "gateway")]
-use super::gateway::GatewayError;
+pub use self::metrics::{DispatchMetrics, EventMetrics};
+pub use self::shared_data::SharedData;
+#[cfg(feature = "gateway")]
+use super::gateway::{GatewayError, TcpSettings};
 #[cfg(feature = "cache")]
 pub use crate::cache::Cache;
 #[cfg(feature = "cache")]
@@ -70,6 +77,7 @@ pub struct ClientBuilder {
     // TODO: data, http and cache_settings are Options in order to take() them out in the Future impl.
     // This should be changed after the stabilization of std::future::IntoFuture.
     data: Option<TypeMap>,
+    shared_data: SharedData,
     http: Option<Http>,
     fut: Option<BoxFuture<'static, Result<Client>>>,
     intents: GatewayIntents,
@@ -81,6 +89,10 @@ pub struct ClientBuilder {
     voice_manager: Option<Arc<dyn VoiceGatewayManager + Send + Sync + 'static>>,
     event_handler: Option<Arc<dyn EventHandler>>,
     raw_event_handler: Option<Arc<dyn RawEventHandler>>,
+    dispatch_metrics: Option<Arc<DispatchMetrics>>,
+    catch_panics: bool,
+    queue_depth_warning: Option<usize>,
+    tcp_settings: TcpSettings,
 }
 
 #[cfg(feature = "gateway")]
@@ -88,6 +100,7 @@ impl ClientBuilder {
     fn _new(http: Http, intents: GatewayIntents) -> Self {
         Self {
             data: Some(TypeMap::new()),
+            shared_data: SharedData::new(),
             http: Some(http),
             fut: None,
             intents,
@@ -99,6 +112,10 @@ impl ClientBuilder {
             voice_manager: None,
             event_handler: None,
             raw_event_handler: None,
+            dispatch_metrics: None,
+            catch_panics: false,
+            queue_depth_warning: None,
+            tcp_settings: TcpSettings::default(),
         }
     }
 
@@ -177,6 +194,23 @@ impl ClientBuilder {
         self
     }
 
+    /// Insert a single `value` that will be available via [`Context::data()`],
+    /// keyed by its type and without any locking.
+    ///
+    /// This is the preferred way to share read-mostly state, such as
+    /// configuration or database pools, with handlers. Refer to
+    /// [`SharedData`] for more information.
+    pub fn data_insert<T: Send + Sync + 'static>(mut self, value: T) -> Self {
+        self.shared_data.insert(value);
+
+        self
+    }
+
+    /// Gets the data inserted via [`Self::data_insert`].
+    pub fn get_shared_data(&self) -> &SharedData {
+        &self.shared_data
+    }
+
     /// Sets the settings of the cache.
     /// Refer to [`Settings`] for more information.
     ///
@@ -345,6 +379,83 @@ impl ClientBuilder {
     pub fn get_raw_event_handler(&self) -> Option<Arc<dyn RawEventHandler>> {
         self.raw_event_handler.clone()
     }
+
+    /// Sets the metrics that [`EventHandler`] invocations are recorded in.
+    ///
+    /// Each invocation's event name, duration and whether it panicked is
+    /// recorded. Keep a clone of the [`Arc`] to read them via
+    /// [`DispatchMetrics::snapshot`], or use [`Client::dispatch_metrics`].
+    ///
+    /// [`RawEventHandler`] invocations are not recorded.
+    pub fn dispatch_metrics(mut self, dispatch_metrics: Arc<DispatchMetrics>) -> Self {
+        self.dispatch_metrics = Some(dispatch_metrics);
+
+        self
+    }
+
+    /// Gets the dispatch metrics, if already initialized. See [`Self::dispatch_metrics`] for more
+    /// info.
+    pub fn get_dispatch_metrics(&self) -> Option<Arc<DispatchMetrics>> {
+        self.dispatch_metrics.clone()
+    }
+
+    /// Sets whether panics of event handlers are caught and logged along with
+    /// the event name, instead of unwinding.
+    ///
+    /// [`EventHandler`] methods are run in their own tasks, so their panics do
+    /// not affect other events either way. [`RawEventHandler`] methods however
+    /// are run by the shard runner itself, which a panic stops unless caught.
+    ///
+    /// Disabled by default, so that panics fail fast.
+    pub fn catch_panics(mut self, catch_panics: bool) -> Self {
+        self.catch_panics = catch_panics;
+
+        self
+    }
+
+    /// Gets whether panics of event handlers are caught. See [`Self::catch_panics`] for more info.
+    pub fn get_catch_panics(&self) -> bool {
+        self.catch_panics
+    }
+
+    /// Sets the number of events waiting on the [`EventHandler`] of a shard
+    /// above which a warning is logged, to help spot a handler that cannot
+    /// keep up. Each shard logs the warning at most once every 30 seconds.
+    ///
+    /// An event is waiting on the handler from when it is dispatched until
+    /// the handler's invocation finishes. The number of events waiting on
+    /// each shard is available regardless as [`ShardHealth::queue_depth`] and
+    /// [`ShardHealth::queue_high_watermark`], as returned by
+    /// [`ShardManager::health`]. No warning is logged by default.
+    ///
+    /// [`ShardHealth::queue_depth`]: crate::client::bridge::gateway::ShardHealth::queue_depth
+    /// [`ShardHealth::queue_high_watermark`]: crate::client::bridge::gateway::ShardHealth::queue_high_watermark
+    pub fn queue_depth_warning(mut self, threshold: usize) -> Self {
+        self.queue_depth_warning = Some(threshold);
+
+        self
+    }
+
+    /// Gets the queue depth above which a warning is logged, if set. See
+    /// [`Self::queue_depth_warning`] for more info.
+    pub fn get_queue_depth_warning(&self) -> Option<usize> {
+        self.queue_depth_warning
+    }
+
+    /// Sets the TCP options applied to every shard's gateway connection.
+    ///
+    /// By default, Nagle's algorithm is disabled and keepalive probes are sent
+    /// after 60 seconds of inactivity. See [`TcpSettings`] for more info.
+    pub fn tcp_settings(mut self, tcp_settings: TcpSettings) -> Self {
+        self.tcp_settings = tcp_settings;
+
+        self
+    }
+
+    /// Gets the TCP settings. See [`Self::tcp_settings`] for more info.
+    pub fn get_tcp_settings(&self) -> TcpSettings {
+        self.tcp_settings
+    }
 }
 
 #[cfg(feature = "gateway")]
@@ -356,17 +467,26 @@ impl Future for ClientBuilder {
     fn poll(mut self: Pin<&mut Self>, ctx: &mut FutContext<'_>) -> Poll<Self::Output> {
         if self.fut.is_none() {
             let data = Arc::new(RwLock::new(self.data.take().unwrap()));
+            let shared_data = Arc::new(std::mem::take(&mut self.shared_data));
             #[cfg(feature = "framework")]
             let framework = self.framework.take()
                 .expect("The `framework`-feature is enabled (it's on by default), but no framework was provided.\n\
                 If you don't want to use the command framework, disable default features and specify all features you want to use.");
             let event_handler = self.event_handler.take();
             let raw_event_handler = self.raw_event_handler.take();
+            let dispatch_metrics = self.dispatch_metrics.take();
+            let catch_panics = self.catch_panics;
+            let queue_depth_warning = self.queue_depth_warning;
             let intents = self.intents;
+            let tcp_settings = self.tcp_settings;
 
             let mut http = self.http.take().unwrap();
             if let Some(event_handler) = event_handler.clone() {
+                let ratelimit_callback = http.ratelimiter.take_ratelimit_callback();
+
                 http.ratelimiter.set_ratelimit_callback(Box::new(move |info| {
+                    ratelimit_callback(info.clone());
+
                     let event_handler = event_handler.clone();
                     tokio::spawn(async move { event_handler.ratelimit(info).await });
                 }));
@@ -391,8 +511,16 @@ impl Future for ClientBuilder {
                     },
                 }));
 
+                let mut settings = ShardSettings::new();
+                settings
+                    .shared_data(Arc::clone(&shared_data))
+                    .dispatch_metrics(dispatch_metrics.clone())
+                    .catch_panics(catch_panics)
+                    .queue_depth_warning(queue_depth_warning)
+                    .tcp_settings(tcp_settings);
+
                 let (shard_manager, shard_manager_worker) = {
-                    ShardManager::new(ShardManagerOptions {
+                    let options = ShardManagerOptions {
                         data: &data,
                         event_handler: &event_handler,
                         raw_event_handler: &raw_event_handler,
@@ -406,18 +534,21 @@ impl Future for ClientBuilder {
                         ws_url: &ws_url,
                         cache_and_http: &cache_and_http,
                         intents,
-                    })
-                    .await
+                    };
+
+                    ShardManager::new_with_settings(options, settings).await
                 };
 
                 Ok(Client {
                     data,
+                    shared_data,
                     shard_manager,
                     shard_manager_worker,
                     #[cfg(feature = "voice")]
                     voice_manager,
                     ws_url,
                     cache_and_http,
+                    dispatch_metrics,
                 })
             }));
         }
@@ -568,6 +699,12 @@ pub struct Client {
     /// [`Event::MessageUpdate`]: crate::model::event::Event::MessageUpdate
     /// [example 05]: https://github.com/serenity-rs/serenity/tree/current/examples/e05_command_framework
     pub data: Arc<RwLock<TypeMap>>,
+    /// The data inserted via [`ClientBuilder::data_insert`], which every
+    /// [`Context`] shares.
+    ///
+    /// Prefer this over [`Self::data`] for read-mostly state, as values are
+    /// accessed via [`Context::data()`] without any locking.
+    pub shared_data: Arc<SharedData>,
     /// A HashMap of all shards instantiated by the Client.
     ///
     /// The key is the shard ID and the value is the shard itself.
@@ -664,6 +801,9 @@ pub struct Client {
     pub ws_url: Arc<Mutex<String>>,
     /// A container for an optional cache and HTTP client.
     pub cache_and_http: Arc<CacheAndHttp>,
+    /// The metrics that [`EventHandler`] invocations are recorded in, if set
+    /// via [`ClientBuilder::dispatch_metrics`].
+    pub dispatch_metrics: Option<Arc<DispatchMetrics>>,
 }
 
 impl Client {
@@ -0,0 +1,77 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Read-mostly data shared with every [`Context`], keyed by its type.
+///
+/// Values are registered once via [`ClientBuilder::data_insert`] and are then
+/// available in every handler via [`Context::data`], without any locking. Types
+/// needing to be mutated can use interior mutability, e.g. atomics or a
+/// [`Mutex`] on only the mutable fields.
+///
+/// Unlike the [`TypeMap`] in [`Context::data`][field], no [`TypeMapKey`] needs
+/// to be declared and values cannot be replaced after the client is built.
+///
+/// [`Context`]: super::Context
+/// [`Context::data`]: super::Context::data()
+/// [field]: super::Context#structfield.data
+/// [`ClientBuilder::data_insert`]: super::ClientBuilder::data_insert
+/// [`Mutex`]: std::sync::Mutex
+/// [`TypeMap`]: typemap_rev::TypeMap
+/// [`TypeMapKey`]: typemap_rev::TypeMapKey
+#[derive(Debug, Default)]
+pub struct SharedData {
+    values: HashMap<TypeId, Arc<dyn Any + Send + Sync>>,
+}
+
+impl SharedData {
+    /// Creates an empty set of data.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Inserts a value, replacing the value of the same type, if any.
+    pub fn insert<T: Send + Sync + 'static>(&mut self, value: T) {
+        self.values.insert(TypeId::of::<T>(), Arc::new(value));
+    }
+
+    /// Gets the value of the given type, if one was inserted.
+    #[must_use]
+    pub fn get<T: Send + Sync + 'static>(&self) -> Option<Arc<T>> {
+        let value = Arc::clone(self.values.get(&TypeId::of::<T>())?);
+
+        value.downcast().ok()
+    }
+
+    /// Whether a value of the given type was inserted.
+    #[must_use]
+    pub fn contains<T: Send + Sync + 'static>(&self) -> bool {
+        self.values.contains_key(&TypeId::of::<T>())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::atomic::{AtomicU64, Ordering};
+
+    use super::SharedData;
+
+    struct Counter(AtomicU64);
+
+    #[test]
+    fn insert_and_get() {
+        let mut data = SharedData::new();
+        data.insert(Counter(AtomicU64::new(1)));
+        data.insert(String::from("prefix"));
+
+        let counter = data.get::<Counter>().expect("counter inserted");
+        counter.0.fetch_add(1, Ordering::Relaxed);
+        assert_eq!(data.get::<Counter>().expect("counter inserted").0.load(Ordering::Relaxed), 2);
+
+        assert_eq!(*data.get::<String>().expect("string inserted"), "prefix");
+        assert!(data.get::<u64>().is_none());
+        assert!(data.contains::<String>());
+        assert!(!data.contains::<u64>());
+    }
+}
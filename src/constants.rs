@@ -6,9 +6,54 @@ pub const EMBED_MAX_LENGTH: usize = 6000;
 /// The maximum number of embeds in a message.
 pub const EMBED_MAX_COUNT: usize = 10;
 
+/// The maximum length of the title of an embed.
+pub const EMBED_TITLE_MAX_LENGTH: usize = 256;
+
+/// The maximum length of the description of an embed.
+pub const EMBED_DESCRIPTION_MAX_LENGTH: usize = 4096;
+
+/// The maximum number of fields in an embed.
+pub const EMBED_FIELD_MAX_COUNT: usize = 25;
+
+/// The maximum length of the name of an embed field.
+pub const EMBED_FIELD_NAME_MAX_LENGTH: usize = 256;
+
+/// The maximum length of the value of an embed field.
+pub const EMBED_FIELD_VALUE_MAX_LENGTH: usize = 1024;
+
+/// The maximum length of the footer text of an embed.
+pub const EMBED_FOOTER_MAX_LENGTH: usize = 2048;
+
+/// The maximum length of the author name of an embed.
+pub const EMBED_AUTHOR_MAX_LENGTH: usize = 256;
+
 /// The maximum number of stickers in a message.
 pub const STICKER_MAX_COUNT: usize = 3;
 
+/// The maximum number of items in a media gallery component.
+pub const MEDIA_GALLERY_MAX_ITEMS: usize = 10;
+
+/// The maximum number of text displays in a section component.
+pub const SECTION_MAX_TEXT_DISPLAYS: usize = 3;
+
+/// The maximum size of a soundboard sound file, in bytes.
+pub const SOUNDBOARD_SOUND_MAX_SIZE: usize = 512 * 1024;
+
+/// The maximum number of tags of an application.
+pub const APPLICATION_TAG_MAX_COUNT: usize = 5;
+
+/// The maximum length of an application tag, in unicode code points.
+pub const APPLICATION_TAG_MAX_LENGTH: usize = 20;
+
+/// The maximum number of channels shown in a guild's welcome screen.
+pub const WELCOME_CHANNEL_MAX_COUNT: usize = 5;
+
+/// The maximum size of an emoji image, in bytes.
+pub const EMOJI_MAX_SIZE: usize = 256 * 1024;
+
+/// The maximum size of an uploaded file in a guild without boosts, in bytes.
+pub const ATTACHMENT_MAX_SIZE: u64 = 10 * 1024 * 1024;
+
 /// The gateway version used by the library. The gateway URL is retrieved via
 /// the REST API.
 pub const GATEWAY_VERSION: u8 = 10;
@@ -76,6 +121,8 @@ pub enum OpCode {
     Hello = 10,
     /// Sent immediately following a client heartbeat that was received.
     HeartbeatAck = 11,
+    /// Used to request the soundboard sounds of guilds.
+    RequestSoundboardSounds = 31,
     /// Unknown opcode.
     Unknown = !0,
 }
@@ -93,6 +140,7 @@ enum_number!(OpCode {
     InvalidSession,
     Hello,
     HeartbeatAck,
+    RequestSoundboardSounds,
 });
 
 pub mod close_codes {
@@ -9,6 +9,8 @@ use reqwest::{header::InvalidHeaderValue, Error as ReqwestError};
 use serde_json::Error as JsonError;
 use tracing::instrument;
 
+#[cfg(feature = "cache")]
+use crate::cache::CacheError;
 #[cfg(feature = "client")]
 use crate::client::ClientError;
 #[cfg(feature = "collector")]
@@ -76,6 +78,11 @@ pub enum Error {
     Other(&'static str),
     /// An error from the [`url`] crate.
     Url(String),
+    /// A [cache] error.
+    ///
+    /// [cache]: crate::cache
+    #[cfg(feature = "cache")]
+    Cache(CacheError),
     /// A [client] error.
     ///
     /// [client]: crate::client
@@ -108,6 +115,13 @@ impl From<simd_json::Error> for Error {
     }
 }
 
+#[cfg(feature = "cache")]
+impl From<CacheError> for Error {
+    fn from(e: CacheError) -> Error {
+        Error::Cache(e)
+    }
+}
+
 impl From<FormatError> for Error {
     fn from(e: FormatError) -> Error {
         Error::Format(e)
@@ -163,7 +177,7 @@ impl From<InvalidHeaderValue> for Error {
 #[cfg(feature = "http")]
 impl From<ReqwestError> for Error {
     fn from(e: ReqwestError) -> Error {
-        HttpError::Request(e).into()
+        HttpError::from(e).into()
     }
 }
 
@@ -180,6 +194,8 @@ impl fmt::Display for Error {
             Self::Url(msg) => f.write_str(msg),
             #[cfg(feature = "simd-json")]
             Error::SimdJson(inner) => fmt::Display::fmt(&inner, f),
+            #[cfg(feature = "cache")]
+            Self::Cache(inner) => fmt::Display::fmt(&inner, f),
             #[cfg(feature = "client")]
             Self::Client(inner) => fmt::Display::fmt(&inner, f),
             #[cfg(feature = "collector")]
@@ -202,6 +218,8 @@ impl StdError for Error {
             Self::Io(inner) => Some(inner),
             Self::Json(inner) => Some(inner),
             Self::Model(inner) => Some(inner),
+            #[cfg(feature = "cache")]
+            Self::Cache(inner) => Some(inner),
             #[cfg(feature = "client")]
             Self::Client(inner) => Some(inner),
             #[cfg(feature = "collector")]
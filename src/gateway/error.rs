@@ -53,6 +53,11 @@ pub enum Error {
     /// If an connection has been established but privileged gateway intents
     /// were provided without enabling them prior.
     DisallowedGatewayIntents,
+    /// When shards did not connect in time, such as after being restarted via
+    /// [`ShardManager::restart`].
+    ///
+    /// [`ShardManager::restart`]: crate::client::bridge::gateway::ShardManager::restart
+    ConnectTimeout,
 }
 
 impl fmt::Display for Error {
@@ -74,6 +79,7 @@ impl fmt::Display for Error {
             Self::DisallowedGatewayIntents => {
                 f.write_str("Disallowed gateway intents were provided")
             },
+            Self::ConnectTimeout => f.write_str("Shards did not connect in time"),
         }
     }
 }
@@ -51,6 +51,7 @@ mod shard;
 mod ws_client_ext;
 
 use std::fmt;
+use std::time::Duration;
 
 pub use self::error::Error as GatewayError;
 pub use self::shard::Shard;
@@ -68,10 +69,79 @@ use async_tungstenite::WebSocketStream;
 
 pub type WsStream = WebSocketStream<ConnectStream>;
 
+/// Options for the TCP socket of a [`Shard`]'s gateway connection.
+///
+/// By default, `TCP_NODELAY` is set, and keepalive probes are sent once the
+/// connection has been idle for 60 seconds. This detects connections which were
+/// silently dropped, such as by a NAT, independently of the gateway's heartbeats.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use serenity::gateway::TcpSettings;
+///
+/// let mut settings = TcpSettings::new();
+/// settings.keepalive(Some(Duration::from_secs(30)));
+/// ```
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub struct TcpSettings {
+    /// Whether to set `TCP_NODELAY`, disabling Nagle's algorithm.
+    ///
+    /// Defaults to `true`.
+    pub nodelay: bool,
+    /// How long the connection needs to be idle before keepalive probes are
+    /// sent, or `None` to disable TCP keepalive.
+    ///
+    /// Defaults to 60 seconds. On platforms where the idle time cannot be
+    /// configured, the system's default is used instead.
+    pub keepalive: Option<Duration>,
+}
+
+impl TcpSettings {
+    /// Creates new settings with the defaults.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets whether to set `TCP_NODELAY`.
+    ///
+    /// Refer to [`nodelay`] for more information.
+    ///
+    /// [`nodelay`]: Self::nodelay
+    pub fn nodelay(&mut self, nodelay: bool) -> &mut Self {
+        self.nodelay = nodelay;
+        self
+    }
+
+    /// Sets the idle time before keepalive probes are sent.
+    ///
+    /// Refer to [`keepalive`] for more information.
+    ///
+    /// [`keepalive`]: Self::keepalive
+    pub fn keepalive(&mut self, keepalive: Option<Duration>) -> &mut Self {
+        self.keepalive = keepalive;
+        self
+    }
+}
+
+impl Default for TcpSettings {
+    fn default() -> Self {
+        Self {
+            nodelay: true,
+            keepalive: Some(Duration::from_secs(60)),
+        }
+    }
+}
+
 /// Indicates the current connection stage of a [`Shard`].
 ///
 /// This can be useful for knowing which shards are currently "down"/"up".
-#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[derive(Copy, Clone, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Serialize)]
 #[non_exhaustive]
 pub enum ConnectionStage {
     /// Indicator that the [`Shard`] is normally connected and is not in, e.g.,
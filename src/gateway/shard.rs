@@ -13,6 +13,7 @@ use super::{
     GatewayError,
     ReconnectType,
     ShardAction,
+    TcpSettings,
     WebSocketGatewayClientExt,
     WsStream,
 };
@@ -88,6 +89,7 @@ pub struct Shard {
     pub token: String,
     ws_url: Arc<Mutex<String>>,
     pub intents: GatewayIntents,
+    tcp_settings: TcpSettings,
 }
 
 impl Shard {
@@ -131,9 +133,29 @@ impl Shard {
         token: &str,
         shard_info: [u64; 2],
         intents: GatewayIntents,
+    ) -> Result<Shard> {
+        Self::new_with_tcp_settings(ws_url, token, shard_info, intents, TcpSettings::default())
+            .await
+    }
+
+    /// Instantiates a new instance of a Shard, using the given options for
+    /// the TCP socket of the gateway connection and all its reconnections.
+    ///
+    /// Refer to [`Self::new`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// On Error, will return either [`Error::Gateway`], [`Error::Tungstenite`],
+    /// [`Error::Io`] or a Rustls/native TLS error.
+    pub async fn new_with_tcp_settings(
+        ws_url: Arc<Mutex<String>>,
+        token: &str,
+        shard_info: [u64; 2],
+        intents: GatewayIntents,
+        tcp_settings: TcpSettings,
     ) -> Result<Shard> {
         let url = ws_url.lock().await.clone();
-        let client = connect(&url).await?;
+        let client = connect(&url, &tcp_settings).await?;
 
         let current_presence = (None, OnlineStatus::Online);
         let heartbeat_instants = (None, None);
@@ -158,6 +180,7 @@ impl Shard {
             shard_info,
             ws_url,
             intents,
+            tcp_settings,
         })
     }
 
@@ -725,6 +748,18 @@ impl Shard {
         self.client.send_chunk_guild(guild_id, &self.shard_info, limit, filter, nonce).await
     }
 
+    /// Requests that the soundboard sounds of the given guilds be sent over
+    /// the gateway.
+    ///
+    /// Discord responds with a `SOUNDBOARD_SOUNDS` dispatch for each guild,
+    /// which is received as a raw event.
+    #[instrument(skip(self))]
+    pub async fn request_soundboard_sounds(&mut self, guild_ids: &[GuildId]) -> Result<()> {
+        debug!("[Shard {:?}] Requesting soundboard sounds", self.shard_info);
+
+        self.client.send_request_soundboard_sounds(&self.shard_info, guild_ids).await
+    }
+
     /// Sets the shard as going into identifying stage, which sets:
     ///
     /// - the time that the last heartbeat sent as being now
@@ -758,7 +793,7 @@ impl Shard {
         self.stage = ConnectionStage::Connecting;
         self.started = Instant::now();
         let url = &self.ws_url.lock().await.clone();
-        let client = connect(url).await?;
+        let client = connect(url, &self.tcp_settings).await?;
         self.stage = ConnectionStage::Handshake;
 
         Ok(client)
@@ -805,7 +840,7 @@ impl Shard {
     }
 }
 
-async fn connect(base_url: &str) -> Result<WsStream> {
+async fn connect(base_url: &str, tcp_settings: &TcpSettings) -> Result<WsStream> {
     let url =
         Url::parse(&format!("{}?v={}", base_url, constants::GATEWAY_VERSION)).map_err(|why| {
             warn!("Error building gateway URL with base `{}`: {:?}", base_url, why);
@@ -813,5 +848,5 @@ async fn connect(base_url: &str) -> Result<WsStream> {
             Error::Gateway(GatewayError::BuildingUrl)
         })?;
 
-    create_client(url).await
+    create_client(url, tcp_settings).await
 }
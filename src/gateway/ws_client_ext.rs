@@ -39,6 +39,12 @@ pub trait WebSocketGatewayClientExt {
         current_presence: &CurrentPresence,
     ) -> Result<()>;
 
+    async fn send_request_soundboard_sounds(
+        &mut self,
+        shard_info: &[u64; 2],
+        guild_ids: &[GuildId],
+    ) -> Result<()>;
+
     async fn send_resume(
         &mut self,
         shard_info: &[u64; 2],
@@ -194,6 +200,26 @@ impl WebSocketGatewayClientExt for WsStream {
         .await
     }
 
+    #[instrument(skip(self))]
+    async fn send_request_soundboard_sounds(
+        &mut self,
+        shard_info: &[u64; 2],
+        guild_ids: &[GuildId],
+    ) -> Result<()> {
+        debug!("[Shard {:?}] Requesting soundboard sounds", shard_info);
+
+        let ids = guild_ids.iter().map(|id| id.0.to_string()).collect::<Vec<String>>();
+
+        self.send_json(&json!({
+            "op": OpCode::RequestSoundboardSounds.num(),
+            "d": {
+                "guild_ids": ids,
+            },
+        }))
+        .await
+        .map_err(From::from)
+    }
+
     #[instrument(skip(self, token))]
     async fn send_resume(
         &mut self,
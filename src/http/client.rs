@@ -6,22 +6,32 @@ use std::fmt;
 use std::str::FromStr;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 
+use futures::stream::Stream;
 use percent_encoding::{utf8_percent_encode, NON_ALPHANUMERIC};
-use reqwest::header::{HeaderMap as Headers, HeaderValue, CONTENT_TYPE};
+use reqwest::header::{HeaderMap as Headers, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
 use reqwest::{Client, ClientBuilder, Response as ReqwestResponse, StatusCode, Url};
 use serde::de::DeserializeOwned;
 use tracing::{debug, instrument, trace};
 
 use super::multipart::Multipart;
-use super::ratelimiting::{RatelimitedRequest, Ratelimiter};
+use super::ratelimiting::{RatelimitInfo, RatelimitedRequest, Ratelimiter};
 use super::request::Request;
 use super::routing::RouteInfo;
 use super::typing::Typing;
-use super::{AttachmentType, GuildPagination, HttpError, UserPagination};
+use super::{
+    ArchivedThreadKind,
+    AttachmentType,
+    GetAuditLogs,
+    GuildPagination,
+    HttpError,
+    UserPagination,
+};
 use crate::internal::prelude::*;
 use crate::json::prelude::*;
 use crate::model::application::command::{Command, CommandPermission};
+use crate::model::error::Error as ModelError;
 use crate::model::guild::automod::Rule;
 use crate::model::prelude::*;
 use crate::{constants, utils};
@@ -45,9 +55,26 @@ use crate::{constants, utils};
 ///     .build();
 /// # }
 /// ```
+///
+/// Share a single [`reqwest::Client`], and thus its connection pool, between
+/// multiple [`Http`] instances. Each instance still has its own ratelimiter:
+///
+/// ```rust
+/// # use serenity::http::HttpBuilder;
+/// # fn run() {
+/// let client = reqwest::Client::new();
+///
+/// let first = HttpBuilder::new("first token").client(client.clone()).build();
+/// let second = HttpBuilder::new("second token").client(client).build();
+/// # }
+/// ```
 pub struct HttpBuilder {
     client: Option<Client>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    timeout: Option<Duration>,
     ratelimiter: Option<Ratelimiter>,
+    ratelimit_callback: Option<Box<dyn Fn(RatelimitInfo) + Send + Sync>>,
     ratelimiter_disabled: bool,
     token: String,
     proxy: Option<Url>,
@@ -60,7 +87,11 @@ impl HttpBuilder {
     pub fn new(token: impl AsRef<str>) -> Self {
         Self {
             client: None,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            timeout: None,
             ratelimiter: None,
+            ratelimit_callback: None,
             ratelimiter_disabled: false,
             token: parse_token(token),
             proxy: None,
@@ -87,6 +118,16 @@ impl HttpBuilder {
 
     /// Sets the [`reqwest::Client`]. If one isn't provided, a default one will
     /// be used.
+    ///
+    /// A [`reqwest::Client`] is cheap to clone and holds its connection pool
+    /// internally, so the same client can be passed to multiple builders to
+    /// reuse connections between them. If no ratelimiter is provided via
+    /// [`Self::ratelimiter`], each built [`Http`] still gets its own
+    /// ratelimiter for its token.
+    ///
+    /// **Note**: [`Self::pool_max_idle_per_host`] and
+    /// [`Self::pool_idle_timeout`] only apply to the default client, and are
+    /// ignored if a client is provided.
     #[must_use]
     pub fn client(mut self, client: Client) -> Self {
         self.client = Some(client);
@@ -94,6 +135,43 @@ impl HttpBuilder {
         self
     }
 
+    /// Sets the maximum number of idle connections kept alive per host by the
+    /// default client.
+    ///
+    /// Defaults to no limit.
+    #[must_use]
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+
+        self
+    }
+
+    /// Sets how long idle connections of the default client are kept alive
+    /// before being closed.
+    ///
+    /// Defaults to 90 seconds.
+    #[must_use]
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+
+        self
+    }
+
+    /// Sets the timeout of each request, from when the connection starts until
+    /// the response body has finished. Time spent waiting for ratelimits is
+    /// not included.
+    ///
+    /// Requests exceeding it return [`HttpError::Timeout`]. Refer to
+    /// [`Http::request_with_timeout`] to override it for a single request.
+    ///
+    /// Defaults to no timeout.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+
+        self
+    }
+
     /// Sets the ratelimiter to be used. If one isn't provided, a default one
     /// will be used.
     #[must_use]
@@ -103,6 +181,25 @@ impl HttpBuilder {
         self
     }
 
+    /// Sets a callback to be called whenever a request is ratelimited, before
+    /// the ratelimiter waits for the ratelimit to pass.
+    ///
+    /// This is useful for emitting metrics when only using the HTTP client.
+    /// When using a [`Client`], its [`EventHandler::ratelimit`] is called as
+    /// well.
+    ///
+    /// [`Client`]: crate::Client
+    /// [`EventHandler::ratelimit`]: crate::client::EventHandler::ratelimit
+    #[must_use]
+    pub fn ratelimit_callback(
+        mut self,
+        ratelimit_callback: impl Fn(RatelimitInfo) + Send + Sync + 'static,
+    ) -> Self {
+        self.ratelimit_callback = Some(Box::new(ratelimit_callback));
+
+        self
+    }
+
     /// Sets whether or not the ratelimiter is disabled. By default if this this
     /// not used, it is enabled. In most cases, this should be used in
     /// conjunction with [`Self::proxy`].
@@ -148,16 +245,31 @@ impl HttpBuilder {
 
         let application_id = AtomicU64::new(self.application_id.unwrap_or_default());
 
+        let pool_max_idle_per_host = self.pool_max_idle_per_host;
+        let pool_idle_timeout = self.pool_idle_timeout;
         let client = self.client.unwrap_or_else(|| {
-            let builder = configure_client_backend(Client::builder());
+            let mut builder = configure_client_backend(Client::builder());
+
+            if let Some(max) = pool_max_idle_per_host {
+                builder = builder.pool_max_idle_per_host(max);
+            }
+
+            if let Some(pool_idle_timeout) = pool_idle_timeout {
+                builder = builder.pool_idle_timeout(pool_idle_timeout);
+            }
+
             builder.build().expect("Cannot build reqwest::Client")
         });
 
-        let ratelimiter = self.ratelimiter.unwrap_or_else(|| {
+        let mut ratelimiter = self.ratelimiter.unwrap_or_else(|| {
             let client = client.clone();
             Ratelimiter::new(client, token.to_string())
         });
 
+        if let Some(ratelimit_callback) = self.ratelimit_callback {
+            ratelimiter.set_ratelimit_callback(ratelimit_callback);
+        }
+
         let ratelimiter_disabled = self.ratelimiter_disabled;
 
         Http {
@@ -167,6 +279,7 @@ impl HttpBuilder {
             proxy: self.proxy,
             token,
             application_id,
+            timeout: self.timeout,
         }
     }
 }
@@ -191,6 +304,22 @@ fn reason_into_header(reason: &str) -> Headers {
 
 /// **Note**: For all member functions that return a [`Result`], the
 /// Error kind will be either [`Error::Http`] or [`Error::Json`].
+///
+/// The returned futures may be cancelled by dropping them, such as with
+/// [`tokio::time::timeout`] or in a [`tokio::select!`], without affecting later
+/// requests.
+///
+/// ```rust,no_run
+/// # use serenity::http::Http;
+/// #
+/// # async fn run(shutdown: tokio::sync::oneshot::Receiver<()>) {
+/// # let http = Http::new("token");
+/// tokio::select! {
+///     user = http.get_current_user() => println!("{:?}", user.map(|user| user.name)),
+///     _ = shutdown => println!("Shutting down, cancelled the request"),
+/// }
+/// # }
+/// ```
 pub struct Http {
     pub(crate) client: Client,
     pub ratelimiter: Ratelimiter,
@@ -198,6 +327,7 @@ pub struct Http {
     pub proxy: Option<Url>,
     pub token: String,
     application_id: AtomicU64,
+    timeout: Option<Duration>,
 }
 
 impl fmt::Debug for Http {
@@ -207,6 +337,7 @@ impl fmt::Debug for Http {
             .field("ratelimiter", &self.ratelimiter)
             .field("ratelimiter_disabled", &self.ratelimiter_disabled)
             .field("proxy", &self.proxy)
+            .field("timeout", &self.timeout)
             .finish()
     }
 }
@@ -228,6 +359,7 @@ impl Http {
             proxy: None,
             token,
             application_id: AtomicU64::new(0),
+            timeout: None,
         }
     }
 
@@ -322,6 +454,9 @@ impl Http {
     ///
     /// **Note**: Requires that you have the [Ban Members] permission.
     ///
+    /// This is equivalent to [`Self::ban_user_with_delete_seconds`], with the days converted to
+    /// seconds.
+    ///
     /// [Ban Members]: Permissions::BAN_MEMBERS
     pub async fn ban_user(
         &self,
@@ -330,12 +465,38 @@ impl Http {
         delete_message_days: u8,
         reason: &str,
     ) -> Result<()> {
+        let delete_message_seconds = u32::from(delete_message_days) * 86_400;
+
+        self.ban_user_with_delete_seconds(guild_id, user_id, delete_message_seconds, Some(reason))
+            .await
+    }
+
+    /// Bans a [`User`] from a [`Guild`], removing their messages sent in the last
+    /// X number of seconds.
+    ///
+    /// Passing a `delete_message_seconds` of `0` is equivalent to not removing any
+    /// messages. Up to `604800` seconds (7 days) worth of messages may be deleted.
+    ///
+    /// **Note**: Requires that you have the [Ban Members] permission.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    pub async fn ban_user_with_delete_seconds(
+        &self,
+        guild_id: u64,
+        user_id: u64,
+        delete_message_seconds: u32,
+        audit_log_reason: Option<&str>,
+    ) -> Result<()> {
+        let body = to_vec(&json!({
+            "delete_message_seconds": delete_message_seconds,
+        }))?;
+
         self.wind(204, Request {
-            body: None,
+            body: Some(&body),
             multipart: None,
-            headers: Some(reason_into_header(reason)),
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::GuildBanUser {
-                delete_message_days: Some(delete_message_days),
+                delete_message_days: None,
                 guild_id,
                 user_id,
             },
@@ -343,6 +504,35 @@ impl Http {
         .await
     }
 
+    /// Bans up to 200 users from a [`Guild`] at once, deleting up to 7 days (604800 seconds)
+    /// worth of their messages.
+    ///
+    /// Refer to [`GuildId::bulk_ban`] for a version that validates its arguments.
+    ///
+    /// [`GuildId::bulk_ban`]: crate::model::id::GuildId::bulk_ban
+    pub async fn bulk_ban(
+        &self,
+        guild_id: u64,
+        user_ids: &[UserId],
+        delete_message_seconds: u32,
+        audit_log_reason: Option<&str>,
+    ) -> Result<BulkBanResponse> {
+        let body = to_vec(&json!({
+            "user_ids": user_ids,
+            "delete_message_seconds": delete_message_seconds,
+        }))?;
+
+        self.fire(Request {
+            body: Some(&body),
+            multipart: None,
+            headers: audit_log_reason.map(reason_into_header),
+            route: RouteInfo::GuildBulkBan {
+                guild_id,
+            },
+        })
+        .await
+    }
+
     /// Broadcasts that the current user is typing in the given [`Channel`].
     ///
     /// This lasts for about 10 seconds, and will then need to be renewed to
@@ -362,6 +552,18 @@ impl Http {
         .await
     }
 
+    /// Broadcasts that the current user is typing in each of the given channels.
+    ///
+    /// The typing indicator is per-channel and only lasts for about 10 seconds,
+    /// so this needs to be called again to keep it shown. Each channel has its
+    /// own ratelimit bucket, and the requests are paced by the ratelimiter like
+    /// any other request.
+    ///
+    /// Returns the result of each broadcast, in the order of the given channels.
+    pub async fn broadcast_typing_all(&self, channel_ids: &[u64]) -> Vec<Result<()>> {
+        futures::future::join_all(channel_ids.iter().map(|&id| self.broadcast_typing(id))).await
+    }
+
     /// Creates a [`GuildChannel`] in the [`Guild`] given its Id.
     ///
     /// Refer to the Discord's [docs] for information on what fields this requires.
@@ -608,12 +810,104 @@ impl Http {
         .await
     }
 
+    /// Creates a new guild from a [`GuildTemplate`], with the current user as its owner.
+    ///
+    /// The `icon` must be base64-encoded image data, see [`utils::read_image`].
+    ///
+    /// **Note**: This endpoint can only be used by bots in less than 10 guilds.
+    ///
+    /// Refer to Discord's [docs] for more information.
+    ///
+    /// [docs]: https://discord.com/developers/docs/resources/guild-template#create-guild-from-guild-template
+    pub async fn create_guild_from_template(
+        &self,
+        code: &str,
+        name: &str,
+        icon: Option<&str>,
+    ) -> Result<PartialGuild> {
+        let map = json!({
+            "name": name,
+            "icon": icon,
+        });
+        let body = to_vec(&map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            multipart: None,
+            headers: None,
+            route: RouteInfo::CreateGuildFromTemplate {
+                code,
+            },
+        })
+        .await
+    }
+
+    /// Creates a [`GuildTemplate`] from the current state of a guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn create_guild_template(
+        &self,
+        guild_id: u64,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<GuildTemplate> {
+        let map = json!({
+            "name": name,
+            "description": description,
+        });
+        let body = to_vec(&map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            multipart: None,
+            headers: None,
+            route: RouteInfo::CreateGuildTemplate {
+                guild_id,
+            },
+        })
+        .await
+    }
+
+    /// Creates a soundboard sound in a guild.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// **Note**: Requires the [Create Guild Expressions] permission.
+    ///
+    /// [docs]: https://discord.com/developers/docs/resources/soundboard#create-guild-soundboard-sound
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    pub async fn create_guild_soundboard_sound(
+        &self,
+        guild_id: u64,
+        map: &JsonMap,
+        audit_log_reason: Option<&str>,
+    ) -> Result<SoundboardSound> {
+        let body = to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            multipart: None,
+            headers: audit_log_reason.map(reason_into_header),
+            route: RouteInfo::CreateGuildSoundboardSound {
+                guild_id,
+            },
+        })
+        .await
+    }
+
     /// Creates a new guild command.
     ///
     /// New guild commands will be available in the guild immediately.
     ///
     /// Refer to Discord's [docs] for field information.
     ///
+    /// **Note**:
+    /// Creating a command with the same name as an existing command for your
+    /// application in the guild will overwrite the old command, leaving other
+    /// commands untouched.
+    ///
     /// [docs]: https://discord.com/developers/docs/interactions/slash-commands#create-guild-application-command
     pub async fn create_guild_application_command(
         &self,
@@ -747,13 +1041,25 @@ impl Http {
         channel_id: u64,
         target_id: u64,
         map: &Value,
+    ) -> Result<()> {
+        self.create_permission_with_reason(channel_id, target_id, map, None).await
+    }
+
+    /// Creates a permission override for a member or a role in a channel, with an optional
+    /// reason for the audit log.
+    pub async fn create_permission_with_reason(
+        &self,
+        channel_id: u64,
+        target_id: u64,
+        map: &Value,
+        audit_log_reason: Option<&str>,
     ) -> Result<()> {
         let body = to_vec(map)?;
 
         self.wind(204, Request {
             body: Some(&body),
             multipart: None,
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::CreatePermission {
                 channel_id,
                 target_id,
@@ -887,6 +1193,54 @@ impl Http {
         .await
     }
 
+    /// Creates a test entitlement to a given SKU for a given guild or user.
+    ///
+    /// Test entitlements don't have a start or end date, and can be deleted with
+    /// [`Self::delete_test_entitlement`]. This allows testing premium offerings without
+    /// making a real purchase.
+    pub async fn create_test_entitlement(
+        &self,
+        sku_id: u64,
+        owner: EntitlementOwner,
+    ) -> Result<Entitlement> {
+        let (owner_id, owner_type) = match owner {
+            EntitlementOwner::Guild(id) => (id.0, 1),
+            EntitlementOwner::User(id) => (id.0, 2),
+        };
+        let map = json!({
+            "sku_id": sku_id.to_string(),
+            "owner_id": owner_id.to_string(),
+            "owner_type": owner_type,
+        });
+        let body = to_vec(&map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            multipart: None,
+            headers: None,
+            route: RouteInfo::CreateTestEntitlement {
+                application_id: self.try_application_id()?,
+            },
+        })
+        .await
+    }
+
+    /// Marks a consumable [`Entitlement`] for the current application as consumed.
+    ///
+    /// The entitlement will have its [`Entitlement::consumed`] field set to `true`.
+    pub async fn consume_entitlement(&self, entitlement_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::ConsumeEntitlement {
+                application_id: self.try_application_id()?,
+                entitlement_id,
+            },
+        })
+        .await
+    }
+
     /// Creates a webhook for the given [channel][`GuildChannel`]'s Id, passing in
     /// the given data.
     ///
@@ -1054,6 +1408,49 @@ impl Http {
         .await
     }
 
+    /// Deletes a guild's [`GuildTemplate`] by code, returning the deleted template.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn delete_guild_template(&self, guild_id: u64, code: &str) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::DeleteGuildTemplate {
+                guild_id,
+                code,
+            },
+        })
+        .await
+    }
+
+    /// Deletes a soundboard sound from a guild.
+    ///
+    /// **Note**: Requires the [Manage Guild Expressions] permission, or the
+    /// [Create Guild Expressions] permission for sounds created by the current user.
+    ///
+    /// [Manage Guild Expressions]: Permissions::MANAGE_EMOJIS_AND_STICKERS
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    pub async fn delete_guild_soundboard_sound(
+        &self,
+        guild_id: u64,
+        sound_id: u64,
+        audit_log_reason: Option<&str>,
+    ) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            multipart: None,
+            headers: audit_log_reason.map(reason_into_header),
+            route: RouteInfo::DeleteGuildSoundboardSound {
+                guild_id,
+                sound_id,
+            },
+        })
+        .await
+    }
+
     /// Deletes an invite by code.
     pub async fn delete_invite(&self, code: &str) -> Result<Invite> {
         self.fire(Request {
@@ -1070,10 +1467,23 @@ impl Http {
     /// Deletes a message if created by us or we have
     /// specific permissions.
     pub async fn delete_message(&self, channel_id: u64, message_id: u64) -> Result<()> {
+        self.delete_message_with_reason(channel_id, message_id, None).await
+    }
+
+    /// Deletes a message if created by us or we have specific permissions, with an optional
+    /// reason for the audit log.
+    ///
+    /// The audit log only records the deletion of messages authored by other users.
+    pub async fn delete_message_with_reason(
+        &self,
+        channel_id: u64,
+        message_id: u64,
+        audit_log_reason: Option<&str>,
+    ) -> Result<()> {
         self.wind(204, Request {
             body: None,
             multipart: None,
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::DeleteMessage {
                 channel_id,
                 message_id,
@@ -1165,10 +1575,21 @@ impl Http {
 
     /// Deletes a permission override from a role or a member in a channel.
     pub async fn delete_permission(&self, channel_id: u64, target_id: u64) -> Result<()> {
+        self.delete_permission_with_reason(channel_id, target_id, None).await
+    }
+
+    /// Deletes a permission override from a role or a member in a channel, with an optional
+    /// reason for the audit log.
+    pub async fn delete_permission_with_reason(
+        &self,
+        channel_id: u64,
+        target_id: u64,
+        audit_log_reason: Option<&str>,
+    ) -> Result<()> {
         self.wind(204, Request {
             body: None,
             multipart: None,
-            headers: None,
+            headers: audit_log_reason.map(reason_into_header),
             route: RouteInfo::DeletePermission {
                 channel_id,
                 target_id,
@@ -1258,6 +1679,20 @@ impl Http {
         .await
     }
 
+    /// Deletes a test entitlement created with [`Self::create_test_entitlement`].
+    pub async fn delete_test_entitlement(&self, entitlement_id: u64) -> Result<()> {
+        self.wind(204, Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::DeleteTestEntitlement {
+                application_id: self.try_application_id()?,
+                entitlement_id,
+            },
+        })
+        .await
+    }
+
     /// Deletes a [`Webhook`] given its Id.
     ///
     /// This method requires authentication, whereas [`Self::delete_webhook_with_token`]
@@ -1461,6 +1896,10 @@ impl Http {
     ///
     /// Refer to Discord's [docs] for field information.
     ///
+    /// **Note**: All fields are optional. Fields missing from the `map` are
+    /// left unchanged, so e.g. the permissions of a command can be changed
+    /// without redefining its options.
+    ///
     /// [docs]: https://discord.com/developers/docs/interactions/slash-commands#edit-global-application-command
     pub async fn edit_global_application_command(
         &self,
@@ -1505,6 +1944,10 @@ impl Http {
     ///
     /// Refer to Discord's [docs] for field information.
     ///
+    /// **Note**: All fields are optional. Fields missing from the `map` are
+    /// left unchanged, so e.g. the permissions of a command can be changed
+    /// without redefining its options.
+    ///
     /// [docs]: https://discord.com/developers/docs/interactions/slash-commands#edit-guild-application-command
     pub async fn edit_guild_application_command(
         &self,
@@ -1529,9 +1972,13 @@ impl Http {
     ///
     /// Updates for guild commands will be available immediately.
     ///
+    /// **Note**: This endpoint requires a Bearer token with the
+    /// `applications.commands.permissions.update` scope, bot tokens are
+    /// rejected by Discord.
+    ///
     /// Refer to Discord's [documentation] for field information.
     ///
-    /// [documentation]: https://discord.com/developers/docs/interactions/slash-commands#edit-guild-application-command
+    /// [documentation]: https://discord.com/developers/docs/interactions/application-commands#edit-application-command-permissions
     pub async fn edit_guild_application_command_permissions(
         &self,
         guild_id: u64,
@@ -1624,11 +2071,42 @@ impl Http {
         .await
     }
 
-    /// Does specific actions to a member.
-    pub async fn edit_member(
+    /// Edits a soundboard sound in a guild.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// **Note**: Requires the [Manage Guild Expressions] permission, or the
+    /// [Create Guild Expressions] permission for sounds created by the current user.
+    ///
+    /// [docs]: https://discord.com/developers/docs/resources/soundboard#modify-guild-soundboard-sound
+    /// [Manage Guild Expressions]: Permissions::MANAGE_EMOJIS_AND_STICKERS
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    pub async fn edit_guild_soundboard_sound(
         &self,
         guild_id: u64,
-        user_id: u64,
+        sound_id: u64,
+        map: &JsonMap,
+        audit_log_reason: Option<&str>,
+    ) -> Result<SoundboardSound> {
+        let body = to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            multipart: None,
+            headers: audit_log_reason.map(reason_into_header),
+            route: RouteInfo::EditGuildSoundboardSound {
+                guild_id,
+                sound_id,
+            },
+        })
+        .await
+    }
+
+    /// Does specific actions to a member.
+    pub async fn edit_member(
+        &self,
+        guild_id: u64,
+        user_id: u64,
         map: &JsonMap,
         audit_log_reason: Option<&str>,
     ) -> Result<Member> {
@@ -1754,10 +2232,32 @@ impl Http {
     }
 
     /// Follow a News Channel to send messages to a target channel.
+    ///
+    /// Refer to [`Self::follow_announcement_channel`] for more information.
+    #[inline]
     pub async fn follow_news_channel(
         &self,
         news_channel_id: u64,
         target_channel_id: u64,
+    ) -> Result<FollowedChannel> {
+        self.follow_announcement_channel(news_channel_id, target_channel_id).await
+    }
+
+    /// Follows an announcement channel, creating a webhook in the target
+    /// channel that receives the messages crossposted in the announcement
+    /// channel.
+    ///
+    /// The returned [`FollowedChannel`] contains the Id of the created
+    /// webhook.
+    ///
+    /// **Note**: The type of the source channel is not checked. Refer to
+    /// [`ChannelId::follow_announcement_channel`] for a method that checks it.
+    ///
+    /// [`ChannelId::follow_announcement_channel`]: crate::model::id::ChannelId::follow_announcement_channel
+    pub async fn follow_announcement_channel(
+        &self,
+        channel_id: u64,
+        target_channel_id: u64,
     ) -> Result<FollowedChannel> {
         let map = json!({ "webhook_channel_id": target_channel_id });
         let body = to_vec(&map)?;
@@ -1767,7 +2267,7 @@ impl Http {
             multipart: None,
             headers: None,
             route: RouteInfo::FollowNewsChannel {
-                channel_id: news_channel_id,
+                channel_id,
             },
         })
         .await
@@ -2363,7 +2863,24 @@ impl Http {
         Ok(status.scheduled_maintenances)
     }
 
+    /// Gets the ban of a user in a specific guild, including its reason.
+    pub async fn get_ban(&self, guild_id: u64, user_id: u64) -> Result<Ban> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::GetBan {
+                guild_id,
+                user_id,
+            },
+        })
+        .await
+    }
+
     /// Gets all the users that are banned in specific guild.
+    ///
+    /// **Note**: Discord only returns the first 1000 bans; use [`Self::get_bans_iter`] to
+    /// retrieve more.
     pub async fn get_bans(&self, guild_id: u64) -> Result<Vec<Ban>> {
         self.fire(Request {
             body: None,
@@ -2376,7 +2893,114 @@ impl Http {
         .await
     }
 
+    /// Gets a page of up to `limit` bans in a specific guild, sorted by user Id, before or after
+    /// the given user.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotInRange`] if `limit` is not between 1 and 1000.
+    pub async fn get_bans_paginated(
+        &self,
+        guild_id: u64,
+        target: Option<UserPagination>,
+        limit: Option<u16>,
+    ) -> Result<Vec<Ban>> {
+        if let Some(l) = limit {
+            if !(1..=1000).contains(&l) {
+                return Err(Error::NotInRange("limit", u64::from(l), 1, 1000));
+            }
+        }
+
+        let (after, before) = match target {
+            None => (None, None),
+            Some(p) => match p {
+                UserPagination::After(id) => (Some(id.0), None),
+                UserPagination::Before(id) => (None, Some(id.0)),
+            },
+        };
+
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::GetBansOptioned {
+                guild_id,
+                after,
+                before,
+                limit,
+            },
+        })
+        .await
+    }
+
+    /// Streams over all of the bans in a specific guild.
+    ///
+    /// This is accomplished and equivalent to repeated calls to [`Self::get_bans_paginated`].
+    /// Pages of 1000 bans are requested, to reduce the number of calls necessary.
+    ///
+    /// The stream ends after yielding the first error encountered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let http = Http::new("token");
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut bans = http.get_bans_iter(81384788765712384).boxed();
+    /// while let Some(ban_result) = bans.next().await {
+    ///     match ban_result {
+    ///         Ok(ban) => println!("{} was banned: {:?}", ban.user.name, ban.reason),
+    ///         Err(error) => eprintln!("Uh oh!  Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn get_bans_iter(&self, guild_id: u64) -> impl Stream<Item = Result<Ban>> + '_ {
+        const GRAB_SIZE: u16 = 1000;
+
+        struct State {
+            buffer: Vec<Ban>,
+            after: Option<UserId>,
+            exhausted: bool,
+        }
+
+        let init_state = State {
+            buffer: Vec::new(),
+            after: None,
+            exhausted: false,
+        };
+
+        futures::stream::unfold(init_state, move |mut state| async move {
+            if state.buffer.is_empty() && !state.exhausted {
+                let target = state.after.map(UserPagination::After);
+
+                match self.get_bans_paginated(guild_id, target, Some(GRAB_SIZE)).await {
+                    Ok(bans) => {
+                        // If shorter than a full page, there are no more results
+                        state.exhausted = bans.len() < GRAB_SIZE as usize;
+                        state.after = bans.last().map(|ban| ban.user.id);
+                        state.buffer = bans;
+
+                        // Reverse to optimize pop()
+                        state.buffer.reverse();
+                    },
+                    Err(error) => {
+                        state.exhausted = true;
+                        return Some((Err(error), state));
+                    },
+                }
+            }
+
+            state.buffer.pop().map(|ban| (Ok(ban), state))
+        })
+    }
+
     /// Gets all audit logs in a specific guild.
+    ///
+    /// Refer to [`Self::get_audit_logs_with`] to also filter by the entries after a given one.
     pub async fn get_audit_logs(
         &self,
         guild_id: u64,
@@ -2400,6 +3024,121 @@ impl Http {
         .await
     }
 
+    /// Gets a page of the audit logs in a specific guild, filtered and paginated by the given
+    /// query.
+    ///
+    /// **Note**: Requires the [View Audit Log] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotInRange`] if the query's `limit` is not between 1 and 100.
+    ///
+    /// [View Audit Log]: Permissions::VIEW_AUDIT_LOG
+    pub async fn get_audit_logs_with(
+        &self,
+        guild_id: u64,
+        query: GetAuditLogs,
+    ) -> Result<AuditLogs> {
+        if let Some(l) = query.limit {
+            if !(1..=100).contains(&l) {
+                return Err(Error::NotInRange("limit", u64::from(l), 1, 100));
+            }
+        }
+
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::GetAuditLogsOptioned {
+                action_type: query.action_type.map(Action::num),
+                after: query.after.map(|id| id.0),
+                before: query.before.map(|id| id.0),
+                guild_id,
+                limit: query.limit,
+                user_id: query.user_id.map(|id| id.0),
+            },
+        })
+        .await
+    }
+
+    /// Streams over the audit log entries in a specific guild, from newest to oldest.
+    ///
+    /// This is accomplished and equivalent to repeated calls to [`Self::get_audit_logs_with`].
+    /// The query's user and action type filters apply to every page, and its `before` is used as
+    /// the starting point, while its `after` and `limit` are ignored. Pages of 100 entries are
+    /// requested, to reduce the number of calls necessary.
+    ///
+    /// The stream ends after yielding the first error encountered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let http = Http::new("token");
+    /// use serenity::futures::StreamExt;
+    /// use serenity::http::GetAuditLogs;
+    /// use serenity::model::guild::audit_log::{Action, MemberAction};
+    ///
+    /// let mut query = GetAuditLogs::new();
+    /// query.action_type(Action::Member(MemberAction::BanAdd));
+    ///
+    /// let mut entries = http.get_audit_logs_iter(81384788765712384, query).boxed();
+    /// while let Some(entry_result) = entries.next().await {
+    ///     match entry_result {
+    ///         Ok(entry) => println!("{} banned {:?}", entry.user_id, entry.target_id),
+    ///         Err(error) => eprintln!("Uh oh!  Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn get_audit_logs_iter(
+        &self,
+        guild_id: u64,
+        query: GetAuditLogs,
+    ) -> impl Stream<Item = Result<AuditLogEntry>> + '_ {
+        const GRAB_SIZE: u8 = 100;
+
+        struct State {
+            buffer: Vec<AuditLogEntry>,
+            query: GetAuditLogs,
+            exhausted: bool,
+        }
+
+        let mut query = query;
+        query.after = None;
+        query.limit = Some(GRAB_SIZE);
+
+        let init_state = State {
+            buffer: Vec::new(),
+            query,
+            exhausted: false,
+        };
+
+        futures::stream::unfold(init_state, move |mut state| async move {
+            if state.buffer.is_empty() && !state.exhausted {
+                match self.get_audit_logs_with(guild_id, state.query).await {
+                    Ok(logs) => {
+                        // If shorter than a full page, there are no more results
+                        state.exhausted = logs.entries.len() < GRAB_SIZE as usize;
+                        state.query.before = logs.entries.last().map(|entry| entry.id);
+                        state.buffer = logs.entries;
+
+                        // Reverse to optimize pop()
+                        state.buffer.reverse();
+                    },
+                    Err(error) => {
+                        state.exhausted = true;
+                        return Some((Err(error), state));
+                    },
+                }
+            }
+
+            state.buffer.pop().map(|entry| (Ok(entry), state))
+        })
+    }
+
     /// Retrieves all auto moderation rules in a guild.
     ///
     /// This method requires `MANAGE_GUILD` permissions.
@@ -2538,6 +3277,11 @@ impl Http {
     }
 
     /// Gets all archived public threads from a channel.
+    ///
+    /// **Note**: Discord expects `before` to be a timestamp, so this should
+    /// only be used without it. Use [`Self::get_public_archived_threads`]
+    /// instead.
+    #[deprecated(note = "use `get_public_archived_threads`")]
     pub async fn get_channel_archived_public_threads(
         &self,
         channel_id: u64,
@@ -2558,6 +3302,11 @@ impl Http {
     }
 
     /// Gets all archived private threads from a channel.
+    ///
+    /// **Note**: Discord expects `before` to be a timestamp, so this should
+    /// only be used without it. Use [`Self::get_private_archived_threads`]
+    /// instead.
+    #[deprecated(note = "use `get_private_archived_threads`")]
     pub async fn get_channel_archived_private_threads(
         &self,
         channel_id: u64,
@@ -2577,7 +3326,69 @@ impl Http {
         .await
     }
 
+    /// Gets the archived public threads of a channel, ordered by archive
+    /// timestamp from newest to oldest.
+    ///
+    /// Only threads archived before the `before` timestamp are returned, if
+    /// set. Refer to [`Self::get_archived_threads_iter`] to stream over all of
+    /// them.
+    ///
+    /// Requires the [Read Message History] permission.
+    ///
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    pub async fn get_public_archived_threads(
+        &self,
+        channel_id: u64,
+        before: Option<Timestamp>,
+        limit: Option<u64>,
+    ) -> Result<ThreadsData> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::GetChannelPublicArchivedThreads {
+                channel_id,
+                before,
+                limit,
+            },
+        })
+        .await
+    }
+
+    /// Gets the archived private threads of a channel, ordered by archive
+    /// timestamp from newest to oldest.
+    ///
+    /// Only threads archived before the `before` timestamp are returned, if
+    /// set. Refer to [`Self::get_archived_threads_iter`] to stream over all of
+    /// them.
+    ///
+    /// Requires the [Read Message History] and [Manage Threads] permissions.
+    ///
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    /// [Manage Threads]: Permissions::MANAGE_THREADS
+    pub async fn get_private_archived_threads(
+        &self,
+        channel_id: u64,
+        before: Option<Timestamp>,
+        limit: Option<u64>,
+    ) -> Result<ThreadsData> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::GetChannelPrivateArchivedThreads {
+                channel_id,
+                before,
+                limit,
+            },
+        })
+        .await
+    }
+
     /// Gets all archived private threads joined from a channel.
+    ///
+    /// Threads are ordered by id from newest to oldest, and only threads with
+    /// an id lower than `before` are returned, if set.
     pub async fn get_channel_joined_archived_private_threads(
         &self,
         channel_id: u64,
@@ -2597,6 +3408,116 @@ impl Http {
         .await
     }
 
+    /// Streams over all of the archived threads of the given kind in a channel,
+    /// from newest to oldest, fetching them in pages of 100 as needed.
+    ///
+    /// Threads the current user has joined have their [`GuildChannel::member`]
+    /// set.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::{ArchivedThreadKind, Http};
+    /// # async fn run() {
+    /// # let http = Http::new("token");
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut threads = http.get_archived_threads_iter(1, ArchivedThreadKind::Public).boxed();
+    /// while let Some(thread_result) = threads.next().await {
+    ///     match thread_result {
+    ///         Ok(thread) => println!("{}", thread.name),
+    ///         Err(error) => eprintln!("Uh oh! Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Each item is an [`Error::Http`] if the current user lacks permission,
+    /// after which the stream ends.
+    pub fn get_archived_threads_iter(
+        &self,
+        channel_id: u64,
+        kind: ArchivedThreadKind,
+    ) -> impl Stream<Item = Result<GuildChannel>> + '_ {
+        const GRAB_SIZE: u64 = 100;
+
+        struct State {
+            buffer: Vec<GuildChannel>,
+            before_timestamp: Option<Timestamp>,
+            before_id: Option<u64>,
+            exhausted: bool,
+        }
+
+        let init_state = State {
+            buffer: Vec::new(),
+            before_timestamp: None,
+            before_id: None,
+            exhausted: false,
+        };
+
+        futures::stream::unfold(init_state, move |mut state| async move {
+            if state.buffer.is_empty() && !state.exhausted {
+                let limit = Some(GRAB_SIZE);
+                let result = match kind {
+                    ArchivedThreadKind::Public => {
+                        self.get_public_archived_threads(channel_id, state.before_timestamp, limit)
+                            .await
+                    },
+                    ArchivedThreadKind::Private => {
+                        self.get_private_archived_threads(channel_id, state.before_timestamp, limit)
+                            .await
+                    },
+                    ArchivedThreadKind::JoinedPrivate => {
+                        self.get_channel_joined_archived_private_threads(
+                            channel_id,
+                            state.before_id,
+                            limit,
+                        )
+                        .await
+                    },
+                };
+
+                match result {
+                    Ok(data) => {
+                        state.exhausted = !data.has_more || data.threads.is_empty();
+
+                        if let Some(last) = data.threads.last() {
+                            state.before_id = Some(last.id.0);
+                            state.before_timestamp =
+                                last.thread_metadata.and_then(|m| m.archive_timestamp);
+                        }
+
+                        // Stop rather than restart from the newest threads.
+                        if kind != ArchivedThreadKind::JoinedPrivate {
+                            state.exhausted |= state.before_timestamp.is_none();
+                        }
+
+                        let mut members = data.members;
+                        state.buffer = data.threads;
+
+                        for thread in &mut state.buffer {
+                            if thread.member.is_none() {
+                                let index = members.iter().position(|m| m.id == Some(thread.id));
+                                thread.member = index.map(|index| members.swap_remove(index));
+                            }
+                        }
+
+                        // Reverse to optimize pop()
+                        state.buffer.reverse();
+                    },
+                    Err(error) => {
+                        state.exhausted = true;
+                        return Some((Err(error), state));
+                    },
+                }
+            }
+
+            state.buffer.pop().map(|thread| (Ok(thread), state))
+        })
+    }
+
     /// Joins a thread channel.
     pub async fn join_thread_channel(&self, channel_id: u64) -> Result<()> {
         self.wind(204, Request {
@@ -2683,6 +3604,10 @@ impl Http {
     }
 
     /// Gets channel information.
+    ///
+    /// The [`Channel`] variant is chosen from the channel's type. Threads, voice, stage, and
+    /// forum channels are all [`Channel::Guild`]; use [`ChannelType::is_thread`] to tell threads
+    /// apart.
     pub async fn get_channel(&self, channel_id: u64) -> Result<Channel> {
         self.fire(Request {
             body: None,
@@ -2734,6 +3659,28 @@ impl Http {
         .await
     }
 
+    /// Edits the current application.
+    ///
+    /// Refer to [`EditApplication`] for the fields that can be edited.
+    ///
+    /// **Note**: Only applications may use this endpoint.
+    ///
+    /// [`EditApplication`]: crate::builder::EditApplication
+    pub async fn edit_current_application(
+        &self,
+        map: &JsonMap,
+    ) -> Result<CurrentApplicationInfo> {
+        let body = to_vec(map)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            multipart: None,
+            headers: None,
+            route: RouteInfo::EditCurrentApplication,
+        })
+        .await
+    }
+
     /// Gets information about the user we're connected with.
     pub async fn get_current_user(&self) -> Result<CurrentUser> {
         self.fire(Request {
@@ -2921,6 +3868,9 @@ impl Http {
     }
 
     /// Gets a guild preview.
+    ///
+    /// **Note**: The current user needs to either be a member of the guild, or
+    /// the guild needs to have the `DISCOVERABLE` feature.
     pub async fn get_guild_preview(&self, guild_id: u64) -> Result<GuildPreview> {
         self.fire(Request {
             body: None,
@@ -2946,54 +3896,145 @@ impl Http {
         .await
     }
 
-    /// Gets integrations that a guild has.
-    pub async fn get_guild_integrations(&self, guild_id: u64) -> Result<Vec<Integration>> {
+    /// Gets a [`GuildTemplate`] by its code.
+    pub async fn get_guild_template(&self, code: &str) -> Result<GuildTemplate> {
         self.fire(Request {
             body: None,
             multipart: None,
             headers: None,
-            route: RouteInfo::GetGuildIntegrations {
-                guild_id,
+            route: RouteInfo::GetGuildTemplate {
+                code,
             },
         })
         .await
     }
 
-    /// Gets all invites to a guild.
-    pub async fn get_guild_invites(&self, guild_id: u64) -> Result<Vec<RichInvite>> {
+    /// Gets the [`GuildTemplate`]s of a guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn get_guild_templates(&self, guild_id: u64) -> Result<Vec<GuildTemplate>> {
         self.fire(Request {
             body: None,
             multipart: None,
             headers: None,
-            route: RouteInfo::GetGuildInvites {
+            route: RouteInfo::GetGuildTemplates {
                 guild_id,
             },
         })
         .await
     }
 
-    /// Gets a guild's vanity URL if it has one.
-    pub async fn get_guild_vanity_url(&self, guild_id: u64) -> Result<String> {
+    /// Gets the default soundboard sounds, which can be used by all users.
+    pub async fn get_default_soundboard_sounds(&self) -> Result<Vec<SoundboardSound>> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::GetDefaultSoundboardSounds,
+        })
+        .await
+    }
+
+    /// Gets all soundboard sounds of a guild.
+    pub async fn get_guild_soundboard_sounds(&self, guild_id: u64) -> Result<Vec<SoundboardSound>> {
         #[derive(Deserialize)]
-        struct GuildVanityUrl {
-            code: String,
+        struct SoundboardSounds {
+            items: Vec<SoundboardSound>,
         }
 
         self.request(Request {
             body: None,
             multipart: None,
             headers: None,
-            route: RouteInfo::GetGuildVanityUrl {
+            route: RouteInfo::GetGuildSoundboardSounds {
                 guild_id,
             },
         })
         .await?
-        .json::<GuildVanityUrl>()
+        .json::<SoundboardSounds>()
         .await
-        .map(|x| x.code)
+        .map(|s| s.items)
         .map_err(From::from)
     }
 
+    /// Gets a soundboard sound of a guild by its Id.
+    pub async fn get_guild_soundboard_sound(
+        &self,
+        guild_id: u64,
+        sound_id: u64,
+    ) -> Result<SoundboardSound> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::GetGuildSoundboardSound {
+                guild_id,
+                sound_id,
+            },
+        })
+        .await
+    }
+
+    /// Gets integrations that a guild has.
+    pub async fn get_guild_integrations(&self, guild_id: u64) -> Result<Vec<Integration>> {
+        let mut value = self
+            .request(Request {
+                body: None,
+                multipart: None,
+                headers: None,
+                route: RouteInfo::GetGuildIntegrations {
+                    guild_id,
+                },
+            })
+            .await?
+            .json::<Value>()
+            .await?;
+
+        // The guild Id is not included in the response, so insert it into
+        // each integration.
+        if let Some(values) = value.as_array_mut() {
+            let num = from_number(guild_id);
+
+            for value in values {
+                if let Some(element) = value.as_object_mut() {
+                    element.insert("guild_id".to_string(), num.clone());
+                }
+            }
+        }
+
+        from_value::<Vec<Integration>>(value).map_err(From::from)
+    }
+
+    /// Gets all invites to a guild.
+    pub async fn get_guild_invites(&self, guild_id: u64) -> Result<Vec<RichInvite>> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::GetGuildInvites {
+                guild_id,
+            },
+        })
+        .await
+    }
+
+    /// Gets a guild's vanity URL and its number of uses.
+    ///
+    /// The [`VanityUrl::code`] is [`None`] if the guild has no vanity invite.
+    pub async fn get_guild_vanity_url(&self, guild_id: u64) -> Result<VanityUrl> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::GetGuildVanityUrl {
+                guild_id,
+            },
+        })
+        .await
+    }
+
     /// Gets the members of a guild. Optionally pass a `limit` and the Id of the
     /// user to offset the result by.
     pub async fn get_guild_members(
@@ -3273,7 +4314,7 @@ impl Http {
 
     /// Gets a paginated list of the current user's guilds.
     ///
-    /// The `limit` has a maximum value of 100.
+    /// The `limit` has a maximum value of 200.
     ///
     /// [Discord's documentation][docs]
     ///
@@ -3301,6 +4342,100 @@ impl Http {
         &self,
         target: Option<&GuildPagination>,
         limit: Option<u64>,
+    ) -> Result<Vec<GuildInfo>> {
+        self._get_guilds(target, limit, false).await
+    }
+
+    /// Gets a paginated list of the current user's guilds, like [`Self::get_guilds`], but with
+    /// the [`GuildInfo::approximate_member_count`] and
+    /// [`GuildInfo::approximate_presence_count`] fields present.
+    ///
+    /// The `limit` has a maximum value of 200.
+    ///
+    /// [Discord's documentation][docs]
+    ///
+    /// [docs]: https://discord.com/developers/docs/resources/user#get-current-user-guilds
+    pub async fn get_guilds_with_counts(
+        &self,
+        target: Option<&GuildPagination>,
+        limit: Option<u64>,
+    ) -> Result<Vec<GuildInfo>> {
+        self._get_guilds(target, limit, true).await
+    }
+
+    /// Streams over all of the current user's guilds.
+    ///
+    /// This is accomplished and equivalent to repeated calls to [`Self::get_guilds`], or
+    /// [`Self::get_guilds_with_counts`] if `with_counts` is `true`. Pages of 200 guilds are
+    /// requested, to reduce the number of calls necessary.
+    ///
+    /// The stream ends after yielding the first error encountered.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() {
+    /// # let http = Http::new("token");
+    /// use serenity::futures::StreamExt;
+    ///
+    /// let mut guilds = http.get_guilds_iter(true).boxed();
+    /// while let Some(guild_result) = guilds.next().await {
+    ///     match guild_result {
+    ///         Ok(guild) => {
+    ///             println!("{} has ~{:?} members", guild.name, guild.approximate_member_count)
+    ///         },
+    ///         Err(error) => eprintln!("Uh oh!  Error: {}", error),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn get_guilds_iter(&self, with_counts: bool) -> impl Stream<Item = Result<GuildInfo>> + '_ {
+        const GRAB_SIZE: u64 = 200;
+
+        struct State {
+            buffer: Vec<GuildInfo>,
+            after: Option<GuildId>,
+            exhausted: bool,
+        }
+
+        let init_state = State {
+            buffer: Vec::new(),
+            after: None,
+            exhausted: false,
+        };
+
+        futures::stream::unfold(init_state, move |mut state| async move {
+            if state.buffer.is_empty() && !state.exhausted {
+                let target = state.after.map(GuildPagination::After);
+
+                match self._get_guilds(target.as_ref(), Some(GRAB_SIZE), with_counts).await {
+                    Ok(guilds) => {
+                        // If shorter than a full page, there are no more results
+                        state.exhausted = guilds.len() < GRAB_SIZE as usize;
+                        state.after = guilds.last().map(|guild| guild.id);
+                        state.buffer = guilds;
+
+                        // Reverse to optimize pop()
+                        state.buffer.reverse();
+                    },
+                    Err(error) => {
+                        state.exhausted = true;
+                        return Some((Err(error), state));
+                    },
+                }
+            }
+
+            state.buffer.pop().map(|guild| (Ok(guild), state))
+        })
+    }
+
+    async fn _get_guilds(
+        &self,
+        target: Option<&GuildPagination>,
+        limit: Option<u64>,
+        with_counts: bool,
     ) -> Result<Vec<GuildInfo>> {
         let (after, before) = match target {
             None => (None, None),
@@ -3310,15 +4445,25 @@ impl Http {
             },
         };
 
+        let route = if with_counts {
+            RouteInfo::GetGuildsWithCounts {
+                after,
+                before,
+                limit,
+            }
+        } else {
+            RouteInfo::GetGuilds {
+                after,
+                before,
+                limit,
+            }
+        };
+
         self.fire(Request {
             body: None,
             multipart: None,
             headers: None,
-            route: RouteInfo::GetGuilds {
-                after,
-                before,
-                limit,
-            },
+            route,
         })
         .await
     }
@@ -3412,6 +4557,82 @@ impl Http {
         .await
     }
 
+    /// Retrieves all SKUs of the current application.
+    ///
+    /// Because of how Discord's SKU and subscription systems work, there will be two SKUs for
+    /// each subscription: one of kind [`SkuKind::Subscription`] and one of kind
+    /// [`SkuKind::SubscriptionGroup`]. Use the former when checking entitlements.
+    pub async fn get_skus(&self) -> Result<Vec<Sku>> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::GetSkus {
+                application_id: self.try_application_id()?,
+            },
+        })
+        .await
+    }
+
+    /// Retrieves the role connection metadata records of the current application.
+    pub async fn get_application_role_connection_metadata_records(
+        &self,
+    ) -> Result<Vec<RoleConnectionMetadata>> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::GetApplicationRoleConnectionMetadataRecords {
+                application_id: self.try_application_id()?,
+            },
+        })
+        .await
+    }
+
+    /// Retrieves the entitlements of the current application, optionally filtered by the
+    /// user or guild they belong to, the SKUs they grant, and ending state.
+    ///
+    /// The `before` and `after` Entitlement Ids can be used for pagination, returning up
+    /// to `limit` entitlements at a time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotInRange`] if `limit` is not between 1 and 100.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn list_entitlements(
+        &self,
+        user_id: Option<u64>,
+        sku_ids: Option<&[u64]>,
+        before: Option<u64>,
+        after: Option<u64>,
+        limit: Option<u8>,
+        guild_id: Option<u64>,
+        exclude_ended: Option<bool>,
+    ) -> Result<Vec<Entitlement>> {
+        if let Some(l) = limit {
+            if !(1..=100).contains(&l) {
+                return Err(Error::NotInRange("limit", u64::from(l), 1, 100));
+            }
+        }
+
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::GetEntitlements {
+                application_id: self.try_application_id()?,
+                user_id,
+                sku_ids,
+                before,
+                after,
+                limit,
+                guild_id,
+                exclude_ended,
+            },
+        })
+        .await
+    }
+
     /// Retrieves a list of all nitro sticker packs.
     pub async fn get_nitro_stickers(&self) -> Result<Vec<StickerPack>> {
         #[derive(Deserialize)]
@@ -3432,7 +4653,10 @@ impl Http {
         .map_err(From::from)
     }
 
-    /// Gets all pins of a channel.
+    /// Gets all pins of a channel, ordered from the most recently pinned to the
+    /// oldest.
+    ///
+    /// A channel can have at most 50 pins, so this is never paginated.
     pub async fn get_pins(&self, channel_id: u64) -> Result<Vec<Message>> {
         self.fire(Request {
             body: None,
@@ -3571,6 +4795,12 @@ impl Http {
     }
 
     /// Gets all voice regions.
+    ///
+    /// A region's [`id`] can be passed to [`EditChannel::voice_region`] to
+    /// pin a voice or stage channel to that region.
+    ///
+    /// [`id`]: VoiceRegion::id
+    /// [`EditChannel::voice_region`]: crate::builder::EditChannel::voice_region
     pub async fn get_voice_regions(&self) -> Result<Vec<VoiceRegion>> {
         self.fire(Request {
             body: None,
@@ -3703,6 +4933,9 @@ impl Http {
     }
 
     /// Leaves a guild.
+    ///
+    /// **Note**: This is irreversible, and the owner of a guild can't leave it. Prefer
+    /// [`GuildId::leave`], which also removes the guild from the cache.
     pub async fn leave_guild(&self, guild_id: u64) -> Result<()> {
         self.wind(204, Request {
             body: None,
@@ -3761,6 +4994,31 @@ impl Http {
         .await
     }
 
+    /// Plays a soundboard sound in a voice channel, for everyone connected to it.
+    ///
+    /// Refer to Discord's [docs] for field information.
+    ///
+    /// **Note**: Requires the [Speak] and [Use Soundboard] permissions, and the
+    /// current user must be connected to the voice channel without being muted
+    /// or deafened.
+    ///
+    /// [docs]: https://discord.com/developers/docs/resources/soundboard#send-soundboard-sound
+    /// [Speak]: Permissions::SPEAK
+    /// [Use Soundboard]: Permissions::USE_SOUNDBOARD
+    pub async fn send_soundboard_sound(&self, channel_id: u64, map: &JsonMap) -> Result<()> {
+        let body = to_vec(map)?;
+
+        self.wind(204, Request {
+            body: Some(&body),
+            multipart: None,
+            headers: None,
+            route: RouteInfo::SendSoundboardSound {
+                channel_id,
+            },
+        })
+        .await
+    }
+
     /// Pins a message in a channel.
     pub async fn pin_message(
         &self,
@@ -3827,12 +5085,29 @@ impl Http {
 
     /// Returns a list of [`Member`]s in a [`Guild`] whose username or nickname
     /// starts with a provided string.
+    ///
+    /// The `limit` defaults to 1000. Refer to [`GuildId::search_members`] for
+    /// more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::MemberSearchLimit`] if the `limit` is not
+    /// between 1 and 1000.
+    ///
+    /// [`GuildId::search_members`]: crate::model::id::GuildId::search_members
+    /// [`ModelError::MemberSearchLimit`]: crate::model::error::Error::MemberSearchLimit
     pub async fn search_guild_members(
         &self,
         guild_id: u64,
         query: &str,
         limit: Option<u64>,
     ) -> Result<Vec<Member>> {
+        if let Some(l) = limit {
+            if !(1..=constants::MEMBER_FETCH_LIMIT).contains(&l) {
+                return Err(Error::Model(ModelError::MemberSearchLimit(l)));
+            }
+        }
+
         let mut value = self
             .request(Request {
                 body: None,
@@ -3892,6 +5167,24 @@ impl Http {
         .await
     }
 
+    /// Syncs a guild's [`GuildTemplate`] to the current state of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    pub async fn sync_guild_template(&self, guild_id: u64, code: &str) -> Result<GuildTemplate> {
+        self.fire(Request {
+            body: None,
+            multipart: None,
+            headers: None,
+            route: RouteInfo::SyncGuildTemplate {
+                guild_id,
+                code,
+            },
+        })
+        .await
+    }
+
     /// Starts typing in the specified [`Channel`] for an indefinite period of time.
     ///
     /// Returns [`Typing`] that is used to trigger the typing. [`Typing::stop`] must be called
@@ -3950,6 +5243,59 @@ impl Http {
         .await
     }
 
+    /// Replaces the role connection metadata records of the current application.
+    ///
+    /// The records are not validated; use [`RoleConnectionMetadata::set_records`]
+    /// to check their amount and keys before sending them.
+    pub async fn update_application_role_connection_metadata_records(
+        &self,
+        records: Vec<RoleConnectionMetadata>,
+    ) -> Result<Vec<RoleConnectionMetadata>> {
+        let body = to_vec(&records)?;
+
+        self.fire(Request {
+            body: Some(&body),
+            multipart: None,
+            headers: None,
+            route: RouteInfo::UpdateApplicationRoleConnectionMetadataRecords {
+                application_id: self.try_application_id()?,
+            },
+        })
+        .await
+    }
+
+    /// Updates the current application's role connection for the user that
+    /// authorized the given OAuth2 bearer token.
+    ///
+    /// The token must have been granted the [`Scope::RoleConnectionsWrite`]
+    /// scope.
+    ///
+    /// [`Scope::RoleConnectionsWrite`]: crate::model::application::oauth::Scope::RoleConnectionsWrite
+    pub async fn update_user_application_role_connection(
+        &self,
+        bearer_token: &str,
+        connection: &ApplicationRoleConnection,
+    ) -> Result<ApplicationRoleConnection> {
+        let body = to_vec(connection)?;
+
+        let mut headers = Headers::new();
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_str(&format!("Bearer {}", bearer_token))
+                .map_err(HttpError::InvalidHeader)?,
+        );
+
+        self.fire(Request {
+            body: Some(&body),
+            multipart: None,
+            headers: Some(headers),
+            route: RouteInfo::UpdateUserApplicationRoleConnection {
+                application_id: self.try_application_id()?,
+            },
+        })
+        .await
+    }
+
     /// Fires off a request, deserializing the response reader via the given type
     /// bound.
     ///
@@ -4036,13 +5382,32 @@ impl Http {
     /// # }
     /// ```
     #[instrument]
-    pub async fn request(&self, mut req: Request<'_>) -> Result<ReqwestResponse> {
+    pub async fn request(&self, req: Request<'_>) -> Result<ReqwestResponse> {
+        self.request_with_timeout(req, self.timeout).await
+    }
+
+    /// Performs a request like [`Self::request`], with the given timeout
+    /// instead of the one set via [`HttpBuilder::timeout`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`HttpError::Timeout`] if the request does not complete within
+    /// the timeout, in addition to the errors of [`Self::request`].
+    #[instrument]
+    pub async fn request_with_timeout(
+        &self,
+        mut req: Request<'_>,
+        timeout: Option<Duration>,
+    ) -> Result<ReqwestResponse> {
         let response = if self.ratelimiter_disabled {
-            let request =
-                req.build(&self.client, &self.token, self.proxy.as_ref()).await?.build()?;
-            self.client.execute(request).await?
+            let mut request = req.build(&self.client, &self.token, self.proxy.as_ref()).await?;
+            if let Some(timeout) = timeout {
+                request = request.timeout(timeout);
+            }
+
+            self.client.execute(request.build()?).await?
         } else {
-            let ratelimiting_req = RatelimitedRequest::from(req);
+            let ratelimiting_req = RatelimitedRequest::with_timeout(req, timeout);
             self.ratelimiter.perform(ratelimiting_req).await?
         };
 
@@ -78,6 +78,15 @@ pub enum Error {
     InvalidPort,
     /// When an application id was expected but missing.
     ApplicationIdMissing,
+    /// When fetching a remote attachment received a non-successful status code.
+    AttachmentRequestFailed(StatusCode),
+    /// When a remote attachment has an unsupported content type, such as that
+    /// of a web page.
+    InvalidAttachmentContentType(String),
+    /// When a remote attachment is larger than the size limit, in bytes.
+    AttachmentTooLarge(u64),
+    /// When a request did not complete within its timeout.
+    Timeout,
 }
 
 impl Error {
@@ -105,14 +114,47 @@ impl Error {
         matches!(self, Self::InvalidHeader(_))
     }
 
+    /// Returns true when the error is caused by a request timing out
+    #[must_use]
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, Self::Timeout)
+    }
+
     /// Returns the status code if the error is an unsuccessful request
     #[must_use]
     pub fn status_code(&self) -> Option<StatusCode> {
         match self {
             Self::UnsuccessfulRequest(res) => Some(res.status_code),
+            Self::AttachmentRequestFailed(status_code) => Some(*status_code),
+            _ => None,
+        }
+    }
+
+    /// Returns Discord's [JSON error code] if the error is an unsuccessful
+    /// request.
+    ///
+    /// [JSON error code]: https://discord.com/developers/docs/topics/opcodes-and-status-codes#json-json-error-codes
+    #[must_use]
+    pub fn json_error_code(&self) -> Option<isize> {
+        match self {
+            Self::UnsuccessfulRequest(res) => Some(res.error.code),
             _ => None,
         }
     }
+
+    /// Returns the codes of the errors Discord explained with their path in
+    /// the request body, such as `BASE_TYPE_REQUIRED`, if the error is an
+    /// unsuccessful request.
+    ///
+    /// Refer to [`DiscordJsonError::errors`] for more information.
+    pub fn json_error_field_codes(&self) -> impl Iterator<Item = &str> {
+        let errors = match self {
+            Self::UnsuccessfulRequest(res) => res.error.errors.as_slice(),
+            _ => &[],
+        };
+
+        errors.iter().map(|error| error.code.as_str())
+    }
 }
 
 impl From<ErrorResponse> for Error {
@@ -123,7 +165,11 @@ impl From<ErrorResponse> for Error {
 
 impl From<ReqwestError> for Error {
     fn from(error: ReqwestError) -> Error {
-        Error::Request(error)
+        if error.is_timeout() {
+            Error::Timeout
+        } else {
+            Error::Request(error)
+        }
     }
 }
 
@@ -172,6 +218,16 @@ impl fmt::Display for Error {
             Self::InvalidScheme => f.write_str("Invalid Url scheme."),
             Self::InvalidPort => f.write_str("Invalid port."),
             Self::ApplicationIdMissing => f.write_str("Application id was expected but missing."),
+            Self::AttachmentRequestFailed(status_code) => {
+                write!(f, "Fetching the attachment failed with status {}.", status_code)
+            },
+            Self::InvalidAttachmentContentType(content_type) => {
+                write!(f, "Attachment has an unsupported content type: {}.", content_type)
+            },
+            Self::AttachmentTooLarge(limit) => {
+                write!(f, "Attachment is larger than the limit of {} bytes.", limit)
+            },
+            Self::Timeout => f.write_str("Request timed out."),
         }
     }
 }
@@ -219,4 +275,28 @@ mod test {
 
         assert_eq!(error_response, known);
     }
+
+    #[test]
+    fn test_json_error_codes() {
+        let error = Error::UnsuccessfulRequest(ErrorResponse {
+            status_code: reqwest::StatusCode::BAD_REQUEST,
+            url: String::from("https://ferris.crab").parse().unwrap(),
+            error: DiscordJsonError {
+                code: 50035,
+                message: String::from("Invalid Form Body"),
+                errors: vec![DiscordJsonSingleError {
+                    code: String::from("USERNAME_RATE_LIMIT"),
+                    message: String::from("You are changing your username too fast."),
+                    path: String::from("username"),
+                }],
+            },
+        });
+
+        assert_eq!(error.json_error_code(), Some(50035));
+        assert_eq!(error.json_error_field_codes().collect::<Vec<_>>(), ["USERNAME_RATE_LIMIT"]);
+
+        let error = Error::InvalidWebhook;
+        assert_eq!(error.json_error_code(), None);
+        assert_eq!(error.json_error_field_codes().count(), 0);
+    }
 }
@@ -45,6 +45,7 @@ pub use self::typing::*;
 use crate::cache::Cache;
 #[cfg(feature = "client")]
 use crate::client::Context;
+use crate::model::guild::audit_log::Action;
 use crate::model::prelude::*;
 #[cfg(feature = "client")]
 use crate::CacheAndHttp;
@@ -193,9 +194,11 @@ pub enum GuildPagination {
     Before(GuildId),
 }
 
-/// Representation of the method of a query to send for the [`get_scheduled_event_users`] function.
+/// Representation of the method of a query to send for the [`get_scheduled_event_users`] and
+/// [`get_bans_paginated`] functions.
 ///
 /// [`get_scheduled_event_users`]: Http::get_scheduled_event_users
+/// [`get_bans_paginated`]: Http::get_bans_paginated
 #[non_exhaustive]
 pub enum UserPagination {
     /// The Id to get the users after.
@@ -203,3 +206,84 @@ pub enum UserPagination {
     /// The Id to get the users before.
     Before(UserId),
 }
+
+/// The kind of archived threads to list with the [`get_archived_threads_iter`] function.
+///
+/// [`get_archived_threads_iter`]: Http::get_archived_threads_iter
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum ArchivedThreadKind {
+    /// Public threads, which require the [Read Message History] permission.
+    ///
+    /// [Read Message History]: crate::model::Permissions::READ_MESSAGE_HISTORY
+    Public,
+    /// Private threads, which require both the [Read Message History] and the
+    /// [Manage Threads] permissions.
+    ///
+    /// [Read Message History]: crate::model::Permissions::READ_MESSAGE_HISTORY
+    /// [Manage Threads]: crate::model::Permissions::MANAGE_THREADS
+    Private,
+    /// Private threads the current user has joined, which require the
+    /// [Read Message History] permission.
+    ///
+    /// [Read Message History]: crate::model::Permissions::READ_MESSAGE_HISTORY
+    JoinedPrivate,
+}
+
+/// The filters and pagination of a query to send for the [`get_audit_logs_with`] function.
+///
+/// Entries are returned from newest to oldest, unless only [`Self::after`] is set.
+///
+/// [`get_audit_logs_with`]: Http::get_audit_logs_with
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub struct GetAuditLogs {
+    /// Only returns entries made by this user.
+    pub user_id: Option<UserId>,
+    /// Only returns entries of this action type.
+    pub action_type: Option<Action>,
+    /// Only returns entries older than this one.
+    pub before: Option<AuditLogEntryId>,
+    /// Only returns entries newer than this one.
+    pub after: Option<AuditLogEntryId>,
+    /// The maximum number of entries to return, between 1 and 100. Defaults to 50.
+    pub limit: Option<u8>,
+}
+
+impl GetAuditLogs {
+    /// Creates a query without any filters.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the user to filter entries by.
+    pub fn user_id(&mut self, user_id: impl Into<UserId>) -> &mut Self {
+        self.user_id = Some(user_id.into());
+        self
+    }
+
+    /// Sets the action type to filter entries by.
+    pub fn action_type(&mut self, action_type: Action) -> &mut Self {
+        self.action_type = Some(action_type);
+        self
+    }
+
+    /// Sets the entry to get the entries before.
+    pub fn before(&mut self, before: impl Into<AuditLogEntryId>) -> &mut Self {
+        self.before = Some(before.into());
+        self
+    }
+
+    /// Sets the entry to get the entries after.
+    pub fn after(&mut self, after: impl Into<AuditLogEntryId>) -> &mut Self {
+        self.after = Some(after.into());
+        self
+    }
+
+    /// Sets the maximum number of entries to return.
+    pub fn limit(&mut self, limit: u8) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+}
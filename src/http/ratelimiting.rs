@@ -58,14 +58,23 @@ use crate::internal::prelude::*;
 
 /// Passed to the [`Ratelimiter::set_ratelimit_callback`] callback. If using Client, that callback
 /// is initialized to call the `EventHandler::ratelimit()` method.
+///
+/// The callback is called before the ratelimiter sleeps for the [`Self::timeout`].
 #[derive(Clone, Debug)]
 #[non_exhaustive]
 pub struct RatelimitInfo {
+    /// How long the request will be delayed for.
     pub timeout: std::time::Duration,
+    /// The total number of requests that can be made in a period of time.
     pub limit: i64,
     pub method: LightMethod,
     pub path: String,
+    /// Whether the global ratelimit was hit, rather than the route's.
     pub global: bool,
+    /// The route that was ratelimited.
+    pub route: Route,
+    /// The ratelimit bucket reported by Discord for the route, if known.
+    pub bucket: Option<String>,
 }
 
 /// Ratelimiter for requests to the Discord API.
@@ -135,6 +144,11 @@ impl Ratelimiter {
         self.ratelimit_callback = ratelimit_callback;
     }
 
+    /// Removes the current ratelimit callback, leaving one that does nothing.
+    pub(crate) fn take_ratelimit_callback(&mut self) -> Box<dyn Fn(RatelimitInfo) + Send + Sync> {
+        std::mem::replace(&mut self.ratelimit_callback, Box::new(|_| {}))
+    }
+
     /// The routes mutex is a HashMap of each [`Route`] and their respective
     /// ratelimit information.
     ///
@@ -174,6 +188,7 @@ impl Ratelimiter {
     pub async fn perform(&self, req: RatelimitedRequest<'_>) -> Result<Response> {
         let RatelimitedRequest {
             mut req,
+            timeout,
         } = req;
 
         loop {
@@ -204,9 +219,25 @@ impl Ratelimiter {
 
             bucket.lock().await.pre_hook(&req.route, &self.ratelimit_callback).await;
 
-            let request = req.build(&self.client, &self.token, None).await?.build()?;
+            let mut request = req.build(&self.client, &self.token, None).await?;
+            if let Some(timeout) = timeout {
+                request = request.timeout(timeout);
+            }
 
-            let response = self.client.execute(request).await?;
+            let response = match self.client.execute(request.build()?).await {
+                Ok(response) => response,
+                Err(why) => {
+                    // Without a response, the bucket can't be updated. If the
+                    // connection failed, the request was never sent, so give its
+                    // slot back rather than waiting for the next reset. A timed
+                    // out request may still have been counted by Discord.
+                    if why.is_connect() {
+                        bucket.lock().await.release();
+                    }
+
+                    return Err(why.into());
+                },
+            };
 
             // Check if the request got ratelimited by checking for status 429,
             // and if so, sleep for the value of the header 'retry-after' -
@@ -239,6 +270,8 @@ impl Ratelimiter {
                             method,
                             path,
                             global: true,
+                            route,
+                            bucket: None,
                         });
                         sleep(Duration::from_secs_f64(retry_after)).await;
 
@@ -278,6 +311,8 @@ pub struct Ratelimit {
     reset: Option<SystemTime>,
     /// The total time when the interval resets.
     reset_after: Option<Duration>,
+    /// The ratelimit bucket reported by Discord.
+    bucket: Option<String>,
 }
 
 impl Ratelimit {
@@ -320,6 +355,8 @@ impl Ratelimit {
                 method,
                 path: path.to_string(),
                 global: false,
+                route,
+                bucket: self.bucket.clone(),
             });
 
             sleep(delay).await;
@@ -345,6 +382,10 @@ impl Ratelimit {
             self.remaining = remaining;
         }
 
+        if let Some(bucket) = parse_header(response.headers(), "x-ratelimit-bucket")? {
+            self.bucket = Some(bucket);
+        }
+
         #[cfg(feature = "absolute_ratelimits")]
         if let Some(reset) = parse_header::<f64>(response.headers(), "x-ratelimit-reset")? {
             self.reset = Some(std::time::UNIX_EPOCH + Duration::from_secs_f64(reset));
@@ -373,6 +414,8 @@ impl Ratelimit {
                 method,
                 path: path.to_string(),
                 global: false,
+                route,
+                bucket: self.bucket.clone(),
             });
 
             sleep(Duration::from_secs_f64(retry_after)).await;
@@ -383,6 +426,14 @@ impl Ratelimit {
         })
     }
 
+    /// Gives back the slot taken by [`Self::pre_hook`] for a request which
+    /// never reached Discord.
+    fn release(&mut self) {
+        if self.remaining < self.limit {
+            self.remaining += 1;
+        }
+    }
+
     /// The total number of requests that can be made in a period of time.
     #[inline]
     #[must_use]
@@ -410,6 +461,14 @@ impl Ratelimit {
     pub fn reset_after(&self) -> Option<Duration> {
         self.reset_after
     }
+
+    /// The ratelimit bucket reported by Discord, shared by all routes with the
+    /// same limits.
+    #[inline]
+    #[must_use]
+    pub fn bucket(&self) -> Option<&str> {
+        self.bucket.as_deref()
+    }
 }
 
 impl Default for Ratelimit {
@@ -419,6 +478,7 @@ impl Default for Ratelimit {
             remaining: i64::MAX,
             reset: None,
             reset_after: None,
+            bucket: None,
         }
     }
 }
@@ -432,16 +492,28 @@ impl Default for Ratelimit {
 #[derive(Debug)]
 pub struct RatelimitedRequest<'a> {
     req: Request<'a>,
+    timeout: Option<Duration>,
 }
 
-impl<'a> From<Request<'a>> for RatelimitedRequest<'a> {
-    fn from(req: Request<'a>) -> Self {
+impl<'a> RatelimitedRequest<'a> {
+    /// Creates a request which fails with [`HttpError::Timeout`] if it does not
+    /// complete within the timeout, excluding the time spent waiting for
+    /// ratelimits.
+    #[must_use]
+    pub fn with_timeout(req: Request<'a>, timeout: Option<Duration>) -> Self {
         Self {
             req,
+            timeout,
         }
     }
 }
 
+impl<'a> From<Request<'a>> for RatelimitedRequest<'a> {
+    fn from(req: Request<'a>) -> Self {
+        Self::with_timeout(req, None)
+    }
+}
+
 fn parse_header<T: FromStr>(headers: &HeaderMap, header: &str) -> Result<Option<T>> {
     let header = match headers.get(header) {
         Some(v) => v,
@@ -463,8 +535,9 @@ mod tests {
 
     use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
 
-    use super::parse_header;
+    use super::{parse_header, Ratelimit};
     use crate::error::Error;
+    use crate::http::routing::RouteInfo;
     use crate::http::HttpError;
 
     type Result<T> = StdResult<T, Box<dyn StdError>>;
@@ -477,6 +550,10 @@ mod tests {
                 HeaderName::from_static("x-ratelimit-reset"),
                 HeaderValue::from_static("1560704880.423"),
             ),
+            (
+                HeaderName::from_static("x-ratelimit-bucket"),
+                HeaderValue::from_static("abcd1234"),
+            ),
             (HeaderName::from_static("x-bad-num"), HeaderValue::from_static("abc")),
             (
                 HeaderName::from_static("x-bad-unicode"),
@@ -501,6 +578,7 @@ mod tests {
         assert_eq!(parse_header::<i64>(&headers, "x-ratelimit-limit")?.unwrap(), 5);
         assert_eq!(parse_header::<i64>(&headers, "x-ratelimit-remaining")?.unwrap(), 4,);
         assert_eq!(parse_header::<f64>(&headers, "x-ratelimit-reset")?.unwrap(), 1_560_704_880.423);
+        assert_eq!(parse_header::<String>(&headers, "x-ratelimit-bucket")?.unwrap(), "abcd1234");
 
         Ok(())
     }
@@ -523,4 +601,23 @@ mod tests {
         assert!(is_err!("x-bad-num", HttpError::RateLimitI64F64));
         assert!(is_err!("x-bad-unicode", HttpError::RateLimitUtf8));
     }
+
+    #[tokio::test]
+    async fn test_release_slot() {
+        let mut ratelimit = Ratelimit {
+            limit: 5,
+            remaining: 5,
+            reset: Some(std::time::SystemTime::now() + std::time::Duration::from_secs(60)),
+            ..Ratelimit::default()
+        };
+
+        ratelimit.pre_hook(&RouteInfo::GetGateway, &|_| {}).await;
+        assert_eq!(ratelimit.remaining(), 4);
+
+        // A request failing to connect gives its slot back, without exceeding the limit.
+        ratelimit.release();
+        assert_eq!(ratelimit.remaining(), 5);
+        ratelimit.release();
+        assert_eq!(ratelimit.remaining(), 5);
+    }
 }
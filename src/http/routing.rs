@@ -3,6 +3,7 @@ use std::fmt::{Display, Write};
 
 use super::LightMethod;
 use crate::constants;
+use crate::model::Timestamp;
 
 /// A representation of all routes registered within the library. These are safe
 /// and memory-efficient representations of each path that functions exist for
@@ -99,6 +100,12 @@ pub enum Route {
     ///
     /// [`ChannelId`]: crate::model::id::ChannelId
     ChannelsIdTyping(u64),
+    /// Route for the `/channels/:channel_id/send-soundboard-sound` path.
+    ///
+    /// The data is the relevant [`ChannelId`].
+    ///
+    /// [`ChannelId`]: crate::model::id::ChannelId
+    ChannelsIdSendSoundboardSound(u64),
     /// Route for the `/channels/:channel_id/webhooks` path.
     ///
     /// The data is the relevant [`ChannelId`].
@@ -192,6 +199,12 @@ pub enum Route {
     ///
     /// [`GuildId`]: crate::model::id::GuildId
     GuildsIdBansUserId(u64),
+    /// Route for the `/guilds/:guild_id/bulk-ban` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdBulkBan(u64),
     /// Route for the `/guilds/:guild_id/channels/:channel_id` path.
     ///
     /// The data is the relevant [`GuildId`].
@@ -337,6 +350,18 @@ pub enum Route {
     ///
     /// [`GuildId`]: crate::model::id::GuildId
     GuildsIdStickersId(u64),
+    /// Route for the `/guilds/:guild_id/soundboard-sounds` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdSoundboardSounds(u64),
+    /// Route for the `/guilds/:guild_id/soundboard-sounds/:sound_id` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdSoundboardSoundsId(u64),
     /// Route for the `/guilds/:guild_id/vanity-url` path.
     ///
     /// The data is the relevant [`GuildId`].
@@ -367,6 +392,20 @@ pub enum Route {
     ///
     /// [`GuildId`]: crate::model::id::GuildId
     GuildsIdWelcomeScreen(u64),
+    /// Route for the `/guilds/:guild_id/templates` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdTemplates(u64),
+    /// Route for the `/guilds/:guild_id/templates/:template_code` path.
+    ///
+    /// The data is the relevant [`GuildId`].
+    ///
+    /// [`GuildId`]: crate::model::id::GuildId
+    GuildsIdTemplatesCode(u64),
+    /// Route for the `/guilds/templates/:template_code` path.
+    GuildsTemplatesCode,
     /// Route for the `/guilds/:guild_id/threads/active` path.
     ///
     /// The data is the relevant [`GuildId`].
@@ -375,6 +414,8 @@ pub enum Route {
     GuildsIdThreadsActive,
     /// Route for the `/invites/:code` path.
     InvitesCode,
+    /// Route for the `/soundboard-default-sounds` path.
+    SoundboardDefaultSounds,
     /// Route for the `/sticker-packs` path.
     StickerPacks,
     /// Route for the `/stickers/:sticker_id` path.
@@ -383,6 +424,12 @@ pub enum Route {
     UsersId,
     /// Route for the `/users/@me` path.
     UsersMe,
+    /// Route for the `/users/@me/applications/:application_id/role-connection` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: crate::model::id::ApplicationId
+    UsersMeApplicationsIdRoleConnection(u64),
     /// Route for the `/users/@me/channels` path.
     UsersMeChannels,
     /// Route for the `/users/@me/connections` path.
@@ -413,6 +460,8 @@ pub enum Route {
     ///
     /// [`InteractionId`]: crate::model::id::InteractionId
     InteractionsId(u64),
+    /// Route for the `/applications/@me` path.
+    ApplicationsMe,
     /// Route for the `/applications/:application_id` path.
     ///
     /// The data is the relevant [`ApplicationId`].
@@ -449,6 +498,36 @@ pub enum Route {
     ///
     /// [`ApplicationId`]: crate::model::id::ApplicationId
     ApplicationsIdGuildsIdCommandsId(u64),
+    /// Route for the `/applications/:application_id/skus` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: crate::model::id::ApplicationId
+    ApplicationsIdSkus(u64),
+    /// Route for the `/applications/:application_id/entitlements` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: crate::model::id::ApplicationId
+    ApplicationsIdEntitlements(u64),
+    /// Route for the `/applications/:application_id/entitlements/:entitlement_id` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: crate::model::id::ApplicationId
+    ApplicationsIdEntitlementsId(u64),
+    /// Route for the `/applications/:application_id/entitlements/:entitlement_id/consume` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: crate::model::id::ApplicationId
+    ApplicationsIdEntitlementsIdConsume(u64),
+    /// Route for the `/applications/:application_id/role-connections/metadata` path.
+    ///
+    /// The data is the relevant [`ApplicationId`].
+    ///
+    /// [`ApplicationId`]: crate::model::id::ApplicationId
+    ApplicationsIdRoleConnectionsMetadata(u64),
     /// Route for the `/stage-instances` path.
     ///
     /// The data is the relevant [`ChannelId`].
@@ -585,6 +664,11 @@ impl Route {
         api!("/channels/{}/typing", channel_id)
     }
 
+    #[must_use]
+    pub fn channel_send_soundboard_sound(channel_id: u64) -> String {
+        api!("/channels/{}/send-soundboard-sound", channel_id)
+    }
+
     #[must_use]
     pub fn channel_webhooks(channel_id: u64) -> String {
         api!("/channels/{}/webhooks", channel_id)
@@ -621,7 +705,7 @@ impl Route {
         before: Option<u64>,
         limit: Option<u64>,
     ) -> String {
-        let mut s = api!("/channels/{}/threads/archived/public", channel_id);
+        let mut s = api!("/channels/{}/threads/archived/public?", channel_id);
 
         if let Some(id) = before {
             write!(s, "&before={}", id).unwrap();
@@ -640,7 +724,7 @@ impl Route {
         before: Option<u64>,
         limit: Option<u64>,
     ) -> String {
-        let mut s = api!("/channels/{}/threads/archived/private", channel_id);
+        let mut s = api!("/channels/{}/threads/archived/private?", channel_id);
 
         if let Some(id) = before {
             write!(s, "&before={}", id).unwrap();
@@ -653,13 +737,51 @@ impl Route {
         s
     }
 
+    #[must_use]
+    pub fn channel_public_archived_threads(
+        channel_id: u64,
+        before: Option<Timestamp>,
+        limit: Option<u64>,
+    ) -> String {
+        let mut s = api!("/channels/{}/threads/archived/public?", channel_id);
+
+        if let Some(before) = before {
+            write!(s, "&before={}", before).unwrap();
+        }
+
+        if let Some(limit) = limit {
+            write!(s, "&limit={}", limit).unwrap();
+        }
+
+        s
+    }
+
+    #[must_use]
+    pub fn channel_private_archived_threads(
+        channel_id: u64,
+        before: Option<Timestamp>,
+        limit: Option<u64>,
+    ) -> String {
+        let mut s = api!("/channels/{}/threads/archived/private?", channel_id);
+
+        if let Some(before) = before {
+            write!(s, "&before={}", before).unwrap();
+        }
+
+        if let Some(limit) = limit {
+            write!(s, "&limit={}", limit).unwrap();
+        }
+
+        s
+    }
+
     #[must_use]
     pub fn channel_joined_private_threads(
         channel_id: u64,
         before: Option<u64>,
         limit: Option<u64>,
     ) -> String {
-        let mut s = api!("/channels/{}/users/@me/threads/archived/private", channel_id);
+        let mut s = api!("/channels/{}/users/@me/threads/archived/private?", channel_id);
 
         if let Some(id) = before {
             write!(s, "&before={}", id).unwrap();
@@ -699,6 +821,18 @@ impl Route {
         user_id: Option<u64>,
         before: Option<u64>,
         limit: Option<u8>,
+    ) -> String {
+        Self::guild_audit_logs_optioned(guild_id, action_type, user_id, before, None, limit)
+    }
+
+    #[must_use]
+    pub fn guild_audit_logs_optioned(
+        guild_id: u64,
+        action_type: Option<u8>,
+        user_id: Option<u64>,
+        before: Option<u64>,
+        after: Option<u64>,
+        limit: Option<u8>,
     ) -> String {
         let mut s = api!("/guilds/{}/audit-logs?", guild_id);
 
@@ -710,6 +844,10 @@ impl Route {
             write!(s, "&before={}", before).unwrap();
         }
 
+        if let Some(after) = after {
+            write!(s, "&after={}", after).unwrap();
+        }
+
         if let Some(limit) = limit {
             write!(s, "&limit={}", limit).unwrap();
         }
@@ -751,6 +889,35 @@ impl Route {
         api!("/guilds/{}/bans", guild_id)
     }
 
+    #[must_use]
+    pub fn guild_bans_optioned(
+        guild_id: u64,
+        after: Option<u64>,
+        before: Option<u64>,
+        limit: Option<u16>,
+    ) -> String {
+        let mut s = api!("/guilds/{}/bans?", guild_id);
+
+        if let Some(limit) = limit {
+            write!(s, "&limit={}", limit).unwrap();
+        }
+
+        if let Some(after) = after {
+            write!(s, "&after={}", after).unwrap();
+        }
+
+        if let Some(before) = before {
+            write!(s, "&before={}", before).unwrap();
+        }
+
+        s
+    }
+
+    #[must_use]
+    pub fn guild_bulk_ban(guild_id: u64) -> String {
+        api!("/guilds/{}/bulk-ban", guild_id)
+    }
+
     #[must_use]
     pub fn guild_channels(guild_id: u64) -> String {
         api!("/guilds/{}/channels", guild_id)
@@ -924,6 +1091,16 @@ impl Route {
         api!("/guilds/{}/stickers", guild_id)
     }
 
+    #[must_use]
+    pub fn guild_soundboard_sound(guild_id: u64, sound_id: u64) -> String {
+        api!("/guilds/{}/soundboard-sounds/{}", guild_id, sound_id)
+    }
+
+    #[must_use]
+    pub fn guild_soundboard_sounds(guild_id: u64) -> String {
+        api!("/guilds/{}/soundboard-sounds", guild_id)
+    }
+
     #[must_use]
     pub fn guild_vanity_url(guild_id: u64) -> String {
         api!("/guilds/{}/vanity-url", guild_id)
@@ -949,6 +1126,21 @@ impl Route {
         api!("/guilds/{}/welcome-screen", guild_id)
     }
 
+    #[must_use]
+    pub fn guild_templates(guild_id: u64) -> String {
+        api!("/guilds/{}/templates", guild_id)
+    }
+
+    #[must_use]
+    pub fn guild_template(guild_id: u64, code: &str) -> String {
+        api!("/guilds/{}/templates/{}", guild_id, code)
+    }
+
+    #[must_use]
+    pub fn template(code: &str) -> String {
+        api!("/guilds/templates/{}", code)
+    }
+
     #[must_use]
     pub fn guild_threads_active(guild_id: u64) -> String {
         api!("/guilds/{}/threads/active", guild_id)
@@ -980,6 +1172,11 @@ impl Route {
         )
     }
 
+    #[must_use]
+    pub fn application_current() -> &'static str {
+        api!("/applications/@me")
+    }
+
     #[must_use]
     pub fn oauth2_application_current() -> &'static str {
         api!("/oauth2/applications/@me")
@@ -1010,6 +1207,11 @@ impl Route {
         api!("/stickers/{}", sticker_id)
     }
 
+    #[must_use]
+    pub fn soundboard_default_sounds() -> &'static str {
+        api!("/soundboard-default-sounds")
+    }
+
     #[must_use]
     pub fn sticker_packs() -> &'static str {
         api!("/sticker-packs")
@@ -1046,6 +1248,17 @@ impl Route {
         after: Option<u64>,
         before: Option<u64>,
         limit: Option<u64>,
+    ) -> String {
+        Self::user_guilds_with_counts_optioned(target, after, before, limit, false)
+    }
+
+    #[must_use]
+    pub fn user_guilds_with_counts_optioned<D: Display>(
+        target: D,
+        after: Option<u64>,
+        before: Option<u64>,
+        limit: Option<u64>,
+        with_counts: bool,
     ) -> String {
         let mut s = api!("/users/{}/guilds?", target);
 
@@ -1061,6 +1274,10 @@ impl Route {
             write!(s, "&before={}", before).unwrap();
         }
 
+        if with_counts {
+            s.push_str("&with_counts=true");
+        }
+
         s
     }
 
@@ -1168,6 +1385,82 @@ impl Route {
         api!("/applications/{}/guilds/{}/commands/permissions", application_id, guild_id)
     }
 
+    #[must_use]
+    pub fn application_skus(application_id: u64) -> String {
+        api!("/applications/{}/skus", application_id)
+    }
+
+    #[must_use]
+    pub fn application_role_connections_metadata(application_id: u64) -> String {
+        api!("/applications/{}/role-connections/metadata", application_id)
+    }
+
+    #[must_use]
+    pub fn user_application_role_connection(application_id: u64) -> String {
+        api!("/users/@me/applications/{}/role-connection", application_id)
+    }
+
+    #[must_use]
+    pub fn application_entitlements(application_id: u64) -> String {
+        api!("/applications/{}/entitlements", application_id)
+    }
+
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn application_entitlements_optioned(
+        application_id: u64,
+        user_id: Option<u64>,
+        sku_ids: Option<&[u64]>,
+        before: Option<u64>,
+        after: Option<u64>,
+        limit: Option<u8>,
+        guild_id: Option<u64>,
+        exclude_ended: Option<bool>,
+    ) -> String {
+        let mut s = api!("/applications/{}/entitlements?", application_id);
+
+        if let Some(user_id) = user_id {
+            write!(s, "&user_id={}", user_id).unwrap();
+        }
+
+        if let Some(sku_ids) = sku_ids {
+            let ids = sku_ids.iter().map(ToString::to_string).collect::<Vec<_>>();
+            write!(s, "&sku_ids={}", ids.join(",")).unwrap();
+        }
+
+        if let Some(before) = before {
+            write!(s, "&before={}", before).unwrap();
+        }
+
+        if let Some(after) = after {
+            write!(s, "&after={}", after).unwrap();
+        }
+
+        if let Some(limit) = limit {
+            write!(s, "&limit={}", limit).unwrap();
+        }
+
+        if let Some(guild_id) = guild_id {
+            write!(s, "&guild_id={}", guild_id).unwrap();
+        }
+
+        if let Some(exclude_ended) = exclude_ended {
+            write!(s, "&exclude_ended={}", exclude_ended).unwrap();
+        }
+
+        s
+    }
+
+    #[must_use]
+    pub fn application_entitlement(application_id: u64, entitlement_id: u64) -> String {
+        api!("/applications/{}/entitlements/{}", application_id, entitlement_id)
+    }
+
+    #[must_use]
+    pub fn application_entitlement_consume(application_id: u64, entitlement_id: u64) -> String {
+        api!("/applications/{}/entitlements/{}/consume", application_id, entitlement_id)
+    }
+
     #[must_use]
     pub fn stage_instances() -> &'static str {
         api!("/stage-instances")
@@ -1191,6 +1484,9 @@ pub enum RouteInfo<'a> {
         role_id: u64,
         user_id: u64,
     },
+    GuildBulkBan {
+        guild_id: u64,
+    },
     GuildBanUser {
         guild_id: u64,
         user_id: u64,
@@ -1199,6 +1495,10 @@ pub enum RouteInfo<'a> {
     BroadcastTyping {
         channel_id: u64,
     },
+    ConsumeEntitlement {
+        application_id: u64,
+        entitlement_id: u64,
+    },
     CreateAutoModRule {
         guild_id: u64,
     },
@@ -1235,6 +1535,15 @@ pub enum RouteInfo<'a> {
         application_id: u64,
         guild_id: u64,
     },
+    CreateGuildFromTemplate {
+        code: &'a str,
+    },
+    CreateGuildTemplate {
+        guild_id: u64,
+    },
+    CreateGuildSoundboardSound {
+        guild_id: u64,
+    },
     CreateGuildIntegration {
         guild_id: u64,
         integration_id: u64,
@@ -1268,6 +1577,9 @@ pub enum RouteInfo<'a> {
     CreateSticker {
         guild_id: u64,
     },
+    CreateTestEntitlement {
+        application_id: u64,
+    },
     CreateWebhook {
         channel_id: u64,
     },
@@ -1306,6 +1618,14 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         integration_id: u64,
     },
+    DeleteGuildTemplate {
+        guild_id: u64,
+        code: &'a str,
+    },
+    DeleteGuildSoundboardSound {
+        guild_id: u64,
+        sound_id: u64,
+    },
     DeleteInvite {
         code: &'a str,
     },
@@ -1351,6 +1671,10 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         sticker_id: u64,
     },
+    DeleteTestEntitlement {
+        application_id: u64,
+        entitlement_id: u64,
+    },
     DeleteWebhook {
         webhook_id: u64,
     },
@@ -1370,6 +1694,7 @@ pub enum RouteInfo<'a> {
     EditChannel {
         channel_id: u64,
     },
+    EditCurrentApplication,
     EditStageInstance {
         channel_id: u64,
     },
@@ -1412,6 +1737,10 @@ pub enum RouteInfo<'a> {
     EditGuildWelcomeScreen {
         guild_id: u64,
     },
+    EditGuildSoundboardSound {
+        guild_id: u64,
+        sound_id: u64,
+    },
     EditMember {
         guild_id: u64,
         user_id: u64,
@@ -1506,6 +1835,14 @@ pub enum RouteInfo<'a> {
         limit: Option<u8>,
         user_id: Option<u64>,
     },
+    GetAuditLogsOptioned {
+        action_type: Option<u8>,
+        after: Option<u64>,
+        before: Option<u64>,
+        guild_id: u64,
+        limit: Option<u8>,
+        user_id: Option<u64>,
+    },
     GetAutoModRules {
         guild_id: u64,
     },
@@ -1513,9 +1850,19 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         rule_id: u64,
     },
+    GetBan {
+        guild_id: u64,
+        user_id: u64,
+    },
     GetBans {
         guild_id: u64,
     },
+    GetBansOptioned {
+        guild_id: u64,
+        after: Option<u64>,
+        before: Option<u64>,
+        limit: Option<u16>,
+    },
     GetBotGateway,
     GetChannel {
         channel_id: u64,
@@ -1550,8 +1897,19 @@ pub enum RouteInfo<'a> {
         before: Option<u64>,
         limit: Option<u64>,
     },
+    GetChannelPublicArchivedThreads {
+        channel_id: u64,
+        before: Option<Timestamp>,
+        limit: Option<u64>,
+    },
+    GetChannelPrivateArchivedThreads {
+        channel_id: u64,
+        before: Option<Timestamp>,
+        limit: Option<u64>,
+    },
     GetCurrentApplicationInfo,
     GetCurrentUser,
+    GetDefaultSoundboardSounds,
     GetEmojis {
         guild_id: u64,
     },
@@ -1559,11 +1917,24 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         emoji_id: u64,
     },
+    GetEntitlements {
+        application_id: u64,
+        user_id: Option<u64>,
+        sku_ids: Option<&'a [u64]>,
+        before: Option<u64>,
+        after: Option<u64>,
+        limit: Option<u8>,
+        guild_id: Option<u64>,
+        exclude_ended: Option<bool>,
+    },
     GetFollowupMessage {
         application_id: u64,
         interaction_token: &'a str,
         message_id: u64,
     },
+    GetApplicationRoleConnectionMetadataRecords {
+        application_id: u64,
+    },
     GetGateway,
     GetGlobalApplicationCommands {
         application_id: u64,
@@ -1608,6 +1979,19 @@ pub enum RouteInfo<'a> {
     GetGuildWelcomeScreen {
         guild_id: u64,
     },
+    GetGuildTemplate {
+        code: &'a str,
+    },
+    GetGuildTemplates {
+        guild_id: u64,
+    },
+    GetGuildSoundboardSound {
+        guild_id: u64,
+        sound_id: u64,
+    },
+    GetGuildSoundboardSounds {
+        guild_id: u64,
+    },
     GetGuildIntegrations {
         guild_id: u64,
     },
@@ -1660,6 +2044,11 @@ pub enum RouteInfo<'a> {
         before: Option<u64>,
         limit: Option<u64>,
     },
+    GetGuildsWithCounts {
+        after: Option<u64>,
+        before: Option<u64>,
+        limit: Option<u64>,
+    },
     GetInvite {
         code: &'a str,
         member_counts: bool,
@@ -1692,6 +2081,9 @@ pub enum RouteInfo<'a> {
         sticker_id: u64,
     },
     GetStickerPacks,
+    GetSkus {
+        application_id: u64,
+    },
     GetGuildSticker {
         guild_id: u64,
         sticker_id: u64,
@@ -1744,6 +2136,9 @@ pub enum RouteInfo<'a> {
         query: &'a str,
         limit: Option<u64>,
     },
+    SendSoundboardSound {
+        channel_id: u64,
+    },
     StartGuildPrune {
         days: u64,
         guild_id: u64,
@@ -1752,6 +2147,10 @@ pub enum RouteInfo<'a> {
         guild_id: u64,
         integration_id: u64,
     },
+    SyncGuildTemplate {
+        guild_id: u64,
+        code: &'a str,
+    },
     StatusIncidentsUnresolved,
     StatusMaintenancesActive,
     StatusMaintenancesUpcoming,
@@ -1759,6 +2158,12 @@ pub enum RouteInfo<'a> {
         channel_id: u64,
         message_id: u64,
     },
+    UpdateApplicationRoleConnectionMetadataRecords {
+        application_id: u64,
+    },
+    UpdateUserApplicationRoleConnection {
+        application_id: u64,
+    },
 }
 
 impl<'a> RouteInfo<'a> {
@@ -1782,19 +2187,24 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdMembersIdRolesId(guild_id),
                 Cow::from(Route::guild_member_role(guild_id, user_id, role_id)),
             ),
+            RouteInfo::GuildBulkBan {
+                guild_id,
+            } => (
+                LightMethod::Post,
+                Route::GuildsIdBulkBan(guild_id),
+                Cow::from(Route::guild_bulk_ban(guild_id)),
+            ),
             RouteInfo::GuildBanUser {
                 guild_id,
                 delete_message_days,
                 user_id,
             } => (
-                // TODO
                 LightMethod::Put,
                 Route::GuildsIdBansUserId(guild_id),
-                Cow::from(Route::guild_ban_optioned(
-                    guild_id,
-                    user_id,
-                    delete_message_days.unwrap_or(0),
-                )),
+                match delete_message_days {
+                    Some(days) => Cow::from(Route::guild_ban_optioned(guild_id, user_id, days)),
+                    None => Cow::from(Route::guild_ban(guild_id, user_id)),
+                },
             ),
             RouteInfo::BroadcastTyping {
                 channel_id,
@@ -1803,6 +2213,14 @@ impl<'a> RouteInfo<'a> {
                 Route::ChannelsIdTyping(channel_id),
                 Cow::from(Route::channel_typing(channel_id)),
             ),
+            RouteInfo::ConsumeEntitlement {
+                application_id,
+                entitlement_id,
+            } => (
+                LightMethod::Post,
+                Route::ApplicationsIdEntitlementsIdConsume(application_id),
+                Cow::from(Route::application_entitlement_consume(application_id, entitlement_id)),
+            ),
             RouteInfo::CreateAutoModRule {
                 guild_id,
             } => (
@@ -1867,6 +2285,23 @@ impl<'a> RouteInfo<'a> {
             RouteInfo::CreateGuild => {
                 (LightMethod::Post, Route::Guilds, Cow::from(Route::guilds()))
             },
+            RouteInfo::CreateGuildFromTemplate {
+                code,
+            } => (LightMethod::Post, Route::GuildsTemplatesCode, Cow::from(Route::template(code))),
+            RouteInfo::CreateGuildTemplate {
+                guild_id,
+            } => (
+                LightMethod::Post,
+                Route::GuildsIdTemplates(guild_id),
+                Cow::from(Route::guild_templates(guild_id)),
+            ),
+            RouteInfo::CreateGuildSoundboardSound {
+                guild_id,
+            } => (
+                LightMethod::Post,
+                Route::GuildsIdSoundboardSounds(guild_id),
+                Cow::from(Route::guild_soundboard_sounds(guild_id)),
+            ),
             RouteInfo::CreateGuildApplicationCommand {
                 application_id,
                 guild_id,
@@ -1956,6 +2391,13 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdStickers(guild_id),
                 Cow::from(Route::guild_stickers(guild_id)),
             ),
+            RouteInfo::CreateTestEntitlement {
+                application_id,
+            } => (
+                LightMethod::Post,
+                Route::ApplicationsIdEntitlements(application_id),
+                Cow::from(Route::application_entitlements(application_id)),
+            ),
             RouteInfo::CrosspostMessage {
                 channel_id,
                 message_id,
@@ -2044,6 +2486,22 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdIntegrationsId(guild_id),
                 Cow::from(Route::guild_integration(guild_id, integration_id)),
             ),
+            RouteInfo::DeleteGuildTemplate {
+                guild_id,
+                code,
+            } => (
+                LightMethod::Delete,
+                Route::GuildsIdTemplatesCode(guild_id),
+                Cow::from(Route::guild_template(guild_id, code)),
+            ),
+            RouteInfo::DeleteGuildSoundboardSound {
+                guild_id,
+                sound_id,
+            } => (
+                LightMethod::Delete,
+                Route::GuildsIdSoundboardSoundsId(guild_id),
+                Cow::from(Route::guild_soundboard_sound(guild_id, sound_id)),
+            ),
             RouteInfo::DeleteInvite {
                 code,
             } => (LightMethod::Delete, Route::InvitesCode, Cow::from(Route::invite(code))),
@@ -2132,6 +2590,14 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdStickersId(guild_id),
                 Cow::from(Route::guild_sticker(guild_id, sticker_id)),
             ),
+            RouteInfo::DeleteTestEntitlement {
+                application_id,
+                entitlement_id,
+            } => (
+                LightMethod::Delete,
+                Route::ApplicationsIdEntitlementsId(application_id),
+                Cow::from(Route::application_entitlement(application_id, entitlement_id)),
+            ),
             RouteInfo::DeleteWebhook {
                 webhook_id,
             } => (
@@ -2174,6 +2640,11 @@ impl<'a> RouteInfo<'a> {
                 Route::ChannelsId(channel_id),
                 Cow::from(Route::channel(channel_id)),
             ),
+            RouteInfo::EditCurrentApplication => (
+                LightMethod::Patch,
+                Route::ApplicationsMe,
+                Cow::from(Route::application_current()),
+            ),
             RouteInfo::EditScheduledEvent {
                 guild_id,
                 event_id,
@@ -2272,6 +2743,14 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdWelcomeScreen(guild_id),
                 Cow::from(Route::guild_welcome_screen(guild_id)),
             ),
+            RouteInfo::EditGuildSoundboardSound {
+                guild_id,
+                sound_id,
+            } => (
+                LightMethod::Patch,
+                Route::GuildsIdSoundboardSoundsId(guild_id),
+                Cow::from(Route::guild_soundboard_sound(guild_id, sound_id)),
+            ),
             RouteInfo::EditMember {
                 guild_id,
                 user_id,
@@ -2414,6 +2893,13 @@ impl<'a> RouteInfo<'a> {
                 Route::FollowNewsChannel(channel_id),
                 Cow::from(Route::channel_follow_news(channel_id)),
             ),
+            RouteInfo::GetApplicationRoleConnectionMetadataRecords {
+                application_id,
+            } => (
+                LightMethod::Get,
+                Route::ApplicationsIdRoleConnectionsMetadata(application_id),
+                Cow::from(Route::application_role_connections_metadata(application_id)),
+            ),
             RouteInfo::GetAuditLogs {
                 action_type,
                 before,
@@ -2425,6 +2911,25 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdAuditLogs(guild_id),
                 Cow::from(Route::guild_audit_logs(guild_id, action_type, user_id, before, limit)),
             ),
+            RouteInfo::GetAuditLogsOptioned {
+                action_type,
+                after,
+                before,
+                guild_id,
+                limit,
+                user_id,
+            } => (
+                LightMethod::Get,
+                Route::GuildsIdAuditLogs(guild_id),
+                Cow::from(Route::guild_audit_logs_optioned(
+                    guild_id,
+                    action_type,
+                    user_id,
+                    before,
+                    after,
+                    limit,
+                )),
+            ),
             RouteInfo::GetAutoModRules {
                 guild_id,
             } => (
@@ -2440,6 +2945,14 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdAutoModRulesId(guild_id),
                 Cow::from(Route::guild_automod_rule(guild_id, rule_id)),
             ),
+            RouteInfo::GetBan {
+                guild_id,
+                user_id,
+            } => (
+                LightMethod::Get,
+                Route::GuildsIdBansUserId(guild_id),
+                Cow::from(Route::guild_ban(guild_id, user_id)),
+            ),
             RouteInfo::GetBans {
                 guild_id,
             } => (
@@ -2447,6 +2960,16 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdBans(guild_id),
                 Cow::from(Route::guild_bans(guild_id)),
             ),
+            RouteInfo::GetBansOptioned {
+                guild_id,
+                after,
+                before,
+                limit,
+            } => (
+                LightMethod::Get,
+                Route::GuildsIdBans(guild_id),
+                Cow::from(Route::guild_bans_optioned(guild_id, after, before, limit)),
+            ),
             RouteInfo::GetBotGateway => {
                 (LightMethod::Get, Route::GatewayBot, Cow::from(Route::gateway_bot()))
             },
@@ -2519,6 +3042,24 @@ impl<'a> RouteInfo<'a> {
                 Route::ChannelsIdMeJoindedArchivedPrivateThreads(channel_id),
                 Cow::from(Route::channel_joined_private_threads(channel_id, before, limit)),
             ),
+            RouteInfo::GetChannelPublicArchivedThreads {
+                channel_id,
+                before,
+                limit,
+            } => (
+                LightMethod::Get,
+                Route::ChannelsIdArchivedPublicThreads(channel_id),
+                Cow::from(Route::channel_public_archived_threads(channel_id, before, limit)),
+            ),
+            RouteInfo::GetChannelPrivateArchivedThreads {
+                channel_id,
+                before,
+                limit,
+            } => (
+                LightMethod::Get,
+                Route::ChannelsIdArchivedPrivateThreads(channel_id),
+                Cow::from(Route::channel_private_archived_threads(channel_id, before, limit)),
+            ),
             RouteInfo::GetFollowupMessage {
                 application_id,
                 interaction_token,
@@ -2575,6 +3116,11 @@ impl<'a> RouteInfo<'a> {
             RouteInfo::GetCurrentUser => {
                 (LightMethod::Get, Route::UsersMe, Cow::from(Route::user("@me")))
             },
+            RouteInfo::GetDefaultSoundboardSounds => (
+                LightMethod::Get,
+                Route::SoundboardDefaultSounds,
+                Cow::from(Route::soundboard_default_sounds()),
+            ),
             RouteInfo::GetEmojis {
                 guild_id,
             } => (
@@ -2590,6 +3136,29 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdEmojisId(guild_id),
                 Cow::from(Route::guild_emoji(guild_id, emoji_id)),
             ),
+            RouteInfo::GetEntitlements {
+                application_id,
+                user_id,
+                sku_ids,
+                before,
+                after,
+                limit,
+                guild_id,
+                exclude_ended,
+            } => (
+                LightMethod::Get,
+                Route::ApplicationsIdEntitlements(application_id),
+                Cow::from(Route::application_entitlements_optioned(
+                    application_id,
+                    user_id,
+                    sku_ids,
+                    before,
+                    after,
+                    limit,
+                    guild_id,
+                    exclude_ended,
+                )),
+            ),
             RouteInfo::GetGateway => {
                 (LightMethod::Get, Route::Gateway, Cow::from(Route::gateway()))
             },
@@ -2677,6 +3246,31 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdWelcomeScreen(guild_id),
                 Cow::from(Route::guild_welcome_screen(guild_id)),
             ),
+            RouteInfo::GetGuildTemplate {
+                code,
+            } => (LightMethod::Get, Route::GuildsTemplatesCode, Cow::from(Route::template(code))),
+            RouteInfo::GetGuildTemplates {
+                guild_id,
+            } => (
+                LightMethod::Get,
+                Route::GuildsIdTemplates(guild_id),
+                Cow::from(Route::guild_templates(guild_id)),
+            ),
+            RouteInfo::GetGuildSoundboardSound {
+                guild_id,
+                sound_id,
+            } => (
+                LightMethod::Get,
+                Route::GuildsIdSoundboardSoundsId(guild_id),
+                Cow::from(Route::guild_soundboard_sound(guild_id, sound_id)),
+            ),
+            RouteInfo::GetGuildSoundboardSounds {
+                guild_id,
+            } => (
+                LightMethod::Get,
+                Route::GuildsIdSoundboardSounds(guild_id),
+                Cow::from(Route::guild_soundboard_sounds(guild_id)),
+            ),
             RouteInfo::GetGuildIntegrations {
                 guild_id,
             } => (
@@ -2758,7 +3352,20 @@ impl<'a> RouteInfo<'a> {
             } => (
                 LightMethod::Get,
                 Route::UsersMeGuilds,
-                Cow::from(Route::user_guilds_optioned("@me", after, before, limit)),
+                Cow::from(Route::user_guilds_with_counts_optioned(
+                    "@me", after, before, limit, false,
+                )),
+            ),
+            RouteInfo::GetGuildsWithCounts {
+                after,
+                before,
+                limit,
+            } => (
+                LightMethod::Get,
+                Route::UsersMeGuilds,
+                Cow::from(Route::user_guilds_with_counts_optioned(
+                    "@me", after, before, limit, true,
+                )),
             ),
             RouteInfo::GetInvite {
                 code,
@@ -2856,6 +3463,13 @@ impl<'a> RouteInfo<'a> {
             RouteInfo::GetStickerPacks => {
                 (LightMethod::Get, Route::StickerPacks, Cow::from(Route::sticker_packs()))
             },
+            RouteInfo::GetSkus {
+                application_id,
+            } => (
+                LightMethod::Get,
+                Route::ApplicationsIdSkus(application_id),
+                Cow::from(Route::application_skus(application_id)),
+            ),
             RouteInfo::GetUser {
                 user_id,
             } => (LightMethod::Get, Route::UsersId, Cow::from(Route::user(user_id))),
@@ -2934,6 +3548,13 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdMembersIdRolesId(guild_id),
                 Cow::from(Route::guild_member_role(guild_id, user_id, role_id)),
             ),
+            RouteInfo::SendSoundboardSound {
+                channel_id,
+            } => (
+                LightMethod::Post,
+                Route::ChannelsIdSendSoundboardSound(channel_id),
+                Cow::from(Route::channel_send_soundboard_sound(channel_id)),
+            ),
             RouteInfo::SearchGuildMembers {
                 guild_id,
                 query,
@@ -2951,6 +3572,14 @@ impl<'a> RouteInfo<'a> {
                 Route::GuildsIdPrune(guild_id),
                 Cow::from(Route::guild_prune(guild_id, days)),
             ),
+            RouteInfo::SyncGuildTemplate {
+                guild_id,
+                code,
+            } => (
+                LightMethod::Put,
+                Route::GuildsIdTemplatesCode(guild_id),
+                Cow::from(Route::guild_template(guild_id, code)),
+            ),
             RouteInfo::StartIntegrationSync {
                 guild_id,
                 integration_id,
@@ -2976,6 +3605,20 @@ impl<'a> RouteInfo<'a> {
                 Route::ChannelsIdPinsMessageId(channel_id),
                 Cow::from(Route::channel_pin(channel_id, message_id)),
             ),
+            RouteInfo::UpdateApplicationRoleConnectionMetadataRecords {
+                application_id,
+            } => (
+                LightMethod::Put,
+                Route::ApplicationsIdRoleConnectionsMetadata(application_id),
+                Cow::from(Route::application_role_connections_metadata(application_id)),
+            ),
+            RouteInfo::UpdateUserApplicationRoleConnection {
+                application_id,
+            } => (
+                LightMethod::Put,
+                Route::UsersMeApplicationsIdRoleConnection(application_id),
+                Cow::from(Route::user_application_role_connection(application_id)),
+            ),
         }
     }
 }
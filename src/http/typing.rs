@@ -1,8 +1,8 @@
 use std::sync::Arc;
 
-use tokio::sync::oneshot::error::TryRecvError;
 use tokio::sync::oneshot::{self, Sender};
 use tokio::time::{sleep, Duration};
+use tracing::warn;
 
 use crate::http::Http;
 use crate::internal::prelude::*;
@@ -17,6 +17,9 @@ use crate::internal::tokio::spawn_named;
 /// Note that on some clients, typing may persist for a few seconds after [`Typing::stop`] is called.
 /// Typing is also stopped when the struct is dropped.
 ///
+/// While started, typing is broadcast again every 7 seconds, until stopped. If a broadcast
+/// fails, typing is stopped and the error is logged as a warning.
+///
 /// If a message is sent while typing is triggered, the user will stop typing for a brief period
 /// of time and then resume again until either [`Typing::stop`] is called or the struct is dropped.
 ///
@@ -66,19 +69,22 @@ impl Typing {
 
         spawn_named("typing::start", async move {
             loop {
-                match rx.try_recv() {
-                    Ok(_) | Err(TryRecvError::Closed) => break,
-                    _ => (),
-                }
+                if let Err(why) = http.broadcast_typing(channel_id).await {
+                    warn!("Stopped typing in channel {} after an error: {:?}", channel_id, why);
 
-                http.broadcast_typing(channel_id).await?;
+                    break;
+                }
 
                 // It is unclear for how long typing persists after this method is called.
                 // It is generally assumed to be 7 or 10 seconds, so we use 7 to be safe.
-                sleep(Duration::from_secs(7)).await;
+                //
+                // Waiting on the receiver as well makes the task end as soon as the
+                // `Typing` is stopped or dropped, instead of after the next sleep.
+                tokio::select! {
+                    _ = &mut rx => break,
+                    _ = sleep(Duration::from_secs(7)) => {},
+                }
             }
-
-            Result::Ok(())
         });
 
         Ok(Self(sx))
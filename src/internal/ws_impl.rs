@@ -4,11 +4,13 @@ use async_trait::async_trait;
 use async_tungstenite::tungstenite::Message;
 use flate2::read::ZlibDecoder;
 use futures::{SinkExt, StreamExt};
+use socket2::{SockRef, TcpKeepalive};
+use tokio::net::TcpStream;
 use tokio::time::timeout;
 use tracing::{instrument, warn};
 use url::Url;
 
-use crate::gateway::{GatewayError, WsStream};
+use crate::gateway::{GatewayError, TcpSettings, WsStream};
 use crate::internal::prelude::*;
 use crate::json::{from_str, to_string};
 
@@ -78,15 +80,36 @@ pub(crate) fn convert_ws_message(message: Option<Message>) -> Result<Option<Valu
 }
 
 #[instrument]
-pub(crate) async fn create_client(url: Url) -> Result<WsStream> {
+pub(crate) async fn create_client(url: Url, tcp_settings: &TcpSettings) -> Result<WsStream> {
     let config = async_tungstenite::tungstenite::protocol::WebSocketConfig {
         max_message_size: None,
         max_frame_size: None,
         max_send_queue: None,
         accept_unmasked_frames: false,
     };
+
+    let host = url.host_str().ok_or(Error::Gateway(GatewayError::BuildingUrl))?;
+    let port = url.port_or_known_default().ok_or(Error::Gateway(GatewayError::BuildingUrl))?;
+
+    let socket = TcpStream::connect((host, port)).await?;
+    apply_tcp_settings(&socket, tcp_settings)?;
+
     let (stream, _) =
-        async_tungstenite::tokio::connect_async_with_config(url, Some(config)).await?;
+        async_tungstenite::tokio::client_async_tls_with_config(url, socket, Some(config)).await?;
 
     Ok(stream)
 }
+
+fn apply_tcp_settings(socket: &TcpStream, tcp_settings: &TcpSettings) -> std::io::Result<()> {
+    socket.set_nodelay(tcp_settings.nodelay)?;
+
+    if let Some(time) = tcp_settings.keepalive {
+        // socket2 exposes the idle time on all platforms, ignoring it where it
+        // can't be configured.
+        let keepalive = TcpKeepalive::new().with_time(time);
+
+        SockRef::from(socket).set_tcp_keepalive(&keepalive)?;
+    }
+
+    Ok(())
+}
@@ -77,6 +77,42 @@ where
     Ok(simd_json::from_str(s)?)
 }
 
+#[cfg(all(feature = "cache", not(feature = "simd-json")))]
+pub(crate) fn to_writer<W, T>(writer: W, v: &T) -> Result<()>
+where
+    W: std::io::Write,
+    T: Serialize,
+{
+    Ok(serde_json::to_writer(writer, v)?)
+}
+
+#[cfg(all(feature = "cache", feature = "simd-json"))]
+pub(crate) fn to_writer<W, T>(writer: W, v: &T) -> Result<()>
+where
+    W: std::io::Write,
+    T: Serialize,
+{
+    Ok(simd_json::to_writer(writer, v)?)
+}
+
+#[cfg(all(feature = "cache", not(feature = "simd-json")))]
+pub(crate) fn from_reader<R, T>(reader: R) -> Result<T>
+where
+    R: std::io::Read,
+    T: DeserializeOwned,
+{
+    Ok(serde_json::from_reader(reader)?)
+}
+
+#[cfg(all(feature = "cache", feature = "simd-json"))]
+pub(crate) fn from_reader<R, T>(reader: R) -> Result<T>
+where
+    R: std::io::Read,
+    T: DeserializeOwned,
+{
+    Ok(simd_json::from_reader(reader)?)
+}
+
 #[cfg(not(feature = "simd-json"))]
 pub(crate) fn from_value<T>(v: Value) -> Result<T>
 where
@@ -5,7 +5,21 @@ pub mod component;
 pub mod interaction;
 pub mod oauth;
 
+use std::collections::HashMap;
+
 use self::oauth::Scope;
+#[cfg(feature = "model")]
+use crate::builder::EditApplication;
+#[cfg(feature = "model")]
+use crate::constants::{APPLICATION_TAG_MAX_COUNT, APPLICATION_TAG_MAX_LENGTH};
+#[cfg(feature = "model")]
+use crate::http::Http;
+#[cfg(feature = "model")]
+use crate::internal::prelude::*;
+#[cfg(feature = "model")]
+use crate::json;
+#[cfg(feature = "model")]
+use crate::model::ModelError;
 use super::id::{snowflake, ApplicationId, GuildId, SkuId, UserId};
 use super::user::User;
 use super::Permissions;
@@ -57,6 +71,81 @@ pub struct CurrentApplicationInfo {
     pub install_params: Option<InstallParams>,
     #[serde(default)]
     pub custom_install_url: Option<String>,
+    #[serde(default)]
+    pub interactions_endpoint_url: Option<String>,
+    #[serde(default)]
+    pub role_connections_verification_url: Option<String>,
+    #[serde(default)]
+    pub approximate_guild_count: Option<u64>,
+}
+
+#[cfg(feature = "model")]
+impl CurrentApplicationInfo {
+    /// Edits the current application.
+    ///
+    /// This mutates the application in-place.
+    ///
+    /// Refer to [`EditApplication`]'s documentation for its methods.
+    ///
+    /// # Examples
+    ///
+    /// Change the description and tags:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// #
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// #     let http = Http::new("token");
+    /// let mut application = http.get_current_application_info().await?;
+    ///
+    /// application
+    ///     .edit(&http, |a| a.description("A friendly bot").tags(["fun", "utility"]))
+    ///     .await?;
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::ApplicationTagAmount`] if more than 5 tags are
+    /// set, or a [`ModelError::ApplicationTagTooLong`] if a tag is over 20
+    /// characters long.
+    ///
+    /// Otherwise returns an [`Error::Http`] if an invalid value is set.
+    pub async fn edit<F>(&mut self, http: impl AsRef<Http>, f: F) -> Result<()>
+    where
+        F: FnOnce(&mut EditApplication) -> &mut EditApplication,
+    {
+        let mut edit_application = EditApplication::default();
+        f(&mut edit_application);
+        let map = json::hashmap_to_json_map(edit_application.0);
+
+        check_tags(&map)?;
+
+        *self = http.as_ref().edit_current_application(&map).await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "model")]
+fn check_tags(map: &JsonMap) -> Result<()> {
+    if let Some(Value::Array(tags)) = map.get("tags") {
+        if tags.len() > APPLICATION_TAG_MAX_COUNT {
+            return Err(Error::Model(ModelError::ApplicationTagAmount));
+        }
+
+        for tag in tags.iter().filter_map(Value::as_str) {
+            let length = tag.chars().count();
+
+            if length > APPLICATION_TAG_MAX_LENGTH {
+                let overflow = length - APPLICATION_TAG_MAX_LENGTH;
+                return Err(Error::Model(ModelError::ApplicationTagTooLong(overflow)));
+            }
+        }
+    }
+
+    Ok(())
 }
 
 /// Information about the Team group of the application.
@@ -133,3 +222,209 @@ pub struct InstallParams {
     pub scopes: Vec<Scope>,
     pub permissions: Permissions,
 }
+
+/// A metadata record that a linked role can require users to satisfy.
+///
+/// The value of each record for a user is provided via
+/// [`Http::update_user_application_role_connection`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/application-role-connection-metadata#application-role-connection-metadata-object).
+///
+/// [`Http::update_user_application_role_connection`]: crate::http::Http::update_user_application_role_connection
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct RoleConnectionMetadata {
+    /// The type of comparison made against the user's value.
+    #[serde(rename = "type")]
+    pub kind: RoleConnectionMetadataKind,
+    /// The dictionary key of the metadata record.
+    ///
+    /// Must be 1-50 characters long and only contain `a-z`, `0-9`, or `_`.
+    pub key: String,
+    /// The name of the metadata record.
+    pub name: String,
+    /// Translations of the name, keyed by locale.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name_localizations: Option<HashMap<String, String>>,
+    /// The description of the metadata record.
+    pub description: String,
+    /// Translations of the description, keyed by locale.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description_localizations: Option<HashMap<String, String>>,
+}
+
+impl RoleConnectionMetadata {
+    /// Creates a new metadata record without any localizations.
+    pub fn new(
+        kind: RoleConnectionMetadataKind,
+        key: impl Into<String>,
+        name: impl Into<String>,
+        description: impl Into<String>,
+    ) -> Self {
+        Self {
+            kind,
+            key: key.into(),
+            name: name.into(),
+            name_localizations: None,
+            description: description.into(),
+            description_localizations: None,
+        }
+    }
+
+    /// Whether the given string is a valid metadata record key.
+    ///
+    /// Keys must be 1-50 characters long and may only contain lowercase
+    /// ASCII letters, digits and underscores.
+    #[must_use]
+    pub fn is_valid_key(key: &str) -> bool {
+        (1..=50).contains(&key.len())
+            && key.bytes().all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'_')
+    }
+}
+
+#[cfg(feature = "model")]
+impl RoleConnectionMetadata {
+    /// Retrieves the role connection metadata records of the current application.
+    ///
+    /// # Errors
+    ///
+    /// If there is an error, it will be either [`Error::Http`] or [`Error::Json`].
+    pub async fn get_records(http: impl AsRef<Http>) -> Result<Vec<Self>> {
+        http.as_ref().get_application_role_connection_metadata_records().await
+    }
+
+    /// Replaces the role connection metadata records of the current application.
+    ///
+    /// An application can have at most 5 records.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotInRange`] if more than 5 records are given, or a
+    /// [`ModelError::InvalidRoleConnectionMetadataKey`] if a record's key is
+    /// invalid.
+    ///
+    /// Otherwise returns an [`Error::Http`] if the API rejects the records.
+    pub async fn set_records(http: impl AsRef<Http>, records: Vec<Self>) -> Result<Vec<Self>> {
+        if records.len() > 5 {
+            return Err(Error::NotInRange("records", records.len() as u64, 0, 5));
+        }
+
+        if let Some(record) = records.iter().find(|r| !Self::is_valid_key(&r.key)) {
+            return Err(Error::Model(ModelError::InvalidRoleConnectionMetadataKey(
+                record.key.clone(),
+            )));
+        }
+
+        http.as_ref().update_application_role_connection_metadata_records(records).await
+    }
+}
+
+/// The type of comparison of a [`RoleConnectionMetadata`] record.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/application-role-connection-metadata#application-role-connection-metadata-object-application-role-connection-metadata-type).
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum RoleConnectionMetadataKind {
+    /// The user's value is less than or equal to the guild's configured value.
+    IntegerLessThanOrEqual = 1,
+    /// The user's value is greater than or equal to the guild's configured value.
+    IntegerGreaterThanOrEqual = 2,
+    /// The user's value is equal to the guild's configured value.
+    IntegerEqual = 3,
+    /// The user's value is not equal to the guild's configured value.
+    IntegerNotEqual = 4,
+    /// The user's date is less than or equal to the guild's configured number of days ago.
+    DatetimeLessThanOrEqual = 5,
+    /// The user's date is greater than or equal to the guild's configured number of days ago.
+    DatetimeGreaterThanOrEqual = 6,
+    /// The user's value is equal to the guild's configured value (`1`).
+    BooleanEqual = 7,
+    /// The user's value is not equal to the guild's configured value (`1`).
+    BooleanNotEqual = 8,
+    Unknown = !0,
+}
+
+enum_number!(RoleConnectionMetadataKind {
+    IntegerLessThanOrEqual,
+    IntegerGreaterThanOrEqual,
+    IntegerEqual,
+    IntegerNotEqual,
+    DatetimeLessThanOrEqual,
+    DatetimeGreaterThanOrEqual,
+    BooleanEqual,
+    BooleanNotEqual
+});
+
+/// The role connection of a user for an application.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/user#application-role-connection-object).
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ApplicationRoleConnection {
+    /// The vanity name of the platform the application is connected to.
+    pub platform_name: Option<String>,
+    /// The username of the user on the platform.
+    pub platform_username: Option<String>,
+    /// The user's values, keyed by [`RoleConnectionMetadata::key`].
+    ///
+    /// Values are stringified integers, ISO 8601 timestamps, or `"1"`/`"0"`
+    /// for booleans, depending on the record's [`RoleConnectionMetadataKind`].
+    #[serde(default)]
+    pub metadata: HashMap<String, String>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn role_connection_metadata_key() {
+        assert!(RoleConnectionMetadata::is_valid_key("level_2"));
+        assert!(RoleConnectionMetadata::is_valid_key(&"a".repeat(50)));
+        assert!(!RoleConnectionMetadata::is_valid_key(""));
+        assert!(!RoleConnectionMetadata::is_valid_key(&"a".repeat(51)));
+        assert!(!RoleConnectionMetadata::is_valid_key("Level"));
+        assert!(!RoleConnectionMetadata::is_valid_key("level-2"));
+    }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn application_tags() {
+        use crate::builder::EditApplication;
+        use crate::json::hashmap_to_json_map;
+
+        let check = |tags: &[&str]| {
+            let mut builder = EditApplication::default();
+            builder.tags(tags);
+            check_tags(&hashmap_to_json_map(builder.0))
+        };
+
+        assert!(check(&["fun", "utility", "a", "b", "twenty_characters_ok"]).is_ok());
+        assert!(matches!(
+            check(&["a", "b", "c", "d", "e", "f"]),
+            Err(Error::Model(ModelError::ApplicationTagAmount))
+        ));
+        assert!(matches!(
+            check(&["twenty_two_characters_"]),
+            Err(Error::Model(ModelError::ApplicationTagTooLong(2)))
+        ));
+    }
+
+    #[test]
+    fn role_connection_metadata_deserialize() {
+        let value = json!({
+            "type": 6,
+            "key": "joined_at",
+            "name": "Joined",
+            "name_localizations": {"fr": "Inscrit"},
+            "description": "Days since joining",
+        });
+
+        let record: RoleConnectionMetadata = from_value(value).unwrap();
+        assert_eq!(record.kind, RoleConnectionMetadataKind::DatetimeGreaterThanOrEqual);
+        assert_eq!(record.name_localizations.unwrap()["fr"], "Inscrit");
+        assert!(record.description_localizations.is_none());
+    }
+}
@@ -14,6 +14,7 @@ use crate::json::{self, JsonMap};
 use crate::model::channel::ChannelType;
 use crate::model::id::{
     ApplicationId,
+    ChannelId,
     CommandId,
     CommandPermissionId,
     CommandVersionId,
@@ -192,6 +193,9 @@ impl Command {
 
     /// Edits a global command by its Id.
     ///
+    /// Only the fields set on the [`CreateApplicationCommand`] are changed,
+    /// all others are left as they are.
+    ///
     /// # Errors
     ///
     /// If there is an error, it will be either [`Error::Http`] or [`Error::Json`].
@@ -433,6 +437,24 @@ enum_number!(CommandPermissionType {
 });
 
 impl CommandPermissionId {
+    /// Returns the permission Id targeting the `@everyone` role of the given
+    /// guild, which shares its Id with the guild.
+    #[must_use]
+    pub fn everyone(guild_id: impl Into<GuildId>) -> Self {
+        Self(guild_id.into().0)
+    }
+
+    /// Returns the permission Id targeting all channels of the given guild,
+    /// which is the guild Id minus one.
+    ///
+    /// Use this with a [`CommandPermissionType::Channel`] permission.
+    #[must_use]
+    pub fn all_channels(guild_id: impl Into<GuildId>) -> Self {
+        // Guild Ids are never 0, so this only wraps for an invalid Id, which
+        // Discord rejects either way.
+        Self(guild_id.into().0.wrapping_sub(1))
+    }
+
     /// Converts this [`CommandPermissionId`] to [`UserId`].
     #[must_use]
     pub fn to_user_id(self) -> UserId {
@@ -444,6 +466,30 @@ impl CommandPermissionId {
     pub fn to_role_id(self) -> RoleId {
         self.0.into()
     }
+
+    /// Converts this [`CommandPermissionId`] to [`ChannelId`].
+    #[must_use]
+    pub fn to_channel_id(self) -> ChannelId {
+        self.0.into()
+    }
+}
+
+impl From<ChannelId> for CommandPermissionId {
+    fn from(id: ChannelId) -> Self {
+        Self(id.0)
+    }
+}
+
+impl<'a> From<&'a ChannelId> for CommandPermissionId {
+    fn from(id: &ChannelId) -> Self {
+        Self(id.0)
+    }
+}
+
+impl From<CommandPermissionId> for ChannelId {
+    fn from(id: CommandPermissionId) -> Self {
+        Self(id.0)
+    }
 }
 
 impl From<RoleId> for CommandPermissionId {
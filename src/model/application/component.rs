@@ -13,6 +13,13 @@ pub enum ComponentType {
     Button = 2,
     SelectMenu = 3,
     InputText = 4,
+    Section = 9,
+    TextDisplay = 10,
+    Thumbnail = 11,
+    MediaGallery = 12,
+    File = 13,
+    Separator = 14,
+    Container = 17,
     Unknown = !0,
 }
 
@@ -20,7 +27,14 @@ enum_number!(ComponentType {
     ActionRow,
     Button,
     SelectMenu,
-    InputText
+    InputText,
+    Section,
+    TextDisplay,
+    Thumbnail,
+    MediaGallery,
+    File,
+    Separator,
+    Container
 });
 
 /// An action row.
@@ -41,6 +55,11 @@ pub enum ActionRowComponent {
     Button(Button),
     SelectMenu(SelectMenu),
     InputText(InputText),
+    /// A component which is not modeled by serenity, such as one of the children of a
+    /// [`ComponentType::Container`] component sent with [`MessageFlags::IS_COMPONENTS_V2`].
+    ///
+    /// [`MessageFlags::IS_COMPONENTS_V2`]: crate::model::channel::MessageFlags::IS_COMPONENTS_V2
+    Unknown(Value),
 }
 
 impl<'de> Deserialize<'de> for ActionRowComponent {
@@ -63,7 +82,7 @@ impl<'de> Deserialize<'de> for ActionRowComponent {
             ComponentType::InputText => from_value::<InputText>(Value::from(map))
                 .map(ActionRowComponent::InputText)
                 .map_err(DeError::custom),
-            _ => Err(DeError::custom("Unknown component type")),
+            _ => Ok(ActionRowComponent::Unknown(Value::from(map))),
         }
     }
 }
@@ -77,6 +96,7 @@ impl Serialize for ActionRowComponent {
             Self::Button(c) => Button::serialize(c, serializer),
             Self::SelectMenu(c) => SelectMenu::serialize(c, serializer),
             Self::InputText(c) => InputText::serialize(c, serializer),
+            Self::Unknown(c) => Value::serialize(c, serializer),
         }
     }
 }
@@ -135,6 +155,23 @@ enum_number!(ButtonStyle {
     Link
 });
 
+/// The spacing of a separator component, sent with [`MessageFlags::IS_COMPONENTS_V2`].
+///
+/// [`MessageFlags::IS_COMPONENTS_V2`]: crate::model::channel::MessageFlags::IS_COMPONENTS_V2
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum SeparatorSpacing {
+    Small = 1,
+    Large = 2,
+    Unknown = !0,
+}
+
+enum_number!(SeparatorSpacing {
+    Small,
+    Large
+});
+
 /// A select menu component.
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct SelectMenu {
@@ -1,4 +1,6 @@
 use std::collections::HashMap;
+#[cfg(feature = "http")]
+use std::future::Future;
 
 use serde::de::Error as DeError;
 use serde::{Deserialize, Deserializer};
@@ -18,6 +20,8 @@ use crate::json::prelude::*;
 use crate::model::application::command::{CommandOptionType, CommandType};
 #[cfg(feature = "http")]
 use crate::model::application::interaction::InteractionResponseType;
+#[cfg(feature = "http")]
+use crate::model::application::interaction::{check_token_age, map_token_error};
 use crate::model::application::interaction::InteractionType;
 use crate::model::channel::{Attachment, Message, PartialChannel};
 use crate::model::guild::{Member, PartialMember, Role};
@@ -35,6 +39,7 @@ use crate::model::id::{
 };
 use crate::model::user::User;
 use crate::model::utils::deserialize_options_with_resolved;
+use crate::model::ModelError;
 use crate::model::Permissions;
 
 /// An interaction when a user invokes a slash command.
@@ -76,6 +81,16 @@ pub struct ApplicationCommandInteraction {
     pub guild_locale: Option<String>,
 }
 
+impl ApplicationCommandInteraction {
+    /// Returns the path of the invoked subcommand and its options.
+    ///
+    /// Refer to [`CommandData::subcommand`] for more information.
+    #[must_use]
+    pub fn subcommand(&self) -> Option<(Vec<&str>, &[CommandDataOption])> {
+        self.data.subcommand()
+    }
+}
+
 #[cfg(feature = "http")]
 impl ApplicationCommandInteraction {
     /// Gets the interaction response.
@@ -119,6 +134,7 @@ impl ApplicationCommandInteraction {
         let map = json::hashmap_to_json_map(interaction_response.0);
 
         Message::check_lengths(&map)?;
+        Message::check_response_components_v2(&map)?;
 
         if interaction_response.1.is_empty() {
             http.as_ref()
@@ -163,6 +179,7 @@ impl ApplicationCommandInteraction {
         let map = json::hashmap_to_json_map(interaction_response.0);
 
         Message::check_lengths(&map)?;
+        Message::check_components_v2(&map, true)?;
 
         http.as_ref().edit_original_interaction_response(&self.token, &Value::from(map)).await
     }
@@ -211,6 +228,7 @@ impl ApplicationCommandInteraction {
         let map = json::hashmap_to_json_map(interaction_response.0);
 
         Message::check_lengths(&map)?;
+        Message::check_components_v2(&map, false)?;
 
         if interaction_response.1.is_empty() {
             http.as_ref().create_followup_message(&self.token, &Value::from(map)).await
@@ -251,6 +269,7 @@ impl ApplicationCommandInteraction {
         let map = json::hashmap_to_json_map(interaction_response.0);
 
         Message::check_lengths(&map)?;
+        Message::check_components_v2(&map, true)?;
 
         let message_id = message_id.into().into();
 
@@ -286,6 +305,9 @@ impl ApplicationCommandInteraction {
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::InteractionTokenExpired`] if the interaction
+    /// token has expired, 15 minutes after the interaction was created.
+    ///
     /// May return [`Error::Http`] if the API returns an error.
     /// Such as if the response was deleted.
     pub async fn get_followup_message<M: Into<MessageId>>(
@@ -293,7 +315,12 @@ impl ApplicationCommandInteraction {
         http: impl AsRef<Http>,
         message_id: M,
     ) -> Result<Message> {
-        http.as_ref().get_followup_message(&self.token, message_id.into().into()).await
+        check_token_age(self.id)?;
+
+        http.as_ref()
+            .get_followup_message(&self.token, message_id.into().into())
+            .await
+            .map_err(map_token_error)
     }
 
     /// Helper function to defer an interaction
@@ -324,6 +351,102 @@ impl ApplicationCommandInteraction {
         })
         .await
     }
+
+    /// Defers the interaction, runs the given closure, and edits the response
+    /// with the [`EditInteractionResponse`] it returns.
+    ///
+    /// Deferring first means that the work done in the closure may take longer
+    /// than the 3 seconds Discord allows for the initial response.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::builder::EditInteractionResponse;
+    /// # use serenity::http::Http;
+    /// # use serenity::model::application::interaction::application_command::*;
+    /// #
+    /// # async fn run(
+    /// #     http: &Http,
+    /// #     interaction: &ApplicationCommandInteraction,
+    /// # ) -> serenity::Result<()> {
+    /// interaction
+    ///     .defer_and_edit(http, || async {
+    ///         let mut response = EditInteractionResponse::default();
+    ///         response.content("Done with the long task!");
+    ///         response
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InteractionTokenExpired`] if the interaction
+    /// token, valid for 15 minutes after the interaction was created, expired
+    /// before the closure finished.
+    ///
+    /// Returns [`Error::Model`] if the edited content is too long.
+    /// May also return [`Error::Http`] if the API returns an error,
+    /// or an [`Error::Json`] if there is an error deserializing the response.
+    pub async fn defer_and_edit<F, Fut>(&self, http: impl AsRef<Http>, f: F) -> Result<Message>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = EditInteractionResponse>,
+    {
+        self._defer_and_edit(http.as_ref(), false, f).await
+    }
+
+    /// Defers the interaction ephemerally, runs the given closure, and edits
+    /// the response with the [`EditInteractionResponse`] it returns.
+    ///
+    /// Refer to [`Self::defer_and_edit`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InteractionTokenExpired`] if the interaction
+    /// token, valid for 15 minutes after the interaction was created, expired
+    /// before the closure finished.
+    ///
+    /// Returns [`Error::Model`] if the edited content is too long.
+    /// May also return [`Error::Http`] if the API returns an error,
+    /// or an [`Error::Json`] if there is an error deserializing the response.
+    pub async fn defer_ephemeral_and_edit<F, Fut>(
+        &self,
+        http: impl AsRef<Http>,
+        f: F,
+    ) -> Result<Message>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = EditInteractionResponse>,
+    {
+        self._defer_and_edit(http.as_ref(), true, f).await
+    }
+
+    async fn _defer_and_edit<F, Fut>(&self, http: &Http, ephemeral: bool, f: F) -> Result<Message>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = EditInteractionResponse>,
+    {
+        if ephemeral {
+            self.defer_ephemeral(http).await?;
+        } else {
+            self.defer(http).await?;
+        }
+
+        let response = f().await;
+
+        check_token_age(self.id)?;
+
+        let map = json::hashmap_to_json_map(response.0);
+
+        Message::check_lengths(&map)?;
+        Message::check_components_v2(&map, true)?;
+
+        http.edit_original_interaction_response(&self.token, &Value::from(map))
+            .await
+            .map_err(map_token_error)
+    }
 }
 
 impl<'de> Deserialize<'de> for ApplicationCommandInteraction {
@@ -504,6 +627,198 @@ impl CommandData {
             _ => None,
         }
     }
+
+    /// Returns the path of the invoked subcommand, with its subcommand group
+    /// if there is one, along with the subcommand's options.
+    ///
+    /// Returns [`None`] if the command was invoked without a subcommand.
+    ///
+    /// # Examples
+    ///
+    /// Dispatching on the invoked subcommand:
+    ///
+    /// ```rust,no_run
+    /// # use serenity::model::application::interaction::application_command::CommandData;
+    /// #
+    /// # fn run(data: &CommandData) {
+    /// match data.subcommand() {
+    ///     Some((path, options)) => match path.as_slice() {
+    ///         ["settings", "show"] => println!("Showing settings"),
+    ///         ["settings", "set"] => println!("Setting {} options", options.len()),
+    ///         _ => println!("Unknown subcommand"),
+    ///     },
+    ///     None => println!("No subcommand given"),
+    /// }
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn subcommand(&self) -> Option<(Vec<&str>, &[CommandDataOption])> {
+        let mut path = Vec::new();
+        let mut options = &self.options[..];
+
+        while let [option] = options {
+            match option.kind {
+                CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup => {
+                    path.push(option.name.as_str());
+                    options = &option.options;
+                },
+                _ => break,
+            }
+        }
+
+        if path.is_empty() {
+            None
+        } else {
+            Some((path, options))
+        }
+    }
+
+    /// The options of the invoked subcommand if there is one, otherwise the
+    /// options of the command itself.
+    fn leaf_options(&self) -> &[CommandDataOption] {
+        let mut options = &self.options[..];
+
+        while let [option] = options {
+            match option.kind {
+                CommandOptionType::SubCommand | CommandOptionType::SubCommandGroup => {
+                    options = &option.options;
+                },
+                _ => break,
+            }
+        }
+
+        options
+    }
+
+    /// Returns the resolved value of the option with the given name.
+    ///
+    /// If a subcommand was invoked, its options are searched instead of the
+    /// top-level ones.
+    #[must_use]
+    pub fn option(&self, name: &str) -> Option<&CommandDataOptionValue> {
+        self.leaf_options()
+            .iter()
+            .find(|option| option.name == name)
+            .and_then(|option| option.resolved.as_ref())
+    }
+
+    /// Returns the resolved value of the required option with the given name.
+    ///
+    /// Refer to [`Self::option`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::MissingCommandOption`] if the option was not
+    /// provided.
+    pub fn required_option(&self, name: &str) -> Result<&CommandDataOptionValue> {
+        self.option(name).ok_or_else(|| Error::Model(ModelError::MissingCommandOption(name.into())))
+    }
+
+    /// Returns the value of the [`String`] option with the given name.
+    ///
+    /// Refer to [`Self::option`] for more information.
+    #[must_use]
+    pub fn get_string(&self, name: &str) -> Option<&str> {
+        match self.option(name)? {
+            CommandDataOptionValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the [`Integer`] option with the given name.
+    ///
+    /// Refer to [`Self::option`] for more information.
+    ///
+    /// [`Integer`]: CommandOptionType::Integer
+    #[must_use]
+    pub fn get_i64(&self, name: &str) -> Option<i64> {
+        match self.option(name)? {
+            CommandDataOptionValue::Integer(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the [`Number`] option with the given name.
+    ///
+    /// Refer to [`Self::option`] for more information.
+    ///
+    /// [`Number`]: CommandOptionType::Number
+    #[must_use]
+    pub fn get_f64(&self, name: &str) -> Option<f64> {
+        match self.option(name)? {
+            CommandDataOptionValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the value of the [`Boolean`] option with the given name.
+    ///
+    /// Refer to [`Self::option`] for more information.
+    ///
+    /// [`Boolean`]: CommandOptionType::Boolean
+    #[must_use]
+    pub fn get_bool(&self, name: &str) -> Option<bool> {
+        match self.option(name)? {
+            CommandDataOptionValue::Boolean(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    /// Returns the user, and their member data if in a guild, of the [`User`]
+    /// or [`Mentionable`] option with the given name.
+    ///
+    /// Refer to [`Self::option`] for more information.
+    ///
+    /// [`User`]: CommandOptionType::User
+    /// [`Mentionable`]: CommandOptionType::Mentionable
+    #[must_use]
+    pub fn get_user(&self, name: &str) -> Option<(&User, Option<&PartialMember>)> {
+        match self.option(name)? {
+            CommandDataOptionValue::User(user, member) => Some((user, member.as_ref())),
+            _ => None,
+        }
+    }
+
+    /// Returns the channel of the [`Channel`] option with the given name.
+    ///
+    /// Refer to [`Self::option`] for more information.
+    ///
+    /// [`Channel`]: CommandOptionType::Channel
+    #[must_use]
+    pub fn get_channel(&self, name: &str) -> Option<&PartialChannel> {
+        match self.option(name)? {
+            CommandDataOptionValue::Channel(channel) => Some(channel),
+            _ => None,
+        }
+    }
+
+    /// Returns the role of the [`Role`] or [`Mentionable`] option with the
+    /// given name.
+    ///
+    /// Refer to [`Self::option`] for more information.
+    ///
+    /// [`Role`]: CommandOptionType::Role
+    /// [`Mentionable`]: CommandOptionType::Mentionable
+    #[must_use]
+    pub fn get_role(&self, name: &str) -> Option<&Role> {
+        match self.option(name)? {
+            CommandDataOptionValue::Role(role) => Some(role),
+            _ => None,
+        }
+    }
+
+    /// Returns the attachment of the [`Attachment`] option with the given name.
+    ///
+    /// Refer to [`Self::option`] for more information.
+    ///
+    /// [`Attachment`]: CommandOptionType::Attachment
+    #[must_use]
+    pub fn get_attachment(&self, name: &str) -> Option<&Attachment> {
+        match self.option(name)? {
+            CommandDataOptionValue::Attachment(attachment) => Some(attachment),
+            _ => None,
+        }
+    }
 }
 
 impl<'de> Deserialize<'de> for CommandData {
@@ -738,3 +1053,62 @@ impl From<TargetId> for UserId {
         Self(id.0)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn typed_option_accessors() {
+        let value = json!({
+            "id": "1",
+            "name": "settings",
+            "type": 1,
+            "options": [{
+                "name": "notifications",
+                "type": 2,
+                "options": [{
+                    "name": "set",
+                    "type": 1,
+                    "options": [
+                        {"name": "label", "type": 3, "value": "daily"},
+                        {"name": "count", "type": 4, "value": 3},
+                        {"name": "enabled", "type": 5, "value": true}
+                    ]
+                }]
+            }]
+        });
+
+        let data: CommandData = from_value(value).unwrap();
+        assert_eq!(data.get_string("label"), Some("daily"));
+        assert_eq!(data.get_i64("count"), Some(3));
+        assert_eq!(data.get_bool("enabled"), Some(true));
+        assert_eq!(data.get_i64("label"), None);
+        assert!(data.get_user("missing").is_none());
+
+        assert!(matches!(data.required_option("count"), Ok(CommandDataOptionValue::Integer(3))));
+        assert!(matches!(
+            data.required_option("missing"),
+            Err(Error::Model(ModelError::MissingCommandOption(name))) if name == "missing"
+        ));
+
+        let (path, options) = data.subcommand().unwrap();
+        assert_eq!(path, ["notifications", "set"]);
+        assert_eq!(options.len(), 3);
+    }
+
+    #[test]
+    fn no_subcommand() {
+        let value = json!({
+            "id": "1",
+            "name": "ping",
+            "type": 1,
+            "options": [{"name": "loud", "type": 5, "value": false}]
+        });
+
+        let data: CommandData = from_value(value).unwrap();
+        assert!(data.subcommand().is_none());
+        assert_eq!(data.get_bool("loud"), Some(false));
+    }
+}
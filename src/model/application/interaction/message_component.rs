@@ -16,6 +16,8 @@ use crate::json::prelude::*;
 use crate::model::application::component::ComponentType;
 #[cfg(feature = "http")]
 use crate::model::application::interaction::InteractionResponseType;
+#[cfg(feature = "http")]
+use crate::model::application::interaction::{check_token_age, map_token_error};
 use crate::model::application::interaction::InteractionType;
 use crate::model::channel::Message;
 use crate::model::guild::Member;
@@ -104,6 +106,7 @@ impl MessageComponentInteraction {
 
         Message::check_content_length(&map)?;
         Message::check_embed_length(&map)?;
+        Message::check_response_components_v2(&map)?;
 
         if interaction_response.1.is_empty() {
             http.as_ref()
@@ -151,6 +154,7 @@ impl MessageComponentInteraction {
 
         Message::check_content_length(&map)?;
         Message::check_embed_length(&map)?;
+        Message::check_components_v2(&map, true)?;
 
         http.as_ref().edit_original_interaction_response(&self.token, &Value::from(map)).await
     }
@@ -193,6 +197,7 @@ impl MessageComponentInteraction {
 
         Message::check_content_length(&map)?;
         Message::check_embed_length(&map)?;
+        Message::check_components_v2(&map, false)?;
 
         http.as_ref().create_followup_message(&self.token, &Value::from(map)).await
     }
@@ -224,6 +229,7 @@ impl MessageComponentInteraction {
 
         Message::check_content_length(&map)?;
         Message::check_embed_length(&map)?;
+        Message::check_components_v2(&map, true)?;
 
         http.as_ref()
             .edit_followup_message(&self.token, message_id.into().into(), &Value::from(map))
@@ -248,6 +254,9 @@ impl MessageComponentInteraction {
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::InteractionTokenExpired`] if the interaction
+    /// token has expired, 15 minutes after the interaction was created.
+    ///
     /// May return [`Error::Http`] if the API returns an error.
     /// Such as if the response was deleted.
     pub async fn get_followup_message<M: Into<MessageId>>(
@@ -255,7 +264,12 @@ impl MessageComponentInteraction {
         http: impl AsRef<Http>,
         message_id: M,
     ) -> Result<Message> {
-        http.as_ref().get_followup_message(&self.token, message_id.into().into()).await
+        check_token_age(self.id)?;
+
+        http.as_ref()
+            .get_followup_message(&self.token, message_id.into().into())
+            .await
+            .map_err(map_token_error)
     }
 
     /// Helper function to defer an interaction
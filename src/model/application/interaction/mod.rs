@@ -12,9 +12,18 @@ use self::autocomplete::AutocompleteInteraction;
 use self::message_component::MessageComponentInteraction;
 use self::modal::ModalSubmitInteraction;
 use self::ping::PingInteraction;
-use crate::json::{from_value, JsonMap, Value};
+#[cfg(feature = "http")]
+use crate::http::{Http, HttpError};
+use crate::internal::prelude::*;
+use crate::json::from_value;
+#[cfg(feature = "http")]
+use crate::model::channel::Message;
+#[cfg(feature = "http")]
+use crate::model::id::MessageId;
 use crate::model::id::{ApplicationId, InteractionId};
 use crate::model::user::User;
+#[cfg(feature = "http")]
+use crate::model::{ModelError, Timestamp};
 use crate::model::Permissions;
 
 /// [Discord docs](https://discord.com/developers/docs/interactions/receiving-and-responding#interaction-object)
@@ -221,8 +230,83 @@ impl Interaction {
     }
 }
 
+#[cfg(feature = "http")]
+impl Interaction {
+    /// Gets the original response to the interaction, such as to read its Id
+    /// or content after responding.
+    ///
+    /// **Note**: Interaction tokens are only valid for 15 minutes after the
+    /// interaction was created.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InteractionTokenExpired`] if the interaction
+    /// token has expired.
+    ///
+    /// Returns an [`Error::Http`] if there is no interaction response.
+    pub async fn get_response(&self, http: impl AsRef<Http>) -> Result<Message> {
+        check_token_age(self.id())?;
+
+        http.as_ref().get_original_interaction_response(self.token()).await.map_err(map_token_error)
+    }
+
+    /// Gets a followup message sent in response to the interaction, such as
+    /// one returned by `create_followup_message`, to read its latest state.
+    ///
+    /// **Note**: Interaction tokens are only valid for 15 minutes after the
+    /// interaction was created.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InteractionTokenExpired`] if the interaction
+    /// token has expired.
+    ///
+    /// Returns an [`Error::Http`] if there is no such followup message.
+    pub async fn get_followup(
+        &self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+    ) -> Result<Message> {
+        check_token_age(self.id())?;
+
+        http.as_ref()
+            .get_followup_message(self.token(), message_id.into().0)
+            .await
+            .map_err(map_token_error)
+    }
+}
+
+/// Checks that an interaction's token has not expired yet, as tokens are only
+/// valid for 15 minutes after the interaction was created.
+#[cfg(feature = "http")]
+pub(crate) fn check_token_age(id: InteractionId) -> Result<()> {
+    const TOKEN_LIFETIME_SECS: i64 = 15 * 60;
+
+    let age = Timestamp::now().unix_timestamp() - id.created_at().unix_timestamp();
+    if age >= TOKEN_LIFETIME_SECS {
+        return Err(Error::Model(ModelError::InteractionTokenExpired));
+    }
+
+    Ok(())
+}
+
+/// Maps the error Discord returns for a request made with an expired
+/// interaction token to [`ModelError::InteractionTokenExpired`].
+#[cfg(feature = "http")]
+pub(crate) fn map_token_error(why: Error) -> Error {
+    // JSON error code 50027: "Invalid Webhook Token"
+    let expired =
+        matches!(&why, Error::Http(http_error) if http_error.json_error_code() == Some(50027));
+
+    if expired {
+        Error::Model(ModelError::InteractionTokenExpired)
+    } else {
+        why
+    }
+}
+
 impl<'de> Deserialize<'de> for Interaction {
-    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> StdResult<Self, D::Error> {
         let map = JsonMap::deserialize(deserializer)?;
 
         let kind = map
@@ -259,7 +343,7 @@ impl<'de> Deserialize<'de> for Interaction {
 }
 
 impl Serialize for Interaction {
-    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> StdResult<S::Ok, S::Error> {
         match self {
             Self::Ping(i) => PingInteraction::serialize(i, serializer),
             Self::ApplicationCommand(i) => ApplicationCommandInteraction::serialize(i, serializer),
@@ -305,6 +389,10 @@ bitflags! {
         /// Interaction message will only be visible to sender and will
         /// be quickly deleted.
         const EPHEMERAL = 1 << 6;
+        /// The message is laid out with components of the Components V2
+        /// system, such as containers and text displays, which replace its
+        /// content and embeds.
+        const IS_COMPONENTS_V2 = 1 << 15;
     }
 }
 
@@ -342,4 +430,7 @@ pub enum InteractionResponseType {
     UpdateMessage = 7,
     Autocomplete = 8,
     Modal = 9,
+    /// Responds to the interaction with an upgrade button, only available for apps with
+    /// monetization enabled.
+    PremiumRequired = 10,
 }
@@ -16,6 +16,8 @@ use crate::json::prelude::*;
 use crate::model::application::component::ActionRow;
 #[cfg(feature = "http")]
 use crate::model::application::interaction::InteractionResponseType;
+#[cfg(feature = "http")]
+use crate::model::application::interaction::{check_token_age, map_token_error};
 use crate::model::application::interaction::InteractionType;
 use crate::model::channel::Message;
 use crate::model::guild::Member;
@@ -107,6 +109,7 @@ impl ModalSubmitInteraction {
 
         Message::check_content_length(&map)?;
         Message::check_embed_length(&map)?;
+        Message::check_response_components_v2(&map)?;
 
         if interaction_response.1.is_empty() {
             http.as_ref()
@@ -154,6 +157,7 @@ impl ModalSubmitInteraction {
 
         Message::check_content_length(&map)?;
         Message::check_embed_length(&map)?;
+        Message::check_components_v2(&map, true)?;
 
         http.as_ref().edit_original_interaction_response(&self.token, &Value::from(map)).await
     }
@@ -196,6 +200,7 @@ impl ModalSubmitInteraction {
 
         Message::check_content_length(&map)?;
         Message::check_embed_length(&map)?;
+        Message::check_components_v2(&map, false)?;
 
         http.as_ref().create_followup_message(&self.token, &Value::from(map)).await
     }
@@ -227,6 +232,7 @@ impl ModalSubmitInteraction {
 
         Message::check_content_length(&map)?;
         Message::check_embed_length(&map)?;
+        Message::check_components_v2(&map, true)?;
 
         http.as_ref()
             .edit_followup_message(&self.token, message_id.into().into(), &Value::from(map))
@@ -246,6 +252,29 @@ impl ModalSubmitInteraction {
     ) -> Result<()> {
         http.as_ref().delete_followup_message(&self.token, message_id.into().into()).await
     }
+
+    /// Gets a followup message.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InteractionTokenExpired`] if the interaction
+    /// token has expired, 15 minutes after the interaction was created.
+    ///
+    /// May return [`Error::Http`] if the API returns an error.
+    /// Such as if the response was deleted.
+    pub async fn get_followup_message<M: Into<MessageId>>(
+        &self,
+        http: impl AsRef<Http>,
+        message_id: M,
+    ) -> Result<Message> {
+        check_token_age(self.id)?;
+
+        http.as_ref()
+            .get_followup_message(&self.token, message_id.into().into())
+            .await
+            .map_err(map_token_error)
+    }
+
     /// Helper function to defer an interaction
     ///
     /// # Errors
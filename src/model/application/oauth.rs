@@ -89,6 +89,9 @@ pub enum Scope {
     /// Allows your app to connect to voice on user's behalf and see all the voice members - requires Discord approval.
     #[serde(rename = "voice")]
     Voice,
+    /// Allows your app to update a user's connection and metadata for the app.
+    #[serde(rename = "role_connections.write")]
+    RoleConnectionsWrite,
 }
 
 impl fmt::Display for Scope {
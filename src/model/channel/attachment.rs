@@ -41,6 +41,30 @@ pub struct Attachment {
     /// the message itself exists.
     #[serde(default, skip_serializing_if = "is_false")]
     pub ephemeral: bool,
+    /// The title of the file.
+    pub title: Option<String>,
+    /// The duration of the audio file, in seconds.
+    ///
+    /// Only present for voice messages.
+    pub duration_secs: Option<f64>,
+    /// A base64 encoded bytearray representing a sampled waveform.
+    ///
+    /// Only present for voice messages.
+    pub waveform: Option<String>,
+    /// The flags of the attachment.
+    #[serde(default)]
+    pub flags: AttachmentFlags,
+}
+
+bitflags! {
+    /// Describes extra features of an [`Attachment`].
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/resources/channel#attachment-object-attachment-flags).
+    #[derive(Default)]
+    pub struct AttachmentFlags: u64 {
+        /// This attachment has been edited using the remix feature on mobile.
+        const IS_REMIX = 1 << 2;
+    }
 }
 
 #[cfg(feature = "model")]
@@ -52,6 +76,14 @@ impl Attachment {
         self.width.and_then(|width| self.height.map(|height| (width, height)))
     }
 
+    /// Whether this attachment is the audio of a voice message.
+    ///
+    /// Voice message attachments carry both a duration and a waveform.
+    #[must_use]
+    pub fn is_voice_message(&self) -> bool {
+        self.duration_secs.is_some() && self.waveform.is_some()
+    }
+
     /// Downloads the attachment, returning back a vector of bytes.
     ///
     /// # Examples
@@ -137,3 +169,32 @@ impl Attachment {
         Ok(bytes.to_vec())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn voice_message_attachment_deserialize() {
+        let value = json!({
+            "id": "1118179717653950464",
+            "filename": "voice-message.ogg",
+            "size": 8367,
+            "url": "https://cdn.discordapp.com/attachments/1/2/voice-message.ogg",
+            "proxy_url": "https://media.discordapp.net/attachments/1/2/voice-message.ogg",
+            "content_type": "audio/ogg",
+            "duration_secs": 3.48,
+            "waveform": "AAAAAAAAAAAA",
+            "flags": 4
+        });
+
+        let attachment: Attachment = from_value(value).unwrap();
+        assert_eq!(attachment.content_type.as_deref(), Some("audio/ogg"));
+        assert_eq!(attachment.duration_secs, Some(3.48));
+        assert_eq!(attachment.flags, AttachmentFlags::IS_REMIX);
+        assert!(attachment.title.is_none());
+        #[cfg(feature = "model")]
+        assert!(attachment.is_voice_message());
+    }
+}
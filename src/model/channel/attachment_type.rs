@@ -3,14 +3,20 @@ use std::borrow::Cow;
 use std::fs::File;
 use std::path::{Path, PathBuf};
 
+#[cfg(feature = "http")]
+use reqwest::header::CONTENT_TYPE;
 #[cfg(feature = "http")]
 use reqwest::Client;
 #[cfg(feature = "http")]
 use tokio::{fs::File, io::AsyncReadExt};
 use url::Url;
 
+#[cfg(feature = "http")]
+use crate::constants;
 #[cfg(feature = "http")]
 use crate::error::{Error, Result};
+#[cfg(feature = "http")]
+use crate::http::{Http, HttpError};
 
 /// Enum that allows a user to pass a [`Path`] or a [`File`] type to [`send_files`]
 ///
@@ -28,6 +34,119 @@ pub enum AttachmentType<'a> {
     Image(Url),
 }
 
+#[cfg(feature = "http")]
+impl AttachmentType<'static> {
+    /// Downloads a remote file into an attachment, using the HTTP client's
+    /// transport.
+    ///
+    /// The filename is inferred from the URL's path, with an extension
+    /// matching the response's content type added if it lacks one.
+    ///
+    /// This is limited to [`constants::ATTACHMENT_MAX_SIZE`], the upload limit
+    /// of guilds without boosts. Refer to [`Self::from_url_with_limit`] to use
+    /// another limit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::id::ChannelId;
+    /// use serenity::model::channel::AttachmentType;
+    ///
+    /// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+    /// # let http = Http::new("token");
+    /// let attachment = AttachmentType::from_url(&http, "https://example.com/ferris.png").await?;
+    /// ChannelId(7).send_files(&http, vec![attachment], |m| m.content("Mirrored")).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Url`] if the URL is invalid, or an [`Error::Http`] if
+    /// the request fails, the response has a non-successful status code, the
+    /// content type of a web page, or a body larger than the limit.
+    pub async fn from_url(http: impl AsRef<Http>, url: &str) -> Result<Self> {
+        Self::from_url_with_limit(http, url, constants::ATTACHMENT_MAX_SIZE).await
+    }
+
+    /// Downloads a remote file into an attachment, limited to the given size
+    /// in bytes, such as the higher upload limit of a boosted guild.
+    ///
+    /// Refer to [`Self::from_url`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Url`] if the URL is invalid, or an [`Error::Http`] if
+    /// the request fails, the response has a non-successful status code, the
+    /// content type of a web page, or a body larger than the limit.
+    pub async fn from_url_with_limit(
+        http: impl AsRef<Http>,
+        url: &str,
+        limit: u64,
+    ) -> Result<Self> {
+        let url = Url::parse(url).map_err(|_| Error::Url(url.to_string()))?;
+        let mut response = http.as_ref().client.get(url.clone()).send().await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(HttpError::AttachmentRequestFailed(status).into());
+        }
+
+        let content_type = response
+            .headers()
+            .get(CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse::<mime::Mime>().ok());
+
+        if let Some(content_type) = &content_type {
+            if content_type.essence_str() == mime::TEXT_HTML.essence_str() {
+                let content_type = content_type.essence_str().to_string();
+                return Err(HttpError::InvalidAttachmentContentType(content_type).into());
+            }
+        }
+
+        if response.content_length().map_or(false, |length| length > limit) {
+            return Err(HttpError::AttachmentTooLarge(limit).into());
+        }
+
+        let mut data = Vec::new();
+        while let Some(chunk) = response.chunk().await? {
+            if (data.len() + chunk.len()) as u64 > limit {
+                return Err(HttpError::AttachmentTooLarge(limit).into());
+            }
+
+            data.extend_from_slice(&chunk);
+        }
+
+        let mut filename = url
+            .path_segments()
+            .and_then(Iterator::last)
+            .filter(|segment| !segment.is_empty())
+            .unwrap_or("attachment")
+            .to_string();
+
+        if Path::new(&filename).extension().is_none() {
+            if let Some(content_type) = content_type {
+                // Prefer the subtype, e.g. `jpeg` over `jfif` for `image/jpeg`
+                let subtype = content_type.subtype().as_str();
+                let extension = mime_guess::get_mime_extensions(&content_type).and_then(|exts| {
+                    exts.iter().find(|&&ext| ext == subtype).or_else(|| exts.first())
+                });
+
+                if let Some(extension) = extension {
+                    filename = format!("{}.{}", filename, extension);
+                }
+            }
+        }
+
+        Ok(AttachmentType::Bytes {
+            data: Cow::Owned(data),
+            filename,
+        })
+    }
+}
+
 #[cfg(feature = "http")]
 impl<'a> AttachmentType<'a> {
     pub(crate) async fn data(&self, client: &Client) -> Result<Vec<u8>> {
@@ -1,4 +1,6 @@
 #[cfg(feature = "model")]
+use std::collections::HashMap;
+#[cfg(feature = "model")]
 use std::fmt::Write as _;
 #[cfg(feature = "model")]
 use std::sync::Arc;
@@ -30,7 +32,7 @@ use crate::collector::{
     ReactionCollectorBuilder,
 };
 #[cfg(feature = "model")]
-use crate::http::{CacheHttp, Http, Typing};
+use crate::http::{ArchivedThreadKind, CacheHttp, Http, HttpError, Typing};
 #[cfg(feature = "model")]
 use crate::json::{self, json};
 #[cfg(feature = "model")]
@@ -113,6 +115,35 @@ impl ChannelId {
         self,
         http: impl AsRef<Http>,
         target: &PermissionOverwrite,
+    ) -> Result<()> {
+        self._create_permission(http.as_ref(), target, None).await
+    }
+
+    /// Creates a [permission overwrite][`PermissionOverwrite`] for either a
+    /// single [`Member`] or [`Role`] within the channel, with a reason for the
+    /// audit log.
+    ///
+    /// Refer to [`Self::create_permission`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission,
+    /// or if an invalid value is set.
+    #[inline]
+    pub async fn create_permission_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        target: &PermissionOverwrite,
+        reason: &str,
+    ) -> Result<()> {
+        self._create_permission(http.as_ref(), target, Some(reason)).await
+    }
+
+    pub(crate) async fn _create_permission(
+        self,
+        http: &Http,
+        target: &PermissionOverwrite,
+        reason: Option<&str>,
     ) -> Result<()> {
         let (id, kind) = match target.kind {
             PermissionOverwriteType::Member(id) => (id.0, "member"),
@@ -126,7 +157,7 @@ impl ChannelId {
             "type": kind,
         });
 
-        http.as_ref().create_permission(self.0, id, &map).await
+        http.create_permission_with_reason(self.0, id, &map, reason).await
     }
 
     /// React to a [`Message`] with a custom [`Emoji`] or unicode character.
@@ -188,6 +219,24 @@ impl ChannelId {
         http.as_ref().delete_message(self.0, message_id.into().0).await
     }
 
+    /// Deletes a [`Message`] given its Id, with a reason for the audit log.
+    ///
+    /// Refer to [`Self::delete_message`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission to
+    /// delete the message.
+    #[inline]
+    pub async fn delete_message_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        reason: &str,
+    ) -> Result<()> {
+        http.as_ref().delete_message_with_reason(self.0, message_id.into().0, Some(reason)).await
+    }
+
     /// Deletes all messages by Ids from the given vector in the given channel.
     ///
     /// The minimum amount of messages is 2 and the maximum amount is 100.
@@ -243,12 +292,39 @@ impl ChannelId {
         http: impl AsRef<Http>,
         permission_type: PermissionOverwriteType,
     ) -> Result<()> {
-        http.as_ref()
-            .delete_permission(self.0, match permission_type {
-                PermissionOverwriteType::Member(id) => id.0,
-                PermissionOverwriteType::Role(id) => id.0,
-            })
-            .await
+        self._delete_permission(http.as_ref(), permission_type, None).await
+    }
+
+    /// Deletes all permission overrides in the channel from a member or role,
+    /// with a reason for the audit log.
+    ///
+    /// Refer to [`Self::delete_permission`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    #[inline]
+    pub async fn delete_permission_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        permission_type: PermissionOverwriteType,
+        reason: &str,
+    ) -> Result<()> {
+        self._delete_permission(http.as_ref(), permission_type, Some(reason)).await
+    }
+
+    pub(crate) async fn _delete_permission(
+        self,
+        http: &Http,
+        permission_type: PermissionOverwriteType,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let target_id = match permission_type {
+            PermissionOverwriteType::Member(id) => id.0,
+            PermissionOverwriteType::Role(id) => id.0,
+        };
+
+        http.delete_permission_with_reason(self.0, target_id, reason).await
     }
 
     /// Deletes the given [`Reaction`] from the channel.
@@ -357,6 +433,9 @@ impl ChannelId {
     /// is over the [`the limit`], containing the number of unicode code points
     /// over the limit.
     ///
+    /// Returns a [`ModelError::InvalidEmbed`] or [`ModelError::EmbedTooLarge`] if an embed
+    /// exceeds one of its limits, unless skipped via [`EditMessage::skip_embed_validation`].
+    ///
     /// [`the limit`]: crate::builder::EditMessage::content
     #[inline]
     pub async fn edit_message<'a, F>(
@@ -377,7 +456,9 @@ impl ChannelId {
             }
         }
 
-        let map = json::hashmap_to_json_map(msg.0);
+        let mut map = json::hashmap_to_json_map(msg.0);
+        Message::check_embeds(&mut map)?;
+        Message::check_components_v2(&map, true)?;
 
         http.as_ref()
             .edit_message_and_attachments(self.0, message_id.into().0, &Value::from(map), msg.1)
@@ -402,6 +483,34 @@ impl ChannelId {
         http.as_ref().follow_news_channel(self.0, target_channel_id.into().0).await
     }
 
+    /// Follows the announcement channel, creating a webhook in the target
+    /// channel that receives the messages crossposted in this channel.
+    ///
+    /// Unlike [`Self::follow`], the channel is first retrieved, from the
+    /// cache if possible, to check that it is an announcement channel.
+    ///
+    /// Requires [Manage Webhook] permissions on the target channel.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidChannelType`] if this channel is not an
+    /// announcement channel.
+    ///
+    /// Returns [`Error::Http`] if the channel could not be retrieved, or the
+    /// current user lacks permission.
+    ///
+    /// [Manage Webhook]: Permissions::MANAGE_WEBHOOKS
+    pub async fn follow_announcement_channel(
+        self,
+        cache_http: impl CacheHttp,
+        target_channel_id: impl Into<ChannelId>,
+    ) -> Result<FollowedChannel> {
+        let channel = self.to_channel(&cache_http).await?;
+        check_announcement_channel(&channel)?;
+
+        cache_http.http().follow_announcement_channel(self.0, target_channel_id.into().0).await
+    }
+
     /// Attempts to find a [`Channel`] by its Id in the cache.
     #[cfg(feature = "cache")]
     #[inline]
@@ -478,6 +587,55 @@ impl ChannelId {
         })
     }
 
+    /// Gets several messages from the channel by their Ids, keyed by Id.
+    ///
+    /// Messages found in the cache are used as is, and only the others are
+    /// fetched, one request at a time. Messages that were deleted are omitted
+    /// from the result.
+    ///
+    /// Requires the [Read Message History] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if a
+    /// request otherwise fails.
+    ///
+    /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+    pub async fn messages_by_ids(
+        self,
+        cache_http: impl CacheHttp,
+        message_ids: &[MessageId],
+    ) -> Result<HashMap<MessageId, Message>> {
+        let mut messages = HashMap::with_capacity(message_ids.len());
+
+        for &message_id in message_ids {
+            if messages.contains_key(&message_id) {
+                continue;
+            }
+
+            #[cfg(feature = "cache")]
+            {
+                if let Some(cache) = cache_http.cache() {
+                    if let Some(message) = cache.message(self, message_id) {
+                        messages.insert(message_id, message);
+                        continue;
+                    }
+                }
+            }
+
+            match self.message(cache_http.http(), message_id).await {
+                Ok(message) => {
+                    messages.insert(message_id, message);
+                },
+                // JSON error code 10008: "Unknown message"
+                Err(Error::Http(http_error)) if http_error.json_error_code() == Some(10008) => {},
+                Err(why) => return Err(why),
+            }
+        }
+
+        Ok(messages)
+    }
+
     /// Gets messages from the channel.
     ///
     /// Refer to [`GetMessages`] for more information on how to use `builder`.
@@ -490,6 +648,10 @@ impl ChannelId {
     /// Returns [`Error::Http`] if the current user does not have
     /// permission to view the channel.
     ///
+    /// Returns a [`ModelError::MultipleMessageAnchors`] if more than one
+    /// message to retrieve the messages relative to is set, or a
+    /// [`ModelError::MessageFetchLimit`] if the limit is not between 1 and 100.
+    ///
     /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
     pub async fn messages<F>(self, http: impl AsRef<Http>, builder: F) -> Result<Vec<Message>>
     where
@@ -497,16 +659,7 @@ impl ChannelId {
     {
         let mut get_messages = GetMessages::default();
         builder(&mut get_messages);
-        let mut map = get_messages.0;
-        let mut query = format!("?limit={}", map.remove(&"limit").unwrap_or(50));
-
-        if let Some(after) = map.remove(&"after") {
-            write!(query, "&after={}", after)?;
-        } else if let Some(around) = map.remove(&"around") {
-            write!(query, "&around={}", around)?;
-        } else if let Some(before) = map.remove(&"before") {
-            write!(query, "&before={}", before)?;
-        }
+        let query = get_messages.build_query()?;
 
         http.as_ref().get_messages(self.0, &query).await.map(|msgs| {
             msgs.into_iter()
@@ -571,13 +724,51 @@ impl ChannelId {
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Http`] if the current user lacks permission,
-    /// or if the channel has too many pinned messages.
+    /// Returns a [`ModelError::TooManyPins`] if the channel already has 50
+    /// pinned messages.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
     ///
     /// [Manage Messages]: Permissions::MANAGE_MESSAGES
     #[inline]
     pub async fn pin(self, http: impl AsRef<Http>, message_id: impl Into<MessageId>) -> Result<()> {
-        http.as_ref().pin_message(self.0, message_id.into().0, None).await
+        self._pin(http.as_ref(), message_id.into(), None).await
+    }
+
+    /// Pins a [`Message`] to the channel, with a reason for the audit log.
+    ///
+    /// Refer to [`Self::pin`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::TooManyPins`] if the channel already has 50
+    /// pinned messages.
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    #[inline]
+    pub async fn pin_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        reason: &str,
+    ) -> Result<()> {
+        self._pin(http.as_ref(), message_id.into(), Some(reason)).await
+    }
+
+    async fn _pin(self, http: &Http, message_id: MessageId, reason: Option<&str>) -> Result<()> {
+        http.pin_message(self.0, message_id.0, reason).await.map_err(|why| {
+            // JSON error code 30003: "Maximum number of pins reached (50)"
+            let limit_reached = matches!(
+                &why,
+                Error::Http(http_error) if http_error.json_error_code() == Some(30003)
+            );
+
+            if limit_reached {
+                Error::Model(ModelError::TooManyPins)
+            } else {
+                why
+            }
+        })
     }
 
     /// Crossposts a [`Message`].
@@ -599,7 +790,8 @@ impl ChannelId {
         http.as_ref().crosspost_message(self.0, message_id.into().0).await
     }
 
-    /// Gets the list of [`Message`]s which are pinned to the channel.
+    /// Gets the list of [`Message`]s which are pinned to the channel, ordered
+    /// from the most recently pinned to the oldest.
     ///
     /// **Note**: Returns an empty [`Vec`] if the current user does not
     /// have the [Read Message History] permission.
@@ -658,6 +850,38 @@ impl ChannelId {
             .await
     }
 
+    /// Plays a [`SoundboardSound`] in this voice channel.
+    ///
+    /// To play a sound from another guild, its `source_guild_id` must be given.
+    /// This is [`None`] for sounds of this channel's guild and default sounds.
+    ///
+    /// **Note**: Requires the [Speak] and [Use Soundboard] permissions, as well
+    /// as [Use External Sounds] for sounds of other guilds. The current user
+    /// must be connected to the channel without being muted or deafened.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or is not
+    /// connected to the channel.
+    ///
+    /// [Speak]: Permissions::SPEAK
+    /// [Use Soundboard]: Permissions::USE_SOUNDBOARD
+    /// [Use External Sounds]: Permissions::USE_EXTERNAL_SOUNDS
+    pub async fn send_soundboard_sound(
+        self,
+        http: impl AsRef<Http>,
+        sound_id: impl Into<SoundId>,
+        source_guild_id: Option<GuildId>,
+    ) -> Result<()> {
+        let mut map = json::JsonMap::new();
+        map.insert("sound_id".to_string(), Value::from(sound_id.into().0.to_string()));
+        if let Some(guild_id) = source_guild_id {
+            map.insert("source_guild_id".to_string(), Value::from(guild_id.0.to_string()));
+        }
+
+        http.as_ref().send_soundboard_sound(self.0, &map).await
+    }
+
     /// Sends a message with just the given message content in the channel.
     ///
     /// # Errors
@@ -758,9 +982,14 @@ impl ChannelId {
         let mut create_message = CreateMessage::default();
         let msg = f(&mut create_message);
 
-        let map = json::hashmap_to_json_map(msg.0.clone());
+        let mut map = json::hashmap_to_json_map(msg.0.clone());
+        let files = files.into_iter().collect::<Vec<_>>();
 
-        Message::check_lengths(&map)?;
+        Message::check_content_length(&map)?;
+        Message::check_embeds(&mut map)?;
+        Message::check_sticker_ids_length(&map)?;
+        Message::check_forward(&map, !files.is_empty() || !msg.2.is_empty())?;
+        Message::check_components_v2(&map, false)?;
 
         http.as_ref().send_files(self.0, files, &map).await
     }
@@ -780,6 +1009,9 @@ impl ChannelId {
     /// is over the above limit, containing the number of unicode code points
     /// over the limit.
     ///
+    /// Returns a [`ModelError::InvalidEmbed`] or [`ModelError::EmbedTooLarge`] if an embed
+    /// exceeds one of its limits, unless skipped via [`CreateMessage::skip_embed_validation`].
+    ///
     /// Returns [`Error::Http`] if the current user lacks permission to
     /// send a message in this channel.
     ///
@@ -794,9 +1026,13 @@ impl ChannelId {
     }
 
     async fn _send_message<'a>(self, http: &Http, msg: CreateMessage<'a>) -> Result<Message> {
-        let map = json::hashmap_to_json_map(msg.0);
+        let mut map = json::hashmap_to_json_map(msg.0);
 
-        Message::check_lengths(&map)?;
+        Message::check_content_length(&map)?;
+        Message::check_embeds(&mut map)?;
+        Message::check_sticker_ids_length(&map)?;
+        Message::check_forward(&map, !msg.2.is_empty())?;
+        Message::check_components_v2(&map, false)?;
 
         let message = if msg.2.is_empty() {
             http.as_ref().send_message(self.0, &Value::from(map)).await?
@@ -874,6 +1110,24 @@ impl ChannelId {
         http.as_ref().unpin_message(self.0, message_id.into().0, None).await
     }
 
+    /// Unpins a [`Message`] in the channel given by its Id, with a reason for
+    /// the audit log.
+    ///
+    /// Refer to [`Self::unpin`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    #[inline]
+    pub async fn unpin_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        message_id: impl Into<MessageId>,
+        reason: &str,
+    ) -> Result<()> {
+        http.as_ref().unpin_message(self.0, message_id.into().0, Some(reason)).await
+    }
+
     /// Retrieves the channel's webhooks.
     ///
     /// **Note**: Requires the [Manage Webhooks] permission.
@@ -971,6 +1225,9 @@ impl ChannelId {
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::StageInstanceTopicLength`] if the topic is not
+    /// 1-120 characters long.
+    ///
     /// Returns [`Error::Http`] if the channel is not a stage channel,
     /// or if there is already a stage instance currently.
     pub async fn create_stage_instance<F>(
@@ -982,9 +1239,11 @@ impl ChannelId {
         F: FnOnce(&mut CreateStageInstance) -> &mut CreateStageInstance,
     {
         let mut instance = CreateStageInstance::default();
+        instance.channel_id(self.0);
         f(&mut instance);
 
         let map = json::hashmap_to_json_map(instance.0);
+        StageInstance::check_topic_length(&map, true)?;
 
         http.as_ref().create_stage_instance(&Value::from(map)).await
     }
@@ -993,6 +1252,9 @@ impl ChannelId {
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::StageInstanceTopicLength`] if a topic is set
+    /// that is not 1-120 characters long.
+    ///
     /// Returns [`Error::Http`] if the channel is not a stage channel,
     /// or if there is not stage instance currently.
     pub async fn edit_stage_instance<F>(
@@ -1007,6 +1269,7 @@ impl ChannelId {
         f(&mut instance);
 
         let map = json::hashmap_to_json_map(instance.0);
+        StageInstance::check_topic_length(&map, false)?;
 
         http.as_ref().edit_stage_instance(self.0, &Value::from(map)).await
     }
@@ -1139,12 +1402,14 @@ impl ChannelId {
     ///
     /// It may return an [`Error::Http`] if the bot doesn't have the
     /// permission to get it.
+    #[deprecated(note = "use `ChannelId::archived_threads_iter`")]
     pub async fn get_archived_private_threads(
         &self,
         http: impl AsRef<Http>,
         before: Option<u64>,
         limit: Option<u64>,
     ) -> Result<ThreadsData> {
+        #[allow(deprecated)]
         http.as_ref().get_channel_archived_private_threads(self.0, before, limit).await
     }
 
@@ -1154,12 +1419,14 @@ impl ChannelId {
     ///
     /// It may return an [`Error::Http`] if the bot doesn't have the
     /// permission to get it.
+    #[deprecated(note = "use `ChannelId::archived_threads_iter`")]
     pub async fn get_archived_public_threads(
         &self,
         http: impl AsRef<Http>,
         before: Option<u64>,
         limit: Option<u64>,
     ) -> Result<ThreadsData> {
+        #[allow(deprecated)]
         http.as_ref().get_channel_archived_public_threads(self.0, before, limit).await
     }
 
@@ -1177,6 +1444,32 @@ impl ChannelId {
     ) -> Result<ThreadsData> {
         http.as_ref().get_channel_joined_archived_private_threads(self.0, before, limit).await
     }
+
+    /// Streams over all of the archived threads of the given kind in the
+    /// channel, from newest to oldest.
+    ///
+    /// Refer to [`Http::get_archived_threads_iter`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Each item is an [`Error::Http`] if the current user lacks permission,
+    /// after which the stream ends.
+    pub fn archived_threads_iter<H: AsRef<Http>>(
+        self,
+        http: &H,
+        kind: ArchivedThreadKind,
+    ) -> impl Stream<Item = Result<GuildChannel>> + '_ {
+        http.as_ref().get_archived_threads_iter(self.0, kind)
+    }
+}
+
+/// Checks that a channel to be followed is an announcement channel.
+#[cfg(feature = "model")]
+fn check_announcement_channel(channel: &Channel) -> Result<()> {
+    match channel {
+        Channel::Guild(channel) if channel.kind == ChannelType::News => Ok(()),
+        _ => Err(Error::Model(ModelError::InvalidChannelType)),
+    }
 }
 
 #[cfg(feature = "model")]
@@ -1335,3 +1628,34 @@ impl<H: AsRef<Http>> MessagesIter<H> {
         })
     }
 }
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use super::check_announcement_channel;
+    use crate::json::{from_value, json};
+    use crate::model::channel::Channel;
+    use crate::model::error::Error as ModelError;
+    use crate::Error;
+
+    #[test]
+    fn announcement_channel() {
+        let channel = |kind: u8| {
+            Channel::Guild(
+                from_value(json!({
+                    "id": "1",
+                    "guild_id": "2",
+                    "type": kind,
+                    "name": "channel",
+                    "position": 0,
+                }))
+                .unwrap(),
+            )
+        };
+
+        assert!(check_announcement_channel(&channel(5)).is_ok());
+        assert!(matches!(
+            check_announcement_channel(&channel(0)),
+            Err(Error::Model(ModelError::InvalidChannelType))
+        ));
+    }
+}
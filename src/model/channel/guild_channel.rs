@@ -1,3 +1,5 @@
+#[cfg(feature = "model")]
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::fmt;
 #[cfg(feature = "model")]
@@ -10,6 +12,7 @@ use futures::stream::StreamExt;
 use crate::builder::EditChannel;
 #[cfg(feature = "model")]
 use crate::builder::{
+    CreateChannel,
     CreateInvite,
     CreateMessage,
     CreateStageInstance,
@@ -346,6 +349,91 @@ impl GuildChannel {
         self.id.create_permission(&http, target).await
     }
 
+    /// Creates a new channel in the same guild with the given name, copying this
+    /// channel's settings: its type, permission overwrites, topic, NSFW status,
+    /// slowmode, category, position, bitrate and user limit.
+    ///
+    /// Messages and threads are not copied.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidChannelType`] if this channel is a thread.
+    ///
+    /// Otherwise returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Channels]: Permissions::MANAGE_CHANNELS
+    #[inline]
+    pub async fn clone_channel(
+        &self,
+        http: impl AsRef<Http>,
+        name: impl ToString,
+    ) -> Result<GuildChannel> {
+        self._clone_channel(http.as_ref(), name.to_string(), None).await
+    }
+
+    /// Creates a new channel with the given name and this channel's settings,
+    /// with a reason for the audit log.
+    ///
+    /// Refer to [`Self::clone_channel`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidChannelType`] if this channel is a thread.
+    ///
+    /// Otherwise returns [`Error::Http`] if the current user lacks permission.
+    #[inline]
+    pub async fn clone_channel_with_reason(
+        &self,
+        http: impl AsRef<Http>,
+        name: impl ToString,
+        reason: &str,
+    ) -> Result<GuildChannel> {
+        self._clone_channel(http.as_ref(), name.to_string(), Some(reason)).await
+    }
+
+    async fn _clone_channel(
+        &self,
+        http: &Http,
+        name: String,
+        reason: Option<&str>,
+    ) -> Result<GuildChannel> {
+        if self.thread_metadata.is_some() || self.kind.is_thread() {
+            return Err(Error::Model(ModelError::InvalidChannelType));
+        }
+
+        let mut builder = CreateChannel::default();
+        builder
+            .name(name)
+            .kind(self.kind)
+            .nsfw(self.nsfw)
+            .permissions(self.permission_overwrites.iter().cloned());
+
+        if let Some(parent_id) = self.parent_id {
+            builder.category(parent_id);
+        }
+        if let Some(topic) = &self.topic {
+            builder.topic(topic);
+        }
+        if let Some(rate_limit) = self.rate_limit_per_user {
+            builder.rate_limit_per_user(rate_limit);
+        }
+        if let Ok(position) = u32::try_from(self.position) {
+            builder.position(position);
+        }
+        if let Some(bitrate) = self.bitrate {
+            builder.bitrate(bitrate as u32);
+        }
+        if let Some(user_limit) = self.user_limit {
+            builder.user_limit(user_limit as u32);
+        }
+
+        let map = json::hashmap_to_json_map(builder.0);
+
+        http.create_channel(self.guild_id.0, &map, reason).await
+    }
+
     /// Deletes this channel, returning the channel on a successful deletion.
     ///
     /// **Note**: Requires the [Manage Channels] permission.
@@ -421,6 +509,263 @@ impl GuildChannel {
         self.id.delete_permission(&http, permission_type).await
     }
 
+    /// Allows the given permissions for a [`Member`] in the channel, updating
+    /// their existing [permission overwrite][`PermissionOverwrite`], if any.
+    ///
+    /// Permissions the overwrite already allows or denies are kept, except for
+    /// those being allowed, which are no longer denied. The channel's
+    /// [`Self::permission_overwrites`] are updated accordingly.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Examples
+    ///
+    /// ```rust,no_run
+    /// # use serenity::http::Http;
+    /// # use serenity::model::channel::GuildChannel;
+    /// # async fn run(http: &Http, mut channel: GuildChannel) -> serenity::Result<()> {
+    /// use serenity::model::id::UserId;
+    /// use serenity::model::Permissions;
+    ///
+    /// let permissions = Permissions::VIEW_CHANNEL | Permissions::SEND_MESSAGES;
+    /// channel.allow_user(http, UserId(7), permissions).await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Channels]: Permissions::MANAGE_CHANNELS
+    pub async fn allow_user(
+        &mut self,
+        http: impl AsRef<Http>,
+        user_id: impl Into<UserId>,
+        permissions: Permissions,
+    ) -> Result<()> {
+        let kind = PermissionOverwriteType::Member(user_id.into());
+
+        self.update_overwrite(http.as_ref(), kind, permissions, Permissions::empty(), None).await
+    }
+
+    /// Allows the given permissions for a [`Member`] in the channel, with a
+    /// reason for the audit log.
+    ///
+    /// Refer to [`Self::allow_user`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    #[inline]
+    pub async fn allow_user_with_reason(
+        &mut self,
+        http: impl AsRef<Http>,
+        user_id: impl Into<UserId>,
+        permissions: Permissions,
+        reason: &str,
+    ) -> Result<()> {
+        let kind = PermissionOverwriteType::Member(user_id.into());
+
+        self.update_overwrite(http.as_ref(), kind, permissions, Permissions::empty(), Some(reason))
+            .await
+    }
+
+    /// Denies the given permissions for a [`Member`] in the channel, updating
+    /// their existing [permission overwrite][`PermissionOverwrite`], if any.
+    ///
+    /// Refer to [`Self::allow_user`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    pub async fn deny_user(
+        &mut self,
+        http: impl AsRef<Http>,
+        user_id: impl Into<UserId>,
+        permissions: Permissions,
+    ) -> Result<()> {
+        let kind = PermissionOverwriteType::Member(user_id.into());
+
+        self.update_overwrite(http.as_ref(), kind, Permissions::empty(), permissions, None).await
+    }
+
+    /// Denies the given permissions for a [`Member`] in the channel, with a
+    /// reason for the audit log.
+    ///
+    /// Refer to [`Self::deny_user`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    #[inline]
+    pub async fn deny_user_with_reason(
+        &mut self,
+        http: impl AsRef<Http>,
+        user_id: impl Into<UserId>,
+        permissions: Permissions,
+        reason: &str,
+    ) -> Result<()> {
+        let kind = PermissionOverwriteType::Member(user_id.into());
+
+        self.update_overwrite(http.as_ref(), kind, Permissions::empty(), permissions, Some(reason))
+            .await
+    }
+
+    /// Allows the given permissions for a [`Role`] in the channel, updating its
+    /// existing [permission overwrite][`PermissionOverwrite`], if any.
+    ///
+    /// Refer to [`Self::allow_user`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    pub async fn allow_role(
+        &mut self,
+        http: impl AsRef<Http>,
+        role_id: impl Into<RoleId>,
+        permissions: Permissions,
+    ) -> Result<()> {
+        let kind = PermissionOverwriteType::Role(role_id.into());
+
+        self.update_overwrite(http.as_ref(), kind, permissions, Permissions::empty(), None).await
+    }
+
+    /// Allows the given permissions for a [`Role`] in the channel, with a
+    /// reason for the audit log.
+    ///
+    /// Refer to [`Self::allow_role`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    #[inline]
+    pub async fn allow_role_with_reason(
+        &mut self,
+        http: impl AsRef<Http>,
+        role_id: impl Into<RoleId>,
+        permissions: Permissions,
+        reason: &str,
+    ) -> Result<()> {
+        let kind = PermissionOverwriteType::Role(role_id.into());
+
+        self.update_overwrite(http.as_ref(), kind, permissions, Permissions::empty(), Some(reason))
+            .await
+    }
+
+    /// Denies the given permissions for a [`Role`] in the channel, updating its
+    /// existing [permission overwrite][`PermissionOverwrite`], if any.
+    ///
+    /// Refer to [`Self::allow_user`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    pub async fn deny_role(
+        &mut self,
+        http: impl AsRef<Http>,
+        role_id: impl Into<RoleId>,
+        permissions: Permissions,
+    ) -> Result<()> {
+        let kind = PermissionOverwriteType::Role(role_id.into());
+
+        self.update_overwrite(http.as_ref(), kind, Permissions::empty(), permissions, None).await
+    }
+
+    /// Denies the given permissions for a [`Role`] in the channel, with a
+    /// reason for the audit log.
+    ///
+    /// Refer to [`Self::deny_role`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    #[inline]
+    pub async fn deny_role_with_reason(
+        &mut self,
+        http: impl AsRef<Http>,
+        role_id: impl Into<RoleId>,
+        permissions: Permissions,
+        reason: &str,
+    ) -> Result<()> {
+        let kind = PermissionOverwriteType::Role(role_id.into());
+
+        self.update_overwrite(http.as_ref(), kind, Permissions::empty(), permissions, Some(reason))
+            .await
+    }
+
+    /// Removes the [permission overwrite][`PermissionOverwrite`] of a member or
+    /// role from the channel, so that only their guild-wide permissions apply.
+    ///
+    /// The channel's [`Self::permission_overwrites`] are updated accordingly.
+    ///
+    /// **Note**: Requires the [Manage Channels] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Manage Channels]: Permissions::MANAGE_CHANNELS
+    pub async fn reset_overwrite(
+        &mut self,
+        http: impl AsRef<Http>,
+        target: PermissionOverwriteType,
+    ) -> Result<()> {
+        self._reset_overwrite(http.as_ref(), target, None).await
+    }
+
+    /// Removes the [permission overwrite][`PermissionOverwrite`] of a member or
+    /// role from the channel, with a reason for the audit log.
+    ///
+    /// Refer to [`Self::reset_overwrite`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission.
+    #[inline]
+    pub async fn reset_overwrite_with_reason(
+        &mut self,
+        http: impl AsRef<Http>,
+        target: PermissionOverwriteType,
+        reason: &str,
+    ) -> Result<()> {
+        self._reset_overwrite(http.as_ref(), target, Some(reason)).await
+    }
+
+    async fn _reset_overwrite(
+        &mut self,
+        http: &Http,
+        target: PermissionOverwriteType,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        self.id._delete_permission(http, target, reason).await?;
+        self.permission_overwrites.retain(|overwrite| overwrite.kind != target);
+
+        Ok(())
+    }
+
+    async fn update_overwrite(
+        &mut self,
+        http: &Http,
+        kind: PermissionOverwriteType,
+        allow: Permissions,
+        deny: Permissions,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        let position = self.permission_overwrites.iter().position(|o| o.kind == kind);
+        let current = position.map(|i| &self.permission_overwrites[i]);
+        let overwrite = merge_overwrite(current, kind, allow, deny);
+
+        self.id._create_permission(http, &overwrite, reason).await?;
+
+        match position {
+            Some(i) => self.permission_overwrites[i] = overwrite,
+            None => self.permission_overwrites.push(overwrite),
+        }
+
+        Ok(())
+    }
+
     /// Deletes the given [`Reaction`] from the channel.
     ///
     /// **Note**: Requires the [Manage Messages] permission, _if_ the current
@@ -661,14 +1006,21 @@ impl GuildChannel {
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::InvalidChannelType`] if this channel is not a
+    /// news channel.
+    ///
     /// Returns [`Error::Http`] if the current user lacks permission.
-    /// [Manage Messages]: Permissions::MANAGE_MESSAGES
-    #[inline]
+    ///
+    /// [Manage Webhook]: Permissions::MANAGE_WEBHOOKS
     pub async fn follow(
         self,
         http: impl AsRef<Http>,
         target_channel_id: impl Into<ChannelId>,
     ) -> Result<FollowedChannel> {
+        if self.kind != ChannelType::News {
+            return Err(Error::Model(ModelError::InvalidChannelType));
+        }
+
         self.id.follow(http, target_channel_id).await
     }
 
@@ -722,6 +1074,23 @@ impl GuildChannel {
         self.id.message(&http, message_id).await
     }
 
+    /// Gets several messages from the channel by their Ids, keyed by Id.
+    ///
+    /// Refer to [`ChannelId::messages_by_ids`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if a
+    /// request otherwise fails.
+    #[inline]
+    pub async fn messages_by_ids(
+        &self,
+        cache_http: impl CacheHttp,
+        message_ids: &[MessageId],
+    ) -> Result<HashMap<MessageId, Message>> {
+        self.id.messages_by_ids(cache_http, message_ids).await
+    }
+
     /// Gets messages from the channel.
     ///
     /// Refer to the [`GetMessages`]-builder for more information on how to
@@ -735,6 +1104,9 @@ impl GuildChannel {
     /// Returns [`Error::Http`] if the current user lacks permission to
     /// view the channel.
     ///
+    /// Returns a [`ModelError`] if the builder is invalid. Refer to
+    /// [`ChannelId::messages`] for more information.
+    ///
     /// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
     #[inline]
     pub async fn messages<F>(&self, http: impl AsRef<Http>, builder: F) -> Result<Vec<Message>>
@@ -1271,6 +1643,7 @@ impl GuildChannel {
     /// # Errors
     ///
     /// Returns [`ModelError::InvalidChannelType`] if the channel is not a stage channel.
+    /// Returns [`ModelError::StageInstanceTopicLength`] if the topic is not 1-120 characters long.
     /// Returns [`Error::Http`] if there is already a stage instance currently.
     pub async fn create_stage_instance<F>(
         &self,
@@ -1292,6 +1665,7 @@ impl GuildChannel {
     /// # Errors
     ///
     /// Returns [`ModelError::InvalidChannelType`] if the channel is not a stage channel.
+    /// Returns [`ModelError::StageInstanceTopicLength`] if the topic is not 1-120 characters long.
     /// Returns [`Error::Http`] if there is no stage instance currently.
     pub async fn edit_stage_instance<F>(
         &self,
@@ -1356,6 +1730,25 @@ impl GuildChannel {
     }
 }
 
+/// Merges the given permissions into the current overwrite, if any, so that
+/// the allowed ones are no longer denied and the denied ones no longer allowed.
+#[cfg(feature = "model")]
+fn merge_overwrite(
+    current: Option<&PermissionOverwrite>,
+    kind: PermissionOverwriteType,
+    allow: Permissions,
+    deny: Permissions,
+) -> PermissionOverwrite {
+    let (current_allow, current_deny) =
+        current.map_or((Permissions::empty(), Permissions::empty()), |o| (o.allow, o.deny));
+
+    PermissionOverwrite {
+        allow: (current_allow - deny) | allow,
+        deny: (current_deny - allow) | deny,
+        kind,
+    }
+}
+
 impl fmt::Display for GuildChannel {
     /// Formats the channel, creating a mention of it.
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -1379,3 +1772,43 @@ pub struct PartialGuildChannel {
     #[serde(rename = "type")]
     pub kind: ChannelType,
 }
+
+#[cfg(test)]
+mod test {
+    #[test]
+    #[cfg(feature = "model")]
+    fn merge_overwrite_allow_deny() {
+        use super::merge_overwrite;
+        use crate::model::channel::{PermissionOverwrite, PermissionOverwriteType};
+        use crate::model::id::UserId;
+        use crate::model::Permissions;
+
+        let kind = PermissionOverwriteType::Member(UserId(7));
+        let none = Permissions::empty();
+
+        let created = merge_overwrite(None, kind, Permissions::VIEW_CHANNEL, none);
+        assert_eq!(created.allow, Permissions::VIEW_CHANNEL);
+        assert_eq!(created.deny, none);
+
+        let current = PermissionOverwrite {
+            allow: Permissions::VIEW_CHANNEL | Permissions::ADD_REACTIONS,
+            deny: Permissions::SEND_MESSAGES | Permissions::ATTACH_FILES,
+            kind,
+        };
+
+        let allowed = merge_overwrite(Some(&current), kind, Permissions::SEND_MESSAGES, none);
+        assert_eq!(
+            allowed.allow,
+            Permissions::VIEW_CHANNEL | Permissions::ADD_REACTIONS | Permissions::SEND_MESSAGES
+        );
+        assert_eq!(allowed.deny, Permissions::ATTACH_FILES);
+
+        let denied = merge_overwrite(Some(&current), kind, none, Permissions::ADD_REACTIONS);
+        assert_eq!(allowed.kind, kind);
+        assert_eq!(denied.allow, Permissions::VIEW_CHANNEL);
+        assert_eq!(
+            denied.deny,
+            Permissions::SEND_MESSAGES | Permissions::ATTACH_FILES | Permissions::ADD_REACTIONS
+        );
+    }
+}
@@ -4,9 +4,16 @@
 use std::fmt::Display;
 #[cfg(all(feature = "cache", feature = "model"))]
 use std::fmt::Write;
+#[cfg(feature = "model")]
+use std::future::Future;
+#[cfg(feature = "model")]
+use std::time::Duration;
+
+#[cfg(feature = "model")]
+use tokio::time::sleep;
 
 #[cfg(all(feature = "model", feature = "utils"))]
-use crate::builder::{CreateEmbed, EditMessage};
+use crate::builder::{validate_embed, CreateEmbed, EditMessage, SKIP_EMBED_VALIDATION};
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::Cache;
 #[cfg(feature = "collector")]
@@ -21,11 +28,13 @@ use crate::collector::{
     ReactionCollectorBuilder,
 };
 #[cfg(feature = "model")]
-use crate::http::{CacheHttp, Http};
+use crate::http::{CacheHttp, Http, HttpError, StatusCode};
 #[cfg(feature = "model")]
 use crate::json;
 use crate::json::prelude::*;
 use crate::model::application::component::ActionRow;
+#[cfg(feature = "model")]
+use crate::model::application::component::ComponentType;
 use crate::model::application::interaction::MessageInteraction;
 use crate::model::prelude::*;
 #[cfg(feature = "model")]
@@ -94,7 +103,13 @@ pub struct Message {
     /// Array of reactions performed on the message.
     #[serde(default)]
     pub reactions: Vec<MessageReaction>,
-    /// Non-repeating number used for ensuring message order.
+    /// Non-repeating number or string used for ensuring message order, and
+    /// for verifying that a message was sent.
+    ///
+    /// Only returned to the sender of the message, as set with
+    /// [`CreateMessage::nonce`].
+    ///
+    /// [`CreateMessage::nonce`]: crate::builder::CreateMessage::nonce
     #[serde(default)]
     pub nonce: Value,
     /// Indicator of whether the message is pinned.
@@ -112,8 +127,12 @@ pub struct Message {
     /// If the message is an Interaction or application-owned webhook, this is the id of the
     /// application.
     pub application_id: Option<ApplicationId>,
-    /// Reference data sent with crossposted messages.
+    /// Reference data sent with crossposted, forwarded, and reply messages.
     pub message_reference: Option<MessageReference>,
+    /// The content of the messages forwarded by this message, if its [`Self::message_reference`]
+    /// is of kind [`MessageReferenceKind::Forward`].
+    #[serde(default)]
+    pub message_snapshots: Vec<MessageSnapshot>,
     /// Bit flags describing extra features of the message.
     pub flags: Option<MessageFlags>,
     /// The message that was replied to using this message.
@@ -157,11 +176,20 @@ impl Message {
     ///
     /// Returns a [`ModelError::CannotCrosspostMessage`] if the message cannot be crossposted.
     ///
+    /// If the `cache` is enabled, returns a [`ModelError::InvalidChannelType`]
+    /// if the channel the message was sent in is not a news channel.
+    ///
     /// [Manage Messages]: Permissions::MANAGE_MESSAGES
     pub async fn crosspost(&self, cache_http: impl CacheHttp) -> Result<Message> {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
+                if let Some(kind) = cache.guild_channel_field(self.channel_id, |c| c.kind) {
+                    if kind != ChannelType::News {
+                        return Err(Error::Model(ModelError::InvalidChannelType));
+                    }
+                }
+
                 if self.author.id != cache.current_user_id() && self.guild_id.is_some() {
                     utils::user_has_perms_cache(
                         cache,
@@ -219,7 +247,27 @@ impl Message {
     /// the required permissions.
     ///
     /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    #[inline]
     pub async fn delete(&self, cache_http: impl CacheHttp) -> Result<()> {
+        self._delete(cache_http, None).await
+    }
+
+    /// Deletes the message, with a reason for the audit log.
+    ///
+    /// Refer to [`Self::delete`] for more information. The reason is only recorded when deleting
+    /// a message authored by another user.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` feature is enabled, then returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    #[inline]
+    pub async fn delete_with_reason(&self, cache_http: impl CacheHttp, reason: &str) -> Result<()> {
+        self._delete(cache_http, Some(reason)).await
+    }
+
+    async fn _delete(&self, cache_http: impl CacheHttp, reason: Option<&str>) -> Result<()> {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
@@ -234,7 +282,7 @@ impl Message {
             }
         }
 
-        self.channel_id.delete_message(&cache_http.http(), self.id).await
+        cache_http.http().delete_message_with_reason(self.channel_id.0, self.id.0, reason).await
     }
 
     /// Deletes all of the [`Reaction`]s associated with the message.
@@ -301,7 +349,9 @@ impl Message {
 
     /// Edits this message, replacing the original content with new content.
     ///
-    /// Message editing preserves all unchanged message data.
+    /// Message editing preserves all unchanged message data: fields which are not set on the
+    /// [`EditMessage`] builder, such as embeds or components, are left as they are. Use
+    /// [`EditMessage::clear_embeds`] or [`EditMessage::clear_components`] to remove them.
     ///
     /// Refer to the documentation for [`EditMessage`] for more information
     /// regarding message restrictions and requirements.
@@ -327,6 +377,9 @@ impl Message {
     /// is over [`the limit`], containing the number of unicode code points
     /// over the limit.
     ///
+    /// Returns a [`ModelError::InvalidEmbed`] or [`ModelError::EmbedTooLarge`] if an embed
+    /// exceeds one of its limits, unless skipped via [`EditMessage::skip_embed_validation`].
+    ///
     /// [`the limit`]: crate::builder::EditMessage::content
     pub async fn edit<'a, F>(&mut self, cache_http: impl CacheHttp, f: F) -> Result<()>
     where
@@ -340,29 +393,40 @@ impl Message {
                 }
             }
         }
-        let mut builder = self._prepare_edit_builder();
-        f(&mut builder);
+        let builder = self._prepare_edit_builder(f);
         self._send_edit(cache_http.http(), builder).await
     }
 
-    fn _prepare_edit_builder<'a>(&self) -> EditMessage<'a> {
+    fn _prepare_edit_builder<'a, F>(&self, f: F) -> EditMessage<'a>
+    where
+        F: for<'b> FnOnce(&'b mut EditMessage<'a>) -> &'b mut EditMessage<'a>,
+    {
         let mut builder = EditMessage::default();
 
-        if !self.content.is_empty() {
-            builder.content(&self.content);
+        // Fields which are not set are left unchanged by Discord, except for attachments, where
+        // adding a new attachment would remove all existing ones not listed.
+        for attachment in &self.attachments {
+            builder.add_existing_attachment(attachment.id);
         }
 
+        // The existing embeds are set so that `EditMessage::add_embed` keeps them, but are left
+        // out again if the embeds were not changed.
         let embeds: Vec<_> = self.embeds.iter().map(|e| CreateEmbed::from(e.clone())).collect();
         builder.set_embeds(embeds);
+        let existing_embeds = builder.0.get("embeds").cloned();
 
-        for attachment in &self.attachments {
-            builder.add_existing_attachment(attachment.id);
+        f(&mut builder);
+
+        if builder.0.get("embeds") == existing_embeds.as_ref() {
+            builder.0.remove("embeds");
         }
         builder
     }
 
     async fn _send_edit<'a>(&mut self, http: &Http, builder: EditMessage<'a>) -> Result<()> {
-        let map = json::hashmap_to_json_map(builder.0);
+        let mut map = json::hashmap_to_json_map(builder.0);
+        Self::check_embeds(&mut map)?;
+        Self::check_components_v2(&map, true)?;
 
         *self = http
             .edit_message_and_attachments(
@@ -531,6 +595,54 @@ impl Message {
         cache_http.http().get_member(guild_id.0, self.author.id.0).await
     }
 
+    /// Retrieves the message this message replies to.
+    ///
+    /// Uses [`Self::referenced_message`] if Discord provided it, otherwise
+    /// looks the message up in the cache, then falls back to requesting it
+    /// via the REST API.
+    ///
+    /// Returns [`None`] if this message is not a reply, or if the referenced
+    /// message has been deleted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission to read
+    /// the referenced message's channel, or if the request otherwise fails.
+    pub async fn referenced_message_full(
+        &self,
+        cache_http: impl CacheHttp,
+    ) -> Result<Option<Message>> {
+        if let Some(message) = &self.referenced_message {
+            return Ok(Some((**message).clone()));
+        }
+
+        let (channel_id, message_id) = match &self.message_reference {
+            Some(MessageReference {
+                kind: MessageReferenceKind::Default,
+                message_id: Some(message_id),
+                channel_id,
+                ..
+            }) => (*channel_id, *message_id),
+            _ => return Ok(None),
+        };
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if let Some(message) = cache.message(channel_id, message_id) {
+                    return Ok(Some(message));
+                }
+            }
+        }
+
+        match channel_id.message(cache_http.http(), message_id).await {
+            Ok(message) => Ok(Some(message)),
+            // JSON error code 10008: "Unknown message"
+            Err(Error::Http(http_error)) if http_error.json_error_code() == Some(10008) => Ok(None),
+            Err(why) => Err(why),
+        }
+    }
+
     /// Checks the length of a string to ensure that it is within Discord's
     /// maximum message length limit.
     ///
@@ -552,6 +664,8 @@ impl Message {
 
     /// Pins this message to its channel.
     ///
+    /// If the cache is enabled, the cached message is marked as pinned.
+    ///
     /// **Note**: Requires the [Manage Messages] permission.
     ///
     /// # Errors
@@ -561,7 +675,26 @@ impl Message {
     /// the required permissions.
     ///
     /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    #[inline]
     pub async fn pin(&self, cache_http: impl CacheHttp) -> Result<()> {
+        self._pin(cache_http, None).await
+    }
+
+    /// Pins this message to its channel, with a reason for the audit log.
+    ///
+    /// Refer to [`Self::pin`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    #[inline]
+    pub async fn pin_with_reason(&self, cache_http: impl CacheHttp, reason: &str) -> Result<()> {
+        self._pin(cache_http, Some(reason)).await
+    }
+
+    async fn _pin(&self, cache_http: impl CacheHttp, reason: Option<&str>) -> Result<()> {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
@@ -576,7 +709,31 @@ impl Message {
             }
         }
 
-        self.channel_id.pin(cache_http.http(), self.id.0).await
+        match reason {
+            Some(reason) => {
+                self.channel_id.pin_with_reason(cache_http.http(), self.id, reason).await?;
+            },
+            None => self.channel_id.pin(cache_http.http(), self.id).await?,
+        }
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                self.set_cached_pinned(cache, true);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Updates whether this message is pinned in the cache, if it is cached.
+    #[cfg(feature = "cache")]
+    fn set_cached_pinned(&self, cache: &Cache, pinned: bool) {
+        if let Some(messages) = cache.messages.get(&self.channel_id) {
+            if let Some(mut message) = messages.get_mut(&self.id) {
+                message.pinned = pinned;
+            }
+        }
     }
 
     /// React to the message with a custom [`Emoji`] or unicode character.
@@ -589,6 +746,9 @@ impl Message {
     /// [`ModelError::InvalidPermissions`] if the current user does not have the
     /// required [permissions].
     ///
+    /// Returns a [`ModelError::ReactionBlocked`] if the reaction was blocked,
+    /// such as when the author of the message blocked the current user.
+    ///
     /// [Add Reactions]: Permissions::ADD_REACTIONS
     /// [permissions]: super::permissions
     #[inline]
@@ -624,7 +784,23 @@ impl Message {
             }
         }
 
-        cache_http.http().create_reaction(self.channel_id.0, self.id.0, &reaction_type).await?;
+        cache_http
+            .http()
+            .create_reaction(self.channel_id.0, self.id.0, &reaction_type)
+            .await
+            .map_err(|why| {
+                // JSON error code 90001: "Reaction was blocked"
+                let blocked = matches!(
+                    &why,
+                    Error::Http(http_error) if http_error.json_error_code() == Some(90001)
+                );
+
+                if blocked {
+                    Error::Model(ModelError::ReactionBlocked)
+                } else {
+                    why
+                }
+            })?;
 
         Ok(Reaction {
             channel_id: self.channel_id,
@@ -636,6 +812,31 @@ impl Message {
         })
     }
 
+    /// React to the message with several reactions, in order, returning the
+    /// result of each reaction in the same order.
+    ///
+    /// The reactions are added one at a time, as Discord displays them in the
+    /// order they were added, waiting a quarter of a second between two of
+    /// them to stay below the reaction ratelimit. A reaction which is
+    /// ratelimited anyway is retried up to 3 times, waiting half a second
+    /// before the first retry and twice as long before each further one.
+    ///
+    /// A failed reaction does not prevent the following ones from being added.
+    /// Refer to [`Self::react`] for the errors each reaction can fail with.
+    ///
+    /// **Note**: Requires the [Add Reactions] permission.
+    ///
+    /// [Add Reactions]: Permissions::ADD_REACTIONS
+    pub async fn react_all(
+        &self,
+        cache_http: impl CacheHttp,
+        reaction_types: &[ReactionType],
+    ) -> Vec<Result<Reaction>> {
+        let cache_http = &cache_http;
+
+        react_paced(reaction_types, |reaction_type| self._react(cache_http, reaction_type)).await
+    }
+
     /// Uses Discord's inline reply to a user without pinging them.
     ///
     /// User mentions are generally around 20 or 21 characters long.
@@ -758,7 +959,8 @@ impl Message {
             .await
     }
 
-    /// Delete all embeds in this message
+    /// Delete all embeds in this message, by setting its [`MessageFlags::SUPPRESS_EMBEDS`] flag.
+    ///
     /// **Note**: The logged in user must either be the author of the message or
     /// have the [Manage Messages] permission.
     ///
@@ -775,15 +977,13 @@ impl Message {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
-                utils::user_has_perms_cache(
-                    cache,
-                    self.channel_id,
-                    self.guild_id,
-                    Permissions::MANAGE_MESSAGES,
-                )?;
-
                 if self.author.id != cache.current_user_id() {
-                    return Err(Error::Model(ModelError::NotAuthor));
+                    utils::user_has_perms_cache(
+                        cache,
+                        self.channel_id,
+                        self.guild_id,
+                        Permissions::MANAGE_MESSAGES,
+                    )?;
                 }
             }
         }
@@ -833,6 +1033,8 @@ impl Message {
 
     /// Unpins the message from its channel.
     ///
+    /// If the cache is enabled, the cached message is marked as unpinned.
+    ///
     /// **Note**: Requires the [Manage Messages] permission.
     ///
     /// # Errors
@@ -842,7 +1044,26 @@ impl Message {
     /// the required permissions.
     ///
     /// [Manage Messages]: Permissions::MANAGE_MESSAGES
+    #[inline]
     pub async fn unpin(&self, cache_http: impl CacheHttp) -> Result<()> {
+        self._unpin(cache_http, None).await
+    }
+
+    /// Unpins the message from its channel, with a reason for the audit log.
+    ///
+    /// Refer to [`Self::unpin`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// If the `cache` is enabled, returns a
+    /// [`ModelError::InvalidPermissions`] if the current user does not have
+    /// the required permissions.
+    #[inline]
+    pub async fn unpin_with_reason(&self, cache_http: impl CacheHttp, reason: &str) -> Result<()> {
+        self._unpin(cache_http, Some(reason)).await
+    }
+
+    async fn _unpin(&self, cache_http: impl CacheHttp, reason: Option<&str>) -> Result<()> {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
@@ -857,7 +1078,16 @@ impl Message {
             }
         }
 
-        cache_http.http().unpin_message(self.channel_id.0, self.id.0, None).await
+        cache_http.http().unpin_message(self.channel_id.0, self.id.0, reason).await?;
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                self.set_cached_pinned(cache, false);
+            }
+        }
+
+        Ok(())
     }
 
     /// Tries to return author's nickname in the current channel's guild.
@@ -942,6 +1172,15 @@ impl Message {
         cache.as_ref().channel_category_id(self.channel_id)
     }
 
+    /// Returns the content of the messages forwarded by this message.
+    ///
+    /// This is empty if the message is not a forward, which is indicated by its
+    /// [`Self::message_reference`] being of kind [`MessageReferenceKind::Forward`].
+    #[must_use]
+    pub fn forwarded_messages(&self) -> &[MessageSnapshot] {
+        &self.message_snapshots
+    }
+
     pub(crate) fn check_lengths(map: &JsonMap) -> Result<()> {
         Self::check_content_length(map)?;
         Self::check_embed_length(map)?;
@@ -950,72 +1189,162 @@ impl Message {
         Ok(())
     }
 
-    pub(crate) fn check_content_length(map: &JsonMap) -> Result<()> {
-        if let Some(Value::String(content)) = map.get("content") {
-            if let Some(length_over) = Message::overflow_length(content) {
-                return Err(Error::Model(ModelError::MessageTooLong(length_over)));
-            }
+    /// Checks that a message forwarding another message doesn't set any content of its own,
+    /// as forwards may only carry the content of the forwarded message.
+    pub(crate) fn check_forward(map: &JsonMap, has_files: bool) -> Result<()> {
+        let is_forward = map
+            .get("message_reference")
+            .and_then(|reference| reference.get("type"))
+            .and_then(|kind| kind.as_u64())
+            .map_or(false, |kind| kind == MessageReferenceKind::Forward as u64);
+
+        if !is_forward {
+            return Ok(());
+        }
+
+        let has_content = Self::has_any_field(map, &[
+            "content",
+            "embeds",
+            "sticker_ids",
+            "components",
+            "attachments",
+        ]);
+
+        if has_content || has_files {
+            return Err(Error::Model(ModelError::ForwardWithContent));
         }
 
         Ok(())
     }
 
-    pub(crate) fn check_embed_length(map: &JsonMap) -> Result<()> {
-        let embeds = match map.get("embeds") {
-            Some(Value::Array(value)) => value,
-            _ => return Ok(()),
-        };
+    /// Checks that a message with the [`MessageFlags::IS_COMPONENTS_V2`] flag doesn't set any
+    /// content, embeds, or stickers, that a message without it only has action rows as
+    /// components, and that the components are within their limits.
+    ///
+    /// This is shared by the paths creating and editing messages and interaction responses.
+    /// When `editing`, a map without flags keeps the message's flags, which are not known here,
+    /// so only the limits are checked.
+    pub(crate) fn check_components_v2(map: &JsonMap, editing: bool) -> Result<()> {
+        if let Some(Value::Array(components)) = map.get("components") {
+            Self::check_component_limits(components)?;
+        }
+
+        let flags = map.get("flags").and_then(Value::as_u64);
 
-        if embeds.len() > 10 {
-            return Err(Error::Model(ModelError::EmbedAmount));
+        if editing && flags.is_none() {
+            return Ok(());
         }
 
-        for embed in embeds {
-            let mut total: usize = 0;
+        let is_v2 = flags.map_or(false, |bits| bits & MessageFlags::IS_COMPONENTS_V2.bits() != 0);
 
-            if let Some(Value::Object(author)) = embed.get("author") {
-                if let Some(Value::Object(name)) = author.get("name") {
-                    total += name.len();
-                }
+        if is_v2 {
+            if Self::has_any_field(map, &["content", "embeds", "sticker_ids", "poll"]) {
+                return Err(Error::Model(ModelError::ComponentsV2WithContent));
             }
-
-            if let Some(Value::String(description)) = embed.get("description") {
-                total += description.len();
+        } else if let Some(Value::Array(components)) = map.get("components") {
+            let has_v2_components = components.iter().any(|component| {
+                component.get("type").and_then(|kind| kind.as_u64())
+                    != Some(ComponentType::ActionRow as u64)
+            });
+
+            if has_v2_components {
+                return Err(Error::Model(ModelError::ComponentsV2FlagMissing));
             }
+        }
 
-            if let Some(Value::Array(fields)) = embed.get("fields") {
-                for field_as_value in fields {
-                    if let Value::Object(ref field) = *field_as_value {
-                        if let Some(Value::String(field_name)) = field.get("name") {
-                            total += field_name.len();
-                        }
+        Ok(())
+    }
+
+    /// Checks the Components V2 rules of an interaction response, whose message is its `data`.
+    pub(crate) fn check_response_components_v2(map: &JsonMap) -> Result<()> {
+        match map.get("data") {
+            Some(Value::Object(data)) => Self::check_components_v2(data, false),
+            _ => Ok(()),
+        }
+    }
 
-                        if let Some(Value::String(field_value)) = field.get("value") {
-                            total += field_value.len();
-                        }
+    fn check_component_limits(components: &[Value]) -> Result<()> {
+        for component in components {
+            let children = |key| match component.get(key) {
+                Some(Value::Array(children)) => &children[..],
+                _ => &[],
+            };
+
+            match component.get("type").and_then(Value::as_u64) {
+                Some(kind) if kind == ComponentType::MediaGallery as u64 => {
+                    if children("items").len() > constants::MEDIA_GALLERY_MAX_ITEMS {
+                        return Err(Error::Model(ModelError::MediaGalleryItemAmount));
                     }
-                }
+                },
+                Some(kind) if kind == ComponentType::Section as u64 => {
+                    if children("components").len() > constants::SECTION_MAX_TEXT_DISPLAYS {
+                        return Err(Error::Model(ModelError::SectionTextDisplayAmount));
+                    }
+                },
+                _ => {},
             }
 
-            if let Some(Value::Object(footer)) = embed.get("footer") {
-                if let Some(Value::String(text)) = footer.get("text") {
-                    total += text.len();
-                }
-            }
+            Self::check_component_limits(children("components"))?;
+        }
+
+        Ok(())
+    }
 
-            if let Some(Value::String(title)) = embed.get("title") {
-                total += title.len();
+    fn has_any_field(map: &JsonMap, keys: &[&str]) -> bool {
+        keys.iter().any(|key| match map.get(*key) {
+            Some(Value::String(s)) => !s.is_empty(),
+            Some(Value::Array(a)) => !a.is_empty(),
+            Some(value) => !value.is_null(),
+            None => false,
+        })
+    }
+
+    pub(crate) fn check_content_length(map: &JsonMap) -> Result<()> {
+        if let Some(Value::String(content)) = map.get("content") {
+            if let Some(length_over) = Message::overflow_length(content) {
+                return Err(Error::Model(ModelError::MessageTooLong(length_over)));
             }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn check_embed_length(map: &JsonMap) -> Result<()> {
+        Self::check_embed_amount(map)?;
+
+        let embeds = match map.get("embeds") {
+            Some(Value::Array(value)) => value,
+            _ => return Ok(()),
+        };
 
-            if total > constants::EMBED_MAX_LENGTH {
-                let overflow = total - constants::EMBED_MAX_LENGTH;
-                return Err(Error::Model(ModelError::EmbedTooLarge(overflow)));
+        for embed in embeds {
+            if let Value::Object(embed) = embed {
+                validate_embed(|key| embed.get(key)).map_err(ModelError::from)?;
             }
         }
 
         Ok(())
     }
 
+    fn check_embed_amount(map: &JsonMap) -> Result<()> {
+        match map.get("embeds") {
+            Some(Value::Array(embeds)) if embeds.len() > 10 => {
+                Err(Error::Model(ModelError::EmbedAmount))
+            },
+            _ => Ok(()),
+        }
+    }
+
+    /// Checks the embeds of a message builder, only checking their number if the builder was
+    /// marked by `skip_embed_validation`. The mark is removed from the map.
+    pub(crate) fn check_embeds(map: &mut JsonMap) -> Result<()> {
+        if map.remove(SKIP_EMBED_VALIDATION).is_some() {
+            Self::check_embed_amount(map)
+        } else {
+            Self::check_embed_length(map)
+        }
+    }
+
     pub(crate) fn check_sticker_ids_length(map: &JsonMap) -> Result<()> {
         if let Some(Value::Array(sticker_ids)) = map.get("sticker_ids") {
             if sticker_ids.len() > constants::STICKER_MAX_COUNT {
@@ -1153,6 +1482,12 @@ enum_number!(MessageType {
     AutoModerationAction,
 });
 
+impl Default for MessageType {
+    fn default() -> Self {
+        Self::Regular
+    }
+}
+
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#message-object-message-activity-types).
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
 #[non_exhaustive]
@@ -1206,12 +1541,16 @@ pub struct MessageActivity {
     pub party_id: Option<String>,
 }
 
-/// Reference data sent with crossposted messages.
+/// Reference data sent with crossposted, forwarded, and reply messages.
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#message-reference-object-message-reference-structure).
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct MessageReference {
+    /// The kind of reference, i.e. whether the message replies to or forwards the
+    /// originating message.
+    #[serde(rename = "type", default)]
+    pub kind: MessageReferenceKind,
     /// ID of the originating message.
     pub message_id: Option<MessageId>,
     /// ID of the originating message's channel.
@@ -1223,6 +1562,7 @@ pub struct MessageReference {
 impl From<&Message> for MessageReference {
     fn from(m: &Message) -> Self {
         Self {
+            kind: MessageReferenceKind::Default,
             message_id: Some(m.id),
             channel_id: m.channel_id,
             guild_id: m.guild_id,
@@ -1233,6 +1573,7 @@ impl From<&Message> for MessageReference {
 impl From<(ChannelId, MessageId)> for MessageReference {
     fn from(pair: (ChannelId, MessageId)) -> Self {
         Self {
+            kind: MessageReferenceKind::Default,
             message_id: Some(pair.1),
             channel_id: pair.0,
             guild_id: None,
@@ -1240,6 +1581,83 @@ impl From<(ChannelId, MessageId)> for MessageReference {
     }
 }
 
+/// The kind of a [`MessageReference`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/channel#message-reference-types).
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum MessageReferenceKind {
+    /// A standard reference, used by replies and crossposts.
+    Default = 0,
+    /// A reference used to forward a message, whose content is then found in
+    /// [`Message::message_snapshots`].
+    Forward = 1,
+    Unknown = !0,
+}
+
+enum_number!(MessageReferenceKind {
+    Default,
+    Forward
+});
+
+impl Default for MessageReferenceKind {
+    fn default() -> Self {
+        Self::Default
+    }
+}
+
+/// A snapshot of a message forwarded by another message.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/channel#message-snapshot-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct MessageSnapshot {
+    /// The forwarded content of the message.
+    pub message: ForwardedMessage,
+}
+
+/// The subset of a [`Message`]'s fields that are kept when it is forwarded.
+///
+/// As this is a partial message, fields that are absent are set to their empty
+/// values.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/channel#message-snapshot-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct ForwardedMessage {
+    /// Indicator of the type of message this is.
+    #[serde(rename = "type", default)]
+    pub kind: MessageType,
+    /// The content of the message.
+    #[serde(default)]
+    pub content: String,
+    /// Array of embeds sent with the message.
+    #[serde(default)]
+    pub embeds: Vec<Embed>,
+    /// An vector of the files attached to the message.
+    #[serde(default)]
+    pub attachments: Vec<Attachment>,
+    /// Initial message creation timestamp.
+    pub timestamp: Option<Timestamp>,
+    /// The timestamp of the last time the message was updated, if it was.
+    pub edited_timestamp: Option<Timestamp>,
+    /// Bit flags describing extra features of the message.
+    pub flags: Option<MessageFlags>,
+    /// Array of users mentioned in the message.
+    #[serde(default)]
+    pub mentions: Vec<User>,
+    /// Array of [`Role`]s' Ids mentioned in the message.
+    #[serde(default)]
+    pub mention_roles: Vec<RoleId>,
+    /// Array of message sticker item objects.
+    #[serde(default)]
+    pub sticker_items: Vec<StickerItem>,
+    /// The components of the message.
+    #[serde(default)]
+    pub components: Vec<ActionRow>,
+}
+
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#channel-mention-object).
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct ChannelMention {
@@ -1280,6 +1698,11 @@ bitflags! {
         const FAILED_TO_MENTION_SOME_ROLES_IN_THREAD = 1 << 8;
         /// This message will not trigger push and desktop notifications.
         const SUPPRESS_NOTIFICATIONS = 1 << 12;
+        /// This message is a voice message.
+        const IS_VOICE_MESSAGE = 1 << 13;
+        /// This message is laid out with components of the Components V2 system, such as
+        /// containers and text displays, which replace its content and embeds.
+        const IS_COMPONENTS_V2 = 1 << 15;
     }
 }
 
@@ -1317,3 +1740,188 @@ impl MessageId {
         self.link(channel_id, guild_id)
     }
 }
+
+/// The time waited between adding two reactions in [`Message::react_all`].
+#[cfg(feature = "model")]
+const REACTION_DELAY: Duration = Duration::from_millis(250);
+
+/// The number of times [`Message::react_all`] retries a ratelimited reaction.
+#[cfg(feature = "model")]
+const REACTION_RETRIES: u32 = 3;
+
+/// Adds each reaction in order with `react`, waiting [`REACTION_DELAY`]
+/// between two reactions and retrying ratelimited ones.
+#[cfg(feature = "model")]
+async fn react_paced<T, F, Fut>(reaction_types: &[ReactionType], mut react: F) -> Vec<Result<T>>
+where
+    F: FnMut(ReactionType) -> Fut,
+    Fut: Future<Output = Result<T>>,
+{
+    let mut results = Vec::with_capacity(reaction_types.len());
+
+    for (index, reaction_type) in reaction_types.iter().enumerate() {
+        if index != 0 {
+            sleep(REACTION_DELAY).await;
+        }
+
+        let mut result = react(reaction_type.clone()).await;
+        let mut delay = REACTION_DELAY;
+
+        for _ in 0..REACTION_RETRIES {
+            let ratelimited = matches!(
+                &result,
+                Err(Error::Http(http_error))
+                    if http_error.status_code() == Some(StatusCode::TOO_MANY_REQUESTS)
+            );
+
+            if !ratelimited {
+                break;
+            }
+
+            delay *= 2;
+            sleep(delay).await;
+            result = react(reaction_type.clone()).await;
+        }
+
+        results.push(result);
+    }
+
+    results
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::json::{from_value, hashmap_to_json_map, json};
+
+    #[test]
+    #[cfg(all(feature = "builder", feature = "model", feature = "utils"))]
+    fn edit_keeps_existing_embeds() {
+        let message: Message = from_value(json!({
+            "id": "1",
+            "channel_id": "2",
+            "author": {"id": "3", "username": "user", "discriminator": "0001", "avatar": null},
+            "content": "old",
+            "timestamp": "2024-07-01T12:00:00.000000+00:00",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [{"title": "existing"}],
+            "pinned": false,
+            "type": 0
+        }))
+        .unwrap();
+
+        let builder = message._prepare_edit_builder(|m| m.content("new"));
+        let map = hashmap_to_json_map(builder.0);
+        assert_eq!(map["content"], "new");
+        assert!(!map.contains_key("embeds"));
+        assert!(!map.contains_key("components"));
+
+        let builder = message._prepare_edit_builder(|m| m.add_embed(|e| e.title("added")));
+        let map = hashmap_to_json_map(builder.0);
+        let embeds = map["embeds"].as_array().unwrap();
+        assert_eq!(embeds.len(), 2);
+        assert_eq!(embeds[0]["title"], "existing");
+        assert_eq!(embeds[1]["title"], "added");
+
+        let builder = message._prepare_edit_builder(|m| m.embed(|e| e.title("replaced")));
+        let map = hashmap_to_json_map(builder.0);
+        assert_eq!(map["embeds"].as_array().unwrap().len(), 1);
+        assert_eq!(map["embeds"][0]["title"], "replaced");
+
+        let builder = message._prepare_edit_builder(|m| m.clear_embeds().clear_components());
+        let map = hashmap_to_json_map(builder.0);
+        assert_eq!(map["embeds"], json!([]));
+        assert_eq!(map["components"], json!([]));
+    }
+
+    #[test]
+    #[cfg(all(feature = "cache", feature = "model"))]
+    fn cached_pinned() {
+        let message: Message = from_value(json!({
+            "id": "1",
+            "channel_id": "2",
+            "author": {"id": "3", "username": "user", "discriminator": "0001", "avatar": null},
+            "content": "",
+            "timestamp": "2024-07-01T12:00:00.000000+00:00",
+            "edited_timestamp": null,
+            "tts": false,
+            "mention_everyone": false,
+            "mentions": [],
+            "mention_roles": [],
+            "attachments": [],
+            "embeds": [],
+            "pinned": false,
+            "type": 0
+        }))
+        .unwrap();
+
+        let cache = Cache::default();
+        cache.messages.entry(message.channel_id).or_default().insert(message.id, message.clone());
+
+        message.set_cached_pinned(&cache, true);
+        assert!(cache.message(message.channel_id, message.id).unwrap().pinned);
+
+        message.set_cached_pinned(&cache, false);
+        assert!(!cache.message(message.channel_id, message.id).unwrap().pinned);
+    }
+
+    #[tokio::test(start_paused = true)]
+    #[cfg(feature = "model")]
+    async fn react_paced_retries_ratelimited() {
+        use std::cell::RefCell;
+
+        use tokio::time::Instant;
+
+        use crate::http::error::{DiscordJsonError, ErrorResponse};
+
+        fn error(status_code: StatusCode, code: isize) -> Error {
+            Error::Http(Box::new(HttpError::UnsuccessfulRequest(ErrorResponse {
+                status_code,
+                url: "https://discord.com/api/v10".parse().unwrap(),
+                error: DiscordJsonError {
+                    code,
+                    message: String::new(),
+                    errors: Vec::new(),
+                },
+            })))
+        }
+
+        let reactions = ['1', '2', '3'].map(ReactionType::from);
+        let start = Instant::now();
+        let calls = RefCell::new(Vec::new());
+
+        let results = react_paced(&reactions, |reaction_type| {
+            let mut calls = calls.borrow_mut();
+            calls.push((reaction_type.clone(), start.elapsed().as_millis()));
+            let attempts = calls.iter().filter(|(r, _)| *r == reaction_type).count();
+
+            let result = match (reaction_type.to_string().as_str(), attempts) {
+                ("2", 1) => Err(error(StatusCode::TOO_MANY_REQUESTS, 0)),
+                ("3", _) => Err(error(StatusCode::BAD_REQUEST, 90001)),
+                (_, _) => Ok(reaction_type),
+            };
+
+            async move { result }
+        })
+        .await;
+
+        let calls: Vec<_> =
+            calls.into_inner().into_iter().map(|(r, at)| (r.to_string(), at)).collect();
+        assert_eq!(calls, [
+            ("1".to_string(), 0),
+            ("2".to_string(), 250),
+            ("2".to_string(), 750),
+            ("3".to_string(), 1000),
+        ]);
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap(), &reactions[0]);
+        assert_eq!(results[1].as_ref().unwrap(), &reactions[1]);
+        assert!(matches!(&results[2], Err(Error::Http(e)) if e.json_error_code() == Some(90001)));
+    }
+}
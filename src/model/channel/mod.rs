@@ -33,7 +33,7 @@ use crate::cache::Cache;
 #[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
 use crate::cache::FromStrAndCache;
 #[cfg(feature = "model")]
-use crate::http::CacheHttp;
+use crate::http::{CacheHttp, Http};
 use crate::json::prelude::*;
 use crate::model::prelude::*;
 use crate::model::utils::is_false;
@@ -183,6 +183,19 @@ impl Channel {
         Ok(())
     }
 
+    /// Gets the list of [`Message`]s which are pinned to the inner channel,
+    /// ordered from the most recently pinned to the oldest.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission
+    /// to view the channel.
+    #[cfg(feature = "model")]
+    #[inline]
+    pub async fn pins(&self, http: impl AsRef<Http>) -> Result<Vec<Message>> {
+        self.id().pins(http).await
+    }
+
     /// Determines if the channel is NSFW.
     #[inline]
     #[must_use]
@@ -249,6 +262,11 @@ impl<'de> Deserialize<'de> for Channel {
             4 => from_value::<ChannelCategory>(Value::from(v))
                 .map(Channel::Category)
                 .map_err(DeError::custom),
+            // Channel types newer than this library are still guild channels if they belong
+            // to a guild, and get a kind of `ChannelType::Unknown`.
+            _ if v.contains_key("guild_id") => from_value::<GuildChannel>(Value::from(v))
+                .map(Channel::Guild)
+                .map_err(DeError::custom),
             _ => Err(DeError::custom("Unknown channel type")),
         }
     }
@@ -357,6 +375,13 @@ impl ChannelType {
             Self::Unknown => "unknown",
         }
     }
+
+    /// Whether the channel type is a news, public, or private thread.
+    #[inline]
+    #[must_use]
+    pub const fn is_thread(self) -> bool {
+        matches!(self, Self::NewsThread | Self::PublicThread | Self::PrivateThread)
+    }
 }
 
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#overwrite-object).
@@ -466,8 +491,57 @@ pub struct StageInstance {
     pub channel_id: ChannelId,
     /// The topic of the stage instance.
     pub topic: String,
+    /// Who the stage instance is visible to.
+    pub privacy_level: StageInstancePrivacyLevel,
+    /// The Id of the scheduled event for the stage instance, if any.
+    pub guild_scheduled_event_id: Option<ScheduledEventId>,
+}
+
+#[cfg(feature = "model")]
+impl StageInstance {
+    /// Checks that the topic of a stage instance is 1-120 characters long.
+    ///
+    /// When the topic is not `required`, such as when editing, a missing topic
+    /// is allowed.
+    pub(crate) fn check_topic_length(map: &JsonMap, required: bool) -> Result<()> {
+        let length = match map.get("topic") {
+            Some(Value::String(topic)) => topic.chars().count(),
+            Some(_) => 0,
+            None if required => 0,
+            None => return Ok(()),
+        };
+
+        if (1..=120).contains(&length) {
+            Ok(())
+        } else {
+            Err(Error::Model(ModelError::StageInstanceTopicLength(length)))
+        }
+    }
+}
+
+/// The privacy level of a [`StageInstance`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/stage-instance#stage-instance-object-privacy-level).
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum StageInstancePrivacyLevel {
+    /// The stage instance is visible publicly.
+    ///
+    /// **Note**: Deprecated by Discord, as new stage instances can no longer be
+    /// public.
+    Public = 1,
+    /// The stage instance is visible to only guild members.
+    GuildOnly = 2,
+    /// An indicator that the privacy level is of unknown type.
+    Unknown = !0,
 }
 
+enum_number!(StageInstancePrivacyLevel {
+    Public,
+    GuildOnly,
+});
+
 /// A thread data.
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/channel#thread-metadata-object).
@@ -513,6 +587,83 @@ pub struct ThreadsData {
 
 #[cfg(test)]
 mod test {
+    use super::*;
+    use crate::json::json;
+
+    #[test]
+    fn channel_deserialize_by_kind() {
+        let guild_channel = |kind: u8| {
+            json!({
+                "id": "1",
+                "guild_id": "2",
+                "type": kind,
+                "name": "channel",
+                "position": 0,
+                "permission_overwrites": [],
+                "nsfw": false,
+            })
+        };
+
+        for (kind, expected) in [
+            (0, ChannelType::Text),
+            (2, ChannelType::Voice),
+            (5, ChannelType::News),
+            (11, ChannelType::PublicThread),
+            (13, ChannelType::Stage),
+            (200, ChannelType::Unknown),
+        ] {
+            let channel: Channel = from_value(guild_channel(kind)).unwrap();
+            let channel = channel.guild().unwrap();
+            assert_eq!(channel.kind, expected);
+            assert_eq!(channel.kind.is_thread(), kind == 11);
+        }
+
+        let category: Channel = from_value(guild_channel(4)).unwrap();
+        assert!(category.category().is_some());
+
+        let private: Channel = from_value(json!({
+            "id": "3",
+            "type": 1,
+            "recipients": [{
+                "id": "4",
+                "username": "user",
+                "discriminator": "0001",
+                "avatar": null,
+            }],
+        }))
+        .unwrap();
+        assert!(private.private().is_some());
+
+        assert!(from_value::<Channel>(json!({"id": "5", "type": 200})).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn stage_instance_topic_length() {
+        let instance: StageInstance = from_value(json!({
+            "id": "1",
+            "guild_id": "2",
+            "channel_id": "3",
+            "topic": "topic",
+            "privacy_level": 2,
+            "guild_scheduled_event_id": null,
+        }))
+        .unwrap();
+        assert_eq!(instance.privacy_level, StageInstancePrivacyLevel::GuildOnly);
+
+        let topic = |topic: &str| {
+            let mut map = JsonMap::new();
+            map.insert("topic".to_string(), Value::from(topic));
+            map
+        };
+
+        assert!(StageInstance::check_topic_length(&topic("topic"), true).is_ok());
+        assert!(StageInstance::check_topic_length(&topic(""), false).is_err());
+        assert!(StageInstance::check_topic_length(&topic(&"a".repeat(121)), true).is_err());
+        assert!(StageInstance::check_topic_length(&JsonMap::new(), false).is_ok());
+        assert!(StageInstance::check_topic_length(&JsonMap::new(), true).is_err());
+    }
+
     #[cfg(all(feature = "model", feature = "utils"))]
     mod model_utils {
         use crate::model::prelude::*;
@@ -563,9 +714,11 @@ mod test {
                     bot: false,
                     discriminator: 1,
                     name: "ab".to_string(),
+                    global_name: None,
                     public_flags: None,
                     banner: None,
                     accent_colour: None,
+                    avatar_decoration_data: None,
                     member: None,
                 },
             }
@@ -601,6 +754,197 @@ mod test {
             assert!(!private_channel.is_nsfw());
         }
     }
+
+    mod message {
+        use crate::json::{from_value, json};
+        use crate::model::prelude::*;
+
+        #[test]
+        fn message_reference_kind() {
+            let reply: MessageReference =
+                from_value(json!({"channel_id": "1", "message_id": "2"})).unwrap();
+            assert_eq!(reply.kind, MessageReferenceKind::Default);
+
+            let forward: MessageReference =
+                from_value(json!({"type": 1, "channel_id": "1", "message_id": "2"})).unwrap();
+            assert_eq!(forward.kind, MessageReferenceKind::Forward);
+        }
+
+        #[test]
+        fn message_snapshot_partial() {
+            let snapshot: MessageSnapshot = from_value(json!({
+                "message": {
+                    "type": 0,
+                    "content": "forwarded",
+                    "attachments": [],
+                    "timestamp": "2024-07-01T12:00:00.000000+00:00",
+                    "edited_timestamp": null,
+                    "flags": 0
+                }
+            }))
+            .unwrap();
+            assert_eq!(snapshot.message.content, "forwarded");
+            assert!(snapshot.message.embeds.is_empty());
+            assert!(snapshot.message.timestamp.is_some());
+
+            let snapshot: MessageSnapshot = from_value(json!({"message": {}})).unwrap();
+            assert_eq!(snapshot.message.kind, MessageType::Regular);
+            assert!(snapshot.message.content.is_empty());
+            assert!(snapshot.message.timestamp.is_none());
+        }
+
+        #[test]
+        #[cfg(all(feature = "builder", feature = "model"))]
+        fn forward_with_content() {
+            use crate::builder::CreateMessage;
+            use crate::json::hashmap_to_json_map;
+
+            let mut builder = CreateMessage::default();
+            builder.forward(ChannelId(1), MessageId(2));
+
+            let map = hashmap_to_json_map(builder.0.clone());
+            assert!(Message::check_forward(&map, false).is_ok());
+            assert!(Message::check_forward(&map, true).is_err());
+
+            builder.content("forwarded");
+            let map = hashmap_to_json_map(builder.0.clone());
+            assert!(Message::check_forward(&map, false).is_err());
+
+            let mut builder = CreateMessage::default();
+            builder.content("reply").reference_message((ChannelId(1), MessageId(2)));
+
+            let map = hashmap_to_json_map(builder.0);
+            assert!(Message::check_forward(&map, true).is_ok());
+        }
+
+        #[test]
+        #[cfg(all(feature = "builder", feature = "model", feature = "utils"))]
+        fn skip_embed_validation() {
+            use crate::builder::{CreateEmbed, CreateMessage};
+            use crate::json::hashmap_to_json_map;
+
+            let mut embed = CreateEmbed::default();
+            embed.title("t".repeat(257));
+
+            let mut builder = CreateMessage::default();
+            builder.add_embed(|e| {
+                *e = embed.clone();
+                e
+            });
+
+            let mut map = hashmap_to_json_map(builder.0.clone());
+            assert!(Message::check_embeds(&mut map).is_err());
+
+            builder.skip_embed_validation(true);
+            let mut map = hashmap_to_json_map(builder.0.clone());
+            assert!(Message::check_embeds(&mut map).is_ok());
+            assert!(!map.contains_key("skip_embed_validation"));
+
+            builder.add_embeds(vec![embed; 10]);
+            let mut map = hashmap_to_json_map(builder.0);
+            assert!(Message::check_embeds(&mut map).is_err());
+        }
+
+        #[test]
+        #[cfg(all(feature = "builder", feature = "model"))]
+        fn components_v2() {
+            use crate::builder::{CreateMediaGalleryItem, CreateMessage, CreateTextDisplay};
+            use crate::json::hashmap_to_json_map;
+            use crate::model::application::component::{ActionRow, ComponentType};
+
+            let item = CreateMediaGalleryItem::new("https://example.com/1.png");
+
+            let mut builder = CreateMessage::default();
+            builder.flags(MessageFlags::SUPPRESS_NOTIFICATIONS).components_v2(|c| {
+                c.create_container(|container| {
+                    container
+                        .add_text_display(CreateTextDisplay::new("text"))
+                        .create_media_gallery(|gallery| gallery.add_item(item))
+                })
+            });
+
+            let map = hashmap_to_json_map(builder.0.clone());
+            assert!(Message::check_components_v2(&map, false).is_ok());
+            assert_eq!(
+                map["flags"].as_u64(),
+                Some((MessageFlags::SUPPRESS_NOTIFICATIONS | MessageFlags::IS_COMPONENTS_V2).bits())
+            );
+
+            let container: ActionRow = from_value(map["components"][0].clone()).unwrap();
+            assert_eq!(container.kind, ComponentType::Container);
+            assert_eq!(container.components.len(), 2);
+
+            builder.content("content");
+            let map = hashmap_to_json_map(builder.0.clone());
+            assert!(Message::check_components_v2(&map, false).is_err());
+
+            builder.0.remove("content");
+            builder.flags(MessageFlags::empty());
+            let map = hashmap_to_json_map(builder.0.clone());
+            assert!(Message::check_components_v2(&map, false).is_err());
+            assert!(Message::check_components_v2(&map, true).is_err());
+
+            // An edit without flags keeps those of the message.
+            builder.0.remove("flags");
+            let map = hashmap_to_json_map(builder.0);
+            assert!(Message::check_components_v2(&map, false).is_err());
+            assert!(Message::check_components_v2(&map, true).is_ok());
+        }
+
+        #[test]
+        #[cfg(all(feature = "builder", feature = "model"))]
+        fn components_v2_limits() {
+            use crate::builder::{
+                CreateComponents,
+                CreateInteractionResponse,
+                CreateMediaGalleryItem,
+                CreateTextDisplay,
+                EditMessage,
+            };
+            use crate::json::hashmap_to_json_map;
+            use crate::model::application::interaction::MessageFlags as ResponseFlags;
+            use crate::model::ModelError;
+            use crate::Error;
+
+            let item = CreateMediaGalleryItem::new("https://example.com/1.png");
+
+            let mut components = CreateComponents::default();
+            components.create_container(|container| {
+                container.create_media_gallery(|gallery| {
+                    for _ in 0..11 {
+                        gallery.add_item(item.clone());
+                    }
+                    gallery
+                })
+            });
+
+            let mut builder = EditMessage::default();
+            builder.set_components(components);
+            let map = hashmap_to_json_map(builder.0);
+            assert!(matches!(
+                Message::check_components_v2(&map, true),
+                Err(Error::Model(ModelError::MediaGalleryItemAmount))
+            ));
+
+            let mut components = CreateComponents::default();
+            components.create_section(|section| {
+                for _ in 0..4 {
+                    section.add_text_display(CreateTextDisplay::new("text"));
+                }
+                section
+            });
+
+            let mut response = CreateInteractionResponse::default();
+            response.interaction_response_data(|data| {
+                data.flags(ResponseFlags::IS_COMPONENTS_V2).set_components(components)
+            });
+            let map = hashmap_to_json_map(response.0);
+            assert!(matches!(
+                Message::check_response_components_v2(&map),
+                Err(Error::Model(ModelError::SectionTextDisplayAmount))
+            ));
+        }
+    }
 }
 
 #[cfg(all(feature = "cache", feature = "model", feature = "utils"))]
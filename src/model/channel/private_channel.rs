@@ -1,3 +1,5 @@
+#[cfg(feature = "model")]
+use std::collections::HashMap;
 use std::fmt;
 #[cfg(feature = "model")]
 use std::sync::Arc;
@@ -5,7 +7,7 @@ use std::sync::Arc;
 #[cfg(feature = "model")]
 use crate::builder::{CreateMessage, EditMessage, GetMessages};
 #[cfg(feature = "http")]
-use crate::http::{Http, Typing};
+use crate::http::{CacheHttp, Http, Typing};
 #[cfg(feature = "model")]
 use crate::model::channel::AttachmentType;
 use crate::model::prelude::*;
@@ -196,13 +198,31 @@ impl PrivateChannel {
         self.id.message(&http, message_id).await
     }
 
+    /// Gets several messages from the channel by their Ids, keyed by Id.
+    ///
+    /// Refer to [`ChannelId::messages_by_ids`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if a request fails.
+    #[inline]
+    pub async fn messages_by_ids(
+        &self,
+        cache_http: impl CacheHttp,
+        message_ids: &[MessageId],
+    ) -> Result<HashMap<MessageId, Message>> {
+        self.id.messages_by_ids(cache_http, message_ids).await
+    }
+
     /// Gets messages from the channel.
     ///
     /// Refer to [`GetMessages`] for more information on how to use `builder`.
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Http`] if an invalid value is set in the builder.
+    /// Returns [`Error::Http`] if the request fails, or a [`ModelError`] if
+    /// the builder is invalid. Refer to [`ChannelId::messages`] for more
+    /// information.
     #[inline]
     pub async fn messages<F>(&self, http: impl AsRef<Http>, builder: F) -> Result<Vec<Message>>
     where
@@ -549,13 +549,6 @@ impl TryFrom<String> for ReactionType {
     type Error = ReactionConversionError;
 
     fn try_from(emoji_string: String) -> std::result::Result<Self, Self::Error> {
-        if emoji_string.is_empty() {
-            return Err(ReactionConversionError);
-        }
-
-        if !emoji_string.starts_with('<') {
-            return Ok(ReactionType::Unicode(emoji_string));
-        }
         ReactionType::try_from(&emoji_string[..])
     }
 }
@@ -563,6 +556,11 @@ impl TryFrom<String> for ReactionType {
 impl<'a> TryFrom<&'a str> for ReactionType {
     /// Creates a [`ReactionType`] from a string slice.
     ///
+    /// Accepts either a unicode emoji, a custom emoji mention such as
+    /// `<:name:123>` or `<a:name:123>`, a custom emoji in the `name:123` form
+    /// used by reaction URLs, or a bare custom emoji Id such as `123`, in which
+    /// case the name is unknown.
+    ///
     /// # Examples
     ///
     /// Creating a [`ReactionType`] from a `🍎`, modeling a similar API as the
@@ -606,36 +604,54 @@ impl<'a> TryFrom<&'a str> for ReactionType {
     type Error = ReactionConversionError;
 
     fn try_from(emoji_str: &str) -> std::result::Result<Self, Self::Error> {
+        let emoji_str = emoji_str.trim();
+
         if emoji_str.is_empty() {
             return Err(ReactionConversionError);
         }
 
-        if !emoji_str.starts_with('<') {
-            return Ok(ReactionType::Unicode(emoji_str.to_string()));
-        }
+        // A bare custom emoji Id, as no unicode emoji consists of only digits.
+        if emoji_str.bytes().all(|b| b.is_ascii_digit()) {
+            let id = emoji_str.parse::<u64>().map_err(|_| ReactionConversionError)?;
 
-        if !emoji_str.ends_with('>') {
-            return Err(ReactionConversionError);
+            return Ok(ReactionType::Custom {
+                animated: false,
+                id: EmojiId(id),
+                name: None,
+            });
         }
 
-        let emoji_str = emoji_str.trim_matches(&['<', '>'] as &[char]);
-
-        let mut split_iter = emoji_str.split(':');
-
-        let animated = split_iter.next().ok_or(ReactionConversionError)? == "a";
-
-        let name = split_iter.next().ok_or(ReactionConversionError)?.to_string().into();
+        let (inner, is_mention) = match emoji_str.strip_prefix('<') {
+            Some(mention) => (mention.strip_suffix('>').ok_or(ReactionConversionError)?, true),
+            // Unicode emoji never contain a colon, unlike the `name:id` form used in reaction
+            // URLs.
+            None if !emoji_str.contains(':') => {
+                return Ok(ReactionType::Unicode(emoji_str.to_string()));
+            },
+            None => (emoji_str, false),
+        };
 
+        let mut split_iter = inner.rsplit(':');
         let id = split_iter
             .next()
             .and_then(|s| s.parse::<u64>().ok())
-            .ok_or(ReactionConversionError)?
-            .into();
+            .ok_or(ReactionConversionError)?;
+        let name = split_iter.next().ok_or(ReactionConversionError)?;
+        let animated = match split_iter.next() {
+            Some("a") => true,
+            Some("") => false,
+            None if !is_mention => false,
+            _ => return Err(ReactionConversionError),
+        };
+
+        if split_iter.next().is_some() {
+            return Err(ReactionConversionError);
+        }
 
         Ok(ReactionType::Custom {
             animated,
-            id,
-            name,
+            id: EmojiId(id),
+            name: (!name.is_empty()).then(|| name.to_string()),
         })
     }
 }
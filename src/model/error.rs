@@ -3,7 +3,9 @@
 use std::error::Error as StdError;
 use std::fmt;
 
+use super::misc::ImageFormat;
 use super::Permissions;
+use crate::builder::EmbedError;
 
 /// An error returned from the [`model`] module.
 ///
@@ -68,11 +70,36 @@ pub enum Error {
     /// When attempting to delete a number of days' worth of messages that is
     /// not allowed.
     DeleteMessageDaysAmount(u8),
+    /// When attempting to delete a number of seconds' worth of messages that is
+    /// over 604800 (7 days).
+    DeleteMessageSecondsAmount(u32),
+    /// When attempting to search for a number of guild members that is not
+    /// allowed.
+    MemberSearchLimit(u64),
+    /// When attempting to fetch a number of messages that is not between 1 and
+    /// 100.
+    ///
+    /// The invalid limit is provided.
+    MessageFetchLimit(u64),
+    /// When attempting to fetch messages relative to more than one of the
+    /// [`GetMessages::after`], [`GetMessages::around`] and
+    /// [`GetMessages::before`] messages at once.
+    ///
+    /// [`GetMessages::after`]: crate::builder::GetMessages::after
+    /// [`GetMessages::around`]: crate::builder::GetMessages::around
+    /// [`GetMessages::before`]: crate::builder::GetMessages::before
+    MultipleMessageAnchors,
     /// When attempting to send a message with over 10 embeds.
     EmbedAmount,
     /// Indicates that the textual content of an embed exceeds the maximum
     /// length.
     EmbedTooLarge(usize),
+    /// Indicates that a part of an embed, such as its title or one of its fields, exceeds its
+    /// limit.
+    ///
+    /// The total length of an embed being exceeded is reported as [`Self::EmbedTooLarge`]
+    /// instead.
+    InvalidEmbed(EmbedError),
     /// An indication that a [guild][`Guild`] could not be found by
     /// [Id][`GuildId`] in the [`Cache`].
     ///
@@ -140,6 +167,16 @@ pub enum Error {
     ///
     /// [`Guild`]: super::guild::Guild
     WrongGuild,
+    /// Indicates that the current user attempted to leave a [`Guild`] it owns, which Discord
+    /// does not allow. Ownership must be transferred, or the guild deleted, instead.
+    ///
+    /// [`Guild`]: super::guild::Guild
+    OwnerCannotLeave,
+    /// Indicates that the `COMMUNITY` feature of a [`Guild`] was being enabled without the guild
+    /// having both a rules channel and a public updates channel.
+    ///
+    /// [`Guild`]: super::guild::Guild
+    MissingCommunityChannels,
     /// Indicates that a [`Message`]s content was too long and will not
     /// successfully send, as the length is over 2000 codepoints.
     ///
@@ -147,13 +184,80 @@ pub enum Error {
     ///
     /// [`Message`]: super::channel::Message
     MessageTooLong(usize),
+    /// Indicates that a [`Message`] could not be pinned, as the channel already has the
+    /// maximum of 50 pinned messages.
+    ///
+    /// [`Message`]: super::channel::Message
+    TooManyPins,
+    /// Indicates that a reaction could not be added to a [`Message`], usually
+    /// because the author of the message blocked the current user.
+    ///
+    /// [`Message`]: super::channel::Message
+    ReactionBlocked,
+    /// Indicates that the current user's username could not be changed, as it
+    /// was changed too many times recently.
+    UsernameRateLimited,
     /// Indicates that the current user is attempting to Direct Message another
     /// bot user, which is disallowed by the API.
     MessagingBot,
+    /// Indicates that a direct message could not be sent to a user, as they have
+    /// direct messages from the current user disabled, or share no guild with it.
+    DirectMessagesClosed,
+    /// Indicates that a message with the [`MessageFlags::IS_COMPONENTS_V2`] flag also has
+    /// content, embeds, or stickers, which is not allowed.
+    ///
+    /// [`MessageFlags::IS_COMPONENTS_V2`]: super::channel::MessageFlags::IS_COMPONENTS_V2
+    ComponentsV2WithContent,
+    /// Indicates that a message has Components V2 components, such as containers, without
+    /// the [`MessageFlags::IS_COMPONENTS_V2`] flag.
+    ///
+    /// [`MessageFlags::IS_COMPONENTS_V2`]: super::channel::MessageFlags::IS_COMPONENTS_V2
+    ComponentsV2FlagMissing,
+    /// Indicates that a media gallery component has more than 10 items.
+    MediaGalleryItemAmount,
+    /// Indicates that a section component has more than 3 text displays.
+    SectionTextDisplayAmount,
+    /// Indicates that a guild's preview could not be retrieved, as the current
+    /// user is not a member of the guild and it is not discoverable.
+    GuildPreviewUnavailable,
     /// An indicator that the [`ChannelType`] cannot perform an action.
     ///
     /// [`ChannelType`]: super::channel::ChannelType
     InvalidChannelType,
+    /// Indicates that an interaction's token expired before its response could
+    /// be edited, as tokens are only valid for 15 minutes.
+    InteractionTokenExpired,
+    /// Indicates that a soundboard sound file is not a base64 encoded MP3 or OGG file.
+    InvalidSoundFile,
+    /// Indicates that a [`RoleConnectionMetadata`] key is not 1-50 characters
+    /// long or contains characters other than `a-z`, `0-9`, and `_`.
+    ///
+    /// The invalid key is provided.
+    ///
+    /// [`RoleConnectionMetadata`]: crate::model::application::RoleConnectionMetadata
+    InvalidRoleConnectionMetadataKey(String),
+    /// Indicates that a soundboard sound file is over the 512 KiB size limit.
+    ///
+    /// The number of bytes larger than the limit is provided.
+    SoundFileTooLarge(usize),
+    /// Indicates that an application has more than 5 tags.
+    ApplicationTagAmount,
+    /// Indicates that an application tag is over the 20 character limit.
+    ///
+    /// The number of code points larger than the limit is provided.
+    ApplicationTagTooLong(usize),
+    /// Indicates that a guild's welcome screen would show more than 5
+    /// channels.
+    WelcomeChannelAmount,
+    /// Indicates that an emoji image is not a PNG, JPEG, GIF, or WebP image.
+    InvalidEmojiImage,
+    /// Indicates that an emoji image is over the 256 KiB size limit.
+    ///
+    /// The number of bytes larger than the limit is provided.
+    EmojiImageTooLarge(usize),
+    /// Indicates that an emoji could not be created, as the guild has no emoji
+    /// slots left.
+    TooManyEmojis,
     /// Indicates that the webhook name is under the 2 characters limit.
     NameTooShort,
     /// Indicates that the webhook name is over the 100 characters limit.
@@ -170,6 +274,31 @@ pub enum Error {
     NoStickerFileSet,
     /// When attempting to send a message with over 3 stickers.
     StickerAmount,
+    /// Indicates that a message forwarding another message also has content,
+    /// embeds, files, stickers or components of its own, which is disallowed by
+    /// the API.
+    ForwardWithContent,
+    /// Indicates that a [`StageInstance`]'s topic is not 1-120 characters long.
+    ///
+    /// The length of the topic is provided.
+    ///
+    /// [`StageInstance`]: super::channel::StageInstance
+    StageInstanceTopicLength(usize),
+    /// Indicates that a CDN image size is not a power of two between 16 and
+    /// 4096.
+    ///
+    /// The invalid size is provided.
+    InvalidImageSize(u16),
+    /// Indicates that a CDN image was requested in a format it is not available
+    /// in, such as a GIF of an image that is not animated.
+    ///
+    /// The requested format is provided.
+    InvalidImageFormat(ImageFormat),
+    /// Indicates that a required option of an application command was not
+    /// provided.
+    ///
+    /// The name of the option is provided.
+    MissingCommandOption(String),
 }
 
 impl Error {
@@ -193,22 +322,62 @@ impl fmt::Display for Error {
         match self {
             Self::BulkDeleteAmount => f.write_str("Too few/many messages to bulk delete."),
             Self::DeleteMessageDaysAmount(_) => f.write_str("Invalid delete message days."),
+            Self::DeleteMessageSecondsAmount(_) => {
+                f.write_str("Invalid delete message seconds.")
+            },
+            Self::MemberSearchLimit(_) => f.write_str("Invalid member search limit."),
+            Self::MessageFetchLimit(_) => f.write_str("Invalid message fetch limit."),
+            Self::MultipleMessageAnchors => {
+                f.write_str("Only one of after, around and before may be set.")
+            },
             Self::EmbedAmount => f.write_str("Too many embeds in a message."),
             Self::EmbedTooLarge(_) => f.write_str("Embed too large."),
+            Self::InvalidEmbed(why) => fmt::Display::fmt(why, f),
             Self::GuildNotFound => f.write_str("Guild not found in the cache."),
             Self::RoleNotFound => f.write_str("Role not found in the cache."),
             Self::MemberNotFound => f.write_str("Member not found in the cache."),
             Self::ChannelNotFound => f.write_str("Channel not found in the cache."),
             Self::Hierarchy => f.write_str("Role hierarchy prevents this action."),
             Self::InvalidChannelType => f.write_str("The channel cannot perform the action."),
+            Self::InteractionTokenExpired => f.write_str("The interaction token has expired."),
+            Self::InvalidSoundFile => f.write_str("Sound file is not an MP3 or OGG file."),
+            Self::SoundFileTooLarge(_) => f.write_str("Sound file is too large."),
+            Self::ApplicationTagAmount => f.write_str("Too many application tags."),
+            Self::ApplicationTagTooLong(_) => f.write_str("Application tag too long."),
+            Self::WelcomeChannelAmount => f.write_str("Too many welcome screen channels."),
+            Self::InvalidEmojiImage => f.write_str("Emoji image is not a PNG, JPEG, GIF, or WebP."),
+            Self::EmojiImageTooLarge(_) => f.write_str("Emoji image is too large."),
+            Self::TooManyEmojis => f.write_str("The guild has reached the emoji limit."),
+            Self::InvalidRoleConnectionMetadataKey(_) => {
+                f.write_str("Role connection metadata key is invalid.")
+            },
             Self::InvalidPermissions(_) => f.write_str("Invalid permissions."),
             Self::InvalidUser => f.write_str("The current user cannot perform the action."),
             Self::ItemMissing => f.write_str("The required item is missing from the cache."),
             Self::WrongGuild => f.write_str("Provided member or channel is from the wrong guild."),
+            Self::OwnerCannotLeave => f.write_str("The owner of a guild cannot leave it."),
+            Self::MissingCommunityChannels => {
+                f.write_str("Community guilds require a rules and a public updates channel.")
+            },
             Self::MessageTooLong(_) => f.write_str("Message too large."),
+            Self::TooManyPins => f.write_str("The channel has reached the pinned message limit."),
+            Self::ReactionBlocked => f.write_str("The reaction was blocked."),
+            Self::UsernameRateLimited => f.write_str("The username was changed too recently."),
             Self::MessageAlreadyCrossposted => f.write_str("Message already crossposted."),
             Self::CannotCrosspostMessage => f.write_str("Cannot crosspost this message type."),
             Self::MessagingBot => f.write_str("Attempted to message another bot user."),
+            Self::DirectMessagesClosed => f.write_str("The user does not accept direct messages."),
+            Self::ComponentsV2WithContent => {
+                f.write_str("Components V2 messages cannot have content, embeds, or stickers.")
+            },
+            Self::ComponentsV2FlagMissing => {
+                f.write_str("Components V2 components require the IS_COMPONENTS_V2 flag.")
+            },
+            Self::MediaGalleryItemAmount => f.write_str("Too many items in a media gallery."),
+            Self::SectionTextDisplayAmount => f.write_str("Too many text displays in a section."),
+            Self::GuildPreviewUnavailable => {
+                f.write_str("The guild preview is only available to members or if discoverable.")
+            },
             Self::NameTooShort => f.write_str("Name is under the character limit."),
             Self::NameTooLong => f.write_str("Name is over the character limit."),
             Self::NotAuthor => f.write_str("The bot is not author of this message."),
@@ -216,8 +385,26 @@ impl fmt::Display for Error {
             Self::DeleteNitroSticker => f.write_str("Cannot delete an official sticker."),
             Self::NoStickerFileSet => f.write_str("Sticker file is not set."),
             Self::StickerAmount => f.write_str("Too many stickers in a message."),
+            Self::ForwardWithContent => f.write_str("Forwarded messages cannot have content."),
+            Self::MissingCommandOption(_) => f.write_str("A required command option is missing."),
+            Self::StageInstanceTopicLength(_) => {
+                f.write_str("Stage instance topic must be 1-120 characters long.")
+            },
+            Self::InvalidImageSize(_) => {
+                f.write_str("Image size must be a power of two between 16 and 4096.")
+            },
+            Self::InvalidImageFormat(_) => f.write_str("Image is not available in that format."),
         }
     }
 }
 
 impl StdError for Error {}
+
+impl From<EmbedError> for Error {
+    fn from(error: EmbedError) -> Self {
+        match error {
+            EmbedError::TooLarge(overflow) => Self::EmbedTooLarge(overflow),
+            error => Self::InvalidEmbed(error),
+        }
+    }
+}
@@ -14,6 +14,7 @@ use crate::internal::prelude::*;
 use crate::json::prelude::*;
 use crate::model::application::command::CommandPermission;
 use crate::model::application::interaction::Interaction;
+use crate::model::guild::audit_log::AuditLogEntry;
 use crate::model::guild::automod::{ActionExecution, Rule};
 
 /// [Discord docs](https://discord.com/developers/docs/topics/gateway#application-command-permissions-update).
@@ -97,6 +98,46 @@ pub struct ChannelUpdateEvent {
     pub channel: Channel,
 }
 
+/// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#entitlement-create).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct EntitlementCreateEvent {
+    pub entitlement: Entitlement,
+}
+
+/// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#entitlement-update).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct EntitlementUpdateEvent {
+    pub entitlement: Entitlement,
+}
+
+/// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#entitlement-delete).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(transparent)]
+#[non_exhaustive]
+pub struct EntitlementDeleteEvent {
+    pub entitlement: Entitlement,
+}
+
+/// Event data for the guild audit log entry creation event.
+///
+/// This is fired when an entry is added to a guild's audit log, and requires
+/// the [`VIEW_AUDIT_LOG`] permission.
+///
+/// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#guild-audit-log-entry-create).
+///
+/// [`VIEW_AUDIT_LOG`]: Permissions::VIEW_AUDIT_LOG
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildAuditLogEntryCreateEvent {
+    pub guild_id: GuildId,
+    #[serde(flatten)]
+    pub entry: AuditLogEntry,
+}
+
 /// [Discord docs](https://discord.com/developers/docs/topics/gateway#guild-ban-add).
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
@@ -428,6 +469,7 @@ pub struct MessageUpdateEvent {
     #[deprecated(note = "deprecated by Discord")]
     pub stickers: Option<Vec<StickerItem>>,
     pub sticker_items: Option<Vec<StickerItem>>,
+    pub message_snapshots: Option<Vec<MessageSnapshot>>,
 
     pub guild_id: Option<GuildId>, // TODO: Is this a Message field that can even change?
 }
@@ -876,6 +918,31 @@ pub enum Event {
     ///
     /// [`EventHandler::channel_update`]: crate::client::EventHandler::channel_update
     ChannelUpdate(ChannelUpdateEvent),
+    /// A user has subscribed to or purchased a SKU of the application.
+    ///
+    /// Fires the [`EventHandler::entitlement_create`] event.
+    ///
+    /// [`EventHandler::entitlement_create`]: crate::client::EventHandler::entitlement_create
+    EntitlementCreate(EntitlementCreateEvent),
+    /// An [`Entitlement`] has been updated, such as when a subscription renews.
+    ///
+    /// Fires the [`EventHandler::entitlement_update`] event.
+    ///
+    /// [`EventHandler::entitlement_update`]: crate::client::EventHandler::entitlement_update
+    EntitlementUpdate(EntitlementUpdateEvent),
+    /// An [`Entitlement`] has been deleted.
+    ///
+    /// Fires the [`EventHandler::entitlement_delete`] event.
+    ///
+    /// [`EventHandler::entitlement_delete`]: crate::client::EventHandler::entitlement_delete
+    EntitlementDelete(EntitlementDeleteEvent),
+    /// An entry was added to a [`Guild`]'s audit log.
+    ///
+    /// Fires the [`EventHandler::guild_audit_log_entry_create`] event.
+    ///
+    /// [`EventHandler::guild_audit_log_entry_create`]:
+    /// crate::client::EventHandler::guild_audit_log_entry_create
+    GuildAuditLogEntryCreate(GuildAuditLogEntryCreateEvent),
     GuildBanAdd(GuildBanAddEvent),
     GuildBanRemove(GuildBanRemoveEvent),
     GuildCreate(GuildCreateEvent),
@@ -1059,6 +1126,30 @@ macro_rules! with_related_ids_for_event_types {
                 channel_id: Some(e.channel.id()),
                 message_id: Never,
             },
+            Self::EntitlementCreate, Self::EntitlementCreate(e) => {
+                user_id: e.entitlement.user_id.into(),
+                guild_id: e.entitlement.guild_id.into(),
+                channel_id: Never,
+                message_id: Never,
+            },
+            Self::EntitlementUpdate, Self::EntitlementUpdate(e) => {
+                user_id: e.entitlement.user_id.into(),
+                guild_id: e.entitlement.guild_id.into(),
+                channel_id: Never,
+                message_id: Never,
+            },
+            Self::EntitlementDelete, Self::EntitlementDelete(e) => {
+                user_id: e.entitlement.user_id.into(),
+                guild_id: e.entitlement.guild_id.into(),
+                channel_id: Never,
+                message_id: Never,
+            },
+            Self::GuildAuditLogEntryCreate, Self::GuildAuditLogEntryCreate(e) => {
+                user_id: Some(e.entry.user_id),
+                guild_id: Some(e.guild_id),
+                channel_id: Never,
+                message_id: Never,
+            },
             Self::GuildBanAdd, Self::GuildBanAdd(e) => {
                 user_id: Some(e.user.id),
                 guild_id: Some(e.guild_id),
@@ -1490,6 +1581,10 @@ impl Event {
             Self::ChannelDelete(_) => EventType::ChannelDelete,
             Self::ChannelPinsUpdate(_) => EventType::ChannelPinsUpdate,
             Self::ChannelUpdate(_) => EventType::ChannelUpdate,
+            Self::EntitlementCreate(_) => EventType::EntitlementCreate,
+            Self::EntitlementUpdate(_) => EventType::EntitlementUpdate,
+            Self::EntitlementDelete(_) => EventType::EntitlementDelete,
+            Self::GuildAuditLogEntryCreate(_) => EventType::GuildAuditLogEntryCreate,
             Self::GuildBanAdd(_) => EventType::GuildBanAdd,
             Self::GuildBanRemove(_) => EventType::GuildBanRemove,
             Self::GuildCreate(_) => EventType::GuildCreate,
@@ -1627,6 +1722,10 @@ pub fn deserialize_event_with_type(kind: EventType, v: Value) -> Result<Event> {
         EventType::ChannelDelete => Event::ChannelDelete(from_value(v)?),
         EventType::ChannelPinsUpdate => Event::ChannelPinsUpdate(from_value(v)?),
         EventType::ChannelUpdate => Event::ChannelUpdate(from_value(v)?),
+        EventType::EntitlementCreate => Event::EntitlementCreate(from_value(v)?),
+        EventType::EntitlementUpdate => Event::EntitlementUpdate(from_value(v)?),
+        EventType::EntitlementDelete => Event::EntitlementDelete(from_value(v)?),
+        EventType::GuildAuditLogEntryCreate => Event::GuildAuditLogEntryCreate(from_value(v)?),
         EventType::GuildBanAdd => Event::GuildBanAdd(from_value(v)?),
         EventType::GuildBanRemove => Event::GuildBanRemove(from_value(v)?),
         EventType::GuildCreate | EventType::GuildUnavailable => {
@@ -1750,6 +1849,22 @@ pub enum EventType {
     ///
     /// This maps to [`ChannelUpdateEvent`].
     ChannelUpdate,
+    /// Indicator that an entitlement creation payload was received.
+    ///
+    /// This maps to [`EntitlementCreateEvent`].
+    EntitlementCreate,
+    /// Indicator that an entitlement update payload was received.
+    ///
+    /// This maps to [`EntitlementUpdateEvent`].
+    EntitlementUpdate,
+    /// Indicator that an entitlement deletion payload was received.
+    ///
+    /// This maps to [`EntitlementDeleteEvent`].
+    EntitlementDelete,
+    /// Indicator that a guild audit log entry creation payload was received.
+    ///
+    /// This maps to [`GuildAuditLogEntryCreateEvent`].
+    GuildAuditLogEntryCreate,
     /// Indicator that a guild ban addition payload was received.
     ///
     /// This maps to [`GuildBanAddEvent`].
@@ -2034,6 +2149,10 @@ impl EventType {
     const CHANNEL_DELETE: &'static str = "CHANNEL_DELETE";
     const CHANNEL_PINS_UPDATE: &'static str = "CHANNEL_PINS_UPDATE";
     const CHANNEL_UPDATE: &'static str = "CHANNEL_UPDATE";
+    const ENTITLEMENT_CREATE: &'static str = "ENTITLEMENT_CREATE";
+    const ENTITLEMENT_UPDATE: &'static str = "ENTITLEMENT_UPDATE";
+    const ENTITLEMENT_DELETE: &'static str = "ENTITLEMENT_DELETE";
+    const GUILD_AUDIT_LOG_ENTRY_CREATE: &'static str = "GUILD_AUDIT_LOG_ENTRY_CREATE";
     const GUILD_BAN_ADD: &'static str = "GUILD_BAN_ADD";
     const GUILD_BAN_REMOVE: &'static str = "GUILD_BAN_REMOVE";
     const GUILD_CREATE: &'static str = "GUILD_CREATE";
@@ -2103,6 +2222,10 @@ impl EventType {
             Self::ChannelDelete => Some(Self::CHANNEL_DELETE),
             Self::ChannelPinsUpdate => Some(Self::CHANNEL_PINS_UPDATE),
             Self::ChannelUpdate => Some(Self::CHANNEL_UPDATE),
+            Self::EntitlementCreate => Some(Self::ENTITLEMENT_CREATE),
+            Self::EntitlementUpdate => Some(Self::ENTITLEMENT_UPDATE),
+            Self::EntitlementDelete => Some(Self::ENTITLEMENT_DELETE),
+            Self::GuildAuditLogEntryCreate => Some(Self::GUILD_AUDIT_LOG_ENTRY_CREATE),
             Self::GuildBanAdd => Some(Self::GUILD_BAN_ADD),
             Self::GuildBanRemove => Some(Self::GUILD_BAN_REMOVE),
             Self::GuildCreate => Some(Self::GUILD_CREATE),
@@ -2197,6 +2320,10 @@ impl<'de> Deserialize<'de> for EventType {
                     EventType::CHANNEL_DELETE => EventType::ChannelDelete,
                     EventType::CHANNEL_PINS_UPDATE => EventType::ChannelPinsUpdate,
                     EventType::CHANNEL_UPDATE => EventType::ChannelUpdate,
+                    EventType::ENTITLEMENT_CREATE => EventType::EntitlementCreate,
+                    EventType::ENTITLEMENT_UPDATE => EventType::EntitlementUpdate,
+                    EventType::ENTITLEMENT_DELETE => EventType::EntitlementDelete,
+                    EventType::GUILD_AUDIT_LOG_ENTRY_CREATE => EventType::GuildAuditLogEntryCreate,
                     EventType::GUILD_BAN_ADD => EventType::GuildBanAdd,
                     EventType::GUILD_BAN_REMOVE => EventType::GuildBanRemove,
                     EventType::GUILD_CREATE => EventType::GuildCreate,
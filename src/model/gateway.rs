@@ -438,6 +438,7 @@ pub struct PresenceUser {
     pub mfa_enabled: Option<bool>,
     #[serde(rename = "username")]
     pub name: Option<String>,
+    pub global_name: Option<String>,
     pub verified: Option<bool>,
     pub public_flags: Option<UserPublicFlags>,
 }
@@ -454,9 +455,11 @@ impl PresenceUser {
             discriminator: self.discriminator?,
             id: self.id,
             name: self.name?,
+            global_name: self.global_name,
             public_flags: self.public_flags,
             banner: None,
             accent_colour: None,
+            avatar_decoration_data: None,
             member: None,
         })
     }
@@ -474,9 +477,11 @@ impl PresenceUser {
             discriminator: self.discriminator?,
             id: self.id,
             name: self.name.clone()?,
+            global_name: self.global_name.clone(),
             public_flags: self.public_flags,
             banner: None,
             accent_colour: None,
+            avatar_decoration_data: None,
             member: None,
         })
     }
@@ -490,6 +495,9 @@ impl PresenceUser {
         self.bot = Some(user.bot);
         self.discriminator = Some(user.discriminator);
         self.name = Some(user.name);
+        if let Some(global_name) = user.global_name {
+            self.global_name = Some(global_name);
+        }
         if let Some(public_flags) = user.public_flags {
             self.public_flags = Some(public_flags);
         }
@@ -630,6 +638,7 @@ bitflags! {
         const GUILD_MEMBERS = 1 << 1;
         /// Enables following gateway events:
         ///
+        /// - GUILD_AUDIT_LOG_ENTRY_CREATE
         /// - GUILD_BAN_ADD
         /// - GUILD_BAN_REMOVE
         const GUILD_BANS = 1 << 2;
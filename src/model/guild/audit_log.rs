@@ -12,6 +12,8 @@ mod utils;
 pub use change::{AffectedRole, Change, EntityType};
 use utils::{optional_string, users, webhooks};
 
+use crate::model::application::command::Command;
+use crate::model::guild::automod::Rule;
 use crate::model::prelude::*;
 
 /// Determines the action that was done on a target.
@@ -286,19 +288,53 @@ pub enum AutoModerationAction {
 }
 
 /// [Discord docs](https://discord.com/developers/docs/resources/audit-log#audit-log-object).
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct AuditLogs {
     #[serde(rename = "audit_log_entries")]
     pub entries: Vec<AuditLogEntry>,
+    /// The application commands referenced in the entries.
+    #[serde(default)]
+    pub application_commands: Vec<Command>,
+    /// The auto moderation rules referenced in the entries.
+    #[serde(default)]
+    pub auto_moderation_rules: Vec<Rule>,
+    /// The scheduled events referenced in the entries.
+    #[serde(default)]
+    pub guild_scheduled_events: Vec<ScheduledEvent>,
+    /// The integrations referenced in the entries.
+    #[serde(default)]
+    pub integrations: Vec<PartialIntegration>,
+    /// The threads referenced in the entries.
+    #[serde(default)]
+    pub threads: Vec<GuildChannel>,
     #[serde(with = "users")]
     pub users: HashMap<UserId, User>,
     #[serde(with = "webhooks")]
     pub webhooks: HashMap<WebhookId, Webhook>,
 }
 
+/// A partial [`Integration`], as referenced in [`AuditLogs`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/audit-log#audit-log-object-example-partial-integration-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct PartialIntegration {
+    /// The Id of the integration.
+    pub id: IntegrationId,
+    /// The name of the integration.
+    pub name: String,
+    /// The type of integration, e.g. `twitch`, `youtube` or `discord`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// The account of the integration.
+    pub account: IntegrationAccount,
+    /// The Id of the application, for `discord` integrations.
+    pub application_id: Option<ApplicationId>,
+}
+
 /// [Discord docs](https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object).
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct AuditLogEntry {
     /// Determines to what entity an [`Self::action`] was used on.
@@ -320,7 +356,7 @@ pub struct AuditLogEntry {
 }
 
 /// [Discord docs](https://discord.com/developers/docs/resources/audit-log#audit-log-entry-object-optional-audit-entry-info).
-#[derive(Debug, Deserialize, Serialize)]
+#[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Options {
     /// Number of days after which inactive members were kicked.
@@ -437,4 +473,61 @@ mod tests {
 
         assert!(matches!(value.action, Action::Unknown(234)));
     }
+
+    #[test]
+    fn audit_logs_side_objects() {
+        use serde_json::json;
+
+        let value = json!({
+            "audit_log_entries": [],
+            "integrations": [{
+                "id": "1",
+                "name": "bot",
+                "type": "discord",
+                "account": {"id": "2", "name": "bot"},
+                "application_id": "2",
+            }],
+            "threads": [],
+            "users": [],
+            "webhooks": [],
+        });
+
+        let logs = serde_json::from_value::<AuditLogs>(value).unwrap();
+        assert_eq!(logs.integrations[0].id, IntegrationId(1));
+        assert_eq!(logs.integrations[0].application_id, Some(ApplicationId(2)));
+        assert!(logs.application_commands.is_empty());
+        assert!(logs.guild_scheduled_events.is_empty());
+    }
+
+    #[test]
+    fn entry_create_event() {
+        use serde_json::json;
+
+        use crate::model::event::GuildAuditLogEntryCreateEvent;
+
+        let value = json!({
+            "guild_id": "1",
+            "id": "2",
+            "user_id": "3",
+            "target_id": "4",
+            "action_type": 22,
+            "reason": "spam",
+            "changes": [{"key": "nick", "old_value": "a", "new_value": "b"}],
+        });
+
+        let event = serde_json::from_value::<GuildAuditLogEntryCreateEvent>(value).unwrap();
+        assert_eq!(event.guild_id, GuildId(1));
+        assert_eq!(event.entry.id, AuditLogEntryId(2));
+        assert_eq!(event.entry.user_id, UserId(3));
+        assert_eq!(event.entry.target_id, Some(4));
+        assert!(matches!(event.entry.action, Action::Member(MemberAction::BanAdd)));
+        assert_eq!(event.entry.reason.as_deref(), Some("spam"));
+        assert_eq!(
+            event.entry.changes,
+            Some(vec![Change::Nick {
+                old: Some("a".to_string()),
+                new: Some("b".to_string()),
+            }])
+        );
+    }
 }
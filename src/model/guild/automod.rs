@@ -4,6 +4,7 @@
 
 use std::borrow::Cow;
 use std::fmt;
+use std::ops::Range;
 use std::time::Duration;
 
 use serde::de::{Deserializer, Error, IgnoredAny, MapAccess};
@@ -82,10 +83,42 @@ impl From<EventType> for u8 {
 #[derive(Clone, Debug, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum Trigger {
-    Keyword(Vec<String>),
+    /// Checks if content contains words from a user defined list of keywords.
+    ///
+    /// Maximum of 1000 keywords, 10 regex patterns and 100 allow list entries.
+    Keyword {
+        /// Substrings which will be searched for in content.
+        ///
+        /// A keyword can be a phrase which contains multiple words. Wildcard
+        /// symbols (`*`) can be used to customize how each keyword will be
+        /// matched.
+        strings: Vec<String>,
+        /// Regular expression patterns which will be matched against content.
+        ///
+        /// Only Rust flavored regex is currently supported.
+        regex_patterns: Vec<String>,
+        /// Substrings which should not trigger the rule.
+        allow_list: Vec<String>,
+    },
     HarmfulLink,
     Spam,
-    KeywordPreset(Vec<KeywordPresetType>),
+    /// Checks if content contains words from internal pre-defined wordsets.
+    KeywordPreset {
+        /// The internally pre-defined wordsets which will be searched for in
+        /// content.
+        presets: Vec<KeywordPresetType>,
+        /// Substrings which should not trigger the rule.
+        ///
+        /// Maximum of 1000.
+        allow_list: Vec<String>,
+    },
+    /// Checks if content contains more unique mentions than allowed.
+    MentionSpam {
+        /// Total number of unique role and user mentions allowed per message.
+        ///
+        /// Maximum of 50.
+        mention_total_limit: u8,
+    },
     Unknown(u8),
 }
 
@@ -102,32 +135,51 @@ struct InterimTrigger<'a> {
 /// Helper struct for the (de)serialization of `Trigger`.
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-trigger-metadata).
-#[derive(Deserialize, Serialize)]
+#[derive(Default, Deserialize, Serialize)]
 #[serde(rename = "TriggerMetadata")]
 struct InterimTriggerMetadata<'a> {
     #[serde(skip_serializing_if = "Option::is_none")]
     keyword_filter: Option<Cow<'a, [String]>>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    regex_patterns: Option<Cow<'a, [String]>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     presets: Option<Cow<'a, [KeywordPresetType]>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_list: Option<Cow<'a, [String]>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    mention_total_limit: Option<u8>,
 }
 
 impl<'de> Deserialize<'de> for Trigger {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
         let trigger = InterimTrigger::deserialize(deserializer)?;
+        let metadata = trigger.metadata;
         let trigger = match trigger.kind {
             TriggerType::Keyword => {
-                let keywords = trigger
-                    .metadata
-                    .keyword_filter
-                    .ok_or_else(|| Error::missing_field("keyword_filter"))?;
-                Self::Keyword(keywords.into_owned())
+                let strings =
+                    metadata.keyword_filter.ok_or_else(|| Error::missing_field("keyword_filter"))?;
+                Self::Keyword {
+                    strings: strings.into_owned(),
+                    regex_patterns: metadata.regex_patterns.unwrap_or_default().into_owned(),
+                    allow_list: metadata.allow_list.unwrap_or_default().into_owned(),
+                }
             },
             TriggerType::HarmfulLink => Self::HarmfulLink,
             TriggerType::Spam => Self::Spam,
             TriggerType::KeywordPreset => {
-                let presets =
-                    trigger.metadata.presets.ok_or_else(|| Error::missing_field("presets"))?;
-                Self::KeywordPreset(presets.into_owned())
+                let presets = metadata.presets.ok_or_else(|| Error::missing_field("presets"))?;
+                Self::KeywordPreset {
+                    presets: presets.into_owned(),
+                    allow_list: metadata.allow_list.unwrap_or_default().into_owned(),
+                }
+            },
+            TriggerType::MentionSpam => {
+                let mention_total_limit = metadata
+                    .mention_total_limit
+                    .ok_or_else(|| Error::missing_field("mention_total_limit"))?;
+                Self::MentionSpam {
+                    mention_total_limit,
+                }
             },
             TriggerType::Unknown(unknown) => Self::Unknown(unknown),
         };
@@ -139,14 +191,28 @@ impl Serialize for Trigger {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         let mut trigger = InterimTrigger {
             kind: self.kind(),
-            metadata: InterimTriggerMetadata {
-                keyword_filter: None,
-                presets: None,
-            },
+            metadata: InterimTriggerMetadata::default(),
         };
         match self {
-            Self::Keyword(keywords) => trigger.metadata.keyword_filter = Some(keywords.into()),
-            Self::KeywordPreset(presets) => trigger.metadata.presets = Some(presets.into()),
+            Self::Keyword {
+                strings,
+                regex_patterns,
+                allow_list,
+            } => {
+                trigger.metadata.keyword_filter = Some(strings.into());
+                trigger.metadata.regex_patterns = Some(regex_patterns.into());
+                trigger.metadata.allow_list = Some(allow_list.into());
+            },
+            Self::KeywordPreset {
+                presets,
+                allow_list,
+            } => {
+                trigger.metadata.presets = Some(presets.into());
+                trigger.metadata.allow_list = Some(allow_list.into());
+            },
+            Self::MentionSpam {
+                mention_total_limit,
+            } => trigger.metadata.mention_total_limit = Some(*mention_total_limit),
             _ => {},
         }
         trigger.serialize(serializer)
@@ -157,13 +223,128 @@ impl Trigger {
     #[must_use]
     pub fn kind(&self) -> TriggerType {
         match self {
-            Self::Keyword(_) => TriggerType::Keyword,
+            Self::Keyword {
+                ..
+            } => TriggerType::Keyword,
             Self::HarmfulLink => TriggerType::HarmfulLink,
             Self::Spam => TriggerType::Spam,
-            Self::KeywordPreset(_) => TriggerType::KeywordPreset,
+            Self::KeywordPreset {
+                ..
+            } => TriggerType::KeywordPreset,
+            Self::MentionSpam {
+                ..
+            } => TriggerType::MentionSpam,
             Self::Unknown(unknown) => TriggerType::Unknown(*unknown),
         }
     }
+
+    /// Checks locally whether the content would trigger a [`Self::Keyword`]
+    /// trigger, returning the first match.
+    ///
+    /// This replicates Discord's matching of keywords, which is
+    /// case-insensitive:
+    /// - `cat` only matches whole words, such as `cat` but not `catch`;
+    /// - `cat*` matches words starting with `cat`, such as `catch`;
+    /// - `*cat` matches words ending with `cat`, such as `bobcat`;
+    /// - `*cat*` matches `cat` anywhere, such as `concatenate`.
+    ///
+    /// Allow list entries use the same rules, and a keyword match overlapping
+    /// an allowed match is ignored.
+    ///
+    /// **Note**: Regex patterns are not evaluated, and other kinds of triggers
+    /// rely on data only known to Discord, so they never match.
+    #[must_use]
+    pub fn matches(&self, content: &str) -> Option<KeywordMatch> {
+        let (strings, allow_list) = match self {
+            Self::Keyword {
+                strings,
+                allow_list,
+                ..
+            } => (strings, allow_list),
+            _ => return None,
+        };
+
+        let chars = content
+            .char_indices()
+            .map(|(index, c)| (index, c.to_lowercase().next().unwrap_or(c)))
+            .collect::<Vec<_>>();
+
+        let allowed = allow_list
+            .iter()
+            .flat_map(|entry| find_keyword(&chars, entry))
+            .collect::<Vec<_>>();
+
+        let byte_index = |index: usize| chars.get(index).map_or(content.len(), |&(i, _)| i);
+
+        strings.iter().find_map(|keyword| {
+            find_keyword(&chars, keyword)
+                .into_iter()
+                .find(|found| {
+                    !allowed.iter().any(|a| a.start < found.end && found.start < a.end)
+                })
+                .map(|found| KeywordMatch {
+                    matched_keyword: keyword.clone(),
+                    matched_content: content[byte_index(found.start)..byte_index(found.end)]
+                        .to_string(),
+                })
+        })
+    }
+}
+
+/// A match of a [`Trigger::Keyword`] in some content, as returned by
+/// [`Trigger::matches`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct KeywordMatch {
+    /// Word or phrase configured in the trigger that matched.
+    pub matched_keyword: String,
+    /// Substring in content that matched the keyword.
+    pub matched_content: String,
+}
+
+/// Finds all matches of a keyword, returning the ranges of matched indices of
+/// `chars`, which are the lowercased characters of the content.
+fn find_keyword(chars: &[(usize, char)], keyword: &str) -> Vec<Range<usize>> {
+    let is_word = |index: usize| chars.get(index).map_or(false, |&(_, c)| c.is_alphanumeric());
+
+    let prefix = keyword.starts_with('*');
+    let suffix = keyword.len() > 1 && keyword.ends_with('*');
+    let needle = keyword
+        .trim_matches('*')
+        .chars()
+        .map(|c| c.to_lowercase().next().unwrap_or(c))
+        .collect::<Vec<_>>();
+
+    if needle.is_empty() || needle.len() > chars.len() {
+        return Vec::new();
+    }
+
+    (0..=chars.len() - needle.len())
+        .filter(|&start| {
+            chars[start..start + needle.len()].iter().map(|&(_, c)| c).eq(needle.iter().copied())
+        })
+        .filter_map(|start| {
+            let end = start + needle.len();
+            let left = start == 0 || !is_word(start - 1);
+            let right = !is_word(end);
+
+            if (!prefix && !left) || (!suffix && !right) {
+                return None;
+            }
+
+            // Wildcard matches cover the rest of the words they are part of.
+            let mut start = start;
+            let mut end = end;
+            while start > 0 && is_word(start - 1) {
+                start -= 1;
+            }
+            while is_word(end) {
+                end += 1;
+            }
+
+            Some(start..end)
+        })
+        .collect()
 }
 
 /// Type of [`Trigger`].
@@ -177,6 +358,7 @@ pub enum TriggerType {
     HarmfulLink,
     Spam,
     KeywordPreset,
+    MentionSpam,
     Unknown(u8),
 }
 
@@ -187,6 +369,7 @@ impl From<u8> for TriggerType {
             2 => Self::HarmfulLink,
             3 => Self::Spam,
             4 => Self::KeywordPreset,
+            5 => Self::MentionSpam,
             _ => Self::Unknown(value),
         }
     }
@@ -199,6 +382,7 @@ impl From<TriggerType> for u8 {
             TriggerType::HarmfulLink => 2,
             TriggerType::Spam => 3,
             TriggerType::KeywordPreset => 4,
+            TriggerType::MentionSpam => 5,
             TriggerType::Unknown(unknown) => unknown,
         }
     }
@@ -213,9 +397,13 @@ impl From<TriggerType> for u8 {
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/auto-moderation#auto-moderation-rule-object-trigger-metadata).
 #[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct TriggerMetadata {
-    keyword_filter: Option<Vec<String>>,
-    presets: Option<Vec<KeywordPresetType>>,
+    pub keyword_filter: Option<Vec<String>>,
+    pub regex_patterns: Option<Vec<String>>,
+    pub presets: Option<Vec<KeywordPresetType>>,
+    pub allow_list: Option<Vec<String>>,
+    pub mention_total_limit: Option<u8>,
 }
 
 /// Internally pre-defined wordsets which will be searched for in content.
@@ -494,6 +682,44 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn keyword_matches() {
+        let trigger = |strings: &[&str], allow_list: &[&str]| Trigger::Keyword {
+            strings: strings.iter().map(|s| s.to_string()).collect(),
+            regex_patterns: vec![],
+            allow_list: allow_list.iter().map(|s| s.to_string()).collect(),
+        };
+        let matched = |trigger: &Trigger, content| {
+            trigger.matches(content).map(|m| (m.matched_keyword, m.matched_content))
+        };
+
+        // Keywords without wildcards only match whole words.
+        let exact = trigger(&["cat", "ice cream"], &[]);
+        assert_eq!(matched(&exact, "my Cat!"), Some(("cat".into(), "Cat".into())));
+        assert_eq!(matched(&exact, "catch bobcat"), None);
+        let phrase = Some(("ice cream".into(), "ice cream".into()));
+        assert_eq!(matched(&exact, "some ice cream"), phrase);
+
+        let prefix = trigger(&["cat*"], &[]);
+        assert_eq!(matched(&prefix, "a catch"), Some(("cat*".into(), "catch".into())));
+        assert_eq!(matched(&prefix, "bobcat"), None);
+
+        let suffix = trigger(&["*cat"], &[]);
+        assert_eq!(matched(&suffix, "a bobcat"), Some(("*cat".into(), "bobcat".into())));
+        assert_eq!(matched(&suffix, "catch"), None);
+
+        let anywhere = trigger(&["*cat*"], &[]);
+        assert_eq!(matched(&anywhere, "Concatenate"), Some(("*cat*".into(), "Concatenate".into())));
+        assert_eq!(matched(&anywhere, "dog"), None);
+
+        // Allowed matches override the keywords they overlap with.
+        let allowed = trigger(&["*cat*"], &["concatenate", "cat food"]);
+        assert_eq!(matched(&allowed, "concatenate some cat food"), None);
+        assert_eq!(matched(&allowed, "concatenate a cat"), Some(("*cat*".into(), "cat".into())));
+
+        assert_eq!(Trigger::Spam.matches("cat"), None);
+    }
+
     #[test]
     fn rule_trigger_serde() -> crate::Result<()> {
         #[derive(Debug, PartialEq, Deserialize, Serialize)]
@@ -504,9 +730,13 @@ mod tests {
 
         assert_eq!(
             crate::json::to_string(&Rule {
-                trigger: Trigger::Keyword(vec![String::from("foo"), String::from("bar")]),
+                trigger: Trigger::Keyword {
+                    strings: vec![String::from("foo"), String::from("bar")],
+                    regex_patterns: vec![String::from("ba[rz]")],
+                    allow_list: vec![String::from("foobar")],
+                },
             })?,
-            r#"{"trigger_type":1,"trigger_metadata":{"keyword_filter":["foo","bar"]}}"#,
+            r#"{"trigger_type":1,"trigger_metadata":{"keyword_filter":["foo","bar"],"regex_patterns":["ba[rz]"],"allow_list":["foobar"]}}"#,
         );
 
         assert_eq!(
@@ -525,13 +755,25 @@ mod tests {
 
         assert_eq!(
             crate::json::to_string(&Rule {
-                trigger: Trigger::KeywordPreset(vec![
-                    KeywordPresetType::Profanity,
-                    KeywordPresetType::SexualContent,
-                    KeywordPresetType::Slurs,
-                ]),
+                trigger: Trigger::KeywordPreset {
+                    presets: vec![
+                        KeywordPresetType::Profanity,
+                        KeywordPresetType::SexualContent,
+                        KeywordPresetType::Slurs,
+                    ],
+                    allow_list: vec![],
+                },
+            })?,
+            r#"{"trigger_type":4,"trigger_metadata":{"presets":[1,2,3],"allow_list":[]}}"#,
+        );
+
+        assert_eq!(
+            crate::json::to_string(&Rule {
+                trigger: Trigger::MentionSpam {
+                    mention_total_limit: 10,
+                },
             })?,
-            r#"{"trigger_type":4,"trigger_metadata":{"presets":[1,2,3]}}"#,
+            r#"{"trigger_type":5,"trigger_metadata":{"mention_total_limit":10}}"#,
         );
 
         assert_eq!(
@@ -541,6 +783,16 @@ mod tests {
             r#"{"trigger_type":123,"trigger_metadata":{}}"#,
         );
 
+        let rule: Rule = crate::json::from_value(crate::json::json!({
+            "trigger_type": 1,
+            "trigger_metadata": {"keyword_filter": ["foo"]},
+        }))?;
+        assert_eq!(rule.trigger, Trigger::Keyword {
+            strings: vec![String::from("foo")],
+            regex_patterns: vec![],
+            allow_list: vec![],
+        });
+
         Ok(())
     }
 
@@ -562,4 +814,27 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn action_execution_deserialize() -> crate::Result<()> {
+        let execution: ActionExecution = crate::json::from_value(crate::json::json!({
+            "guild_id": "1",
+            "action": {"type": 3, "metadata": {"duration_seconds": 60}},
+            "rule_id": "2",
+            "rule_trigger_type": 1,
+            "user_id": "3",
+            "channel_id": "4",
+            "content": "foo bar",
+            "matched_keyword": "foo*",
+            "matched_content": "foo",
+        }))?;
+
+        assert_eq!(execution.action, Action::Timeout(Duration::from_secs(60)));
+        assert_eq!(execution.trigger_type, TriggerType::Keyword);
+        assert_eq!(execution.channel_id, Some(ChannelId(4)));
+        assert_eq!(execution.message_id, None);
+        assert_eq!(execution.matched_keyword.as_deref(), Some("foo*"));
+
+        Ok(())
+    }
 }
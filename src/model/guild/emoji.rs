@@ -2,18 +2,22 @@ use std::fmt;
 
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::cache::Cache;
+#[cfg(feature = "model")]
+use crate::constants::EMOJI_MAX_SIZE;
 #[cfg(feature = "cache")]
 use crate::http::Http;
-#[cfg(all(feature = "cache", feature = "model"))]
+#[cfg(feature = "model")]
 use crate::internal::prelude::*;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::json::json;
 #[cfg(all(feature = "cache", feature = "model"))]
 use crate::model::id::GuildId;
 use crate::model::id::{EmojiId, RoleId};
+#[cfg(feature = "model")]
+use crate::model::misc::{cdn_image_url, ImageFormat};
 use crate::model::user::User;
 use crate::model::utils::default_true;
-#[cfg(all(feature = "cache", feature = "model"))]
+#[cfg(feature = "model")]
 use crate::model::ModelError;
 
 /// Represents a custom guild emoji, which can either be created using the API,
@@ -102,7 +106,10 @@ impl Emoji {
     pub async fn delete<T: AsRef<Cache> + AsRef<Http>>(&self, cache_http: T) -> Result<()> {
         match self.find_guild_id(&cache_http) {
             Some(guild_id) => {
-                AsRef::<Http>::as_ref(&cache_http).delete_emoji(guild_id.0, self.id.0).await
+                AsRef::<Http>::as_ref(&cache_http).delete_emoji(guild_id.0, self.id.0).await?;
+                AsRef::<Cache>::as_ref(&cache_http).remove_guild_emoji(guild_id, self.id);
+
+                Ok(())
             },
             None => Err(Error::Model(ModelError::ItemMissing)),
         }
@@ -135,6 +142,7 @@ impl Emoji {
                 *self = AsRef::<Http>::as_ref(&cache_http)
                     .edit_emoji(guild_id.0, self.id.0, &map, None)
                     .await?;
+                AsRef::<Cache>::as_ref(&cache_http).insert_guild_emoji(guild_id, self);
 
                 Ok(())
             },
@@ -197,6 +205,23 @@ impl Emoji {
         let extension = if self.animated { "gif" } else { "png" };
         cdn!("/emojis/{}.{}", self.id, extension)
     }
+
+    /// Generates a URL to the emoji's image in the given format and size.
+    ///
+    /// The format defaults to a GIF for animated emojis, or a WEBP otherwise,
+    /// and the size to 1024.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidImageFormat`] if a GIF is requested of
+    /// an emoji that is not animated, or a [`ModelError::InvalidImageSize`] if the
+    /// size is not a power of two between 16 and 4096.
+    ///
+    /// [`ModelError::InvalidImageFormat`]: crate::model::error::Error::InvalidImageFormat
+    /// [`ModelError::InvalidImageSize`]: crate::model::error::Error::InvalidImageSize
+    pub fn url_with(&self, format: Option<ImageFormat>, size: Option<u16>) -> Result<String> {
+        cdn_image_url(&format!("emojis/{}", self.id), self.animated, format, size)
+    }
 }
 
 impl fmt::Display for Emoji {
@@ -231,3 +256,91 @@ impl<'a> From<&'a Emoji> for EmojiId {
         emoji.id
     }
 }
+
+/// Encodes an emoji image as a base64 data URI, checking that it is a PNG,
+/// JPEG, GIF, or WebP image and is not over [`EMOJI_MAX_SIZE`].
+#[cfg(feature = "model")]
+pub(crate) fn encode_emoji_image(image: &[u8]) -> Result<String> {
+    let mime = if image.starts_with(b"\x89PNG\r\n\x1a\n") {
+        "image/png"
+    } else if image.starts_with(b"\xff\xd8\xff") {
+        "image/jpeg"
+    } else if image.starts_with(b"GIF87a") || image.starts_with(b"GIF89a") {
+        "image/gif"
+    } else if image.len() >= 12 && image.starts_with(b"RIFF") && &image[8..12] == b"WEBP" {
+        "image/webp"
+    } else {
+        return Err(Error::Model(ModelError::InvalidEmojiImage));
+    };
+
+    if image.len() > EMOJI_MAX_SIZE {
+        return Err(Error::Model(ModelError::EmojiImageTooLarge(image.len() - EMOJI_MAX_SIZE)));
+    }
+
+    Ok(format!("data:{};base64,{}", mime, base64::encode(image)))
+}
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use super::{encode_emoji_image, Emoji};
+    use crate::constants::EMOJI_MAX_SIZE;
+    use crate::error::Error;
+    use crate::json::{from_value, json};
+    use crate::model::misc::ImageFormat;
+    use crate::model::ModelError;
+
+    #[test]
+    fn emoji_urls() {
+        let emoji = |animated: bool| -> Emoji {
+            from_value(json!({"id": "1", "name": "emoji", "animated": animated})).unwrap()
+        };
+
+        let emoji_static = emoji(false);
+        assert_eq!(emoji_static.url(), "https://cdn.discordapp.com/emojis/1.png");
+        assert_eq!(
+            emoji_static.url_with(None, Some(64)).unwrap(),
+            "https://cdn.discordapp.com/emojis/1.webp?size=64"
+        );
+        assert!(matches!(
+            emoji_static.url_with(Some(ImageFormat::Gif), None),
+            Err(Error::Model(ModelError::InvalidImageFormat(ImageFormat::Gif)))
+        ));
+
+        let animated = emoji(true);
+        assert_eq!(animated.url(), "https://cdn.discordapp.com/emojis/1.gif");
+        assert_eq!(
+            animated.url_with(None, Some(128)).unwrap(),
+            "https://cdn.discordapp.com/emojis/1.gif?size=128"
+        );
+        assert_eq!(
+            animated.url_with(Some(ImageFormat::Png), Some(16)).unwrap(),
+            "https://cdn.discordapp.com/emojis/1.png?size=16"
+        );
+        assert!(matches!(
+            animated.url_with(None, Some(100)),
+            Err(Error::Model(ModelError::InvalidImageSize(100)))
+        ));
+    }
+
+    #[test]
+    fn emoji_image_encoding() {
+        let gif = b"GIF89a\x01\x00\x01\x00";
+        let encoded = encode_emoji_image(gif).expect("valid gif");
+        assert_eq!(encoded, format!("data:image/gif;base64,{}", base64::encode(gif)));
+
+        let png = b"\x89PNG\r\n\x1a\n\x00\x00";
+        assert!(encode_emoji_image(png).expect("valid png").starts_with("data:image/png;base64,"));
+
+        assert!(matches!(
+            encode_emoji_image(b"<html></html>"),
+            Err(Error::Model(ModelError::InvalidEmojiImage))
+        ));
+
+        let mut large = png.to_vec();
+        large.resize(EMOJI_MAX_SIZE + 10, 0);
+        assert!(matches!(
+            encode_emoji_image(&large),
+            Err(Error::Model(ModelError::EmojiImageTooLarge(10)))
+        ));
+    }
+}
@@ -2,6 +2,8 @@ use std::fmt;
 
 #[cfg(feature = "model")]
 use futures::stream::Stream;
+#[cfg(feature = "model")]
+use reqwest::StatusCode;
 
 #[cfg(feature = "model")]
 use crate::builder::{
@@ -11,6 +13,7 @@ use crate::builder::{
     CreateApplicationCommands,
     CreateChannel,
     CreateScheduledEvent,
+    CreateSoundboardSound,
     CreateSticker,
     EditAutoModRule,
     EditGuild,
@@ -19,6 +22,7 @@ use crate::builder::{
     EditMember,
     EditRole,
     EditScheduledEvent,
+    EditSoundboardSound,
     EditSticker,
 };
 #[cfg(all(feature = "cache", feature = "model"))]
@@ -33,7 +37,7 @@ use crate::collector::{
     ReactionCollectorBuilder,
 };
 #[cfg(feature = "model")]
-use crate::http::{CacheHttp, Http, UserPagination};
+use crate::http::{CacheHttp, GetAuditLogs, Http, HttpError, UserPagination};
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
 #[cfg(feature = "model")]
@@ -46,7 +50,11 @@ use crate::json::prelude::*;
 use crate::model::application::command::{Command, CommandPermission};
 #[cfg(feature = "model")]
 use crate::model::guild::automod::Rule;
+#[cfg(feature = "model")]
+use crate::model::guild::emoji::encode_emoji_image;
 use crate::model::prelude::*;
+#[cfg(feature = "model")]
+use crate::model::soundboard::check_sound_file;
 
 #[cfg(feature = "model")]
 impl GuildId {
@@ -102,7 +110,11 @@ impl GuildId {
     /// let _rule = GuildId(7)
     ///     .create_automod_rule(&http, |r| {
     ///         r.name("foobar filter")
-    ///             .trigger(Trigger::Keyword(vec!["foo*".to_string(), "*bar".to_string()]))
+    ///             .trigger(Trigger::Keyword {
+    ///                 strings: vec!["foo*".to_string(), "*bar".to_string()],
+    ///                 regex_patterns: vec![],
+    ///                 allow_list: vec![],
+    ///             })
     ///             .actions(vec![Action::BlockMessage, Action::Timeout(Duration::from_secs(60))])
     ///     })
     ///     .await;
@@ -233,6 +245,52 @@ impl GuildId {
         self._ban_with_reason(http, user.into(), dmd, "").await
     }
 
+    /// Ban a [`User`] from the guild, deleting a number of seconds' worth of
+    /// messages (`delete_message_seconds`) between the range 0 and 604800 (7
+    /// days).
+    ///
+    /// This allows finer control than the days given to [`Self::ban`].
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::DeleteMessageSecondsAmount`] if the number of
+    /// seconds' worth of messages to delete is over the maximum.
+    ///
+    /// Also can return [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    #[inline]
+    pub async fn ban_with_delete_seconds(
+        self,
+        http: impl AsRef<Http>,
+        user: impl Into<UserId>,
+        delete_message_seconds: u32,
+    ) -> Result<()> {
+        self._ban(http.as_ref(), user.into(), delete_message_seconds, None).await
+    }
+
+    /// Ban a [`User`] from the guild with a reason, deleting a number of
+    /// seconds' worth of messages. Refer to [`Self::ban_with_delete_seconds`]
+    /// for further documentation.
+    ///
+    /// # Errors
+    ///
+    /// In addition to the reasons [`Self::ban_with_delete_seconds`] may return
+    /// an error, may also return [`Error::ExceededLimit`] if `reason` is too
+    /// long.
+    #[inline]
+    pub async fn ban_with_delete_seconds_and_reason(
+        self,
+        http: impl AsRef<Http>,
+        user: impl Into<UserId>,
+        delete_message_seconds: u32,
+        reason: impl AsRef<str>,
+    ) -> Result<()> {
+        self._ban(http.as_ref(), user.into(), delete_message_seconds, Some(reason.as_ref())).await
+    }
+
     /// Ban a [`User`] from the guild with a reason. Refer to [`Self::ban`] to further documentation.
     ///
     /// # Errors
@@ -261,11 +319,99 @@ impl GuildId {
             return Err(Error::Model(ModelError::DeleteMessageDaysAmount(dmd)));
         }
 
+        self._ban(http.as_ref(), user, u32::from(dmd) * 86_400, Some(reason)).await
+    }
+
+    async fn _ban(
+        self,
+        http: &Http,
+        user: UserId,
+        delete_message_seconds: u32,
+        reason: Option<&str>,
+    ) -> Result<()> {
+        if delete_message_seconds > 604_800 {
+            return Err(Error::Model(ModelError::DeleteMessageSecondsAmount(
+                delete_message_seconds,
+            )));
+        }
+
+        if let Some(reason) = reason {
+            if reason.chars().count() > 512 {
+                return Err(Error::ExceededLimit(reason.to_string(), 512));
+            }
+        }
+
+        http.ban_user_with_delete_seconds(self.0, user.0, delete_message_seconds, reason).await
+    }
+
+    /// Bans up to 200 users from the guild at once, deleting up to 7 days (604800 seconds) worth
+    /// of their messages.
+    ///
+    /// Users that could not be banned, e.g. because they were already banned, are listed in
+    /// [`BulkBanResponse::failed_users`].
+    ///
+    /// **Note**: Requires the [Ban Members] and [Manage Guild] permissions.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotInRange`] if no or more than 200 users are given, or a
+    /// [`ModelError::DeleteMessageSecondsAmount`] if `delete_message_seconds` is over 604800.
+    ///
+    /// Also can return [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn bulk_ban(
+        self,
+        http: impl AsRef<Http>,
+        user_ids: &[UserId],
+        delete_message_seconds: u32,
+    ) -> Result<BulkBanResponse> {
+        self._bulk_ban(http.as_ref(), user_ids, delete_message_seconds, None).await
+    }
+
+    /// Bans up to 200 users from the guild at once with a reason. Refer to [`Self::bulk_ban`] for
+    /// further documentation.
+    ///
+    /// # Errors
+    ///
+    /// In addition to the reasons [`Self::bulk_ban`] may return an error, may also return
+    /// [`Error::ExceededLimit`] if `reason` is too long.
+    pub async fn bulk_ban_with_reason(
+        self,
+        http: impl AsRef<Http>,
+        user_ids: &[UserId],
+        delete_message_seconds: u32,
+        reason: impl AsRef<str>,
+    ) -> Result<BulkBanResponse> {
+        let reason = reason.as_ref();
+
         if reason.chars().count() > 512 {
             return Err(Error::ExceededLimit(reason.to_string(), 512));
         }
 
-        http.as_ref().ban_user(self.0, user.0, dmd, reason).await
+        self._bulk_ban(http.as_ref(), user_ids, delete_message_seconds, Some(reason)).await
+    }
+
+    async fn _bulk_ban(
+        self,
+        http: &Http,
+        user_ids: &[UserId],
+        delete_message_seconds: u32,
+        reason: Option<&str>,
+    ) -> Result<BulkBanResponse> {
+        if !(1..=200).contains(&user_ids.len()) {
+            return Err(Error::NotInRange("user_ids", user_ids.len() as u64, 1, 200));
+        }
+
+        if delete_message_seconds > 604_800 {
+            return Err(Error::Model(ModelError::DeleteMessageSecondsAmount(
+                delete_message_seconds,
+            )));
+        }
+
+        http.bulk_ban(self.0, user_ids, delete_message_seconds, reason).await
     }
 
     /// Gets a list of the guild's bans.
@@ -282,6 +428,21 @@ impl GuildId {
         http.as_ref().get_bans(self.0).await
     }
 
+    /// Gets the ban of a user in the guild, including its reason.
+    ///
+    /// **Note**: Requires the [Ban Members] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission, or if the user is not
+    /// banned.
+    ///
+    /// [Ban Members]: Permissions::BAN_MEMBERS
+    #[inline]
+    pub async fn ban_info(self, http: impl AsRef<Http>, user: impl Into<UserId>) -> Result<Ban> {
+        http.as_ref().get_ban(self.0, user.into().0).await
+    }
+
     /// Gets a list of the guild's audit log entries
     ///
     /// **Note**: Requires the [View Audit Log] permission.
@@ -306,6 +467,27 @@ impl GuildId {
             .await
     }
 
+    /// Gets a page of the guild's audit log entries, filtered and paginated by the given query.
+    ///
+    /// Refer to [`Http::get_audit_logs_iter`] to stream over all of the matching entries.
+    ///
+    /// **Note**: Requires the [View Audit Log] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotInRange`] if the query's `limit` is not between 1 and 100, or
+    /// [`Error::Http`] if the current user lacks permission.
+    ///
+    /// [View Audit Log]: Permissions::VIEW_AUDIT_LOG
+    #[inline]
+    pub async fn audit_logs_with(
+        self,
+        http: impl AsRef<Http>,
+        query: GetAuditLogs,
+    ) -> Result<AuditLogs> {
+        http.as_ref().get_audit_logs_with(self.0, query).await
+    }
+
     /// Gets all of the guild's channels over the REST API.
     ///
     /// # Errors
@@ -385,17 +567,22 @@ impl GuildId {
     /// how to read an image from the filesystem and encode it as base64. Most
     /// of the example can be applied similarly for this method.
     ///
+    /// If the cache is enabled and contains the guild, the created emoji is
+    /// added to it.
+    ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::TooManyEmojis`] if the guild has no emoji slots
+    /// left.
+    ///
     /// Returns [`Error::Http`] if the current user lacks permission,
     /// if the name is too long, or if the image is too big.
     ///
     /// [`EditProfile::avatar`]: crate::builder::EditProfile::avatar
     /// [Manage Emojis and Stickers]: Permissions::MANAGE_EMOJIS_AND_STICKERS
-    #[inline]
     pub async fn create_emoji(
         self,
-        http: impl AsRef<Http>,
+        cache_http: impl CacheHttp,
         name: &str,
         image: &str,
     ) -> Result<Emoji> {
@@ -404,7 +591,54 @@ impl GuildId {
             "image": image,
         });
 
-        http.as_ref().create_emoji(self.0, &map, None).await
+        let emoji = cache_http.http().create_emoji(self.0, &map, None).await.map_err(|why| {
+            // JSON error code 30008: "Maximum number of emojis reached"
+            let limit_reached = matches!(
+                &why,
+                Error::Http(http_error) if http_error.json_error_code() == Some(30008)
+            );
+
+            if limit_reached {
+                Error::Model(ModelError::TooManyEmojis)
+            } else {
+                why
+            }
+        })?;
+
+        #[cfg(feature = "cache")]
+        if let Some(cache) = cache_http.cache() {
+            cache.insert_guild_emoji(self, &emoji);
+        }
+
+        Ok(emoji)
+    }
+
+    /// Creates an emoji in the guild with a name and raw image data, which must
+    /// be a PNG, JPEG, GIF, or WebP image of up to 256 KiB.
+    ///
+    /// If the cache is enabled and contains the guild, the created emoji is
+    /// added to it.
+    ///
+    /// Requires the [Manage Emojis and Stickers] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidEmojiImage`] if the image is not in a
+    /// supported format, or a [`ModelError::EmojiImageTooLarge`] if it is over
+    /// [`EMOJI_MAX_SIZE`].
+    ///
+    /// Otherwise returns the same errors as [`Self::create_emoji`].
+    ///
+    /// [`EMOJI_MAX_SIZE`]: crate::constants::EMOJI_MAX_SIZE
+    /// [Manage Emojis and Stickers]: Permissions::MANAGE_EMOJIS_AND_STICKERS
+    pub async fn create_emoji_from_bytes(
+        self,
+        cache_http: impl CacheHttp,
+        name: &str,
+        image: &[u8],
+    ) -> Result<Emoji> {
+        let image = encode_emoji_image(image)?;
+        self.create_emoji(cache_http, name, &image).await
     }
 
     /// Creates an integration for the guild.
@@ -516,6 +750,38 @@ impl GuildId {
         Ok(sticker)
     }
 
+    /// Creates a new soundboard sound in the guild with the data set.
+    ///
+    /// **Note**: Requires the [Create Guild Expressions] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidSoundFile`] if no sound file was set, or it is
+    /// not a base64 encoded MP3 or OGG file, and a [`ModelError::SoundFileTooLarge`]
+    /// if it is over 512 KiB.
+    ///
+    /// Otherwise returns [`Error::Http`] if the current user lacks permission,
+    /// or if invalid data is given.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    pub async fn create_soundboard_sound(
+        self,
+        http: impl AsRef<Http>,
+        f: impl FnOnce(&mut CreateSoundboardSound) -> &mut CreateSoundboardSound,
+    ) -> Result<SoundboardSound> {
+        let mut builder = CreateSoundboardSound::default();
+        f(&mut builder);
+
+        match builder.0.get("sound") {
+            Some(Value::String(sound)) => check_sound_file(sound)?,
+            _ => return Err(Error::Model(ModelError::InvalidSoundFile)),
+        }
+
+        let map = json::hashmap_to_json_map(builder.0);
+
+        http.as_ref().create_guild_soundboard_sound(self.0, &map, None).await
+    }
+
     /// Deletes the current guild if the current account is the owner of the
     /// guild.
     ///
@@ -533,6 +799,9 @@ impl GuildId {
 
     /// Deletes an [`Emoji`] from the guild.
     ///
+    /// If the cache is enabled and contains the guild, the emoji is removed
+    /// from it.
+    ///
     /// **Note**: Requires the [Manage Emojis and Stickers] permission.
     ///
     /// # Errors
@@ -541,13 +810,20 @@ impl GuildId {
     /// or if an Emoji with that Id does not exist.
     ///
     /// [Manage Emojis and Stickers]: Permissions::MANAGE_EMOJIS_AND_STICKERS
-    #[inline]
     pub async fn delete_emoji(
         self,
-        http: impl AsRef<Http>,
+        cache_http: impl CacheHttp,
         emoji_id: impl Into<EmojiId>,
     ) -> Result<()> {
-        http.as_ref().delete_emoji(self.0, emoji_id.into().0).await
+        let emoji_id = emoji_id.into();
+        cache_http.http().delete_emoji(self.0, emoji_id.0).await?;
+
+        #[cfg(feature = "cache")]
+        if let Some(cache) = cache_http.cache() {
+            cache.remove_guild_emoji(self, emoji_id);
+        }
+
+        Ok(())
     }
 
     /// Deletes an integration by Id from the guild.
@@ -628,6 +904,27 @@ impl GuildId {
         http.as_ref().delete_sticker(self.0, sticker_id.into().0, None).await
     }
 
+    /// Deletes a [`SoundboardSound`] by Id from the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild Expressions] permission, or the
+    /// [Create Guild Expressions] permission for sounds created by the current user.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission,
+    /// or if a sound with that Id does not exist.
+    ///
+    /// [Manage Guild Expressions]: Permissions::MANAGE_EMOJIS_AND_STICKERS
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    #[inline]
+    pub async fn delete_soundboard_sound(
+        self,
+        http: impl AsRef<Http>,
+        sound_id: impl Into<SoundId>,
+    ) -> Result<()> {
+        http.as_ref().delete_guild_soundboard_sound(self.0, sound_id.into().0, None).await
+    }
+
     /// Edits the current guild with new data where specified.
     ///
     /// Refer to [`Guild::edit`] for more information.
@@ -637,6 +934,9 @@ impl GuildId {
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::MissingCommunityChannels`] if the `COMMUNITY` feature is enabled
+    /// while explicitly removing the rules channel or the public updates channel.
+    ///
     /// Returns [`Error::Http`] if the current user lacks permission,
     /// or if an invalid value is set.
     ///
@@ -648,6 +948,8 @@ impl GuildId {
     {
         let mut edit_guild = EditGuild::default();
         f(&mut edit_guild);
+        edit_guild.check_community_channels(None)?;
+
         let map = json::hashmap_to_json_map(edit_guild.0);
 
         http.as_ref().edit_guild(self.0, &map, None).await
@@ -658,6 +960,9 @@ impl GuildId {
     /// Also see [`Emoji::edit`] if you have the `cache` and `methods` features
     /// enabled.
     ///
+    /// If the cache is enabled and contains the guild, the emoji is replaced
+    /// with the edited one.
+    ///
     /// Requires the [Manage Emojis and Stickers] permission.
     ///
     /// # Errors
@@ -665,10 +970,9 @@ impl GuildId {
     /// Returns [`Error::Http`] if the current user lacks permission.
     ///
     /// [Manage Emojis and Stickers]: Permissions::MANAGE_EMOJIS_AND_STICKERS
-    #[inline]
     pub async fn edit_emoji(
         self,
-        http: impl AsRef<Http>,
+        cache_http: impl CacheHttp,
         emoji_id: impl Into<EmojiId>,
         name: &str,
     ) -> Result<Emoji> {
@@ -676,7 +980,14 @@ impl GuildId {
             "name": name,
         });
 
-        http.as_ref().edit_emoji(self.0, emoji_id.into().0, &map, None).await
+        let emoji = cache_http.http().edit_emoji(self.0, emoji_id.into().0, &map, None).await?;
+
+        #[cfg(feature = "cache")]
+        if let Some(cache) = cache_http.cache() {
+            cache.insert_guild_emoji(self, &emoji);
+        }
+
+        Ok(emoji)
     }
 
     /// Edits the properties of member of the guild, such as muting or
@@ -828,6 +1139,31 @@ impl GuildId {
         http.as_ref().edit_sticker(self.0, sticker_id.into().0, &map, None).await
     }
 
+    /// Edits a [`SoundboardSound`] of the guild, setting the fields given.
+    ///
+    /// **Note**: Requires the [Manage Guild Expressions] permission, or the
+    /// [Create Guild Expressions] permission for sounds created by the current user.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user lacks permission,
+    /// or if invalid data is given.
+    ///
+    /// [Manage Guild Expressions]: Permissions::MANAGE_EMOJIS_AND_STICKERS
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    pub async fn edit_soundboard_sound(
+        self,
+        http: impl AsRef<Http>,
+        sound_id: impl Into<SoundId>,
+        f: impl FnOnce(&mut EditSoundboardSound) -> &mut EditSoundboardSound,
+    ) -> Result<SoundboardSound> {
+        let mut builder = EditSoundboardSound::default();
+        f(&mut builder);
+        let map = json::hashmap_to_json_map(builder.0);
+
+        http.as_ref().edit_guild_soundboard_sound(self.0, sound_id.into().0, &map, None).await
+    }
+
     /// Edits the order of [`Role`]s
     /// Requires the [Manage Roles] permission.
     ///
@@ -857,9 +1193,16 @@ impl GuildId {
 
     /// Edits the [`GuildWelcomeScreen`].
     ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::WelcomeChannelAmount`] if more than 5 channels
+    /// are set.
+    ///
     /// Returns an [`Error::Http`] if some mandatory fields are not provided.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
     pub async fn edit_welcome_screen<F>(
         &self,
         http: impl AsRef<Http>,
@@ -870,6 +1213,7 @@ impl GuildId {
     {
         let mut map = EditGuildWelcomeScreen::default();
         f(&mut map);
+        map.check_welcome_channels()?;
 
         http.as_ref()
             .edit_guild_welcome_screen(self.0, &Value::from(json::hashmap_to_json_map(map.0)))
@@ -896,18 +1240,27 @@ impl GuildId {
 
     /// Gets all of the guild's roles over the REST API.
     ///
+    /// If the cache is enabled and contains the guild, its roles are replaced
+    /// with the fetched ones.
+    ///
     /// # Errors
     ///
     /// Returns [`Error::Http`] if the current user is not in
     /// the guild.
-    pub async fn roles(self, http: impl AsRef<Http>) -> Result<HashMap<RoleId, Role>> {
+    pub async fn roles(self, cache_http: impl CacheHttp) -> Result<HashMap<RoleId, Role>> {
         let mut roles = HashMap::new();
 
-        #[allow(clippy::useless_conversion)]
-        for role in http.as_ref().get_guild_roles(self.0).await? {
+        for role in cache_http.http().get_guild_roles(self.0).await? {
             roles.insert(role.id, role);
         }
 
+        #[cfg(feature = "cache")]
+        if let Some(cache) = cache_http.cache() {
+            if let Some(mut guild) = cache.guilds.get_mut(&self) {
+                guild.roles = roles.clone();
+            }
+        }
+
         Ok(roles)
     }
 
@@ -949,12 +1302,25 @@ impl GuildId {
 
     /// Gets all [`Emoji`]s of this guild via HTTP.
     ///
+    /// If the `cache` is enabled, the emojis of the cached guild are replaced
+    /// by those fetched, so that [`Guild::emojis`][field] is up to date.
+    ///
     /// # Errors
     ///
     /// Returns an [`Error::Http`] if the guild is unavailable.
-    #[inline]
-    pub async fn emojis(&self, http: impl AsRef<Http>) -> Result<Vec<Emoji>> {
-        http.as_ref().get_emojis(self.0).await
+    ///
+    /// [field]: crate::model::guild::Guild#structfield.emojis
+    pub async fn emojis(&self, cache_http: impl CacheHttp) -> Result<Vec<Emoji>> {
+        let emojis = cache_http.http().get_emojis(self.0).await?;
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                cache.set_guild_emojis(*self, &emojis);
+            }
+        }
+
+        Ok(emojis)
     }
 
     /// Gets an [`Emoji`] of this guild by its ID via HTTP.
@@ -977,6 +1343,16 @@ impl GuildId {
         http.as_ref().get_guild_stickers(self.0).await
     }
 
+    /// Gets all [`SoundboardSound`]s of this guild via HTTP.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the guild is unavailable.
+    #[inline]
+    pub async fn soundboard_sounds(self, http: impl AsRef<Http>) -> Result<Vec<SoundboardSound>> {
+        http.as_ref().get_guild_soundboard_sounds(self.0).await
+    }
+
     /// Gets an [`Sticker`] of this guild by its ID via HTTP.
     ///
     /// # Errors
@@ -1050,13 +1426,38 @@ impl GuildId {
 
     /// Leaves the guild.
     ///
+    /// **Note**: This is irreversible. The current user can only rejoin the guild through a new
+    /// invite or, for bots, a new authorization.
+    ///
+    /// If the cache is enabled, the guild is removed from it once the guild has been left.
+    ///
     /// # Errors
     ///
-    /// May return an [`Error::Http`] if the current user
-    /// cannot leave the guild, or currently is not in the guild.
-    #[inline]
-    pub async fn leave(self, http: impl AsRef<Http>) -> Result<()> {
-        http.as_ref().leave_guild(self.0).await
+    /// If the `cache` is enabled, returns a [`ModelError::OwnerCannotLeave`] if the current user
+    /// owns the guild, as guild owners can't leave their guild. Without the cache, Discord
+    /// rejects the request instead, returning an [`Error::Http`].
+    ///
+    /// May also return an [`Error::Http`] if the current user currently is not in the guild.
+    pub async fn leave(self, cache_http: impl CacheHttp) -> Result<()> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                let current_user_id = cache.current_user_id();
+
+                if cache.guild_field(self, |guild| guild.owner_id) == Some(current_user_id) {
+                    return Err(Error::Model(ModelError::OwnerCannotLeave));
+                }
+            }
+        }
+
+        cache_http.http().leave_guild(self.0).await?;
+
+        #[cfg(feature = "cache")]
+        if let Some(cache) = cache_http.cache() {
+            cache.remove_guild(self);
+        }
+
+        Ok(())
     }
 
     /// Gets a user's [`Member`] for the guild by Id.
@@ -1213,6 +1614,21 @@ impl GuildId {
         http.as_ref().get_guild_prune_count(self.0, &map).await
     }
 
+    /// Gets the voice regions that the guild can use.
+    ///
+    /// If the guild has the `VIP_REGIONS` feature enabled, VIP-only regions
+    /// are returned as well. Use [`Http::get_voice_regions`] to list all
+    /// regions regardless of a guild.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the guild is unavailable, or
+    /// [`Error::Json`] if there is an error deserializing the API response.
+    #[inline]
+    pub async fn regions(self, http: impl AsRef<Http>) -> Result<Vec<VoiceRegion>> {
+        http.as_ref().get_guild_regions(self.0).await
+    }
+
     /// Re-orders the channels of the guild.
     ///
     /// Accepts an iterator of a tuple of the channel ID to modify and its new
@@ -1253,10 +1669,18 @@ impl GuildId {
     /// Optionally pass in the `limit` to limit the number of results.
     /// Minimum value is 1, maximum and default value is 1000.
     ///
+    /// **Note**: Queries are case insensitive. Unlike requesting members over
+    /// the gateway, this does not require the [`GatewayIntents::GUILD_MEMBERS`]
+    /// intent.
+    ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::MemberSearchLimit`] if the `limit` is not
+    /// between 1 and 1000.
+    ///
     /// Returns an [`Error::Http`] if the API returns an error.
-    #[inline]
+    ///
+    /// [`GatewayIntents::GUILD_MEMBERS`]: crate::gateway::GatewayIntents::GUILD_MEMBERS
     pub async fn search_members(
         self,
         http: impl AsRef<Http>,
@@ -1446,6 +1870,8 @@ impl GuildId {
 
     /// Retrieve's the guild's vanity URL.
     ///
+    /// The [`VanityUrl::code`] is [`None`] if the guild has no vanity invite.
+    ///
     /// **Note**: Requires the [Manage Guild] permission.
     ///
     /// # Errors
@@ -1456,10 +1882,83 @@ impl GuildId {
     ///
     /// [Manage Guild]: Permissions::MANAGE_GUILD
     #[inline]
-    pub async fn vanity_url(self, http: impl AsRef<Http>) -> Result<String> {
+    pub async fn vanity_url(self, http: impl AsRef<Http>) -> Result<VanityUrl> {
         http.as_ref().get_guild_vanity_url(self.0).await
     }
 
+    /// Retrieves the guild's [`GuildTemplate`]s.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`Error::Http`] if the current user lacks permission.
+    /// Can also return [`Error::Json`] if there is an error deserializing
+    /// the API response.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn templates(self, http: impl AsRef<Http>) -> Result<Vec<GuildTemplate>> {
+        http.as_ref().get_guild_templates(self.0).await
+    }
+
+    /// Creates a [`GuildTemplate`] from the current state of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`Error::Http`] if the current user lacks permission.
+    /// Can also return [`Error::Json`] if there is an error deserializing
+    /// the API response.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn create_template(
+        self,
+        http: impl AsRef<Http>,
+        name: &str,
+        description: Option<&str>,
+    ) -> Result<GuildTemplate> {
+        http.as_ref().create_guild_template(self.0, name, description).await
+    }
+
+    /// Syncs one of the guild's [`GuildTemplate`]s to the current state of the guild.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`Error::Http`] if the current user lacks permission.
+    /// Can also return [`Error::Json`] if there is an error deserializing
+    /// the API response.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn sync_template(self, http: impl AsRef<Http>, code: &str) -> Result<GuildTemplate> {
+        http.as_ref().sync_guild_template(self.0, code).await
+    }
+
+    /// Deletes one of the guild's [`GuildTemplate`]s.
+    ///
+    /// **Note**: Requires the [Manage Guild] permission.
+    ///
+    /// # Errors
+    ///
+    /// Will return [`Error::Http`] if the current user lacks permission.
+    /// Can also return [`Error::Json`] if there is an error deserializing
+    /// the API response.
+    ///
+    /// [Manage Guild]: Permissions::MANAGE_GUILD
+    #[inline]
+    pub async fn delete_template(
+        self,
+        http: impl AsRef<Http>,
+        code: &str,
+    ) -> Result<GuildTemplate> {
+        http.as_ref().delete_guild_template(self.0, code).await
+    }
+
     /// Retrieves the guild's webhooks.
     ///
     /// **Note**: Requires the [Manage Webhooks] permission.
@@ -1510,6 +2009,9 @@ impl GuildId {
     ///
     /// **Note**: Unlike global `Command`s, guild commands will update instantly.
     ///
+    /// **Note**: If a guild command with the same name already exists, it is
+    /// overwritten. Other commands are left untouched.
+    ///
     /// # Errors
     ///
     /// Returns the same possible errors as [`create_global_application_command`].
@@ -1626,6 +2128,9 @@ impl GuildId {
 
     /// Edit guild application command by its Id.
     ///
+    /// Only the fields set on the [`CreateApplicationCommand`] are changed,
+    /// all others are left as they are.
+    ///
     /// # Errors
     ///
     /// If there is an error, it will be either [`Error::Http`] or [`Error::Json`].
@@ -1698,9 +2203,21 @@ impl GuildId {
     ///
     /// # Errors
     ///
-    /// Returns [`Error::Http`] if the bot cannot see the guild preview, see the note.
+    /// Returns [`ModelError::GuildPreviewUnavailable`] if the bot cannot see the guild
+    /// preview, see the note.
     pub async fn get_preview(&self, http: impl AsRef<Http>) -> Result<GuildPreview> {
-        http.as_ref().get_guild_preview(self.0).await
+        http.as_ref().get_guild_preview(self.0).await.map_err(|why| {
+            let forbidden = matches!(
+                &why,
+                Error::Http(http_error) if http_error.status_code() == Some(StatusCode::FORBIDDEN)
+            );
+
+            if forbidden {
+                Error::Model(ModelError::GuildPreviewUnavailable)
+            } else {
+                why
+            }
+        })
     }
 
     /// Get the guild widget.
@@ -1727,6 +2244,37 @@ impl GuildId {
     pub async fn get_active_threads(&self, http: impl AsRef<Http>) -> Result<ThreadsData> {
         http.as_ref().get_guild_active_threads(self.0).await
     }
+
+    /// Gets all of the guild's channels along with its active threads over the
+    /// REST API, as threads are not returned by [`Self::channels`].
+    ///
+    /// Threads the current user has joined have their [`GuildChannel::member`]
+    /// set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user is not in the guild.
+    pub async fn all_channels_and_threads(
+        self,
+        http: impl AsRef<Http>,
+    ) -> Result<HashMap<ChannelId, GuildChannel>> {
+        let http = http.as_ref();
+        let (mut channels, threads) =
+            futures::future::try_join(self.channels(http), self.get_active_threads(http)).await?;
+
+        let mut members: HashMap<_, _> =
+            threads.members.into_iter().filter_map(|m| Some((m.id?, m))).collect();
+
+        for mut thread in threads.threads {
+            if thread.member.is_none() {
+                thread.member = members.remove(&thread.id);
+            }
+
+            channels.insert(thread.id, thread);
+        }
+
+        Ok(channels)
+    }
 }
 
 impl From<PartialGuild> for GuildId {
@@ -1896,3 +2444,24 @@ impl fmt::Display for GuildWidgetStyle {
         }
     }
 }
+
+#[cfg(all(test, feature = "model"))]
+mod test {
+    use crate::http::Http;
+    use crate::model::error::Error as ModelError;
+    use crate::model::id::GuildId;
+    use crate::Error;
+
+    #[tokio::test]
+    async fn member_search_limit() {
+        let http = Http::new("");
+
+        // The limit is rejected before any request is made.
+        for limit in [0, 1001] {
+            assert!(matches!(
+                GuildId(1).search_members(&http, "user", Some(limit)).await,
+                Err(Error::Model(ModelError::MemberSearchLimit(l))) if l == limit
+            ));
+        }
+    }
+}
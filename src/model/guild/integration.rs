@@ -7,23 +7,46 @@ use crate::model::Timestamp;
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[non_exhaustive]
 pub struct Integration {
+    /// The Id of the integration.
     pub id: IntegrationId,
+    /// The Id of the guild the integration belongs to.
     pub guild_id: GuildId,
+    /// The account the integration is connected to, such as a Twitch channel.
     pub account: IntegrationAccount,
+    /// Whether the integration is enabled.
     pub enabled: bool,
+    /// What happens to subscribers once their subscription expires.
+    ///
+    /// **Note**: Not provided for bot integrations.
     #[serde(rename = "expire_behaviour")]
     pub expire_behaviour: Option<IntegrationExpireBehaviour>,
+    /// The number of days before an expired subscription is acted upon.
+    ///
+    /// **Note**: Not provided for bot integrations.
     pub expire_grace_period: Option<u64>,
+    /// The type of integration: `twitch`, `youtube`, `discord` or
+    /// `guild_subscription`.
     #[serde(rename = "type")]
     pub kind: String,
+    /// The name of the integration.
     pub name: String,
+    /// The Id of the role given to subscribers.
+    ///
+    /// **Note**: Not provided for bot integrations.
     pub role_id: Option<RoleId>,
+    /// When the integration was last synced.
     pub synced_at: Option<Timestamp>,
+    /// Whether the integration is currently syncing.
     pub syncing: Option<bool>,
+    /// The user who added the integration.
     pub user: Option<User>,
+    /// Whether emoticons should be synced, currently only for Twitch.
     pub enable_emoticons: Option<bool>,
+    /// The number of subscribers the integration has.
     pub subscriber_count: Option<u64>,
+    /// Whether the integration has been revoked.
     pub revoked: Option<bool>,
+    /// The bot or OAuth2 application, for `discord` integrations.
     pub application: Option<IntegrationApplication>,
 }
 
@@ -14,6 +14,8 @@ use crate::http::{CacheHttp, Http};
 use crate::internal::prelude::*;
 #[cfg(feature = "model")]
 use crate::json;
+#[cfg(feature = "model")]
+use crate::model::misc::cdn_hash_url;
 use crate::model::permissions::Permissions;
 use crate::model::prelude::*;
 use crate::model::Timestamp;
@@ -140,35 +142,97 @@ impl Member {
     }
 
     /// Adds one or multiple [`Role`]s to the member, editing
-    /// its roles in-place if the request was successful.
+    /// its roles in-place if the request was successful. Returns the member's
+    /// new roles.
+    ///
+    /// The roles are added with a single request, on top of the member's
+    /// roles as currently held in the cache if it is enabled and contains the
+    /// member, or else as fetched over the REST API, so that roles changed
+    /// since this member was retrieved are not lost or restored.
     ///
     /// **Note**: Requires the [Manage Roles] permission.
     ///
     /// # Errors
     ///
     /// Returns [`Error::Http`] if the current user lacks permission,
-    /// or if a role with a given Id does not exist.
+    /// if a role with a given Id does not exist, or if the member could not
+    /// be fetched.
     ///
     /// [Manage Roles]: Permissions::MANAGE_ROLES
+    #[inline]
     pub async fn add_roles(
         &mut self,
-        http: impl AsRef<Http>,
+        cache_http: impl CacheHttp,
         role_ids: &[RoleId],
     ) -> Result<Vec<RoleId>> {
-        self.roles.extend_from_slice(role_ids);
+        self._edit_roles(&cache_http, role_ids, true, None).await
+    }
 
-        let mut builder = EditMember::default();
-        builder.roles(&self.roles);
-        let map = json::hashmap_to_json_map(builder.0);
+    /// Adds one or multiple [`Role`]s to the member with a reason for the audit
+    /// log. Refer to [`Self::add_roles`] for further documentation.
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors [`Self::add_roles`] may return, can also return
+    /// [`Error::ExceededLimit`] if the length of the reason is greater than 512.
+    #[inline]
+    pub async fn add_roles_with_reason(
+        &mut self,
+        cache_http: impl CacheHttp,
+        role_ids: &[RoleId],
+        reason: impl AsRef<str>,
+    ) -> Result<Vec<RoleId>> {
+        self._edit_roles(&cache_http, role_ids, true, Some(reason.as_ref())).await
+    }
 
-        match http.as_ref().edit_member(self.guild_id.0, self.user.id.0, &map, None).await {
-            Ok(member) => Ok(member.roles),
-            Err(why) => {
-                self.roles.retain(|r| !role_ids.contains(r));
+    async fn _edit_roles(
+        &mut self,
+        cache_http: impl CacheHttp,
+        role_ids: &[RoleId],
+        add: bool,
+        reason: Option<&str>,
+    ) -> Result<Vec<RoleId>> {
+        if let Some(reason) = reason {
+            if reason.chars().count() > 512 {
+                return Err(Error::ExceededLimit(reason.to_string(), 512));
+            }
+        }
 
-                Err(why)
+        let mut roles = None;
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                roles = cache.member_field(self.guild_id, self.user.id, |m| m.roles.clone());
+            }
+        }
+
+        let mut roles = match roles {
+            Some(roles) => roles,
+            None => {
+                cache_http.http().get_member(self.guild_id.0, self.user.id.0).await?.roles
             },
+        };
+
+        if add {
+            for role_id in role_ids {
+                if !roles.contains(role_id) {
+                    roles.push(*role_id);
+                }
+            }
+        } else {
+            roles.retain(|r| !role_ids.contains(r));
         }
+
+        let mut builder = EditMember::default();
+        builder.roles(&roles);
+        let map = json::hashmap_to_json_map(builder.0);
+
+        let member =
+            cache_http.http().edit_member(self.guild_id.0, self.user.id.0, &map, reason).await?;
+        self.roles = member.roles.clone();
+
+        Ok(member.roles)
     }
 
     /// Ban a [`User`] from the guild, deleting a number of
@@ -277,10 +341,13 @@ impl Member {
 
     /// Calculates the member's display name.
     ///
-    /// The nickname takes priority over the member's username if it exists.
+    /// The nickname takes priority over the member's global name, which in turn
+    /// takes priority over their username.
     #[inline]
     pub fn display_name(&self) -> Cow<'_, String> {
-        self.nick.as_ref().map_or_else(|| Cow::Owned(self.user.name.clone()), Cow::Borrowed)
+        let name = self.nick.as_ref().or(self.user.global_name.as_ref());
+
+        Cow::Borrowed(name.unwrap_or(&self.user.name))
     }
 
     /// Returns the DiscordTag of a Member, taking possible nickname into account.
@@ -549,36 +616,47 @@ impl Member {
         }
     }
 
-    /// Removes one or multiple [`Role`]s from the member. Returns the member's
-    /// new roles.
+    /// Removes one or multiple [`Role`]s from the member, editing its roles
+    /// in-place if the request was successful. Returns the member's new roles.
+    ///
+    /// Like [`Self::add_roles`], the roles are removed with a single request
+    /// from the member's roles as currently held in the cache, falling back to
+    /// fetching the member over the REST API.
     ///
     /// **Note**: Requires the [Manage Roles] permission.
     ///
     /// # Errors
     ///
     /// Returns [`Error::Http`] if a role with a given Id does not exist,
-    /// or if the current user lacks permission.
+    /// if the current user lacks permission, or if the member could not be
+    /// fetched.
     ///
     /// [Manage Roles]: Permissions::MANAGE_ROLES
+    #[inline]
     pub async fn remove_roles(
         &mut self,
-        http: impl AsRef<Http>,
+        cache_http: impl CacheHttp,
         role_ids: &[RoleId],
     ) -> Result<Vec<RoleId>> {
-        self.roles.retain(|r| !role_ids.contains(r));
-
-        let mut builder = EditMember::default();
-        builder.roles(&self.roles);
-        let map = json::hashmap_to_json_map(builder.0);
-
-        match http.as_ref().edit_member(self.guild_id.0, self.user.id.0, &map, None).await {
-            Ok(member) => Ok(member.roles),
-            Err(why) => {
-                self.roles.extend_from_slice(role_ids);
+        self._edit_roles(&cache_http, role_ids, false, None).await
+    }
 
-                Err(why)
-            },
-        }
+    /// Removes one or multiple [`Role`]s from the member with a reason for the
+    /// audit log. Refer to [`Self::remove_roles`] for further documentation.
+    ///
+    /// # Errors
+    ///
+    /// In addition to the errors [`Self::remove_roles`] may return, can also
+    /// return [`Error::ExceededLimit`] if the length of the reason is greater
+    /// than 512.
+    #[inline]
+    pub async fn remove_roles_with_reason(
+        &mut self,
+        cache_http: impl CacheHttp,
+        role_ids: &[RoleId],
+        reason: impl AsRef<str>,
+    ) -> Result<Vec<RoleId>> {
+        self._edit_roles(&cache_http, role_ids, false, Some(reason.as_ref())).await
     }
 
     /// Retrieves the full role data for the user's roles.
@@ -622,6 +700,30 @@ impl Member {
         avatar_url(self.guild_id, self.user.id, self.avatar.as_ref())
     }
 
+    /// Returns the formatted URL of the member's per guild avatar in the given format and size, if
+    /// one exists.
+    ///
+    /// The format defaults to a GIF if the avatar is animated, or a WEBP otherwise,
+    /// and the size to 1024.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidImageFormat`] if a GIF is requested of
+    /// an avatar that is not animated, or a [`ModelError::InvalidImageSize`] if the
+    /// size is not a power of two between 16 and 4096.
+    ///
+    /// [`ModelError::InvalidImageFormat`]: crate::model::error::Error::InvalidImageFormat
+    /// [`ModelError::InvalidImageSize`]: crate::model::error::Error::InvalidImageSize
+    pub fn avatar_url_with(
+        &self,
+        format: Option<ImageFormat>,
+        size: Option<u16>,
+    ) -> Result<Option<String>> {
+        let prefix = format!("guilds/{}/users/{}/avatars", self.guild_id, self.user.id);
+
+        cdn_hash_url(&prefix, self.avatar.as_ref(), format, size)
+    }
+
     /// Retrieves the URL to the current member's avatar, falling back to the
     /// user's avatar, then default avatar if needed.
     ///
@@ -12,6 +12,7 @@ mod premium_tier;
 mod role;
 mod scheduled_event;
 mod system_channel;
+mod template;
 mod welcome_screen;
 
 #[cfg(feature = "model")]
@@ -35,6 +36,7 @@ pub use self::premium_tier::*;
 pub use self::role::*;
 pub use self::scheduled_event::*;
 pub use self::system_channel::*;
+pub use self::template::*;
 pub use self::welcome_screen::*;
 use super::utils::*;
 #[cfg(feature = "model")]
@@ -76,6 +78,8 @@ use crate::json::{from_number, from_value};
 use crate::model::application::command::{Command, CommandPermission};
 #[cfg(feature = "model")]
 use crate::model::guild::automod::Rule;
+#[cfg(feature = "model")]
+use crate::model::misc::cdn_hash_url;
 use crate::model::prelude::*;
 use crate::model::utils::{emojis, presences, roles, stickers};
 use crate::model::Timestamp;
@@ -91,6 +95,19 @@ pub struct Ban {
     pub user: User,
 }
 
+/// The result of a bulk ban, as returned by [`GuildId::bulk_ban`].
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#bulk-guild-ban-bulk-ban-response).
+#[derive(Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[non_exhaustive]
+pub struct BulkBanResponse {
+    /// The users that were successfully banned.
+    pub banned_users: Vec<UserId>,
+    /// The users that could not be banned, e.g. because they were already banned or outrank
+    /// the current user.
+    pub failed_users: Vec<UserId>,
+}
+
 /// Information about a Discord guild, such as channels, emojis, etc.
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-object) plus
@@ -212,6 +229,13 @@ pub struct Guild {
     ///
     /// **Note**: Only available on `COMMUNITY` guild, see [`Self::features`].
     pub public_updates_channel_id: Option<ChannelId>,
+    /// The id of the channel where admins and moderators of Community guilds
+    /// receive safety alerts from Discord.
+    ///
+    /// **Note**: Only available on `COMMUNITY` guild, see [`Self::features`].
+    pub safety_alerts_channel_id: Option<ChannelId>,
+    /// Whether the guild has the boost progress bar enabled.
+    pub premium_progress_bar_enabled: bool,
     /// Indicator of the current verification level of the guild.
     pub verification_level: VerificationLevel,
     /// A mapping of [`User`]s to their current voice state.
@@ -316,7 +340,11 @@ impl Guild {
     /// let _rule = guild
     ///     .create_automod_rule(&http, |r| {
     ///         r.name("foobar filter")
-    ///             .trigger(Trigger::Keyword(vec!["foo*".to_string(), "*bar".to_string()]))
+    ///             .trigger(Trigger::Keyword {
+    ///                 strings: vec!["foo*".to_string(), "*bar".to_string()],
+    ///                 regex_patterns: vec![],
+    ///                 allow_list: vec![],
+    ///             })
     ///             .actions(vec![Action::BlockMessage, Action::Timeout(60)])
     ///     })
     ///     .await;
@@ -552,6 +580,28 @@ impl Guild {
         self.banner.as_ref().map(|banner| cdn!("/banners/{}/{}.webp?size=1024", self.id, banner))
     }
 
+    /// Returns the formatted URL of the guild's banner in the given format and size, if
+    /// one exists.
+    ///
+    /// The format defaults to a GIF if the banner is animated, or a WEBP otherwise,
+    /// and the size to 1024.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidImageFormat`] if a GIF is requested of
+    /// a banner that is not animated, or a [`ModelError::InvalidImageSize`] if the
+    /// size is not a power of two between 16 and 4096.
+    ///
+    /// [`ModelError::InvalidImageFormat`]: crate::model::error::Error::InvalidImageFormat
+    /// [`ModelError::InvalidImageSize`]: crate::model::error::Error::InvalidImageSize
+    pub fn banner_url_with(
+        &self,
+        format: Option<ImageFormat>,
+        size: Option<u16>,
+    ) -> Result<Option<String>> {
+        cdn_hash_url(&format!("banners/{}", self.id), self.banner.as_ref(), format, size)
+    }
+
     /// Retrieves a list of [`Ban`]s for the guild.
     ///
     /// **Note**: Requires the [Ban Members] permission.
@@ -738,11 +788,35 @@ impl Guild {
     #[inline]
     pub async fn create_emoji(
         &self,
-        http: impl AsRef<Http>,
+        cache_http: impl CacheHttp,
         name: &str,
         image: &str,
     ) -> Result<Emoji> {
-        self.id.create_emoji(&http, name, image).await
+        self.id.create_emoji(cache_http, name, image).await
+    }
+
+    /// Creates an emoji in the guild with a name and raw image data.
+    ///
+    /// Refer to [`GuildId::create_emoji_from_bytes`] for more information.
+    ///
+    /// Requires the [Manage Emojis and Stickers] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidEmojiImage`] or
+    /// [`ModelError::EmojiImageTooLarge`] if the image is invalid.
+    ///
+    /// Otherwise returns the same errors as [`Self::create_emoji`].
+    ///
+    /// [Manage Emojis and Stickers]: Permissions::MANAGE_EMOJIS_AND_STICKERS
+    #[inline]
+    pub async fn create_emoji_from_bytes(
+        &self,
+        cache_http: impl CacheHttp,
+        name: &str,
+        image: &[u8],
+    ) -> Result<Emoji> {
+        self.id.create_emoji_from_bytes(cache_http, name, image).await
     }
 
     /// Creates an integration for the guild.
@@ -1062,10 +1136,10 @@ impl Guild {
     #[inline]
     pub async fn delete_emoji(
         &self,
-        http: impl AsRef<Http>,
+        cache_http: impl CacheHttp,
         emoji_id: impl Into<EmojiId>,
     ) -> Result<()> {
-        self.id.delete_emoji(&http, emoji_id).await
+        self.id.delete_emoji(cache_http, emoji_id).await
     }
 
     /// Deletes an integration by Id from the guild.
@@ -1173,6 +1247,9 @@ impl Guild {
     /// If the `cache` is enabled, returns a [`ModelError::InvalidPermissions`]
     /// if the current user does not have permission to edit the guild.
     ///
+    /// Returns a [`ModelError::MissingCommunityChannels`] if the `COMMUNITY` feature is enabled
+    /// without the guild having both a rules channel and a public updates channel.
+    ///
     /// Otherwise will return [`Error::Http`] if the current user does not have
     /// permission.
     ///
@@ -1192,7 +1269,17 @@ impl Guild {
             }
         }
 
-        match self.id.edit(cache_http.http(), f).await {
+        let mut edit_guild = EditGuild::default();
+        f(&mut edit_guild);
+        let current_channels = (self.rules_channel_id, self.public_updates_channel_id);
+        edit_guild.check_community_channels(Some(current_channels))?;
+
+        match self.id.edit(cache_http.http(), |e| {
+            *e = edit_guild;
+            e
+        })
+        .await
+        {
             Ok(guild) => {
                 self.afk_channel_id = guild.afk_channel_id;
                 self.afk_timeout = guild.afk_timeout;
@@ -1206,6 +1293,10 @@ impl Guild {
                 self.roles = guild.roles;
                 self.splash = guild.splash;
                 self.verification_level = guild.verification_level;
+                self.rules_channel_id = guild.rules_channel_id;
+                self.public_updates_channel_id = guild.public_updates_channel_id;
+                self.safety_alerts_channel_id = guild.safety_alerts_channel_id;
+                self.premium_progress_bar_enabled = guild.premium_progress_bar_enabled;
 
                 Ok(())
             },
@@ -1228,11 +1319,11 @@ impl Guild {
     #[inline]
     pub async fn edit_emoji(
         &self,
-        http: impl AsRef<Http>,
+        cache_http: impl CacheHttp,
         emoji_id: impl Into<EmojiId>,
         name: &str,
     ) -> Result<Emoji> {
-        self.id.edit_emoji(&http, emoji_id, name).await
+        self.id.edit_emoji(cache_http, emoji_id, name).await
     }
 
     /// Edits the properties of member of the guild, such as muting or
@@ -1414,6 +1505,9 @@ impl Guild {
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::WelcomeChannelAmount`] if more than 5 channels
+    /// are set.
+    ///
     /// Returns an [`Error::Http`] if some mandatory fields are not provided.
     pub async fn edit_welcome_screen<F>(
         &self,
@@ -1537,14 +1631,44 @@ impl Guild {
         })
     }
 
+    /// Returns the formatted URL of the guild's icon in the given format and size, if
+    /// one exists.
+    ///
+    /// The format defaults to a GIF if the icon is animated, or a WEBP otherwise,
+    /// and the size to 1024.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidImageFormat`] if a GIF is requested of
+    /// an icon that is not animated, or a [`ModelError::InvalidImageSize`] if the
+    /// size is not a power of two between 16 and 4096.
+    ///
+    /// [`ModelError::InvalidImageFormat`]: crate::model::error::Error::InvalidImageFormat
+    /// [`ModelError::InvalidImageSize`]: crate::model::error::Error::InvalidImageSize
+    pub fn icon_url_with(
+        &self,
+        format: Option<ImageFormat>,
+        size: Option<u16>,
+    ) -> Result<Option<String>> {
+        cdn_hash_url(&format!("icons/{}", self.id), self.icon.as_ref(), format, size)
+    }
+
+    /// Returns an iterator over the emojis of the guild, as held by this
+    /// model.
+    ///
+    /// Use [`Self::emojis`] to fetch them via HTTP instead.
+    pub fn emojis_iter(&self) -> impl Iterator<Item = &Emoji> {
+        self.emojis.values()
+    }
+
     /// Gets all [`Emoji`]s of this guild via HTTP.
     ///
     /// # Errors
     ///
     /// Returns [`Error::Http`] if the guild is unavailable
     #[inline]
-    pub async fn emojis(&self, http: impl AsRef<Http>) -> Result<Vec<Emoji>> {
-        self.id.emojis(http).await
+    pub async fn emojis(&self, cache_http: impl CacheHttp) -> Result<Vec<Emoji>> {
+        self.id.emojis(cache_http).await
     }
 
     /// Gets an [`Emoji`] of this guild by its ID via HTTP.
@@ -1646,13 +1770,18 @@ impl Guild {
 
     /// Leaves the guild.
     ///
+    /// Refer to [`GuildId::leave`] for more information.
+    ///
     /// # Errors
     ///
+    /// If the `cache` is enabled, returns a [`ModelError::OwnerCannotLeave`] if the current user
+    /// owns the guild.
+    ///
     /// May return an [`Error::Http`] if the current user
     /// cannot leave the guild, or currently is not in the guild.
     #[inline]
-    pub async fn leave(&self, http: impl AsRef<Http>) -> Result<()> {
-        self.id.leave(&http).await
+    pub async fn leave(&self, cache_http: impl CacheHttp) -> Result<()> {
+        self.id.leave(cache_http).await
     }
 
     /// Gets a user's [`Member`] for the guild by Id.
@@ -1710,97 +1839,110 @@ impl Guild {
         members
     }
 
-    /// Retrieves the first [`Member`] found that matches the name - with an
-    /// optional discriminator - provided.
+    /// Retrieves the first [`Member`] found that matches the given name, id or
+    /// mention.
     ///
-    /// Searching with a discriminator given is the most precise form of lookup,
-    /// as no two people can share the same username *and* discriminator.
+    /// Lookups are tried in the following order, returning the first match:
     ///
-    /// If a member can not be found by username or username#discriminator,
-    /// then a search will be done for the nickname. When searching by nickname,
-    /// the hash (`#`) and everything after it is included in the search.
+    /// 1. **id**: "114941315417899012"
+    /// 2. **mention**: "<@114941315417899012>" or "<@!114941315417899012>"
+    /// 3. **username and discriminator**: "zey#5479"
+    /// 4. **exact username, global name or nickname**: "zey"
+    /// 5. **case-insensitive username, global name or nickname**: "ZEY"
     ///
-    /// The following are valid types of searches:
+    /// Searching with a discriminator given is the most precise form of name
+    /// lookup, as no two people can share the same username *and*
+    /// discriminator. Users that migrated to the new username system have a
+    /// discriminator of `0` and unique usernames, so they are found by their
+    /// username alone.
     ///
-    /// - **username**: "zey"
-    /// - **username and discriminator**: "zey#5479"
+    /// When searching by nickname or global name, the hash (`#`) and
+    /// everything after it is included in the search.
     ///
     /// **Note**: This will only search members that are cached. If you want to
     /// search all members in the guild via the Http API, use
     /// [`Self::search_members`].
     #[must_use]
     pub fn member_named(&self, name: &str) -> Option<&Member> {
-        let (username, discrim) = match crate::utils::parse_user_tag(name) {
-            Some((username, discrim)) => (username, Some(discrim)),
-            None => (name, None),
-        };
+        if let Ok(id) = name.parse::<u64>() {
+            if let Some(member) = self.members.get(&UserId(id)) {
+                return Some(member);
+            }
+        }
 
-        for member in self.members.values() {
-            if member.user.name == username
-                && discrim.map_or(true, |d| member.user.discriminator == d)
-            {
+        if let Some(id) = crate::utils::parse_username(name) {
+            return self.members.get(&UserId(id));
+        }
+
+        if let Some((username, discrim)) = crate::utils::parse_user_tag(name) {
+            if let Some(member) = self.members.values().find(|member| {
+                member.user.name == username && member.user.discriminator == discrim
+            }) {
                 return Some(member);
             }
         }
 
-        self.members.values().find(|member| member.nick.as_ref().map_or(false, |nick| nick == name))
+        self.members
+            .values()
+            .find(|member| member_names(member).any(|candidate| candidate == name))
+            .or_else(|| {
+                self.members.values().find(|member| {
+                    member_names(member).any(|candidate| candidate.eq_ignore_ascii_case(name))
+                })
+            })
     }
 
-    /// Retrieves all [`Member`] that start with a given [`String`].
+    /// Retrieves all [`Member`] whose username, global name or nickname start
+    /// with a given [`String`], in that order of priority.
+    ///
+    /// Each member is paired with the name that matched the `prefix`.
     ///
     /// `sorted` decides whether the best early match of the `prefix`
     /// should be the criteria to sort the result.
     /// For the `prefix` "zey" and the unsorted result:
-    /// - "zeya", "zeyaa", "zeyla", "zeyzey", "zeyzeyzey"
+    /// - "zeyla", "zeyzeyzey", "zeya", "zeyzey", "zeyaa"
     /// It would be sorted:
     /// - "zeya", "zeyaa", "zeyla", "zeyzey", "zeyzeyzey"
     ///
-    /// **Locking**:
-    /// First collects a [`Member`]'s [`User`]-name by read-locking all inner
-    /// [`User`]s, and then sorts. This ensures that no name is being changed
-    /// after being sorted in the originally correct position.
-    /// However, since the read-locks are dropped after borrowing the name,
-    /// the names might have been changed by the user, the sorted list cannot
-    /// account for this.
+    /// If a `limit` is given, at most that many members are returned. When
+    /// combined with `sorted`, the closest matches are kept.
     ///
     /// **Note**: This will only search members that are cached. If you want to
     /// search all members in the guild via the Http API, use
     /// [`Self::search_members`].
-    pub async fn members_starting_with(
+    #[must_use]
+    pub fn members_starting_with(
         &self,
         prefix: &str,
         case_sensitive: bool,
         sorted: bool,
+        limit: Option<usize>,
     ) -> Vec<(&Member, String)> {
-        fn starts_with(prefix: &str, case_sensitive: bool, name: &str) -> bool {
-            case_sensitive && name.starts_with(prefix)
-                || !case_sensitive && starts_with_case_insensitive(name, prefix)
-        }
+        let starts_with = |name: &str| {
+            if case_sensitive {
+                name.starts_with(prefix)
+            } else {
+                starts_with_case_insensitive(name, prefix)
+            }
+        };
 
-        let mut members = futures::stream::iter(self.members.values())
-            .filter_map(|member| async move {
-                let username = &member.user.name;
+        let matches = self.members.values().filter_map(|member| {
+            member_names(member)
+                .find(|name| starts_with(name))
+                .map(|name| (member, name.to_string()))
+        });
 
-                if starts_with(prefix, case_sensitive, username) {
-                    Some((member, username.to_string()))
-                } else {
-                    match member.nick {
-                        Some(ref nick) => {
-                            if starts_with(prefix, case_sensitive, nick) {
-                                Some((member, nick.to_string()))
-                            } else {
-                                None
-                            }
-                        },
-                        None => None,
-                    }
-                }
-            })
-            .collect::<Vec<(&Member, String)>>()
-            .await;
+        let mut members: Vec<(&Member, String)> = match limit {
+            Some(limit) if !sorted => matches.take(limit).collect(),
+            _ => matches.collect(),
+        };
 
         if sorted {
             members.sort_by(|a, b| closest_to_origin(prefix, &a.1[..], &b.1[..]));
+
+            if let Some(limit) = limit {
+                members.truncate(limit);
+            }
         }
 
         members
@@ -2296,6 +2438,21 @@ impl Guild {
         permissions
     }
 
+    /// Gets the voice regions that the guild can use.
+    ///
+    /// If the guild has the `VIP_REGIONS` feature enabled, VIP-only regions
+    /// are returned as well. Use [`Http::get_voice_regions`] to list all
+    /// regions regardless of a guild.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the guild is unavailable, or
+    /// [`Error::Json`] if there is an error deserializing the API response.
+    #[inline]
+    pub async fn regions(&self, http: impl AsRef<Http>) -> Result<Vec<VoiceRegion>> {
+        self.id.regions(&http).await
+    }
+
     /// Re-orders the channels of the guild.
     ///
     /// Although not required, you should specify all channels' positions,
@@ -2320,13 +2477,12 @@ impl Guild {
     /// Returns a list of [`Member`]s in a [`Guild`] whose username or nickname
     /// starts with a provided string.
     ///
-    /// Optionally pass in the `limit` to limit the number of results.
-    /// Minimum value is 1, maximum and default value is 1000.
-    ///
-    /// **Note**: Queries are case insensitive.
+    /// Refer to [`GuildId::search_members`] for more information.
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::MemberSearchLimit`] if the `limit` is invalid.
+    ///
     /// Returns an [`Error::Http`] if the API returns an error.
     #[inline]
     pub async fn search_members(
@@ -2469,6 +2625,28 @@ impl Guild {
         self.splash.as_ref().map(|splash| cdn!("/splashes/{}/{}.webp?size=4096", self.id, splash))
     }
 
+    /// Returns the formatted URL of the guild's splash image in the given format and size, if
+    /// one exists.
+    ///
+    /// The format defaults to a WEBP, and the size to 1024 rather than the 4096 of
+    /// [`Self::splash_url`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidImageFormat`] if a GIF is requested of
+    /// a splash that is not animated, or a [`ModelError::InvalidImageSize`] if the
+    /// size is not a power of two between 16 and 4096.
+    ///
+    /// [`ModelError::InvalidImageFormat`]: crate::model::error::Error::InvalidImageFormat
+    /// [`ModelError::InvalidImageSize`]: crate::model::error::Error::InvalidImageSize
+    pub fn splash_url_with(
+        &self,
+        format: Option<ImageFormat>,
+        size: Option<u16>,
+    ) -> Result<Option<String>> {
+        cdn_hash_url(&format!("splashes/{}", self.id), self.splash.as_ref(), format, size)
+    }
+
     /// Starts an integration sync for the given integration Id.
     ///
     /// Requires the [Manage Guild] permission.
@@ -2565,7 +2743,7 @@ impl Guild {
     /// Can also return an [`Error::Json`] if there is an error deserializing
     /// the API response.
     #[inline]
-    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<String> {
+    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<VanityUrl> {
         self.id.vanity_url(&http).await
     }
 
@@ -2587,8 +2765,8 @@ impl Guild {
 
     /// Obtain a reference to a role by its name.
     ///
-    /// **Note**: If two or more roles have the same name, obtained reference will be one of
-    /// them.
+    /// The name is matched case-sensitively. If two or more roles have the same name, the one
+    /// with the lowest position is returned.
     ///
     /// # Examples
     ///
@@ -2624,7 +2802,65 @@ impl Guild {
     /// ```
     #[must_use]
     pub fn role_by_name(&self, role_name: &str) -> Option<&Role> {
-        self.roles.values().find(|role| role_name == role.name)
+        self.roles
+            .values()
+            .filter(|role| role_name == role.name)
+            .min_by_key(|role| (role.position, role.id))
+    }
+
+    /// Obtain a reference to a role by its name, ignoring ASCII case.
+    ///
+    /// Like [`Self::role_by_name`], the role with the lowest position is returned if multiple
+    /// roles match.
+    #[must_use]
+    pub fn role_by_name_case_insensitive(&self, role_name: &str) -> Option<&Role> {
+        self.roles
+            .values()
+            .filter(|role| role.name.eq_ignore_ascii_case(role_name))
+            .min_by_key(|role| (role.position, role.id))
+    }
+
+    /// Obtain a reference to the `@everyone` role, whose Id is the same as the guild's.
+    #[must_use]
+    pub fn everyone_role(&self) -> Option<&Role> {
+        self.roles.get(&RoleId(self.id.0))
+    }
+
+    /// Obtain a reference to the highest of the member's roles in the hierarchy, if they have
+    /// any.
+    ///
+    /// If two or more roles share the highest position, the one with the lowest Id is the
+    /// highest, matching [`Member::highest_role_info`].
+    #[must_use]
+    pub fn member_highest_role(&self, member: &Member) -> Option<&Role> {
+        member
+            .roles
+            .iter()
+            .filter_map(|role_id| self.roles.get(role_id))
+            .max_by(|a, b| a.position.cmp(&b.position).then(b.id.cmp(&a.id)))
+    }
+
+    /// Whether the role hierarchy allows `actor` to moderate `target`, e.g. to kick, ban, or edit
+    /// the roles of them.
+    ///
+    /// The guild owner can act on everyone else, and nobody can act on the owner. Otherwise, the
+    /// actor's highest role must be strictly higher than the target's; members whose highest
+    /// roles share a position cannot act on each other.
+    ///
+    /// **Note**: This does not check that the actor has the permission required for the action.
+    #[must_use]
+    pub fn can_member_act_on(&self, actor: &Member, target: &Member) -> bool {
+        if target.user.id == self.owner_id {
+            return false;
+        }
+
+        if actor.user.id == self.owner_id {
+            return true;
+        }
+
+        let position = |member| self.member_highest_role(member).map_or(i64::MIN, |r| r.position);
+
+        position(actor) > position(target)
     }
 
     /// Returns a future that will await one message sent in this guild.
@@ -2666,6 +2902,22 @@ impl Guild {
     pub async fn get_active_threads(&self, http: impl AsRef<Http>) -> Result<ThreadsData> {
         self.id.get_active_threads(http).await
     }
+
+    /// Gets all of the guild's channels along with its active threads over the
+    /// REST API.
+    ///
+    /// Refer to [`GuildId::all_channels_and_threads`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user is not in the guild.
+    #[inline]
+    pub async fn all_channels_and_threads(
+        &self,
+        http: impl AsRef<Http>,
+    ) -> Result<HashMap<ChannelId, GuildChannel>> {
+        self.id.all_channels_and_threads(http).await
+    }
 }
 
 impl<'de> Deserialize<'de> for Guild {
@@ -2840,37 +3092,35 @@ impl<'de> Deserialize<'de> for Guild {
             .and_then(String::deserialize)
             .map_err(DeError::custom)?;
 
-        let welcome_screen = map
-            .remove("welcome_screen")
-            .map(GuildWelcomeScreen::deserialize)
-            .transpose()
-            .map_err(DeError::custom)?;
+        let welcome_screen = match map.remove("welcome_screen") {
+            Some(v) => Option::<GuildWelcomeScreen>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
 
-        let approximate_member_count = map
-            .remove("approximate_member_count")
-            .map(u64::deserialize)
-            .transpose()
-            .map_err(DeError::custom)?;
+        let approximate_member_count = match map.remove("approximate_member_count") {
+            Some(v) => Option::<u64>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
 
-        let approximate_presence_count = map
-            .remove("approximate_presence_count")
-            .map(u64::deserialize)
-            .transpose()
-            .map_err(DeError::custom)?;
+        let approximate_presence_count = match map.remove("approximate_presence_count") {
+            Some(v) => Option::<u64>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
 
-        let max_video_channel_users = map
-            .remove("max_video_channel_users")
-            .map(u64::deserialize)
-            .transpose()
-            .map_err(DeError::custom)?;
+        let max_video_channel_users = match map.remove("max_video_channel_users") {
+            Some(v) => Option::<u64>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
 
         let max_presences = match map.remove("max_presences") {
             Some(v) => Option::<u64>::deserialize(v).map_err(DeError::custom)?,
             None => None,
         };
 
-        let max_members =
-            map.remove("max_members").map(u64::deserialize).transpose().map_err(DeError::custom)?;
+        let max_members = match map.remove("max_members") {
+            Some(v) => Option::<u64>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
 
         let discovery_splash = match map.remove("discovery_splash") {
             Some(v) => Option::<String>::deserialize(v).map_err(DeError::custom)?,
@@ -2903,6 +3153,16 @@ impl<'de> Deserialize<'de> for Guild {
             None => None,
         };
 
+        let safety_alerts_channel_id = match map.remove("safety_alerts_channel_id") {
+            Some(v) => Option::<ChannelId>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
+
+        let premium_progress_bar_enabled = match map.remove("premium_progress_bar_enabled") {
+            Some(v) => bool::deserialize(v).map_err(DeError::custom)?,
+            None => false,
+        };
+
         let system_channel_flags = map
             .remove("system_channel_flags")
             .ok_or_else(|| DeError::custom("expected system_channel_flags"))
@@ -2951,6 +3211,8 @@ impl<'de> Deserialize<'de> for Guild {
             system_channel_flags,
             rules_channel_id,
             public_updates_channel_id,
+            safety_alerts_channel_id,
+            premium_progress_bar_enabled,
             verification_level,
             voice_states,
             description,
@@ -2975,6 +3237,16 @@ impl<'de> Deserialize<'de> for Guild {
     }
 }
 
+/// Returns the username, global name and nickname of a member, in that order.
+#[cfg(feature = "model")]
+fn member_names(member: &Member) -> impl Iterator<Item = &str> {
+    let user = &member.user;
+
+    std::iter::once(user.name.as_str())
+        .chain(user.global_name.as_deref())
+        .chain(member.nick.as_deref())
+}
+
 /// Checks if a `&str` contains another `&str`.
 #[cfg(feature = "model")]
 fn contains_case_insensitive(to_look_at: &str, to_find: &str) -> bool {
@@ -2984,7 +3256,9 @@ fn contains_case_insensitive(to_look_at: &str, to_find: &str) -> bool {
 /// Checks if a `&str` starts with another `&str`.
 #[cfg(feature = "model")]
 fn starts_with_case_insensitive(to_look_at: &str, to_find: &str) -> bool {
-    to_look_at.to_lowercase().starts_with(&to_find.to_lowercase())
+    let mut to_look_at = to_look_at.chars().flat_map(char::to_lowercase);
+
+    to_find.chars().flat_map(char::to_lowercase).all(|c| to_look_at.next() == Some(c))
 }
 
 /// Takes a `&str` as `origin` and tests if either
@@ -3045,11 +3319,25 @@ pub struct GuildPrune {
     pub pruned: u64,
 }
 
+/// A [`Guild`]'s vanity invite.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild#get-guild-vanity-url).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct VanityUrl {
+    /// The code of the vanity invite, if the guild has one.
+    pub code: Option<String>,
+    /// The number of times the vanity invite has been used.
+    #[serde(default)]
+    pub uses: u64,
+}
+
 /// Basic information about a guild.
 ///
 /// [Discord docs](https://discord.com/developers/docs/resources/guild#guild-object), subset undocumented (closest thing is
 /// [this](https://discord.com/developers/docs/topics/rpc#getguilds-get-guilds-response-structure)).
 #[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
 pub struct GuildInfo {
     /// The unique Id of the guild.
     ///
@@ -3065,6 +3353,18 @@ pub struct GuildInfo {
     pub owner: bool,
     /// The permissions that the current user has.
     pub permissions: Permissions,
+    /// The approximate number of members in the guild.
+    ///
+    /// Only present when fetched with [`Http::get_guilds_with_counts`].
+    ///
+    /// [`Http::get_guilds_with_counts`]: crate::http::Http::get_guilds_with_counts
+    pub approximate_member_count: Option<u64>,
+    /// The approximate number of online members in the guild.
+    ///
+    /// Only present when fetched with [`Http::get_guilds_with_counts`].
+    ///
+    /// [`Http::get_guilds_with_counts`]: crate::http::Http::get_guilds_with_counts
+    pub approximate_presence_count: Option<u64>,
 }
 
 #[cfg(any(feature = "model", feature = "utils"))]
@@ -3332,6 +3632,8 @@ mod test {
                 discovery_splash: None,
                 widget_channel_id: None,
                 public_updates_channel_id: None,
+                safety_alerts_channel_id: None,
+                premium_progress_bar_enabled: false,
                 stage_instances: vec![],
                 threads: vec![],
                 stickers: hm7,
@@ -3353,5 +3655,184 @@ mod test {
 
             assert_eq!(lhs, gen_member().display_name());
         }
+
+        #[test]
+        fn member_named_id() {
+            let guild = gen();
+
+            assert!(guild.member_named("210").is_some());
+            assert!(guild.member_named("211").is_none());
+        }
+
+        #[test]
+        fn member_named_mention() {
+            let guild = gen();
+
+            assert!(guild.member_named("<@210>").is_some());
+            assert!(guild.member_named("<@!210>").is_some());
+            assert!(guild.member_named("<@211>").is_none());
+        }
+
+        #[test]
+        fn member_named_wrong_discriminator() {
+            let guild = gen();
+
+            assert!(guild.member_named("test#0001").is_none());
+        }
+
+        #[test]
+        fn member_named_case_insensitive() {
+            let guild = gen();
+
+            assert!(guild.member_named("TEST").is_some());
+            assert!(guild.member_named("AaAa").is_some());
+        }
+
+        #[test]
+        fn member_named_global_name() {
+            let mut guild = gen();
+            let member = guild.members.get_mut(&UserId(210)).unwrap();
+            member.user.global_name = Some("Zeyla".to_string());
+
+            assert!(guild.member_named("Zeyla").is_some());
+            assert!(guild.member_named("zeyla").is_some());
+        }
+
+        #[test]
+        fn members_starting_with_fields() {
+            let mut guild = gen();
+            let mut other = gen_member();
+            other.user.id = UserId(211);
+            other.user.name = "other".to_string();
+            other.user.global_name = Some("Zeyla".to_string());
+            other.nick = None;
+            guild.members.insert(other.user.id, other);
+
+            let matches = guild.members_starting_with("ZEY", false, true, None);
+            assert_eq!(matches.len(), 1);
+            assert_eq!(matches[0].1, "Zeyla");
+
+            let matches = guild.members_starting_with("aa", true, true, None);
+            assert_eq!(matches.len(), 1);
+            assert_eq!(matches[0].1, "aaaa");
+
+            let matches = guild.members_starting_with("ZEY", true, false, None);
+            assert!(matches.is_empty());
+        }
+
+        #[test]
+        fn members_starting_with_limit() {
+            let mut guild = gen();
+            let mut other = gen_member();
+            other.user.id = UserId(211);
+            other.user.name = "testing".to_string();
+            guild.members.insert(other.user.id, other);
+
+            let matches = guild.members_starting_with("test", true, true, None);
+            assert_eq!(matches.len(), 2);
+
+            let matches = guild.members_starting_with("test", true, true, Some(1));
+            assert_eq!(matches.len(), 1);
+            assert_eq!(matches[0].1, "test");
+        }
+
+        #[test]
+        fn member_named_exact_before_case_insensitive() {
+            let mut guild = gen();
+            let mut other = gen_member();
+            other.user.id = UserId(211);
+            other.user.name = "TEST".to_string();
+            other.nick = None;
+            guild.members.insert(other.user.id, other);
+
+            assert_eq!(guild.member_named("TEST").unwrap().user.id, UserId(211));
+            assert_eq!(guild.member_named("test").unwrap().user.id, UserId(210));
+        }
+
+        fn gen_role(id: u64, name: &str, position: i64) -> Role {
+            Role {
+                id: RoleId(id),
+                guild_id: GuildId(1),
+                colour: Default::default(),
+                hoist: false,
+                managed: false,
+                mentionable: false,
+                name: name.to_string(),
+                permissions: Permissions::empty(),
+                position,
+                tags: RoleTags::default(),
+                icon: None,
+                unicode_emoji: None,
+            }
+        }
+
+        #[test]
+        fn role_by_name_duplicates() {
+            let mut guild = gen();
+            for role in [
+                gen_role(1, "@everyone", 0),
+                gen_role(2, "Mod", 3),
+                gen_role(3, "Mod", 1),
+                gen_role(4, "mod", 2),
+            ] {
+                guild.roles.insert(role.id, role);
+            }
+
+            assert_eq!(guild.role_by_name("Mod").unwrap().id, RoleId(3));
+            assert_eq!(guild.role_by_name("mod").unwrap().id, RoleId(4));
+            assert!(guild.role_by_name("MOD").is_none());
+            assert_eq!(guild.role_by_name_case_insensitive("MOD").unwrap().id, RoleId(3));
+            assert_eq!(guild.everyone_role().unwrap().id, RoleId(1));
+        }
+
+        #[test]
+        fn member_role_hierarchy() {
+            let mut guild = gen();
+            for role in [gen_role(2, "Admin", 3), gen_role(3, "Mod", 2), gen_role(4, "Helper", 2)]
+            {
+                guild.roles.insert(role.id, role);
+            }
+
+            let member = |id: u64, roles: Vec<u64>| {
+                let mut member = gen_member();
+                member.user.id = UserId(id);
+                member.roles = roles.into_iter().map(RoleId).collect();
+                member
+            };
+            let owner = member(210, vec![]);
+            let admin = member(1, vec![3, 2]);
+            let moderator = member(2, vec![3]);
+            let helper = member(3, vec![4]);
+            let nobody = member(4, vec![]);
+
+            assert_eq!(guild.member_highest_role(&admin).unwrap().id, RoleId(2));
+            assert!(guild.member_highest_role(&nobody).is_none());
+
+            // Equal positions cannot act on each other.
+            assert!(!guild.can_member_act_on(&moderator, &helper));
+            assert!(!guild.can_member_act_on(&helper, &moderator));
+            assert!(!guild.can_member_act_on(&nobody, &nobody));
+
+            assert!(guild.can_member_act_on(&admin, &moderator));
+            assert!(!guild.can_member_act_on(&moderator, &admin));
+            assert!(guild.can_member_act_on(&helper, &nobody));
+
+            // The owner can act on everyone, and nobody can act on the owner.
+            assert!(guild.can_member_act_on(&owner, &admin));
+            assert!(!guild.can_member_act_on(&admin, &owner));
+            assert!(!guild.can_member_act_on(&owner, &owner));
+        }
+    }
+
+    #[test]
+    fn vanity_url_without_code() {
+        use crate::json::{from_value, json};
+
+        let vanity: super::VanityUrl = from_value(json!({"code": null, "uses": 0})).unwrap();
+        assert!(vanity.code.is_none());
+
+        let vanity: super::VanityUrl = from_value(json!({"code": "serenity", "uses": 42})).unwrap();
+        assert_eq!(vanity.code.as_deref(), Some("serenity"));
+        assert_eq!(vanity.uses, 42);
     }
 }
@@ -36,6 +36,8 @@ use crate::json::{from_number, from_value};
 use crate::model::application::command::{Command, CommandPermission};
 #[cfg(feature = "model")]
 use crate::model::guild::automod::Rule;
+#[cfg(feature = "model")]
+use crate::model::misc::cdn_hash_url;
 use crate::model::prelude::*;
 use crate::model::utils::{emojis, roles, stickers};
 
@@ -110,6 +112,13 @@ pub struct PartialGuild {
     ///
     /// **Note**: Only available on `COMMUNITY` guild, see [`Self::features`].
     pub public_updates_channel_id: Option<ChannelId>,
+    /// The id of the channel where admins and moderators of Community guilds
+    /// receive safety alerts from Discord.
+    ///
+    /// **Note**: Only available on `COMMUNITY` guild, see [`Self::features`].
+    pub safety_alerts_channel_id: Option<ChannelId>,
+    /// Whether the guild has the boost progress bar enabled.
+    pub premium_progress_bar_enabled: bool,
     /// Indicator of the current verification level of the guild.
     pub verification_level: VerificationLevel,
     /// The guild's description, if it has one.
@@ -198,7 +207,11 @@ impl PartialGuild {
     /// let _rule = guild
     ///     .create_automod_rule(&http, |r| {
     ///         r.name("foobar filter")
-    ///             .trigger(Trigger::Keyword(vec!["foo*".to_string(), "*bar".to_string()]))
+    ///             .trigger(Trigger::Keyword {
+    ///                 strings: vec!["foo*".to_string(), "*bar".to_string()],
+    ///                 regex_patterns: vec![],
+    ///                 allow_list: vec![],
+    ///             })
     ///             .actions(vec![Action::BlockMessage, Action::Timeout(60)])
     ///     })
     ///     .await;
@@ -433,11 +446,35 @@ impl PartialGuild {
     #[inline]
     pub async fn create_emoji(
         &self,
-        http: impl AsRef<Http>,
+        cache_http: impl CacheHttp,
         name: &str,
         image: &str,
     ) -> Result<Emoji> {
-        self.id.create_emoji(&http, name, image).await
+        self.id.create_emoji(cache_http, name, image).await
+    }
+
+    /// Creates an emoji in the guild with a name and raw image data.
+    ///
+    /// Refer to [`GuildId::create_emoji_from_bytes`] for more information.
+    ///
+    /// Requires the [Manage Emojis and Stickers] permission.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidEmojiImage`] or
+    /// [`ModelError::EmojiImageTooLarge`] if the image is invalid.
+    ///
+    /// Otherwise returns the same errors as [`Self::create_emoji`].
+    ///
+    /// [Manage Emojis and Stickers]: Permissions::MANAGE_EMOJIS_AND_STICKERS
+    #[inline]
+    pub async fn create_emoji_from_bytes(
+        &self,
+        cache_http: impl CacheHttp,
+        name: &str,
+        image: &[u8],
+    ) -> Result<Emoji> {
+        self.id.create_emoji_from_bytes(cache_http, name, image).await
     }
 
     /// Creates an integration for the guild.
@@ -693,10 +730,10 @@ impl PartialGuild {
     #[inline]
     pub async fn delete_emoji(
         &self,
-        http: impl AsRef<Http>,
+        cache_http: impl CacheHttp,
         emoji_id: impl Into<EmojiId>,
     ) -> Result<()> {
-        self.id.delete_emoji(&http, emoji_id).await
+        self.id.delete_emoji(cache_http, emoji_id).await
     }
 
     /// Deletes an integration by Id from the guild.
@@ -766,6 +803,9 @@ impl PartialGuild {
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::MissingCommunityChannels`] if the `COMMUNITY` feature is enabled
+    /// without the guild having both a rules channel and a public updates channel.
+    ///
     /// Returns [`Error::Http`] if an invalid value is set, or if the current user
     /// lacks permission to edit the guild.
     ///
@@ -774,7 +814,17 @@ impl PartialGuild {
     where
         F: FnOnce(&mut EditGuild) -> &mut EditGuild,
     {
-        match self.id.edit(&http, f).await {
+        let mut edit_guild = EditGuild::default();
+        f(&mut edit_guild);
+        let current_channels = (self.rules_channel_id, self.public_updates_channel_id);
+        edit_guild.check_community_channels(Some(current_channels))?;
+
+        match self.id.edit(&http, |e| {
+            *e = edit_guild;
+            e
+        })
+        .await
+        {
             Ok(guild) => {
                 self.afk_channel_id = guild.afk_channel_id;
                 self.afk_timeout = guild.afk_timeout;
@@ -788,6 +838,10 @@ impl PartialGuild {
                 self.roles = guild.roles;
                 self.splash = guild.splash;
                 self.verification_level = guild.verification_level;
+                self.rules_channel_id = guild.rules_channel_id;
+                self.public_updates_channel_id = guild.public_updates_channel_id;
+                self.safety_alerts_channel_id = guild.safety_alerts_channel_id;
+                self.premium_progress_bar_enabled = guild.premium_progress_bar_enabled;
 
                 Ok(())
             },
@@ -811,11 +865,11 @@ impl PartialGuild {
     #[inline]
     pub async fn edit_emoji(
         &self,
-        http: impl AsRef<Http>,
+        cache_http: impl CacheHttp,
         emoji_id: impl Into<EmojiId>,
         name: &str,
     ) -> Result<Emoji> {
-        self.id.edit_emoji(&http, emoji_id, name).await
+        self.id.edit_emoji(cache_http, emoji_id, name).await
     }
 
     /// Edits the properties of member of the guild, such as muting or
@@ -962,6 +1016,9 @@ impl PartialGuild {
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::WelcomeChannelAmount`] if more than 5 channels
+    /// are set.
+    ///
     /// Returns an [`Error::Http`] if some mandatory fields are not provided.
     pub async fn edit_welcome_screen<F>(
         &self,
@@ -1141,6 +1198,21 @@ impl PartialGuild {
         Ok(permissions)
     }
 
+    /// Gets the voice regions that the guild can use.
+    ///
+    /// If the guild has the `VIP_REGIONS` feature enabled, VIP-only regions
+    /// are returned as well. Use [`Http::get_voice_regions`] to list all
+    /// regions regardless of a guild.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`Error::Http`] if the guild is unavailable, or
+    /// [`Error::Json`] if there is an error deserializing the API response.
+    #[inline]
+    pub async fn regions(&self, http: impl AsRef<Http>) -> Result<Vec<VoiceRegion>> {
+        self.id.regions(&http).await
+    }
+
     /// Re-orders the channels of the guild.
     ///
     /// Although not required, you should specify all channels' positions,
@@ -1165,13 +1237,12 @@ impl PartialGuild {
     /// Returns a list of [`Member`]s in a [`Guild`] whose username or nickname
     /// starts with a provided string.
     ///
-    /// Optionally pass in the `limit` to limit the number of results.
-    /// Minimum value is 1, maximum and default value is 1000.
-    ///
-    /// **Note**: Queries are case insensitive.
+    /// Refer to [`GuildId::search_members`] for more information.
     ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::MemberSearchLimit`] if the `limit` is invalid.
+    ///
     /// Returns an [`Error::Http`] if the API returns an error.
     #[inline]
     pub async fn search_members(
@@ -1268,20 +1339,64 @@ impl PartialGuild {
         self.icon.as_ref().map(|icon| cdn!("/icons/{}/{}.webp", self.id, icon))
     }
 
+    /// Returns the formatted URL of the guild's icon in the given format and size, if
+    /// one exists.
+    ///
+    /// The format defaults to a GIF if the icon is animated, or a WEBP otherwise,
+    /// and the size to 1024.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidImageFormat`] if a GIF is requested of
+    /// an icon that is not animated, or a [`ModelError::InvalidImageSize`] if the
+    /// size is not a power of two between 16 and 4096.
+    ///
+    /// [`ModelError::InvalidImageFormat`]: crate::model::error::Error::InvalidImageFormat
+    /// [`ModelError::InvalidImageSize`]: crate::model::error::Error::InvalidImageSize
+    pub fn icon_url_with(
+        &self,
+        format: Option<ImageFormat>,
+        size: Option<u16>,
+    ) -> Result<Option<String>> {
+        cdn_hash_url(&format!("icons/{}", self.id), self.icon.as_ref(), format, size)
+    }
+
     /// Returns a formatted URL of the guild's banner, if the guild has a banner.
     #[must_use]
     pub fn banner_url(&self) -> Option<String> {
         self.banner.as_ref().map(|banner| cdn!("/banners/{}/{}.webp", self.id, banner))
     }
 
+    /// Returns the formatted URL of the guild's banner in the given format and size, if
+    /// one exists.
+    ///
+    /// The format defaults to a GIF if the banner is animated, or a WEBP otherwise,
+    /// and the size to 1024.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidImageFormat`] if a GIF is requested of
+    /// a banner that is not animated, or a [`ModelError::InvalidImageSize`] if the
+    /// size is not a power of two between 16 and 4096.
+    ///
+    /// [`ModelError::InvalidImageFormat`]: crate::model::error::Error::InvalidImageFormat
+    /// [`ModelError::InvalidImageSize`]: crate::model::error::Error::InvalidImageSize
+    pub fn banner_url_with(
+        &self,
+        format: Option<ImageFormat>,
+        size: Option<u16>,
+    ) -> Result<Option<String>> {
+        cdn_hash_url(&format!("banners/{}", self.id), self.banner.as_ref(), format, size)
+    }
+
     /// Gets all [`Emoji`]s of this guild via HTTP.
     ///
     /// # Errors
     ///
     /// Returns [`Error::Http`] if the guild is unavailable.
     #[inline]
-    pub async fn emojis(&self, http: impl AsRef<Http>) -> Result<Vec<Emoji>> {
-        self.id.emojis(http).await
+    pub async fn emojis(&self, cache_http: impl CacheHttp) -> Result<Vec<Emoji>> {
+        self.id.emojis(cache_http).await
     }
 
     /// Gets an [`Emoji`] of this guild by its ID via HTTP.
@@ -1325,13 +1440,18 @@ impl PartialGuild {
 
     /// Leaves the guild.
     ///
+    /// Refer to [`GuildId::leave`] for more information.
+    ///
     /// # Errors
     ///
+    /// If the `cache` is enabled, returns a [`ModelError::OwnerCannotLeave`] if the current user
+    /// owns the guild.
+    ///
     /// Returns [`Error::Http`] if the current user is unable to
     /// leave the Guild, or currently is not in the guild.
     #[inline]
-    pub async fn leave(&self, http: impl AsRef<Http>) -> Result<()> {
-        self.id.leave(&http).await
+    pub async fn leave(&self, cache_http: impl CacheHttp) -> Result<()> {
+        self.id.leave(cache_http).await
     }
 
     /// Gets a user's [`Member`] for the guild by Id.
@@ -1487,6 +1607,28 @@ impl PartialGuild {
         self.splash.as_ref().map(|splash| cdn!("/splashes/{}/{}.webp?size=4096", self.id, splash))
     }
 
+    /// Returns the formatted URL of the guild's splash image in the given format and size, if
+    /// one exists.
+    ///
+    /// The format defaults to a WEBP, and the size to 1024 rather than the 4096 of
+    /// [`Self::splash_url`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidImageFormat`] if a GIF is requested of
+    /// a splash that is not animated, or a [`ModelError::InvalidImageSize`] if the
+    /// size is not a power of two between 16 and 4096.
+    ///
+    /// [`ModelError::InvalidImageFormat`]: crate::model::error::Error::InvalidImageFormat
+    /// [`ModelError::InvalidImageSize`]: crate::model::error::Error::InvalidImageSize
+    pub fn splash_url_with(
+        &self,
+        format: Option<ImageFormat>,
+        size: Option<u16>,
+    ) -> Result<Option<String>> {
+        cdn_hash_url(&format!("splashes/{}", self.id), self.splash.as_ref(), format, size)
+    }
+
     /// Starts an integration sync for the given integration Id.
     ///
     /// Requires the [Manage Guild] permission.
@@ -1532,7 +1674,7 @@ impl PartialGuild {
     /// [Manage Guild]: Permissions::MANAGE_GUILD
     /// [`Guild::vanity_url`]: crate::model::guild::Guild::vanity_url
     #[inline]
-    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<String> {
+    pub async fn vanity_url(&self, http: impl AsRef<Http>) -> Result<VanityUrl> {
         self.id.vanity_url(&http).await
     }
 
@@ -1553,8 +1695,8 @@ impl PartialGuild {
 
     /// Obtain a reference to a role by its name.
     ///
-    /// **Note**: If two or more roles have the same name, obtained reference will be one of
-    /// them.
+    /// The name is matched case-sensitively. If two or more roles have the same name, the one
+    /// with the lowest position is returned.
     ///
     /// # Examples
     ///
@@ -1588,10 +1730,30 @@ impl PartialGuild {
     /// #    Ok(())
     /// # }
     /// ```
-    #[inline]
     #[must_use]
     pub fn role_by_name(&self, role_name: &str) -> Option<&Role> {
-        self.roles.values().find(|role| role_name == role.name)
+        self.roles
+            .values()
+            .filter(|role| role_name == role.name)
+            .min_by_key(|role| (role.position, role.id))
+    }
+
+    /// Obtain a reference to a role by its name, ignoring ASCII case.
+    ///
+    /// Like [`Self::role_by_name`], the role with the lowest position is returned if multiple
+    /// roles match.
+    #[must_use]
+    pub fn role_by_name_case_insensitive(&self, role_name: &str) -> Option<&Role> {
+        self.roles
+            .values()
+            .filter(|role| role.name.eq_ignore_ascii_case(role_name))
+            .min_by_key(|role| (role.position, role.id))
+    }
+
+    /// Obtain a reference to the `@everyone` role, whose Id is the same as the guild's.
+    #[must_use]
+    pub fn everyone_role(&self) -> Option<&Role> {
+        self.roles.get(&RoleId(self.id.0))
     }
 
     /// Returns a future that will await one message sent in this guild.
@@ -1633,6 +1795,22 @@ impl PartialGuild {
     pub async fn get_active_threads(&self, http: impl AsRef<Http>) -> Result<ThreadsData> {
         self.id.get_active_threads(http).await
     }
+
+    /// Gets all of the guild's channels along with its active threads over the
+    /// REST API.
+    ///
+    /// Refer to [`GuildId::all_channels_and_threads`] for more information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if the current user is not in the guild.
+    #[inline]
+    pub async fn all_channels_and_threads(
+        &self,
+        http: impl AsRef<Http>,
+    ) -> Result<HashMap<ChannelId, GuildChannel>> {
+        self.id.all_channels_and_threads(http).await
+    }
 }
 
 impl<'de> Deserialize<'de> for PartialGuild {
@@ -1795,6 +1973,16 @@ impl<'de> Deserialize<'de> for PartialGuild {
             None => None,
         };
 
+        let safety_alerts_channel_id = match map.remove("safety_alerts_channel_id") {
+            Some(v) => Option::<ChannelId>::deserialize(v).map_err(DeError::custom)?,
+            None => None,
+        };
+
+        let premium_progress_bar_enabled = match map.remove("premium_progress_bar_enabled") {
+            Some(v) => bool::deserialize(v).map_err(DeError::custom)?,
+            None => false,
+        };
+
         let system_channel_flags = map
             .remove("system_channel_flags")
             .ok_or_else(|| DeError::custom("expected system_channel_flags"))
@@ -1855,6 +2043,8 @@ impl<'de> Deserialize<'de> for PartialGuild {
             system_channel_flags,
             rules_channel_id,
             public_updates_channel_id,
+            safety_alerts_channel_id,
+            premium_progress_bar_enabled,
             verification_level,
             description,
             premium_tier,
@@ -1904,6 +2094,8 @@ impl From<Guild> for PartialGuild {
             system_channel_flags: guild.system_channel_flags,
             rules_channel_id: guild.rules_channel_id,
             public_updates_channel_id: guild.public_updates_channel_id,
+            safety_alerts_channel_id: guild.safety_alerts_channel_id,
+            premium_progress_bar_enabled: guild.premium_progress_bar_enabled,
             verification_level: guild.verification_level,
             description: guild.description,
             premium_tier: guild.premium_tier,
@@ -12,5 +12,29 @@ bitflags! {
         const SUPPRESS_GUILD_REMINDER_NOTIFICATIONS = 1 << 2;
         /// Hide member join sticker reply buttons.
         const SUPPRESS_JOIN_NOTIFICATION_REPLIES = 1 << 3;
+        /// Suppress role subscription purchase and renewal notifications.
+        const SUPPRESS_ROLE_SUBSCRIPTION_PURCHASE_NOTIFICATIONS = 1 << 4;
+        /// Hide role subscription sticker reply buttons.
+        const SUPPRESS_ROLE_SUBSCRIPTION_PURCHASE_NOTIFICATION_REPLIES = 1 << 5;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::SystemChannelFlags;
+    use crate::json::{from_value, json, to_value};
+
+    #[test]
+    fn system_channel_flags_round_trip() {
+        let flags = SystemChannelFlags::SUPPRESS_JOIN_NOTIFICATIONS
+            | SystemChannelFlags::SUPPRESS_JOIN_NOTIFICATION_REPLIES;
+
+        let value = to_value(flags).unwrap();
+        assert_eq!(value, json!(9));
+        assert_eq!(from_value::<SystemChannelFlags>(value).unwrap(), flags);
+
+        // Unknown bits are dropped rather than rejected.
+        let flags = from_value::<SystemChannelFlags>(json!(1_u64 << 2 | 1_u64 << 40)).unwrap();
+        assert_eq!(flags, SystemChannelFlags::SUPPRESS_GUILD_REMINDER_NOTIFICATIONS);
     }
 }
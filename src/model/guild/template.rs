@@ -0,0 +1,95 @@
+use crate::json::Value;
+use crate::model::id::{GuildId, UserId};
+use crate::model::timestamp::Timestamp;
+use crate::model::user::User;
+
+/// A template of a guild's structure, which can be used to create new guilds with the same
+/// roles, channels and settings.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/guild-template#guild-template-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct GuildTemplate {
+    /// The template code, which is unique.
+    pub code: String,
+    /// The name of the template.
+    pub name: String,
+    /// The description of the template.
+    pub description: Option<String>,
+    /// The number of times the template has been used.
+    pub usage_count: u64,
+    /// The Id of the user who created the template.
+    pub creator_id: UserId,
+    /// The user who created the template.
+    pub creator: User,
+    /// When the template was created.
+    pub created_at: Timestamp,
+    /// When the template was last synced to the source guild.
+    pub updated_at: Timestamp,
+    /// The Id of the guild the template is based on.
+    pub source_guild_id: GuildId,
+    /// A snapshot of the source guild, as it was when the template was last synced.
+    ///
+    /// This is a partial guild object, which contains the guild's settings along with its
+    /// `roles` and `channels`. The Ids in it are placeholders local to the template, rather than
+    /// the Ids of the source guild's roles and channels.
+    pub serialized_source_guild: Value,
+    /// Whether the template has unsynced changes.
+    pub is_dirty: Option<bool>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn guild_template_deserialize() {
+        let value = json!({
+            "code": "hgM48av5Q69A",
+            "name": "Friends & Family",
+            "description": "",
+            "usage_count": 49605,
+            "creator_id": "132693355574148895",
+            "creator": {
+                "username": "Merlin",
+                "discriminator": "0001",
+                "id": "132693355574148895",
+                "avatar": null
+            },
+            "created_at": "2020-04-02T21:10:38+00:00",
+            "updated_at": "2020-05-01T17:57:38+00:00",
+            "source_guild_id": "678070694164299796",
+            "serialized_source_guild": {
+                "name": "Friends & Family",
+                "description": null,
+                "region": "us-west",
+                "verification_level": 0,
+                "default_message_notifications": 0,
+                "explicit_content_filter": 0,
+                "preferred_locale": "en-US",
+                "afk_timeout": 300,
+                "roles": [{
+                    "id": 0,
+                    "name": "@everyone",
+                    "permissions": 104324097,
+                    "color": 0,
+                    "hoist": false,
+                    "mentionable": false
+                }],
+                "channels": [],
+                "afk_channel_id": null,
+                "system_channel_id": 2,
+                "system_channel_flags": 0,
+                "icon_hash": null
+            },
+            "is_dirty": null
+        });
+
+        let template: GuildTemplate = from_value(value).unwrap();
+        assert_eq!(template.code, "hgM48av5Q69A");
+        assert_eq!(template.source_guild_id, GuildId(678070694164299796));
+        assert!(template.is_dirty.is_none());
+        assert!(template.serialized_source_guild.get("roles").is_some());
+    }
+}
@@ -186,6 +186,12 @@ pub struct StickerPackBannerId(#[serde(with = "snowflake")] pub u64);
 )]
 pub struct SkuId(#[serde(with = "snowflake")] pub u64);
 
+/// An identifier for an entitlement.
+#[derive(
+    Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Deserialize, Serialize,
+)]
+pub struct EntitlementId(#[serde(with = "snowflake")] pub u64);
+
 /// An identifier for an interaction.
 #[derive(
     Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Deserialize, Serialize,
@@ -230,6 +236,12 @@ pub struct StageInstanceId(#[serde(with = "snowflake")] pub u64);
 )]
 pub struct ForumTagId(#[serde(with = "snowflake")] pub u64);
 
+/// An identifier for a soundboard sound.
+#[derive(
+    Copy, Clone, Default, Debug, Eq, Hash, PartialEq, PartialOrd, Ord, Deserialize, Serialize,
+)]
+pub struct SoundId(#[serde(with = "snowflake")] pub u64);
+
 id_u64! {
     AttachmentId;
     ApplicationId;
@@ -245,6 +257,7 @@ id_u64! {
     StickerPackId;
     StickerPackBannerId;
     SkuId;
+    EntitlementId;
     UserId;
     WebhookId;
     AuditLogEntryId;
@@ -256,6 +269,7 @@ id_u64! {
     StageInstanceId;
     RuleId;
     ForumTagId;
+    SoundId;
 }
 
 /// Used with `#[serde(with|deserialize_with|serialize_with)]`
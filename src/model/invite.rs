@@ -319,11 +319,11 @@ pub struct RichInvite {
     /// The maximum age of the invite in seconds, from when it was created.
     pub max_age: u64,
     /// The maximum number of times that an invite may be used before it expires.
-
+    ///
     /// Note that this does not supersede the [`Self::max_age`] value, if the value of
     /// [`Self::temporary`] is `true`. If the value of `temporary` is `false`, then the
     /// invite _will_ self-expire after the given number of max uses.
-
+    ///
     /// If the value is `0`, then the invite is permanent.
     pub max_uses: u64,
     /// Indicator of whether the invite self-expires after a certain amount of
@@ -331,6 +331,10 @@ pub struct RichInvite {
     pub temporary: bool,
     /// The amount of times that an invite has been used.
     pub uses: u64,
+    /// The expiration date of this invite, if it expires.
+    pub expires_at: Option<Timestamp>,
+    /// The type of target for this voice channel invite.
+    pub target_type: Option<InviteTargetType>,
 }
 
 #[cfg(feature = "model")]
@@ -270,9 +270,11 @@ mod test {
             bot: false,
             discriminator: 4132,
             name: "fake".to_string(),
+            global_name: None,
             public_flags: None,
             banner: None,
             accent_colour: None,
+            avatar_decoration_data: None,
             member: None,
         };
         let member = Member {
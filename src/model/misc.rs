@@ -176,10 +176,109 @@ pub struct Maintenance {
     pub stop: String,
 }
 
+/// The format of an image served by Discord's CDN.
+///
+/// Used by the `*_url_with` methods, such as [`User::avatar_url_with`], which also take a size.
+/// The size must be a power of two between 16 and 4096, or else a
+/// [`ModelError::InvalidImageSize`] is returned. Requesting a [`Self::Gif`] of an image that is
+/// not animated returns a [`ModelError::InvalidImageFormat`].
+///
+/// [Discord docs](https://discord.com/developers/docs/reference#image-formatting-image-formats).
+///
+/// [`User::avatar_url_with`]: super::user::User::avatar_url_with
+/// [`ModelError::InvalidImageSize`]: super::error::Error::InvalidImageSize
+/// [`ModelError::InvalidImageFormat`]: super::error::Error::InvalidImageFormat
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum ImageFormat {
+    /// A PNG image.
+    Png,
+    /// A JPEG image.
+    Jpeg,
+    /// A WEBP image, the default for images that are not animated.
+    Webp,
+    /// A GIF image, the default for animated images.
+    ///
+    /// Only available for animated images.
+    Gif,
+}
+
+impl ImageFormat {
+    /// The file extension of the format, as used in CDN URLs.
+    #[must_use]
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpg",
+            Self::Webp => "webp",
+            Self::Gif => "gif",
+        }
+    }
+}
+
+/// Formats the CDN URL of an image, such as `"avatars/{user_id}/{hash}"`.
+///
+/// When no format is given, animated images are GIFs and others WEBPs. When no
+/// size is given, it defaults to 1024.
+#[cfg(feature = "model")]
+pub(crate) fn cdn_image_url(
+    path: &str,
+    animated: bool,
+    format: Option<ImageFormat>,
+    size: Option<u16>,
+) -> crate::Result<String> {
+    let format = match format {
+        Some(ImageFormat::Gif) if !animated => {
+            return Err(Error::Model(ModelError::InvalidImageFormat(ImageFormat::Gif)));
+        },
+        Some(format) => format,
+        None if animated => ImageFormat::Gif,
+        None => ImageFormat::Webp,
+    };
+
+    let size = size.unwrap_or(1024);
+    if !(16..=4096).contains(&size) || !size.is_power_of_two() {
+        return Err(Error::Model(ModelError::InvalidImageSize(size)));
+    }
+
+    Ok(cdn!("/{}.{}?size={}", path, format.extension(), size))
+}
+
+/// Formats the CDN URL of an optional image hash under `prefix`, treating hashes
+/// starting with `a_` as animated. See [`cdn_image_url`].
+#[cfg(feature = "model")]
+pub(crate) fn cdn_hash_url(
+    prefix: &str,
+    hash: Option<&String>,
+    format: Option<ImageFormat>,
+    size: Option<u16>,
+) -> crate::Result<Option<String>> {
+    hash.map(|hash| {
+        cdn_image_url(&format!("{}/{}", prefix, hash), hash.starts_with("a_"), format, size)
+    })
+    .transpose()
+}
+
 #[cfg(test)]
 mod test {
     use crate::model::prelude::*;
 
+    #[test]
+    #[cfg(feature = "model")]
+    fn cdn_image_urls() {
+        use super::cdn_image_url;
+
+        let url = cdn_image_url("avatars/1/a_hash", true, None, None).unwrap();
+        assert!(url.ends_with("/avatars/1/a_hash.gif?size=1024"));
+
+        let url = cdn_image_url("avatars/1/hash", false, Some(ImageFormat::Png), Some(64)).unwrap();
+        assert!(url.ends_with("/avatars/1/hash.png?size=64"));
+
+        assert!(cdn_image_url("avatars/1/hash", false, Some(ImageFormat::Gif), None).is_err());
+        assert!(cdn_image_url("avatars/1/hash", false, None, Some(100)).is_err());
+        assert!(cdn_image_url("avatars/1/hash", false, None, Some(8192)).is_err());
+    }
+
     #[test]
     fn test_formatters() {
         assert_eq!(ChannelId(1).to_string(), "1");
@@ -35,8 +35,10 @@ pub mod interactions;
 pub mod invite;
 pub mod mention;
 pub mod misc;
+pub mod monetization;
 pub mod permissions;
 pub mod prelude;
+pub mod soundboard;
 pub mod sticker;
 pub mod timestamp;
 pub mod user;
@@ -0,0 +1,179 @@
+//! Models for app monetization, such as SKUs and entitlements.
+
+use super::prelude::*;
+
+/// A premium offering that can be made available to an application's users or guilds.
+///
+/// [Discord docs](https://discord.com/developers/docs/monetization/skus#sku-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Sku {
+    /// The unique Id of the SKU.
+    pub id: SkuId,
+    /// The type of the SKU.
+    #[serde(rename = "type")]
+    pub kind: SkuKind,
+    /// The Id of the parent application.
+    pub application_id: ApplicationId,
+    /// The customer-facing name of the premium offering.
+    pub name: String,
+    /// A system-generated URL slug based on the SKU's name.
+    pub slug: String,
+    /// The flags of the SKU.
+    pub flags: SkuFlags,
+}
+
+/// The type of a [`Sku`].
+///
+/// [Discord docs](https://discord.com/developers/docs/monetization/skus#sku-object-sku-types).
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum SkuKind {
+    /// Represents a durable one-time purchase.
+    Durable = 2,
+    /// Represents a consumable one-time purchase.
+    Consumable = 3,
+    /// Represents a recurring subscription.
+    Subscription = 5,
+    /// A system-generated group for each subscription SKU.
+    SubscriptionGroup = 6,
+    Unknown = !0,
+}
+
+enum_number!(SkuKind {
+    Durable,
+    Consumable,
+    Subscription,
+    SubscriptionGroup
+});
+
+bitflags! {
+    /// The flags of a [`Sku`].
+    ///
+    /// [Discord docs](https://discord.com/developers/docs/monetization/skus#sku-object-sku-flags).
+    #[derive(Default)]
+    pub struct SkuFlags: u64 {
+        /// The SKU is available for purchase.
+        const AVAILABLE = 1 << 2;
+        /// A subscription purchased by a user and applied to a single guild.
+        const GUILD_SUBSCRIPTION = 1 << 7;
+        /// A subscription purchased by a user for themselves.
+        const USER_SUBSCRIPTION = 1 << 8;
+    }
+}
+
+/// Represents that a user or guild has access to a premium offering in the application.
+///
+/// [Discord docs](https://discord.com/developers/docs/monetization/entitlements#entitlement-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct Entitlement {
+    /// The unique Id of the entitlement.
+    pub id: EntitlementId,
+    /// The Id of the SKU this entitlement grants access to.
+    pub sku_id: SkuId,
+    /// The Id of the parent application.
+    pub application_id: ApplicationId,
+    /// The Id of the user that is granted access to the SKU.
+    pub user_id: Option<UserId>,
+    /// The Id of the guild that is granted access to the SKU.
+    pub guild_id: Option<GuildId>,
+    /// The type of the entitlement.
+    #[serde(rename = "type")]
+    pub kind: EntitlementKind,
+    /// Whether the entitlement has been deleted.
+    #[serde(default)]
+    pub deleted: bool,
+    /// The start date at which the entitlement is valid.
+    ///
+    /// Not present for test entitlements.
+    pub starts_at: Option<Timestamp>,
+    /// The date at which the entitlement is no longer valid.
+    ///
+    /// Not present for test entitlements.
+    pub ends_at: Option<Timestamp>,
+    /// For consumable items, whether or not the entitlement has been consumed.
+    #[serde(default)]
+    pub consumed: bool,
+}
+
+/// The type of an [`Entitlement`].
+///
+/// [Discord docs](https://discord.com/developers/docs/monetization/entitlements#entitlement-object-entitlement-types).
+#[derive(Copy, Clone, Debug, Hash, Eq, PartialEq, PartialOrd, Ord)]
+#[non_exhaustive]
+#[repr(u8)]
+pub enum EntitlementKind {
+    /// The entitlement was purchased by a user.
+    Purchase = 1,
+    /// The entitlement was granted through Discord Nitro.
+    PremiumSubscription = 2,
+    /// The entitlement was gifted by the developer.
+    DeveloperGift = 3,
+    /// The entitlement was purchased by a developer in application test mode.
+    TestModePurchase = 4,
+    /// The entitlement was granted when the SKU was free.
+    FreePurchase = 5,
+    /// The entitlement was gifted by another user.
+    UserGift = 6,
+    /// The entitlement was claimed by a user for free as a Nitro subscriber.
+    PremiumPurchase = 7,
+    /// The entitlement was purchased as an app subscription.
+    ApplicationSubscription = 8,
+    Unknown = !0,
+}
+
+enum_number!(EntitlementKind {
+    Purchase,
+    PremiumSubscription,
+    DeveloperGift,
+    TestModePurchase,
+    FreePurchase,
+    UserGift,
+    PremiumPurchase,
+    ApplicationSubscription
+});
+
+/// The owner of a test entitlement, used in [`Http::create_test_entitlement`].
+///
+/// [`Http::create_test_entitlement`]: crate::http::Http::create_test_entitlement
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+#[non_exhaustive]
+pub enum EntitlementOwner {
+    /// The entitlement is granted to a guild.
+    Guild(GuildId),
+    /// The entitlement is granted to a user.
+    User(UserId),
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn entitlement_deserialize() {
+        let value = json!({
+            "id": "1019653849998299136",
+            "sku_id": "1019475255913222144",
+            "application_id": "1019370614521200640",
+            "user_id": "771129655544643584",
+            "promotion_id": null,
+            "type": 8,
+            "deleted": false,
+            "gift_code_flags": 0,
+            "consumed": false,
+            "starts_at": "2022-09-14T17:00:18.704163+00:00",
+            "ends_at": "2022-10-14T17:00:18.704163+00:00",
+            "guild_id": null,
+            "subscription_id": "1019653835926409216"
+        });
+
+        let entitlement: Entitlement = from_value(value).unwrap();
+        assert_eq!(entitlement.kind, EntitlementKind::ApplicationSubscription);
+        assert_eq!(entitlement.user_id, Some(UserId(771129655544643584)));
+        assert!(entitlement.guild_id.is_none());
+        assert!(entitlement.ends_at.is_some());
+    }
+}
@@ -7,7 +7,8 @@
 //!
 //! Additionally, presets equivalent to the official client's `@everyone` role
 //! presets are available. These are [`PRESET_GENERAL`], [`PRESET_TEXT`], and
-//! [`PRESET_VOICE`].
+//! [`PRESET_VOICE`]. For overwrites, the [`PRESET_MODERATOR`] and
+//! [`PRESET_READ_ONLY`] bundles are also provided.
 //!
 //! Permissions follow a hierarchy:
 //!
@@ -219,6 +220,63 @@ pub const PRESET_VOICE: Permissions = Permissions {
     bits: Permissions::CONNECT.bits | Permissions::SPEAK.bits | Permissions::USE_VAD.bits,
 };
 
+/// Returns a set of permissions commonly given to moderators.
+///
+/// This includes the following permissions:
+///
+/// - [Ban Members]
+/// - [Deafen Members]
+/// - [Kick Members]
+/// - [Manage Messages]
+/// - [Manage Nicknames]
+/// - [Manage Threads]
+/// - [Moderate Members]
+/// - [Move Members]
+/// - [Mute Members]
+/// - [View Audit Log]
+///
+/// [Ban Members]: Permissions::BAN_MEMBERS
+/// [Deafen Members]: Permissions::DEAFEN_MEMBERS
+/// [Kick Members]: Permissions::KICK_MEMBERS
+/// [Manage Messages]: Permissions::MANAGE_MESSAGES
+/// [Manage Nicknames]: Permissions::MANAGE_NICKNAMES
+/// [Manage Threads]: Permissions::MANAGE_THREADS
+/// [Moderate Members]: Permissions::MODERATE_MEMBERS
+/// [Move Members]: Permissions::MOVE_MEMBERS
+/// [Mute Members]: Permissions::MUTE_MEMBERS
+/// [View Audit Log]: Permissions::VIEW_AUDIT_LOG
+pub const PRESET_MODERATOR: Permissions = Permissions {
+    bits: Permissions::BAN_MEMBERS.bits
+        | Permissions::DEAFEN_MEMBERS.bits
+        | Permissions::KICK_MEMBERS.bits
+        | Permissions::MANAGE_MESSAGES.bits
+        | Permissions::MANAGE_NICKNAMES.bits
+        | Permissions::MANAGE_THREADS.bits
+        | Permissions::MODERATE_MEMBERS.bits
+        | Permissions::MOVE_MEMBERS.bits
+        | Permissions::MUTE_MEMBERS.bits
+        | Permissions::VIEW_AUDIT_LOG.bits,
+};
+
+/// Returns a set of permissions allowing to read a channel, but not to
+/// interact with it.
+///
+/// This includes the following permissions:
+///
+/// - [Read Message History]
+/// - [View Channel]
+///
+/// Meant to be used as the allowed set of a [`PermissionOverwrite`], whose
+/// denied set contains [Send Messages] and similar permissions.
+///
+/// [Read Message History]: Permissions::READ_MESSAGE_HISTORY
+/// [View Channel]: Permissions::VIEW_CHANNEL
+/// [Send Messages]: Permissions::SEND_MESSAGES
+/// [`PermissionOverwrite`]: super::channel::PermissionOverwrite
+pub const PRESET_READ_ONLY: Permissions = Permissions {
+    bits: Permissions::READ_MESSAGE_HISTORY.bits | Permissions::VIEW_CHANNEL.bits,
+};
+
 bitflags::bitflags! {
     /// A set of permissions that can be assigned to [`User`]s and [`Role`]s via
     /// [`PermissionOverwrite`]s, roles globally in a [`Guild`], and to
@@ -355,6 +413,13 @@ bitflags::bitflags! {
         /// Allows for timing out users to prevent them from sending or reacting to messages in
         /// chat and threads, and from speaking in voice and stage channels.
         const MODERATE_MEMBERS = 1 << 40;
+        /// Allows for using the soundboard in a voice channel.
+        const USE_SOUNDBOARD = 1 << 42;
+        /// Allows for creating emojis, stickers, and soundboard sounds, and editing
+        /// and deleting those created by the current user.
+        const CREATE_GUILD_EXPRESSIONS = 1 << 43;
+        /// Allows the usage of custom soundboard sounds from other servers.
+        const USE_EXTERNAL_SOUNDS = 1 << 45;
     }
 }
 
@@ -366,6 +431,7 @@ generate_get_permission_names! {
     ban_members: "Ban Members",
     change_nickname: "Change Nickname",
     connect: "Connect",
+    create_guild_expressions: "Create Guild Expressions",
     create_instant_invite: "Create Instant Invite",
     create_private_threads: "Create Private Threads",
     create_public_threads: "Create Public Threads",
@@ -396,14 +462,49 @@ generate_get_permission_names! {
     stream: "Stream",
     use_embedded_activities: "Use Embedded Activities",
     use_external_emojis: "Use External Emojis",
+    use_external_sounds: "Use External Sounds",
     use_external_stickers: "Use External Stickers",
     use_slash_commands: "Use Slash Commands",
+    use_soundboard: "Use Soundboard",
     use_vad: "Use Voice Activity",
     view_audit_log: "View Audit Log",
     view_channel: "View Channel",
     view_guild_insights: "View Guild Insights"
 }
 
+impl Permissions {
+    /// The [`PRESET_MODERATOR`] bundle.
+    ///
+    /// # Examples
+    ///
+    /// Presets compose with other permissions:
+    ///
+    /// ```rust
+    /// use serenity::model::permissions::Permissions;
+    ///
+    /// let permissions = Permissions::PRESET_MODERATOR | Permissions::MANAGE_ROLES;
+    ///
+    /// assert!(permissions.contains(Permissions::BAN_MEMBERS | Permissions::MANAGE_ROLES));
+    /// ```
+    pub const PRESET_MODERATOR: Self = PRESET_MODERATOR;
+    /// The [`PRESET_READ_ONLY`] bundle.
+    pub const PRESET_READ_ONLY: Self = PRESET_READ_ONLY;
+    /// The [`PRESET_TEXT`] bundle.
+    pub const PRESET_TEXT: Self = PRESET_TEXT;
+    /// The [`PRESET_VOICE`] bundle.
+    pub const PRESET_VOICE: Self = PRESET_VOICE;
+
+    /// Returns a list of names of all contained permissions.
+    ///
+    /// This is an alias of [`Self::get_permission_names`].
+    #[cfg(feature = "model")]
+    #[inline]
+    #[must_use]
+    pub fn describe(self) -> Vec<&'static str> {
+        self.get_permission_names()
+    }
+}
+
 /// TODO: use a macro to shorten this entire file lol
 #[cfg(feature = "model")]
 impl Permissions {
@@ -533,6 +634,15 @@ impl Permissions {
         self.contains(Self::CREATE_PUBLIC_THREADS)
     }
 
+    /// Shorthand for checking that the set of permissions contains the
+    /// [Create Guild Expressions] permission.
+    ///
+    /// [Create Guild Expressions]: Self::CREATE_GUILD_EXPRESSIONS
+    #[must_use]
+    pub fn create_guild_expressions(self) -> bool {
+        self.contains(Self::CREATE_GUILD_EXPRESSIONS)
+    }
+
     /// Shorthand for checking that the set of permissions contains the
     /// [Deafen Members] permission.
     ///
@@ -758,6 +868,15 @@ impl Permissions {
         self.contains(Self::USE_EXTERNAL_EMOJIS)
     }
 
+    /// Shorthand for checking that the set of permissions contains the
+    /// [Use External Sounds] permission.
+    ///
+    /// [Use External Sounds]: Self::USE_EXTERNAL_SOUNDS
+    #[must_use]
+    pub fn use_external_sounds(self) -> bool {
+        self.contains(Self::USE_EXTERNAL_SOUNDS)
+    }
+
     /// Shorthand for checking that the set of permissions contains the
     /// [Use External Stickers] permission.
     ///
@@ -776,6 +895,15 @@ impl Permissions {
         self.contains(Self::USE_SLASH_COMMANDS)
     }
 
+    /// Shorthand for checking that the set of permissions contains the
+    /// [Use Soundboard] permission.
+    ///
+    /// [Use Soundboard]: Self::USE_SOUNDBOARD
+    #[must_use]
+    pub fn use_soundboard(self) -> bool {
+        self.contains(Self::USE_SOUNDBOARD)
+    }
+
     /// Shorthand for checking that the set of permissions contains the
     /// [Use VAD] permission.
     ///
@@ -851,4 +979,16 @@ mod tests {
         let value = Permissions::MANAGE_GUILD | Permissions::MANAGE_ROLES;
         assert_tokens(&value, &[Token::Str("268435488")]);
     }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn presets() {
+        assert_eq!(Permissions::PRESET_READ_ONLY.describe(), vec![
+            "Read Message History",
+            "View Channel"
+        ]);
+        assert!(Permissions::PRESET_TEXT.contains(Permissions::PRESET_READ_ONLY));
+        assert!(!Permissions::PRESET_MODERATOR.administrator());
+        assert_eq!(Permissions::PRESET_VOICE | Permissions::PRESET_TEXT, PRESET_GENERAL);
+    }
 }
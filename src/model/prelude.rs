@@ -26,7 +26,9 @@ pub use super::{
     invite::*,
     mention::*,
     misc::*,
+    monetization::*,
     permissions::*,
+    soundboard::*,
     sticker::*,
     user::*,
     voice::*,
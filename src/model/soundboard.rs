@@ -0,0 +1,110 @@
+//! Models for soundboard sounds, which can be played in voice channels.
+
+use super::prelude::*;
+#[cfg(feature = "model")]
+use crate::constants::SOUNDBOARD_SOUND_MAX_SIZE;
+#[cfg(feature = "model")]
+use crate::internal::prelude::*;
+
+/// A sound that can be played in voice channels through the soundboard.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/soundboard#soundboard-sound-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct SoundboardSound {
+    /// The unique Id of the sound.
+    pub sound_id: SoundId,
+    /// The name of the sound.
+    pub name: String,
+    /// The volume of the sound, from 0 to 1.
+    pub volume: f64,
+    /// The Id of the sound's custom emoji.
+    pub emoji_id: Option<EmojiId>,
+    /// The unicode character of the sound's standard emoji.
+    pub emoji_name: Option<String>,
+    /// The Id of the guild the sound is in.
+    ///
+    /// Not present for Discord's default sounds.
+    pub guild_id: Option<GuildId>,
+    /// Whether the sound can be used.
+    ///
+    /// This may be false if the guild lost the boosts needed for the sound.
+    pub available: bool,
+    /// The user who created the sound.
+    ///
+    /// Only present if the current user has the [Create Guild Expressions] or
+    /// [Manage Guild Expressions] permission.
+    ///
+    /// [Create Guild Expressions]: Permissions::CREATE_GUILD_EXPRESSIONS
+    /// [Manage Guild Expressions]: Permissions::MANAGE_EMOJIS_AND_STICKERS
+    pub user: Option<User>,
+}
+
+#[cfg(feature = "model")]
+impl SoundboardSound {
+    /// Returns the URL of the sound file.
+    #[must_use]
+    pub fn url(&self) -> String {
+        cdn!("/soundboard-sounds/{}", self.sound_id.0)
+    }
+}
+
+/// Checks that a base64 data URI of a sound file is in a supported format,
+/// being MP3 or OGG, and is not over [`SOUNDBOARD_SOUND_MAX_SIZE`].
+#[cfg(feature = "model")]
+pub(crate) fn check_sound_file(sound: &str) -> Result<()> {
+    let data = ["data:audio/mpeg;base64,", "data:audio/mp3;base64,", "data:audio/ogg;base64,"]
+        .iter()
+        .find_map(|prefix| sound.strip_prefix(prefix))
+        .ok_or(Error::Model(ModelError::InvalidSoundFile))?;
+
+    // Every 4 base64 characters encode 3 bytes, minus the padding.
+    let padding = data.bytes().rev().take_while(|&b| b == b'=').count();
+    let size = (data.len() / 4 * 3).saturating_sub(padding);
+
+    if size > SOUNDBOARD_SOUND_MAX_SIZE {
+        return Err(Error::Model(ModelError::SoundFileTooLarge(size - SOUNDBOARD_SOUND_MAX_SIZE)));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::json::{from_value, json};
+
+    #[test]
+    fn soundboard_sound_deserialize() {
+        let value = json!({
+            "name": "quack",
+            "sound_id": "1",
+            "volume": 1.0,
+            "emoji_id": null,
+            "emoji_name": "\u{1f986}",
+            "guild_id": "613425648685547541",
+            "available": true
+        });
+
+        let sound: SoundboardSound = from_value(value).unwrap();
+        assert_eq!(sound.sound_id, SoundId(1));
+        assert_eq!(sound.guild_id, Some(GuildId(613425648685547541)));
+        assert!(sound.user.is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "model")]
+    fn sound_file_validation() {
+        assert!(check_sound_file("data:audio/ogg;base64,T2dnUw==").is_ok());
+        assert!(matches!(
+            check_sound_file("data:image/png;base64,iVBORw0K"),
+            Err(Error::Model(ModelError::InvalidSoundFile))
+        ));
+
+        let large = format!("data:audio/mpeg;base64,{}", "A".repeat(SOUNDBOARD_SOUND_MAX_SIZE * 2));
+        assert!(matches!(
+            check_sound_file(&large),
+            Err(Error::Model(ModelError::SoundFileTooLarge(_)))
+        ));
+    }
+}
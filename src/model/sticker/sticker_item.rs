@@ -1,7 +1,7 @@
 #[cfg(feature = "model")]
 use super::sticker_url;
 #[cfg(feature = "model")]
-use crate::http::Http;
+use crate::http::{CacheHttp, Http};
 #[cfg(feature = "model")]
 use crate::internal::prelude::*;
 use crate::model::prelude::*;
@@ -34,6 +34,41 @@ impl StickerItem {
         self.id.to_sticker(&http).await
     }
 
+    /// Resolves the sticker to a [`Sticker`] with all details, such as its
+    /// format, pack and availability.
+    ///
+    /// If the `cache` is enabled, guild stickers are retrieved from the cache
+    /// instead of the REST API, and a guild sticker requested via the REST API
+    /// is added to its guild in the cache.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Http`] if a [`Sticker`] with that [`StickerId`] does
+    /// not exist, or is otherwise unavailable.
+    pub async fn resolve(&self, cache_http: impl CacheHttp) -> Result<Sticker> {
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                if let Some(sticker) = cache.sticker(self.id) {
+                    return Ok(sticker);
+                }
+            }
+        }
+
+        let sticker = self.to_sticker(cache_http.http()).await?;
+
+        #[cfg(feature = "cache")]
+        {
+            if let (Some(cache), Some(guild_id)) = (cache_http.cache(), sticker.guild_id) {
+                if let Some(mut guild) = cache.guilds.get_mut(&guild_id) {
+                    guild.stickers.insert(sticker.id, sticker.clone());
+                }
+            }
+        }
+
+        Ok(sticker)
+    }
+
     /// Retrieves the URL to the sticker image.
     ///
     /// **Note**: This will only be `None` if the format_type is unknown.
@@ -31,6 +31,7 @@
 
 use std::fmt;
 use std::str::FromStr;
+use std::time::SystemTime;
 
 use serde::{Deserialize, Serialize};
 
@@ -127,6 +128,18 @@ cfg_if::cfg_if! {
                 Self(dt.with_timezone(&Utc))
             }
         }
+
+        impl From<Timestamp> for DateTime<Utc> {
+            fn from(ts: Timestamp) -> Self {
+                ts.0
+            }
+        }
+
+        impl From<SystemTime> for Timestamp {
+            fn from(st: SystemTime) -> Self {
+                Self(DateTime::from(st))
+            }
+        }
     } else {
         use dep_time::format_description::well_known::Rfc3339;
         use dep_time::serde::rfc3339;
@@ -224,6 +237,18 @@ cfg_if::cfg_if! {
                 Self(dt)
             }
         }
+
+        impl From<Timestamp> for OffsetDateTime {
+            fn from(ts: Timestamp) -> Self {
+                ts.0
+            }
+        }
+
+        impl From<SystemTime> for Timestamp {
+            fn from(st: SystemTime) -> Self {
+                Self(OffsetDateTime::from(st))
+            }
+        }
     }
 }
 
@@ -298,4 +323,24 @@ mod tests {
             assert_eq!(timestamp.to_string(), "2016-04-30T11:18:25Z");
         }
     }
+
+    #[test]
+    fn inner_round_trip() {
+        let timestamp = Timestamp::from_unix_timestamp(1462015105).unwrap();
+
+        #[cfg(all(feature = "chrono", not(feature = "time")))]
+        let inner = chrono::DateTime::<chrono::Utc>::from(timestamp);
+        #[cfg(not(all(feature = "chrono", not(feature = "time"))))]
+        let inner = dep_time::OffsetDateTime::from(timestamp);
+
+        assert_eq!(Timestamp::from(inner), timestamp);
+    }
+
+    #[test]
+    fn from_system_time() {
+        use std::time::{Duration, SystemTime};
+
+        let st = SystemTime::UNIX_EPOCH + Duration::from_secs(1462015105);
+        assert_eq!(Timestamp::from(st).unix_timestamp(), 1462015105);
+    }
 }
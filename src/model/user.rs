@@ -35,6 +35,8 @@ use crate::json::to_string;
 #[cfg(feature = "model")]
 use crate::model::application::oauth::Scope;
 use crate::model::mention::Mentionable;
+#[cfg(feature = "model")]
+use crate::model::misc::cdn_hash_url;
 
 /// Used with `#[serde(with|deserialize_with|serialize_with)]`
 ///
@@ -185,6 +187,7 @@ pub struct CurrentUser {
     pub mfa_enabled: bool,
     #[serde(rename = "username")]
     pub name: String,
+    pub global_name: Option<String>,
     pub verified: Option<bool>,
     pub public_flags: Option<UserPublicFlags>,
     pub banner: Option<String>,
@@ -192,6 +195,7 @@ pub struct CurrentUser {
     pub accent_colour: Option<Colour>,
     #[cfg(not(feature = "utils"))]
     pub accent_colour: Option<u32>,
+    pub avatar_decoration_data: Option<AvatarDecorationData>,
 }
 
 #[cfg(feature = "model")]
@@ -225,13 +229,35 @@ impl CurrentUser {
         avatar_url(self.id, self.avatar.as_ref())
     }
 
+    /// Returns the formatted URL of the user's avatar in the given format and size, if
+    /// one exists.
+    ///
+    /// The format defaults to a GIF if the avatar is animated, or a WEBP otherwise,
+    /// and the size to 1024.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidImageFormat`] if a GIF is requested of
+    /// an avatar that is not animated, or a [`ModelError::InvalidImageSize`] if the
+    /// size is not a power of two between 16 and 4096.
+    ///
+    /// [`ModelError::InvalidImageFormat`]: crate::model::error::Error::InvalidImageFormat
+    /// [`ModelError::InvalidImageSize`]: crate::model::error::Error::InvalidImageSize
+    pub fn avatar_url_with(
+        &self,
+        format: Option<ImageFormat>,
+        size: Option<u16>,
+    ) -> Result<Option<String>> {
+        cdn_hash_url(&format!("avatars/{}", self.id), self.avatar.as_ref(), format, size)
+    }
+
     /// Returns the formatted URL to the user's default avatar URL.
     ///
     /// This will produce a PNG URL.
     #[inline]
     #[must_use]
     pub fn default_avatar_url(&self) -> String {
-        default_avatar_url(self.discriminator)
+        default_avatar_url(self.id, self.discriminator)
     }
 
     /// Edits the current user's profile settings.
@@ -258,12 +284,18 @@ impl CurrentUser {
     /// # }
     /// ```
     ///
+    /// If the `cache` is enabled, the cached current user is updated as well.
+    ///
     /// # Errors
     ///
+    /// Returns a [`ModelError::UsernameRateLimited`] if the username was
+    /// changed too many times recently. Username changes are not tracked by
+    /// the library, so this only maps the error Discord responds with.
+    ///
     /// Returns an [`Error::Http`] if an invalid value is set.
     /// May also return an [`Error::Json`] if there is an error in
     /// deserializing the API response.
-    pub async fn edit<F>(&mut self, http: impl AsRef<Http>, f: F) -> Result<()>
+    pub async fn edit<F>(&mut self, cache_http: impl CacheHttp, f: F) -> Result<()>
     where
         F: FnOnce(&mut EditProfile) -> &mut EditProfile,
     {
@@ -278,7 +310,26 @@ impl CurrentUser {
         f(&mut edit_profile);
         let map = json::hashmap_to_json_map(edit_profile.0);
 
-        *self = http.as_ref().edit_profile(&map).await?;
+        *self = cache_http.http().edit_profile(&map).await.map_err(|why| {
+            let rate_limited = matches!(
+                &why,
+                Error::Http(http_error)
+                    if http_error.json_error_field_codes().any(|code| code == "USERNAME_RATE_LIMIT")
+            );
+
+            if rate_limited {
+                Error::Model(ModelError::UsernameRateLimited)
+            } else {
+                why
+            }
+        })?;
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                cache.user.write().clone_from(self);
+            }
+        }
 
         Ok(())
     }
@@ -636,6 +687,10 @@ pub struct User {
     /// change if the username+discriminator pair becomes non-unique.
     #[serde(rename = "username")]
     pub name: String,
+    /// The user's display name, if set. Users that migrated to the new
+    /// username system have a discriminator of `0` and are identified by their
+    /// [`Self::name`] alone; this is the name shown on their profile.
+    pub global_name: Option<String>,
     /// The public flags on a user's account
     pub public_flags: Option<UserPublicFlags>,
     /// Optional banner hash.
@@ -654,6 +709,8 @@ pub struct User {
     #[cfg(not(feature = "utils"))]
     #[serde(rename = "accent_color")]
     pub accent_colour: Option<u32>,
+    /// The decoration shown around the user's avatar, if any.
+    pub avatar_decoration_data: Option<AvatarDecorationData>,
     /// Only included in [`Message::mentions`] for messages from the gateway.
     ///
     /// [Discord docs](https://discord.com/developers/docs/topics/gateway-events#message-create-message-create-extra-fields).
@@ -661,6 +718,18 @@ pub struct User {
     pub member: Option<Box<PartialMember>>,
 }
 
+/// The decoration shown around a user's avatar.
+///
+/// [Discord docs](https://discord.com/developers/docs/resources/user#avatar-decoration-data-object).
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[non_exhaustive]
+pub struct AvatarDecorationData {
+    /// The hash of the avatar decoration.
+    pub asset: String,
+    /// The Id of the SKU of the avatar decoration.
+    pub sku_id: SkuId,
+}
+
 bitflags! {
     /// User's public flags
     ///
@@ -709,6 +778,7 @@ impl Default for User {
     /// - **bot** to `true`.
     /// - **discriminator** to `1432`.
     /// - **name** to `"test"`.
+    /// - **global_name** to [`None`].
     /// - **public_flags** to [`None`].
     fn default() -> Self {
         User {
@@ -717,9 +787,11 @@ impl Default for User {
             bot: true,
             discriminator: 1432,
             name: "test".to_string(),
+            global_name: None,
             public_flags: None,
             banner: None,
             accent_colour: None,
+            avatar_decoration_data: None,
             member: None,
         }
     }
@@ -752,6 +824,28 @@ impl User {
         avatar_url(self.id, self.avatar.as_ref())
     }
 
+    /// Returns the formatted URL of the user's avatar in the given format and size, if
+    /// one exists.
+    ///
+    /// The format defaults to a GIF if the avatar is animated, or a WEBP otherwise,
+    /// and the size to 1024.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidImageFormat`] if a GIF is requested of
+    /// an avatar that is not animated, or a [`ModelError::InvalidImageSize`] if the
+    /// size is not a power of two between 16 and 4096.
+    ///
+    /// [`ModelError::InvalidImageFormat`]: crate::model::error::Error::InvalidImageFormat
+    /// [`ModelError::InvalidImageSize`]: crate::model::error::Error::InvalidImageSize
+    pub fn avatar_url_with(
+        &self,
+        format: Option<ImageFormat>,
+        size: Option<u16>,
+    ) -> Result<Option<String>> {
+        cdn_hash_url(&format!("avatars/{}", self.id), self.avatar.as_ref(), format, size)
+    }
+
     /// Returns the formatted URL of the user's banner, if one exists.
     ///
     /// This will produce a WEBP image URL, or GIF if the user has a GIF banner.
@@ -764,6 +858,49 @@ impl User {
         banner_url(self.id, self.banner.as_ref())
     }
 
+    /// Returns the formatted URL of the user's banner in the given format and size, if
+    /// one exists.
+    ///
+    /// The format defaults to a GIF if the banner is animated, or a WEBP otherwise,
+    /// and the size to 1024.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ModelError::InvalidImageFormat`] if a GIF is requested of
+    /// a banner that is not animated, or a [`ModelError::InvalidImageSize`] if the
+    /// size is not a power of two between 16 and 4096.
+    ///
+    /// [`ModelError::InvalidImageFormat`]: crate::model::error::Error::InvalidImageFormat
+    /// [`ModelError::InvalidImageSize`]: crate::model::error::Error::InvalidImageSize
+    pub fn banner_url_with(
+        &self,
+        format: Option<ImageFormat>,
+        size: Option<u16>,
+    ) -> Result<Option<String>> {
+        cdn_hash_url(&format!("banners/{}", self.id), self.banner.as_ref(), format, size)
+    }
+
+    /// Returns the name shown for the user, which is their
+    /// [`global_name`] if set, falling back to their username.
+    ///
+    /// [`global_name`]: Self::global_name
+    #[inline]
+    #[must_use]
+    pub fn display_name(&self) -> &str {
+        self.global_name.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Returns the formatted URL of the user's avatar decoration, if one
+    /// exists.
+    ///
+    /// This will produce a PNG image URL, which is animated for animated
+    /// decorations.
+    #[inline]
+    #[must_use]
+    pub fn avatar_decoration_url(&self) -> Option<String> {
+        avatar_decoration_url(self.avatar_decoration_data.as_ref())
+    }
+
     /// Creates a direct message channel between the [current user] and the
     /// user. This can also retrieve the channel if one already exists.
     ///
@@ -794,7 +931,7 @@ impl User {
     #[inline]
     #[must_use]
     pub fn default_avatar_url(&self) -> String {
-        default_avatar_url(self.discriminator)
+        default_avatar_url(self.id, self.discriminator)
     }
 
     /// Sends a message to a user through a direct message channel. This is a
@@ -860,8 +997,10 @@ impl User {
     /// Returns a [`ModelError::MessagingBot`] if the user being direct messaged
     /// is a bot user.
     ///
-    /// May also return an [`Error::Http`] if the message was illformed, or if the
-    /// user cannot be sent a direct message.
+    /// Returns a [`ModelError::DirectMessagesClosed`] if the user does not accept
+    /// direct messages from the current user.
+    ///
+    /// May also return an [`Error::Http`] if the message was illformed.
     ///
     /// [`Error::Json`] can also be returned if there is an error deserializing
     /// the API response.
@@ -869,7 +1008,21 @@ impl User {
     where
         for<'b> F: FnOnce(&'b mut CreateMessage<'a>) -> &'b mut CreateMessage<'a>,
     {
-        self.create_dm_channel(&cache_http).await?.send_message(&cache_http.http(), f).await
+        let channel = self.create_dm_channel(&cache_http).await?;
+
+        channel.send_message(&cache_http.http(), f).await.map_err(|why| {
+            // JSON error code 50007: "Cannot send messages to this user"
+            let closed = matches!(
+                &why,
+                Error::Http(http_error) if http_error.json_error_code() == Some(50007)
+            );
+
+            if closed {
+                Error::Model(ModelError::DirectMessagesClosed)
+            } else {
+                why
+            }
+        })
     }
 
     /// This is an alias of [`Self::direct_message`].
@@ -1105,6 +1258,11 @@ impl UserId {
     /// Creates a direct message channel between the [current user] and the
     /// user. This can also retrieve the channel if one already exists.
     ///
+    /// If the cache is enabled, the channel is cached once opened and reused by
+    /// later calls. Use [`Cache::remove_user_private_channel`] to discard it.
+    ///
+    /// [`Cache::remove_user_private_channel`]: crate::cache::Cache::remove_user_private_channel
+    ///
     /// [current user]: CurrentUser
     ///
     /// # Errors
@@ -1118,12 +1276,8 @@ impl UserId {
         #[cfg(feature = "cache")]
         {
             if let Some(cache) = cache_http.cache() {
-                for channel_entry in cache.private_channels().iter() {
-                    let channel = channel_entry.value();
-
-                    if channel.recipient.id == self {
-                        return Ok(channel.clone());
-                    }
+                if let Some(channel) = cache.user_private_channel(self) {
+                    return Ok(channel);
                 }
             }
         }
@@ -1132,7 +1286,16 @@ impl UserId {
             "recipient_id": self.0,
         });
 
-        cache_http.http().create_private_channel(&map).await
+        let channel = cache_http.http().create_private_channel(&map).await?;
+
+        #[cfg(feature = "cache")]
+        {
+            if let Some(cache) = cache_http.cache() {
+                cache.insert_private_channel(channel.clone());
+            }
+        }
+
+        Ok(channel)
     }
 
     /// Attempts to find a [`User`] by its Id in the cache.
@@ -1190,9 +1353,11 @@ impl From<CurrentUser> for User {
             discriminator: user.discriminator,
             id: user.id,
             name: user.name,
+            global_name: user.global_name,
             public_flags: user.public_flags,
             banner: user.banner,
             accent_colour: user.accent_colour,
+            avatar_decoration_data: user.avatar_decoration_data,
             member: None,
         }
     }
@@ -1206,9 +1371,11 @@ impl<'a> From<&'a CurrentUser> for User {
             discriminator: user.discriminator,
             id: user.id,
             name: user.name.clone(),
+            global_name: user.global_name.clone(),
             public_flags: user.public_flags,
             banner: user.banner.clone(),
             accent_colour: user.accent_colour,
+            avatar_decoration_data: user.avatar_decoration_data.clone(),
             member: None,
         }
     }
@@ -1266,8 +1433,16 @@ fn avatar_url(user_id: UserId, hash: Option<&String>) -> Option<String> {
 }
 
 #[cfg(feature = "model")]
-fn default_avatar_url(discriminator: u16) -> String {
-    cdn!("/embed/avatars/{}.png", discriminator % 5u16)
+fn default_avatar_url(user_id: UserId, discriminator: u16) -> String {
+    // Users on the new username system have a discriminator of `0`, and their
+    // default avatar is based on their Id instead.
+    let index = if discriminator == 0 {
+        (user_id.0 >> 22) % 6
+    } else {
+        u64::from(discriminator % 5)
+    };
+
+    cdn!("/embed/avatars/{}.png", index)
 }
 
 #[cfg(feature = "model")]
@@ -1284,6 +1459,11 @@ fn banner_url(user_id: UserId, hash: Option<&String>) -> Option<String> {
     })
 }
 
+#[cfg(feature = "model")]
+fn avatar_decoration_url(data: Option<&AvatarDecorationData>) -> Option<String> {
+    data.map(|data| cdn!("/avatar-decoration-presets/{}.png", data.asset))
+}
+
 #[cfg(feature = "model")]
 fn tag(name: &str, discriminator: u16) -> String {
     // 32: max length of username
@@ -1381,6 +1561,8 @@ mod test {
 
     #[cfg(feature = "model")]
     mod model {
+        use crate::model::id::UserId;
+        use crate::model::misc::ImageFormat;
         use crate::model::user::User;
 
         #[test]
@@ -1397,8 +1579,13 @@ mod test {
                 .unwrap()
                 .ends_with("/avatars/210/a_aaa.webp?size=1024"));
 
+            let url = user.avatar_url_with(Some(ImageFormat::Png), Some(128)).unwrap().unwrap();
+            assert!(url.ends_with("/avatars/210/a_aaa.png?size=128"));
+            assert!(user.avatar_url_with(None, Some(1000)).is_err());
+
             user.avatar = None;
             assert!(user.avatar_url().is_none());
+            assert!(user.avatar_url_with(None, None).unwrap().is_none());
 
             assert_eq!(user.tag(), "test#1432");
         }
@@ -1420,5 +1607,44 @@ mod test {
             user.discriminator = 4;
             assert!(user.default_avatar_url().ends_with("4.png"));
         }
+
+        #[test]
+        fn new_username_system() {
+            let mut user = User {
+                id: UserId(6 << 22),
+                discriminator: 0,
+                ..Default::default()
+            };
+
+            assert_eq!(user.display_name(), "test");
+            assert!(user.default_avatar_url().ends_with("/embed/avatars/0.png"));
+
+            user.id = UserId(5 << 22);
+            assert!(user.default_avatar_url().ends_with("/embed/avatars/5.png"));
+
+            user.global_name = Some("Test".to_string());
+            assert_eq!(user.display_name(), "Test");
+        }
+
+        #[test]
+        fn banner_and_decoration_urls() {
+            let mut user: User = crate::json::from_value(crate::json::json!({
+                "id": "210",
+                "username": "test",
+                "discriminator": "0",
+                "avatar": null,
+                "banner": "a_banner",
+                "avatar_decoration_data": {"asset": "a_decoration", "sku_id": "1"},
+            }))
+            .unwrap();
+
+            assert!(user.banner_url().unwrap().ends_with("/banners/210/a_banner.gif?size=1024"));
+            user.banner = Some("banner".to_string());
+            assert!(user.banner_url().unwrap().ends_with("/banners/210/banner.webp?size=1024"));
+            assert!(user
+                .avatar_decoration_url()
+                .unwrap()
+                .ends_with("/avatar-decoration-presets/a_decoration.png"));
+        }
     }
 }
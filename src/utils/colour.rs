@@ -1,6 +1,12 @@
 // Disable this lint to avoid it wanting to change `0xABCDEF` to `0xAB_CDEF`.
 #![allow(clippy::unreadable_literal)]
 
+use std::convert::TryFrom;
+use std::fmt;
+
+use serde::de::{Error, Unexpected, Visitor};
+use serde::{Deserialize, Deserializer};
+
 /// A utility struct to help with working with the basic representation of a
 /// colour. This is particularly useful when working with a [`Role`]'s colour,
 /// as the API works with an integer value instead of an RGB value.
@@ -67,8 +73,12 @@
 /// assert!(blitz_blue > fooyoo);
 /// ```
 ///
+/// Colours are serialized as integers, as expected by the API, and can be
+/// deserialized from either an integer or a hex string in the form of `#RRGGBB`
+/// or `RRGGBB`. Use [`colour_hex`] to serialize them as hex strings instead.
+///
 /// [`Role`]: crate::model::guild::Role
-#[derive(Clone, Copy, Debug, Deserialize, Eq, Ord, PartialEq, PartialOrd, Serialize)]
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct Colour(pub u32);
 
 impl Colour {
@@ -201,6 +211,66 @@ impl Colour {
     pub fn hex(self) -> String {
         format!("{:06X}", self.0)
     }
+
+    /// Parses a Colour from a hexadecimal string, in the form of either
+    /// `#RRGGBB` or `RRGGBB`.
+    ///
+    /// Returns [`None`] if the string is not 6 hexadecimal digits, optionally
+    /// prefixed by `#`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::Colour;
+    ///
+    /// assert_eq!(Colour::from_hex("#644C43"), Some(Colour::new(6573123)));
+    /// assert_eq!(Colour::from_hex("644c43"), Some(Colour::new(6573123)));
+    /// assert_eq!(Colour::from_hex("#644C4"), None);
+    /// ```
+    #[must_use]
+    pub fn from_hex(hex: &str) -> Option<Colour> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+
+        if hex.len() != 6 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+
+        u32::from_str_radix(hex, 16).ok().map(Colour)
+    }
+
+    /// Linearly interpolates between this Colour and `other`, for each of the
+    /// red, green and blue components.
+    ///
+    /// A `t` of `0.0` returns this Colour, and a `t` of `1.0` returns `other`.
+    /// Values outside of that range are clamped. This is useful to build
+    /// gradients, e.g. to colour embeds by progress.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::Colour;
+    ///
+    /// let red = Colour::from_rgb(255, 0, 0);
+    /// let blue = Colour::from_rgb(0, 0, 255);
+    ///
+    /// assert_eq!(red.lerp(blue, 0.5).tuple(), (128, 0, 128));
+    /// assert_eq!(red.lerp(blue, 2.0), blue);
+    /// ```
+    #[must_use]
+    pub fn lerp(self, other: Colour, t: f32) -> Colour {
+        let t = t.clamp(0.0, 1.0);
+        let lerp = |from: u8, to: u8| {
+            let (from, to) = (f32::from(from), f32::from(to));
+
+            (from + (to - from) * t).round() as u8
+        };
+
+        Colour::from_rgb(
+            lerp(self.r(), other.r()),
+            lerp(self.g(), other.g()),
+            lerp(self.b(), other.b()),
+        )
+    }
 }
 
 impl From<i32> for Colour {
@@ -322,6 +392,90 @@ impl Colour {
     pub const TEAL: Colour = Colour(0x1ABC9C);
 }
 
+impl<'de> Deserialize<'de> for Colour {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(ColourVisitor)
+        } else {
+            // Formats which are not self-describing, such as bincode, need to be told what to
+            // expect, which is the newtype struct `Colour` is serialized as.
+            deserializer.deserialize_newtype_struct("Colour", ColourVisitor)
+        }
+    }
+}
+
+struct ColourVisitor;
+
+impl<'de> Visitor<'de> for ColourVisitor {
+    type Value = Colour;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("integer or hex string colour")
+    }
+
+    fn visit_i64<E: Error>(self, value: i64) -> Result<Self::Value, E> {
+        u32::try_from(value).map(Colour).map_err(Error::custom)
+    }
+
+    fn visit_u64<E: Error>(self, value: u64) -> Result<Self::Value, E> {
+        u32::try_from(value).map(Colour).map_err(Error::custom)
+    }
+
+    fn visit_str<E: Error>(self, value: &str) -> Result<Self::Value, E> {
+        Colour::from_hex(value).ok_or_else(|| Error::invalid_value(Unexpected::Str(value), &self))
+    }
+
+    fn visit_newtype_struct<D: Deserializer<'de>>(
+        self,
+        deserializer: D,
+    ) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_u32(self)
+    }
+}
+
+/// Used with `#[serde(with = "colour_hex")]` to serialize a [`Colour`] as a
+/// hex string in the form of `#RRGGBB`.
+///
+/// Deserialization of human-readable formats accepts both integers and hex
+/// strings, as for [`Colour`] itself.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde::{Deserialize, Serialize};
+/// use serenity::utils::Colour;
+///
+/// #[derive(Deserialize, Serialize)]
+/// struct Config {
+///     #[serde(with = "serenity::utils::colour_hex")]
+///     accent: Colour,
+/// }
+///
+/// let config = Config {
+///     accent: Colour::new(0x644C43),
+/// };
+///
+/// assert_eq!(serde_json::to_string(&config).unwrap(), r##"{"accent":"#644C43"}"##);
+/// ```
+pub mod colour_hex {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    use super::{Colour, ColourVisitor};
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Colour, D::Error> {
+        if deserializer.is_human_readable() {
+            Colour::deserialize(deserializer)
+        } else {
+            deserializer.deserialize_str(ColourVisitor)
+        }
+    }
+
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub fn serialize<S: Serializer>(colour: &Colour, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("#{}", colour.hex()))
+    }
+}
+
 impl Default for Colour {
     /// Creates a default value for a [`Colour`], setting the inner value to `0`.
     fn default() -> Colour {
@@ -405,6 +559,23 @@ pub mod colours {
         /// Creates a new [`Colour`], setting its value to `rgb(84, 110, 122)`.
         pub const DARKER_GREY: Colour = Colour(0x546E7A);
     }
+    /// Colours commonly used to indicate the rarity of an item.
+    pub mod rarity {
+        use crate::utils::Colour;
+
+        /// Creates a new [`Colour`], setting its value to `rgb(157, 157, 157)`.
+        pub const COMMON: Colour = Colour(0x9D9D9D);
+        /// Creates a new [`Colour`], setting its value to `rgb(30, 255, 0)`.
+        pub const UNCOMMON: Colour = Colour(0x1EFF00);
+        /// Creates a new [`Colour`], setting its value to `rgb(0, 112, 221)`.
+        pub const RARE: Colour = Colour(0x0070DD);
+        /// Creates a new [`Colour`], setting its value to `rgb(163, 53, 238)`.
+        pub const EPIC: Colour = Colour(0xA335EE);
+        /// Creates a new [`Colour`], setting its value to `rgb(255, 128, 0)`.
+        pub const LEGENDARY: Colour = Colour(0xFF8000);
+        /// Creates a new [`Colour`], setting its value to `rgb(230, 204, 128)`.
+        pub const ARTIFACT: Colour = Colour(0xE6CC80);
+    }
 }
 
 #[cfg(test)]
@@ -452,10 +623,151 @@ mod test {
         assert_eq!(Colour::default().0, 0);
     }
 
+    #[test]
+    fn from_hex() {
+        assert_eq!(Colour::from_hex("#336123"), Some(Colour(0x336123)));
+        assert_eq!(Colour::from_hex("336123"), Some(Colour(0x336123)));
+        assert_eq!(Colour::from_hex("abcDEF"), Some(Colour(0xABCDEF)));
+        assert_eq!(Colour::from_hex("#33612"), None);
+        assert_eq!(Colour::from_hex("##336123"), None);
+        assert_eq!(Colour::from_hex("+33612"), None);
+        assert_eq!(Colour::from_hex("33612G"), None);
+    }
+
+    #[test]
+    fn lerp() {
+        let black = Colour::new(0x000000);
+        let white = Colour::new(0xFFFFFF);
+
+        assert_eq!(black.lerp(white, 0.0), black);
+        assert_eq!(black.lerp(white, 1.0), white);
+        assert_eq!(black.lerp(white, 0.5), Colour::new(0x808080));
+        assert_eq!(black.lerp(white, -1.0), black);
+    }
+
     #[test]
     fn from() {
         assert_eq!(Colour::from(7i32).0, 7);
         assert_eq!(Colour::from(7u32).0, 7);
         assert_eq!(Colour::from(7u64).0, 7);
     }
+
+    #[test]
+    fn serde() {
+        use serde::{Deserialize, Serialize};
+        use serde_test::{
+            assert_de_tokens,
+            assert_de_tokens_error,
+            assert_ser_tokens,
+            assert_tokens,
+            Configure,
+            Readable,
+            Token,
+        };
+
+        use super::colour_hex;
+
+        #[derive(Clone, Copy, Debug, PartialEq, Deserialize, Serialize)]
+        struct S {
+            #[serde(with = "colour_hex")]
+            colour: Colour,
+        }
+
+        let colour = Colour::new(0x336123);
+        assert_ser_tokens(&colour, &[
+            Token::NewtypeStruct {
+                name: "Colour",
+            },
+            Token::U32(0x336123),
+        ]);
+        assert_de_tokens(&colour.readable(), &[Token::U32(0x336123)]);
+        assert_de_tokens(&colour.readable(), &[Token::Str("#336123")]);
+        assert_de_tokens(&colour.readable(), &[Token::Str("336123")]);
+        assert_de_tokens_error::<Readable<Colour>>(
+            &[Token::Str("#33612")],
+            "invalid value: string \"#33612\", expected integer or hex string colour",
+        );
+
+        let s = S {
+            colour,
+        };
+        assert_tokens(&s.readable(), &[
+            Token::Struct {
+                name: "S",
+                len: 1,
+            },
+            Token::Str("colour"),
+            Token::Str("#336123"),
+            Token::StructEnd,
+        ]);
+        assert_de_tokens(&s.readable(), &[
+            Token::Struct {
+                name: "S",
+                len: 1,
+            },
+            Token::Str("colour"),
+            Token::U32(0x336123),
+            Token::StructEnd,
+        ]);
+        assert_tokens(&s.compact(), &[
+            Token::Struct {
+                name: "S",
+                len: 1,
+            },
+            Token::Str("colour"),
+            Token::Str("#336123"),
+            Token::StructEnd,
+        ]);
+    }
+
+    #[test]
+    fn serde_non_self_describing() {
+        use serde::de::value::Error;
+        use serde::de::{Deserialize, Deserializer, Error as _, Visitor};
+        use serde::forward_to_deserialize_any;
+        use serde_test::{assert_tokens, Configure, Token};
+
+        /// Deserializes a single `u32` which, like bincode, can only be read when the expected
+        /// type is known.
+        struct U32Deserializer(u32);
+
+        impl<'de> Deserializer<'de> for U32Deserializer {
+            type Error = Error;
+
+            fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Error> {
+                Err(Error::custom("the format is not self-describing"))
+            }
+
+            fn deserialize_u32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                visitor.visit_u32(self.0)
+            }
+
+            fn deserialize_newtype_struct<V: Visitor<'de>>(
+                self,
+                _: &'static str,
+                visitor: V,
+            ) -> Result<V::Value, Error> {
+                visitor.visit_newtype_struct(self)
+            }
+
+            fn is_human_readable(&self) -> bool {
+                false
+            }
+
+            forward_to_deserialize_any! {
+                bool i8 i16 i32 i64 i128 u8 u16 u64 u128 f32 f64 char str string bytes byte_buf
+                option unit unit_struct seq tuple tuple_struct map struct enum identifier
+                ignored_any
+            }
+        }
+
+        let colour = Colour::new(0x336123);
+        assert_tokens(&colour.compact(), &[
+            Token::NewtypeStruct {
+                name: "Colour",
+            },
+            Token::U32(0x336123),
+        ]);
+        assert_eq!(Colour::deserialize(U32Deserializer(0x336123)), Ok(colour));
+    }
 }
@@ -289,9 +289,11 @@ mod tests {
             bot: false,
             discriminator: 0000,
             name: "Crab".to_string(),
+            global_name: None,
             public_flags: None,
             banner: None,
             accent_colour: None,
+            avatar_decoration_data: None,
             member: None,
         };
 
@@ -301,9 +303,11 @@ mod tests {
             bot: false,
             discriminator: 0000,
             name: "Boat".to_string(),
+            global_name: None,
             public_flags: None,
             banner: None,
             accent_colour: None,
+            avatar_decoration_data: None,
             member: None,
         };
 
@@ -333,6 +337,8 @@ mod tests {
             system_channel_flags: SystemChannelFlags::default(),
             rules_channel_id: None,
             public_updates_channel_id: None,
+            safety_alerts_channel_id: None,
+            premium_progress_bar_enabled: false,
             verification_level: VerificationLevel::None,
             voice_states: HashMap::new(),
             description: None,
@@ -0,0 +1,181 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use crate::model::id::{ChannelId, GuildId, UserId};
+
+/// The scope a cooldown applies to, for use as the key of a
+/// [`CooldownManager`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[non_exhaustive]
+pub enum CooldownScope {
+    /// A single cooldown shared by everyone.
+    Global,
+    /// A cooldown per user.
+    User(UserId),
+    /// A cooldown per channel.
+    Channel(ChannelId),
+    /// A cooldown per guild.
+    Guild(GuildId),
+}
+
+/// Limits how often an action may be taken per key, such as a command per
+/// [user] or per [guild], independently of any framework.
+///
+/// Each key may consume `rate` uses within a window of `per`, starting with its
+/// first use. Once the window has elapsed, the key's uses are restored.
+///
+/// The manager is `Send + Sync`, so it can be shared between event and
+/// interaction handlers, e.g. by storing it in the client's data.
+///
+/// # Examples
+///
+/// Allow a command to be used twice every 10 seconds per user:
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use serenity::model::id::UserId;
+/// use serenity::utils::{CooldownManager, CooldownScope};
+///
+/// let cooldowns = CooldownManager::new(2, Duration::from_secs(10));
+/// let key = CooldownScope::User(UserId(1));
+///
+/// assert!(cooldowns.check_and_consume(key).is_ok());
+/// assert!(cooldowns.check_and_consume(key).is_ok());
+///
+/// if let Err(remaining) = cooldowns.check_and_consume(key) {
+///     println!("Try again in {} seconds", remaining.as_secs());
+/// }
+/// ```
+///
+/// [user]: CooldownScope::User
+/// [guild]: CooldownScope::Guild
+#[derive(Debug)]
+pub struct CooldownManager<K = CooldownScope> {
+    rate: u32,
+    per: Duration,
+    windows: Mutex<HashMap<K, Window>>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Window {
+    start: Instant,
+    uses: u32,
+}
+
+impl<K: Eq + Hash> CooldownManager<K> {
+    /// Creates a manager allowing `rate` uses per key every `per`.
+    ///
+    /// A `rate` of 0 is treated as 1.
+    #[must_use]
+    pub fn new(rate: u32, per: Duration) -> Self {
+        Self {
+            rate: rate.max(1),
+            per,
+            windows: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Consumes a use for the given key, if it has any left in its current
+    /// window.
+    ///
+    /// # Errors
+    ///
+    /// Returns the time remaining until the key's window elapses if it has no
+    /// uses left, in which case no use is consumed.
+    pub fn check_and_consume(&self, key: K) -> Result<(), Duration> {
+        self.check_and_consume_at(key, Instant::now())
+    }
+
+    fn check_and_consume_at(&self, key: K, now: Instant) -> Result<(), Duration> {
+        let mut windows = self.windows.lock().unwrap_or_else(PoisonError::into_inner);
+        let window = windows.entry(key).or_insert(Window {
+            start: now,
+            uses: 0,
+        });
+
+        let elapsed = now.saturating_duration_since(window.start);
+        if elapsed >= self.per {
+            window.start = now;
+            window.uses = 0;
+        } else if window.uses >= self.rate {
+            return Err(self.per - elapsed);
+        }
+
+        window.uses += 1;
+
+        Ok(())
+    }
+
+    /// Restores all uses of the given key.
+    pub fn reset(&self, key: &K) {
+        self.windows.lock().unwrap_or_else(PoisonError::into_inner).remove(key);
+    }
+
+    /// Removes the keys whose windows have elapsed, to free their memory.
+    ///
+    /// This does not change the result of [`Self::check_and_consume`] for any
+    /// key, and may be called periodically by bots tracking many keys.
+    pub fn clear_expired(&self) {
+        let now = Instant::now();
+        let mut windows = self.windows.lock().unwrap_or_else(PoisonError::into_inner);
+
+        windows.retain(|_, window| now.saturating_duration_since(window.start) < self.per);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::{Duration, Instant};
+
+    use super::{CooldownManager, CooldownScope};
+    use crate::model::id::UserId;
+
+    #[test]
+    fn window_expiry() {
+        let cooldowns = CooldownManager::new(2, Duration::from_secs(10));
+        let key = CooldownScope::User(UserId(1));
+        let start = Instant::now();
+
+        assert!(cooldowns.check_and_consume_at(key, start).is_ok());
+        assert!(cooldowns.check_and_consume_at(key, start + Duration::from_secs(1)).is_ok());
+        assert_eq!(
+            cooldowns.check_and_consume_at(key, start + Duration::from_secs(4)),
+            Err(Duration::from_secs(6))
+        );
+
+        // Other keys have their own windows.
+        assert!(cooldowns.check_and_consume_at(CooldownScope::Global, start).is_ok());
+
+        // Once the window elapsed, the uses are restored.
+        let later = start + Duration::from_secs(10);
+        assert!(cooldowns.check_and_consume_at(key, later).is_ok());
+        assert!(cooldowns.check_and_consume_at(key, later).is_ok());
+        assert!(cooldowns.check_and_consume_at(key, later).is_err());
+
+        cooldowns.reset(&key);
+        assert!(cooldowns.check_and_consume_at(key, later).is_ok());
+    }
+
+    #[test]
+    fn concurrent_access() {
+        let cooldowns = Arc::new(CooldownManager::new(20, Duration::from_secs(60)));
+
+        let handles = (0..8)
+            .map(|_| {
+                let cooldowns = Arc::clone(&cooldowns);
+
+                thread::spawn(move || {
+                    (0..10).filter(|_| cooldowns.check_and_consume(0_u64).is_ok()).count()
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let consumed: usize = handles.into_iter().map(|h| h.join().expect("thread panicked")).sum();
+        assert_eq!(consumed, 20);
+    }
+}
@@ -248,9 +248,11 @@ fn dummy_message() -> Message {
             bot: false,
             discriminator: 0x0000,
             name: String::new(),
+            global_name: None,
             public_flags: None,
             banner: None,
             accent_colour: None,
+            avatar_decoration_data: None,
             member: None,
         },
         channel_id: ChannelId::default(),
@@ -273,6 +275,7 @@ fn dummy_message() -> Message {
         activity: None,
         application: None,
         message_reference: None,
+        message_snapshots: Vec::new(),
         flags: None,
         sticker_items: Vec::new(),
         referenced_message: None,
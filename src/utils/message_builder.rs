@@ -346,9 +346,47 @@ impl MessageBuilder {
         self
     }
 
-    /// Pushes a quoted inline text to the content
+    /// Pushes a quoted inline text to the content.
+    ///
+    /// Each line of the text is quoted.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push_quote("hello\nworld").build();
+    ///
+    /// assert_eq!(content, "> hello\n> world");
+    /// ```
     pub fn push_quote(&mut self, content: impl Into<Content>) -> &mut Self {
         self.0.push_str("> ");
+        self.0.push_str(&content.into().to_string().replace('\n', "\n> "));
+
+        self
+    }
+
+    /// Pushes a block quote to the content, quoting the text and everything
+    /// pushed after it.
+    ///
+    /// A newline is pushed first if the content does not already end with one,
+    /// as block quotes must start on their own line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serenity::utils::MessageBuilder;
+    ///
+    /// let content = MessageBuilder::new().push("Rules:").push_block_quote("1. Be nice").build();
+    ///
+    /// assert_eq!(content, "Rules:\n>>> 1. Be nice");
+    /// ```
+    pub fn push_block_quote(&mut self, content: impl Into<Content>) -> &mut Self {
+        if !self.0.is_empty() && !self.0.ends_with('\n') {
+            self.0.push('\n');
+        }
+
+        self.0.push_str(">>> ");
         self.0.push_str(&content.into().to_string());
 
         self
@@ -630,8 +668,29 @@ impl MessageBuilder {
     }
 
     /// Pushes a quoted inline text to the content normalizing content.
+    ///
+    /// Each line of the text is quoted.
     pub fn push_quote_safe(&mut self, content: impl Into<Content>) -> &mut Self {
         self.0.push_str("> ");
+        {
+            let mut c = content.into();
+            c.inner = normalize(&c.inner).replace("> ", " ");
+            self.0.push_str(&c.to_string().replace('\n', "\n> "));
+        }
+
+        self
+    }
+
+    /// Pushes a block quote to the content normalizing content, quoting the
+    /// text and everything pushed after it.
+    ///
+    /// Refer to [`Self::push_block_quote`] for more information.
+    pub fn push_block_quote_safe(&mut self, content: impl Into<Content>) -> &mut Self {
+        if !self.0.is_empty() && !self.0.ends_with('\n') {
+            self.0.push('\n');
+        }
+
+        self.0.push_str(">>> ");
         {
             let mut c = content.into();
             c.inner = normalize(&c.inner).replace("> ", " ");
@@ -1311,6 +1370,35 @@ mod test {
         assert_eq!(MessageBuilder::new().push_codeblock_safe("```.```", None).0, "```\n . \n```",);
     }
 
+    #[test]
+    fn quotes() {
+        gen! {
+            push_quote => [
+                "foo" => "> foo",
+                "foo\nbar" => "> foo\n> bar",
+                "foo\n\nbar" => "> foo\n> \n> bar"
+            ],
+            push_quote_safe => [
+                "foo\nbar" => "> foo\n> bar",
+                "foo\n> @everyone" => "> foo\n>  @\u{200B}everyone"
+            ],
+            push_block_quote => [
+                "foo\nbar" => ">>> foo\nbar"
+            ],
+            push_block_quote_safe => [
+                "foo\n>>> bar" => ">>> foo\n>> bar"
+            ]
+        };
+
+        let content = MessageBuilder::new()
+            .push_line("Quote:")
+            .push_quote_line("foo\nbar")
+            .push("baz")
+            .push_block_quote(Bold + "rest")
+            .build();
+        assert_eq!(content, "Quote:\n> foo\n> bar\nbaz\n>>> **rest**");
+    }
+
     #[test]
     fn push_safe() {
         gen! {
@@ -7,6 +7,7 @@ pub(crate) mod backports;
 mod colour;
 #[cfg(feature = "cache")]
 mod content_safe;
+mod cooldown;
 mod custom_message;
 mod message_builder;
 
@@ -18,7 +19,8 @@ pub use argument_convert::*;
 pub use content_safe::*;
 use url::Url;
 
-pub use self::colour::{colours, Colour};
+pub use self::colour::{colour_hex, colours, Colour};
+pub use self::cooldown::{CooldownManager, CooldownScope};
 pub use self::custom_message::CustomMessage;
 pub use self::message_builder::{Content, ContentModifier, EmbedMessageBuilding, MessageBuilder};
 #[doc(inline)]
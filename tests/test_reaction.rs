@@ -82,6 +82,58 @@ fn str_fromstr() {
     ReactionType::from_str(emoji_str).unwrap_err();
 }
 
+#[test]
+fn str_to_reaction_type_forms() {
+    let custom = |animated, name: Option<&str>| ReactionType::Custom {
+        animated,
+        id: EmojiId(600404340292059257),
+        name: name.map(ToString::to_string),
+    };
+
+    for (input, expected) in [
+        ("👍", ReactionType::Unicode("👍".to_string())),
+        (" 🍎 ", ReactionType::Unicode("🍎".to_string())),
+        ("customemoji:600404340292059257", custom(false, Some("customemoji"))),
+        ("a:customemoji:600404340292059257", custom(true, Some("customemoji"))),
+        ("600404340292059257", custom(false, None)),
+    ] {
+        assert_eq!(ReactionType::from_str(input).unwrap(), expected, "{}", input);
+    }
+}
+
+#[test]
+fn str_to_reaction_type_invalid_id_or_marker() {
+    for emoji_str in ["<:customemoji:abc>", "<x:customemoji:1234>", "x:customemoji:1234"] {
+        ReactionType::from_str(emoji_str).unwrap_err();
+    }
+}
+
+#[test]
+fn reaction_type_display_round_trip() {
+    let reactions = [
+        ReactionType::Unicode("👍".to_string()),
+        ReactionType::Custom {
+            animated: false,
+            id: EmojiId(600404340292059257),
+            name: Some("customemoji".to_string()),
+        },
+        ReactionType::Custom {
+            animated: true,
+            id: EmojiId(600404340292059257),
+            name: Some("customemoji".to_string()),
+        },
+        ReactionType::Custom {
+            animated: false,
+            id: EmojiId(600404340292059257),
+            name: None,
+        },
+    ];
+
+    for reaction in reactions {
+        assert_eq!(ReactionType::from_str(&reaction.to_string()).unwrap(), reaction);
+    }
+}
+
 #[test]
 fn json_to_reaction_type() {
     let s = r#"{"name": "foo", "id": "1"}"#;